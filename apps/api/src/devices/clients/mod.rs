@@ -2,6 +2,7 @@ mod address_names;
 mod fiat;
 mod notifications;
 mod portfolio;
+mod recurring_swaps;
 mod rewards;
 mod rewards_redemption;
 mod scan;
@@ -12,6 +13,7 @@ pub use address_names::AddressNamesClient;
 pub use fiat::FiatQuotesClient;
 pub use notifications::NotificationsClient;
 pub use portfolio::PortfolioClient;
+pub use recurring_swaps::RecurringSwapsClient;
 pub use rewards::RewardsClient;
 pub use rewards_redemption::RewardsRedemptionClient;
 pub use scan::{ScanClient, ScanProviderFactory};
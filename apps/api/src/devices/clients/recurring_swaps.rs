@@ -0,0 +1,37 @@
+use primitives::{RecurringSwap, RecurringSwapExecution, RecurringSwapExecutionUpdate, RecurringSwapStatus};
+use std::error::Error;
+use storage::{Database, RecurringSwapsRepository};
+
+#[derive(Clone)]
+pub struct RecurringSwapsClient {
+    database: Database,
+}
+
+impl RecurringSwapsClient {
+    pub fn new(database: Database) -> Self {
+        Self { database }
+    }
+
+    pub fn get_recurring_swaps(&self, wallet_id: i32) -> Result<Vec<RecurringSwap>, Box<dyn Error + Send + Sync>> {
+        Ok(self.database.recurring_swaps()?.get_recurring_swaps(wallet_id)?)
+    }
+
+    pub fn add_recurring_swap(&self, device_id: &str, wallet_id: i32, recurring_swap: RecurringSwap) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        Ok(self.database.recurring_swaps()?.add_recurring_swap(device_id, wallet_id, recurring_swap)?)
+    }
+
+    pub fn cancel_recurring_swap(&self, wallet_id: i32, id: &str) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .database
+            .recurring_swaps()?
+            .update_recurring_swap_status(wallet_id, id, RecurringSwapStatus::Cancelled)?)
+    }
+
+    pub fn get_recurring_swap_executions(&self, wallet_id: i32, id: &str) -> Result<Vec<RecurringSwapExecution>, Box<dyn Error + Send + Sync>> {
+        Ok(self.database.recurring_swaps()?.get_recurring_swap_executions(wallet_id, id)?)
+    }
+
+    pub fn update_recurring_swap_execution(&self, wallet_id: i32, id: &str, update: RecurringSwapExecutionUpdate) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        Ok(self.database.recurring_swaps()?.update_recurring_swap_execution(wallet_id, id, update)?)
+    }
+}
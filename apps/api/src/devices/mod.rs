@@ -12,8 +12,8 @@ use auth_config::AuthConfig;
 pub use client::DevicesClient;
 pub(crate) use clients::WalletSubscriptionInput;
 pub use clients::{
-    AddressNamesClient, FiatQuotesClient, NotificationsClient, PortfolioClient, RewardsClient, RewardsRedemptionClient, ScanClient, ScanProviderFactory, TransactionsClient,
-    WalletsClient,
+    AddressNamesClient, FiatQuotesClient, NotificationsClient, PortfolioClient, RecurringSwapsClient, RewardsClient, RewardsRedemptionClient, ScanClient, ScanProviderFactory,
+    TransactionsClient, WalletsClient,
 };
 use gem_auth::AuthClient;
 use guard::{AuthenticatedDevice, AuthenticatedDeviceWallet, VerifiedDeviceId};
@@ -25,7 +25,8 @@ use primitives::name::NameRecord;
 use primitives::rewards::{RedemptionRequest, RedemptionResult, RewardRedemptionOption};
 use primitives::{
     AddressName, AssetId, AuthNonce, ChainAddress, FiatAssets, FiatQuote, FiatQuoteRequest, FiatQuoteType, FiatQuoteUrl, FiatQuotes, InAppNotification, NFTData, PortfolioAssets,
-    PortfolioAssetsRequest, PriceAlerts, ReportNft, RewardEvent, Rewards, ScanTransaction, ScanTransactionPayload, Transaction, TransactionsResponse, WalletSubscriptionChains,
+    PortfolioAssetsRequest, PriceAlerts, RecurringSwap, RecurringSwapExecution, RecurringSwapExecutionUpdate, ReportNft, RewardEvent, Rewards, ScanTransaction,
+    ScanTransactionPayload, Transaction, TransactionsResponse, WalletSubscriptionChains,
 };
 use rocket::{State, delete, get, post, put, serde::json::Json, tokio::sync::Mutex};
 use std::sync::Arc;
@@ -306,6 +307,48 @@ pub async fn delete_device_price_alerts_v2(
     Ok(client.lock().await.delete_price_alerts(&device.device_row.device_id, price_alerts.0).await?.into())
 }
 
+#[get("/devices/recurring_swaps")]
+pub async fn get_device_recurring_swaps_v2(device: AuthenticatedDeviceWallet, client: &State<Mutex<RecurringSwapsClient>>) -> Result<ApiResponse<Vec<RecurringSwap>>, ApiError> {
+    Ok(client.lock().await.get_recurring_swaps(device.wallet_id)?.into())
+}
+
+#[post("/devices/recurring_swaps", format = "json", data = "<recurring_swap>")]
+pub async fn add_device_recurring_swap_v2(
+    device: AuthenticatedDeviceWallet,
+    recurring_swap: Json<RecurringSwap>,
+    client: &State<Mutex<RecurringSwapsClient>>,
+) -> Result<ApiResponse<usize>, ApiError> {
+    Ok(client
+        .lock()
+        .await
+        .add_recurring_swap(&device.device_row.device_id, device.wallet_id, recurring_swap.0)?
+        .into())
+}
+
+#[post("/devices/recurring_swaps/<id>/cancel")]
+pub async fn cancel_device_recurring_swap_v2(device: AuthenticatedDeviceWallet, id: &str, client: &State<Mutex<RecurringSwapsClient>>) -> Result<ApiResponse<usize>, ApiError> {
+    Ok(client.lock().await.cancel_recurring_swap(device.wallet_id, id)?.into())
+}
+
+#[get("/devices/recurring_swaps/<id>/executions")]
+pub async fn get_device_recurring_swap_executions_v2(
+    device: AuthenticatedDeviceWallet,
+    id: &str,
+    client: &State<Mutex<RecurringSwapsClient>>,
+) -> Result<ApiResponse<Vec<RecurringSwapExecution>>, ApiError> {
+    Ok(client.lock().await.get_recurring_swap_executions(device.wallet_id, id)?.into())
+}
+
+#[post("/devices/recurring_swaps/<id>/executions", format = "json", data = "<update>")]
+pub async fn update_device_recurring_swap_execution_v2(
+    device: AuthenticatedDeviceWallet,
+    id: &str,
+    update: Json<RecurringSwapExecutionUpdate>,
+    client: &State<Mutex<RecurringSwapsClient>>,
+) -> Result<ApiResponse<usize>, ApiError> {
+    Ok(client.lock().await.update_recurring_swap_execution(device.wallet_id, id, update.0)?.into())
+}
+
 #[get("/devices/fiat/transactions")]
 pub async fn get_device_fiat_transactions_v2(
     device: AuthenticatedDeviceWallet,
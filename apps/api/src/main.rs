@@ -34,8 +34,8 @@ use cacher::CacherClient;
 use config::ConfigClient;
 use devices::DevicesClient;
 use devices::{
-    AddressNamesClient, FiatQuotesClient, NotificationsClient, PortfolioClient, RewardsClient, RewardsRedemptionClient, ScanClient, ScanProviderFactory, TransactionsClient,
-    WalletsClient,
+    AddressNamesClient, FiatQuotesClient, NotificationsClient, PortfolioClient, RecurringSwapsClient, RewardsClient, RewardsRedemptionClient, ScanClient, ScanProviderFactory,
+    TransactionsClient, WalletsClient,
 };
 use gem_auth::AuthClient;
 use gem_rewards::{AbuseIPDBClient, IpApiClient, IpCheckProvider, IpSecurityClient};
@@ -135,6 +135,11 @@ fn mount_routes(rocket: Rocket<Build>, admin_enabled: bool) -> Rocket<Build> {
                 devices::get_device_price_alerts_v2,
                 devices::add_device_price_alerts_v2,
                 devices::delete_device_price_alerts_v2,
+                devices::get_device_recurring_swaps_v2,
+                devices::add_device_recurring_swap_v2,
+                devices::cancel_device_recurring_swap_v2,
+                devices::get_device_recurring_swap_executions_v2,
+                devices::update_device_recurring_swap_execution_v2,
                 devices::get_auth_nonce_v2,
                 devices::get_device_token_v2,
                 devices::get_device_portfolio_assets_v2,
@@ -222,6 +227,7 @@ async fn rocket_api(settings: Settings) -> Result<Rocket<Build>, Box<dyn std::er
     let rewards_client = RewardsClient::new(database.clone(), stream_producer.clone(), ip_security_client, pusher_client.clone());
     let redemption_client = RewardsRedemptionClient::new(database.clone(), stream_producer.clone());
     let notifications_client = NotificationsClient::new(database.clone());
+    let recurring_swaps_client = RecurringSwapsClient::new(database.clone());
     let near_intents_client = swap::NearIntentsProxyClient::new(cacher_client.clone());
     let jwt_config = devices::auth_config::JwtConfig {
         secret: settings.api.auth.jwt.secret.clone(),
@@ -254,6 +260,7 @@ async fn rocket_api(settings: Settings) -> Result<Rocket<Build>, Box<dyn std::er
         .manage(Mutex::new(redemption_client))
         .manage(Mutex::new(wallets_client))
         .manage(Mutex::new(notifications_client))
+        .manage(Mutex::new(recurring_swaps_client))
         .manage(Mutex::new(near_intents_client))
         .manage(Mutex::new(portfolio_client))
         .manage(auth_client)
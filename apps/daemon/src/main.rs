@@ -23,8 +23,11 @@ use crate::worker::runtime::WorkerRuntime;
 use cacher::CacherClient;
 use gem_tracing::{error_with_fields, info_with_fields};
 use job_runner::{JobHandle, JobSchedule};
+use settings_chain::ProviderFactory;
 use std::sync::atomic::{AtomicBool, Ordering};
 use streamer::ConsumerStatusReporter;
+use swapper::NativeProvider;
+use swapper::swapper::GemSwapper;
 
 #[tokio::main]
 pub async fn main() {
@@ -83,7 +86,16 @@ async fn run_worker_services(settings: settings::Settings, services: &[WorkerSer
 
     let service_name = services.first().map(|s| s.as_ref()).unwrap_or("worker");
     let job_metrics = Arc::new(metrics::job::JobMetrics::new(service_name));
-    let composite = Arc::new(metrics::Metrics::new(vec![job_metrics.clone()]));
+    let swapper = services.contains(&WorkerService::Swaps).then(|| {
+        Arc::new(GemSwapper::new(Arc::new(NativeProvider::new_with_endpoints(ProviderFactory::get_chain_endpoints(
+            &settings,
+        )))))
+    });
+    let mut metrics_providers: Vec<Arc<dyn metrics::MetricsProvider>> = vec![job_metrics.clone()];
+    if let Some(swapper) = &swapper {
+        metrics_providers.push(Arc::new(metrics::swapper::SwapperMetrics::new(swapper.clone())));
+    }
+    let composite = Arc::new(metrics::Metrics::new(metrics_providers));
     let health_state = health::spawn_server(composite);
 
     let signal_handle = shutdown::spawn_signal_handler(shutdown_tx);
@@ -94,7 +106,7 @@ async fn run_worker_services(settings: settings::Settings, services: &[WorkerSer
         let reporter = Arc::new(JobReporter::new(job_metrics.clone()));
         let schedule: Arc<dyn JobSchedule> = tracker;
         let runtime = WorkerRuntime::new(reporter, schedule);
-        let context = WorkerContext::new(settings.clone(), database.clone(), runtime, options.job.clone());
+        let context = WorkerContext::new(settings.clone(), database.clone(), runtime, swapper.clone(), options.job.clone());
         let shutdown_rx = shutdown_rx.clone();
         async move {
             match svc.run_jobs(context, shutdown_rx).await {
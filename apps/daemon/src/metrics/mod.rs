@@ -1,6 +1,7 @@
 pub mod consumer;
 pub mod job;
 pub mod parser;
+pub mod swapper;
 
 use std::sync::Arc;
 use std::time::SystemTime;
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::registry::Registry;
+use swapper::ProviderHealthState;
+use swapper::swapper::GemSwapper;
+
+use super::MetricsProvider;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ProviderLabels {
+    provider: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ProviderErrorLabels {
+    provider: String,
+    error: String,
+}
+
+pub struct SwapperMetrics {
+    swapper: Arc<GemSwapper>,
+}
+
+impl SwapperMetrics {
+    pub fn new(swapper: Arc<GemSwapper>) -> Self {
+        Self { swapper }
+    }
+}
+
+impl MetricsProvider for SwapperMetrics {
+    fn register(&self, registry: &mut Registry) {
+        let requests = Family::<ProviderLabels, Gauge>::default();
+        let success_rate = Family::<ProviderLabels, Gauge<f64, AtomicU64>>::default();
+        let latency = Family::<ProviderLabels, Gauge>::default();
+        let unavailable = Family::<ProviderLabels, Gauge>::default();
+        let errors = Family::<ProviderErrorLabels, Gauge>::default();
+
+        for health in self.swapper.providers_health() {
+            let labels = ProviderLabels {
+                provider: health.provider.as_ref().to_string(),
+            };
+            requests.get_or_create(&labels).set(health.requests as i64);
+            success_rate.get_or_create(&labels).set(health.success_rate);
+            latency.get_or_create(&labels).set(health.average_latency_ms as i64);
+            unavailable.get_or_create(&labels).set((health.state == ProviderHealthState::Unavailable) as i64);
+            for error in health.errors {
+                let labels = ProviderErrorLabels {
+                    provider: labels.provider.clone(),
+                    error: error.error,
+                };
+                errors.get_or_create(&labels).set(error.count as i64);
+            }
+        }
+
+        registry.register("swapper_provider_requests", "Provider quote requests in the health window", requests);
+        registry.register("swapper_provider_success_rate", "Provider quote success rate in the health window", success_rate);
+        registry.register("swapper_provider_latency_milliseconds", "Average provider quote latency in milliseconds", latency);
+        registry.register("swapper_provider_unavailable", "Provider skipped by quote requests until its cooldown elapses", unavailable);
+        registry.register("swapper_provider_errors", "Provider quote errors by class in the health window", errors);
+    }
+}
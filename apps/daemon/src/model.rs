@@ -50,6 +50,7 @@ pub enum WorkerService {
     Rewards,
     Transactions,
     Perpetuals,
+    Swaps,
 }
 
 impl WorkerService {
@@ -1,13 +1,15 @@
 mod parser_options;
 mod parser_state;
 mod plan;
+mod reorg;
 
 pub use parser_options::ParserOptions;
 use parser_state::ParserStateService;
+use reorg::{BlockVerification, ReorgService};
 
 use std::{
     error::Error,
-    sync::Arc,
+    sync::{Arc, Once},
     time::{Duration, Instant},
 };
 
@@ -15,21 +17,27 @@ use crate::health::HealthState;
 use crate::metrics::parser::ParserMetrics;
 use crate::reporters::parser::ParserReporter;
 use chain_traits::ChainTraits;
-use gem_tracing::{DurationMs, error_with_fields, info_with_fields};
-use primitives::Chain;
+use futures::future::try_join_all;
+use gem_tracing::{DurationMs, error_fields, error_with_fields, info_with_fields};
+use primitives::{BlockHeader, Chain, Transaction};
 use settings::Settings;
 use std::str::FromStr;
 use streamer::{StreamProducer, StreamProducerConfig, StreamProducerQueue, TransactionsPayload};
 
 use crate::shutdown::{self, ShutdownReceiver};
 use plan::{BlockPlan, BlockPlanKind, plan_next_block, should_reload_catchup, timeout_for_state};
-use storage::{Database, models::ParserStateRow};
+use storage::{
+    Database,
+    models::{ParserBlockRow, ParserStateRow},
+};
 
 pub struct Parser {
     chain: Chain,
     provider: Box<dyn ChainTraits>,
     stream_producer: StreamProducer,
     state_service: ParserStateService,
+    reorg_service: ReorgService,
+    untracked_reorgs: Once,
     reporter: ParserReporter,
     options: ParserOptions,
     shutdown_rx: ShutdownReceiver,
@@ -45,6 +53,7 @@ impl Parser {
         shutdown_rx: ShutdownReceiver,
     ) -> Self {
         let chain = provider.get_chain();
+        let reorg_service = ReorgService::new(chain, database.clone(), options.reorg_depth);
         let state_service = ParserStateService::new(chain, database);
         let reporter = ParserReporter::new(chain, parser_metrics);
         Self {
@@ -52,6 +61,8 @@ impl Parser {
             provider,
             stream_producer,
             state_service,
+            reorg_service,
+            untracked_reorgs: Once::new(),
             reporter,
             options,
             shutdown_rx,
@@ -107,7 +118,25 @@ impl Parser {
             BlockPlanKind::Parse => {}
         }
 
-        match self.parse_blocks(plan.range.blocks).await {
+        let result = match self.verify_blocks(&plan.range.blocks).await {
+            Ok(BlockVerification::Untracked(transactions)) => {
+                self.untracked_reorgs
+                    .call_once(|| info_with_fields!("parser reorg detection unavailable, chain has no block headers", chain = self.chain.as_ref()));
+                self.publish_transactions(plan.range.blocks, transactions).await
+            }
+            Ok(BlockVerification::Linked(blocks)) => self.parse_blocks(plan.range.blocks, blocks).await,
+            Ok(BlockVerification::Reorg { fork_block, blocks }) => {
+                self.rollback(fork_block, &blocks).await?;
+                return Ok(false);
+            }
+            Err(err) => {
+                error_with_fields!("parser verify_blocks", &*err, chain = self.chain.as_ref(), blocks = blocks_desc);
+                self.sleep_or_shutdown(timeout).await;
+                return Ok(false);
+            }
+        };
+
+        match result {
             Ok(result) => {
                 let _ = self.state_service.set_current_block(plan.range.end_block);
 
@@ -202,8 +231,64 @@ impl Parser {
         Ok(())
     }
 
-    async fn parse_blocks(&self, blocks: Vec<u64>) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        let transactions = self.provider.get_transactions_in_blocks(blocks.clone()).await?;
+    async fn verify_blocks(&self, blocks: &[u64]) -> Result<BlockVerification, Box<dyn Error + Send + Sync>> {
+        let blocks = try_join_all(blocks.iter().map(|block| self.provider.get_block_transactions(*block))).await?;
+        let Some(headers) = reorg::block_headers(&blocks) else {
+            return Ok(BlockVerification::Untracked(blocks.into_iter().flat_map(|(_, transactions)| transactions).collect()));
+        };
+        if !reorg::is_linked(&headers) {
+            return Err("block headers are not linked".into());
+        }
+        let Some(first) = headers.first() else {
+            return Ok(BlockVerification::Linked(vec![]));
+        };
+        match self.reorg_service.get_block(first.number.saturating_sub(1))? {
+            Some(parent) if !reorg::is_parent(&parent, first) => {
+                let blocks = self.reorg_service.get_blocks()?;
+                let fork_block = self.find_fork_block(&blocks).await?;
+                Ok(BlockVerification::Reorg { fork_block, blocks })
+            }
+            _ => Ok(BlockVerification::Linked(
+                headers.into_iter().zip(blocks.into_iter().map(|(_, transactions)| transactions)).collect(),
+            )),
+        }
+    }
+
+    async fn find_fork_block(&self, blocks: &[ParserBlockRow]) -> Result<u64, Box<dyn Error + Send + Sync>> {
+        let numbers = blocks.iter().map(|block| block.block_number as u64).collect();
+        let headers = self.provider.get_block_headers(numbers).await?.ok_or("chain has no block headers")?;
+        if let Some(fork_block) = reorg::find_fork_block(blocks, &headers) {
+            return Ok(fork_block);
+        }
+        let fork_block = reorg::fallback_fork_block(blocks).ok_or("no stored blocks to rewind")?;
+        error_fields!("parser reorg deeper than tracked blocks", chain = self.chain.as_ref(), fork_block = fork_block);
+        Ok(fork_block)
+    }
+
+    async fn rollback(&self, fork_block: u64, blocks: &[ParserBlockRow]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transactions = self.reorg_service.revert_transactions(fork_block, blocks)?;
+        let reverted = transactions.len();
+        if !transactions.is_empty() {
+            self.stream_producer.publish_transactions(TransactionsPayload::new(self.chain, transactions)).await?;
+        }
+        self.state_service.set_current_block(fork_block as i64)?;
+        self.reorg_service.delete_blocks_after(fork_block)?;
+
+        info_with_fields!("parser reorg", chain = self.chain.as_ref(), fork_block = fork_block, reverted = reverted);
+        Ok(())
+    }
+
+    async fn parse_blocks(&self, numbers: Vec<u64>, blocks: Vec<(BlockHeader, Vec<Transaction>)>) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let (parsed_blocks, block_transactions): (Vec<_>, Vec<_>) = blocks
+            .into_iter()
+            .map(|(header, transactions)| ((header, transactions.iter().map(|x| x.hash.clone()).collect()), transactions))
+            .unzip();
+        let count = self.publish_transactions(numbers, block_transactions.into_iter().flatten().collect()).await?;
+        self.reorg_service.add_blocks(parsed_blocks)?;
+        Ok(count)
+    }
+
+    async fn publish_transactions(&self, blocks: Vec<u64>, transactions: Vec<Transaction>) -> Result<usize, Box<dyn Error + Send + Sync>> {
         if transactions.is_empty() {
             return Ok(0);
         }
@@ -223,6 +308,7 @@ pub async fn run(settings: Settings, chain: Option<Chain>, health_state: Arc<Hea
     let min_check = config.get_duration(primitives::ConfigKey::ParserMinCheckInterval)?;
     let max_check = config.get_duration(primitives::ConfigKey::ParserMaxCheckInterval)?;
     let error_interval = config.get_duration(primitives::ConfigKey::ParserErrorInterval)?;
+    let reorg_depth = config.get_i64(primitives::ConfigKey::ParserReorgDepth)?;
 
     let chains: Vec<Chain> = if let Some(chain) = chain {
         vec![chain]
@@ -262,6 +348,7 @@ pub async fn run(settings: Settings, chain: Option<Chain>, health_state: Arc<Hea
             min_check,
             max_check,
             error_interval,
+            reorg_depth,
         };
 
         handles.push(tokio::spawn(async move {
@@ -7,4 +7,5 @@ pub struct ParserOptions {
     pub min_check: Duration,
     pub max_check: Duration,
     pub error_interval: Duration,
+    pub reorg_depth: i64,
 }
@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use primitives::{BlockHeader, Chain, Transaction};
+use storage::models::ParserBlockRow;
+use storage::{Database, ParserBlocksRepository, TransactionState, TransactionsRepository};
+
+pub struct ReorgService {
+    chain: Chain,
+    database: Database,
+    depth: i64,
+}
+
+impl ReorgService {
+    pub fn new(chain: Chain, database: Database, depth: i64) -> Self {
+        Self { chain, database, depth }
+    }
+
+    pub fn get_block(&self, block_number: u64) -> Result<Option<BlockHeader>, Box<dyn Error + Send + Sync>> {
+        let block = self.database.parser_blocks()?.get_parser_block(self.chain, block_number as i64)?;
+        Ok(block.map(|x| x.as_primitive()))
+    }
+
+    pub fn get_blocks(&self) -> Result<Vec<ParserBlockRow>, Box<dyn Error + Send + Sync>> {
+        Ok(self.database.parser_blocks()?.get_parser_blocks(self.chain, self.depth)?)
+    }
+
+    pub fn add_blocks(&self, blocks: Vec<(BlockHeader, Vec<String>)>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let Some(latest_block) = blocks.iter().map(|(header, _)| header.number as i64).max() else {
+            return Ok(());
+        };
+        let rows = blocks
+            .into_iter()
+            .map(|(header, transaction_hashes)| ParserBlockRow::from_primitive(self.chain, header, transaction_hashes))
+            .collect();
+        let mut client = self.database.parser_blocks()?;
+        client.add_parser_blocks(rows)?;
+        client.delete_parser_blocks_before(self.chain, latest_block - self.depth)?;
+        Ok(())
+    }
+
+    pub fn revert_transactions(&self, fork_block: u64, blocks: &[ParserBlockRow]) -> Result<Vec<Transaction>, Box<dyn Error + Send + Sync>> {
+        let hashes = orphaned_transaction_hashes(blocks, fork_block);
+        let rows = self
+            .database
+            .transactions()?
+            .update_transactions_state(self.chain.as_ref(), hashes, TransactionState::Reverted)?;
+        Ok(rows.into_iter().map(|row| row.as_primitive(row.get_addresses())).collect())
+    }
+
+    pub fn delete_blocks_after(&self, fork_block: u64) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.database.parser_blocks()?.delete_parser_blocks_after(self.chain, fork_block as i64)?;
+        Ok(())
+    }
+}
+
+pub enum BlockVerification {
+    Untracked(Vec<Transaction>),
+    Linked(Vec<(BlockHeader, Vec<Transaction>)>),
+    Reorg { fork_block: u64, blocks: Vec<ParserBlockRow> },
+}
+
+pub fn is_linked(headers: &[BlockHeader]) -> bool {
+    headers.windows(2).all(|pair| is_parent(&pair[0], &pair[1]))
+}
+
+pub fn block_headers(blocks: &[(Option<BlockHeader>, Vec<Transaction>)]) -> Option<Vec<BlockHeader>> {
+    blocks.iter().map(|(header, _)| header.clone()).collect()
+}
+
+pub fn is_parent(parent: &BlockHeader, block: &BlockHeader) -> bool {
+    block.number == parent.number + 1 && block.parent_hash == parent.hash
+}
+
+pub fn find_fork_block(blocks: &[ParserBlockRow], headers: &[BlockHeader]) -> Option<u64> {
+    blocks
+        .iter()
+        .find(|block| headers.iter().any(|header| header.number == block.block_number as u64 && header.hash == block.hash))
+        .map(|block| block.block_number as u64)
+}
+
+pub fn fallback_fork_block(blocks: &[ParserBlockRow]) -> Option<u64> {
+    blocks.iter().map(|block| block.block_number).min().map(|block| block.saturating_sub(1) as u64)
+}
+
+pub fn orphaned_transaction_hashes(blocks: &[ParserBlockRow], fork_block: u64) -> Vec<String> {
+    blocks
+        .iter()
+        .filter(|block| block.block_number > fork_block as i64)
+        .flat_map(|block| block.transaction_hashes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(number: u64, hash: &str, parent_hash: &str) -> BlockHeader {
+        BlockHeader::new(number, hash, parent_hash)
+    }
+
+    fn row(number: u64, hash: &str, transactions: &[&str]) -> ParserBlockRow {
+        let transactions = transactions.iter().map(|x| x.to_string()).collect();
+        ParserBlockRow::from_primitive(Chain::Ethereum, header(number, hash, ""), transactions)
+    }
+
+    #[test]
+    fn test_is_linked() {
+        assert!(is_linked(&[header(10, "a", "z"), header(11, "b", "a"), header(12, "c", "b")]));
+        assert!(is_linked(&[header(10, "a", "z")]));
+        assert!(!is_linked(&[header(10, "a", "z"), header(11, "b", "x")]));
+        assert!(!is_linked(&[header(10, "a", "z"), header(12, "b", "a")]));
+    }
+
+    #[test]
+    fn test_block_headers() {
+        assert_eq!(
+            block_headers(&[(Some(header(10, "a", "z")), vec![]), (Some(header(11, "b", "a")), vec![])]),
+            Some(vec![header(10, "a", "z"), header(11, "b", "a")])
+        );
+        assert_eq!(block_headers(&[(Some(header(10, "a", "z")), vec![]), (None, vec![])]), None);
+        assert_eq!(block_headers(&[]), Some(vec![]));
+    }
+
+    #[test]
+    fn test_find_fork_block() {
+        let blocks = [row(12, "c", &[]), row(11, "b", &[]), row(10, "a", &[])];
+
+        assert_eq!(find_fork_block(&blocks, &[header(12, "c2", "b2"), header(11, "b2", "a"), header(10, "a", "z")]), Some(10));
+        assert_eq!(find_fork_block(&blocks, &[header(12, "c", "b"), header(11, "b", "a")]), Some(12));
+        assert_eq!(find_fork_block(&blocks, &[header(12, "c2", "b2")]), None);
+    }
+
+    #[test]
+    fn test_fallback_fork_block() {
+        assert_eq!(fallback_fork_block(&[row(12, "c", &[]), row(10, "a", &[])]), Some(9));
+        assert_eq!(fallback_fork_block(&[]), None);
+    }
+
+    #[test]
+    fn test_orphaned_transaction_hashes() {
+        let blocks = [row(12, "c", &["0x3"]), row(11, "b", &["0x1", "0x2"]), row(10, "a", &["0x0"])];
+
+        assert_eq!(orphaned_transaction_hashes(&blocks, 10), vec!["0x3", "0x1", "0x2"]);
+        assert!(orphaned_transaction_hashes(&blocks, 12).is_empty());
+    }
+}
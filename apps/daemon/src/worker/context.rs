@@ -5,21 +5,24 @@ use crate::worker::runtime::WorkerRuntime;
 use settings::Settings;
 use std::sync::Arc;
 use storage::{ConfigCacher, Database};
+use swapper::swapper::GemSwapper;
 
 #[derive(Clone)]
 pub struct WorkerContext {
     settings: Arc<Settings>,
     database: Database,
     runtime: WorkerRuntime,
+    swapper: Option<Arc<GemSwapper>>,
     job_filter: Option<String>,
 }
 
 impl WorkerContext {
-    pub fn new(settings: Arc<Settings>, database: Database, runtime: WorkerRuntime, job_filter: Option<String>) -> Self {
+    pub fn new(settings: Arc<Settings>, database: Database, runtime: WorkerRuntime, swapper: Option<Arc<GemSwapper>>, job_filter: Option<String>) -> Self {
         Self {
             settings,
             database,
             runtime,
+            swapper,
             job_filter,
         }
     }
@@ -32,6 +35,10 @@ impl WorkerContext {
         self.database.clone()
     }
 
+    pub fn swapper(&self) -> Option<Arc<GemSwapper>> {
+        self.swapper.clone()
+    }
+
     pub fn plan_builder<'a>(&self, worker: WorkerService, config: &'a ConfigCacher, shutdown_rx: ShutdownReceiver) -> JobPlanBuilder<'a> {
         JobPlanBuilder::with_config(worker, self.runtime.plan(shutdown_rx), config).filter(self.job_filter.clone())
     }
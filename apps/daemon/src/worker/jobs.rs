@@ -147,6 +147,7 @@ pub enum WorkerJob {
     ObservePerpetualActiveAddresses,
     ObservePerpetualPriorityAddresses,
     RefreshPerpetualTrackedAddresses,
+    ScheduleRecurringSwaps,
 }
 
 impl WorkerJob {
@@ -202,6 +203,7 @@ impl WorkerJob {
             ObservePerpetualActiveAddresses => JobSpec::new(WorkerService::Perpetuals, JobInterval::Config(ConfigKey::PerpetualObserverInterval)),
             ObservePerpetualPriorityAddresses => JobSpec::new(WorkerService::Perpetuals, JobInterval::Config(ConfigKey::PerpetualPriorityObserverInterval)),
             RefreshPerpetualTrackedAddresses => JobSpec::new(WorkerService::Perpetuals, JobInterval::Config(ConfigKey::PerpetualAddressRefreshInterval)),
+            ScheduleRecurringSwaps => JobSpec::new(WorkerService::Swaps, JobInterval::Config(ConfigKey::SwapsTimerRecurringSwaps)),
         }
     }
 
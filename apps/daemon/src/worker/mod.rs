@@ -10,6 +10,7 @@ pub mod prices;
 pub mod rewards;
 pub mod runtime;
 pub mod search;
+pub mod swaps;
 pub mod system;
 pub mod transactions;
 
@@ -33,6 +34,7 @@ impl WorkerService {
             WorkerService::Rewards => rewards::jobs(ctx, shutdown_rx).await,
             WorkerService::Transactions => transactions::jobs(ctx, shutdown_rx).await,
             WorkerService::Perpetuals => perpetuals::jobs(ctx, shutdown_rx).await,
+            WorkerService::Swaps => swaps::jobs(ctx, shutdown_rx).await,
         }
     }
 }
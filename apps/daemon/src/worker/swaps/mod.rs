@@ -0,0 +1,33 @@
+mod recurring_swaps_scheduler;
+
+use std::error::Error;
+use std::sync::Arc;
+
+use job_runner::{JobHandle, ShutdownReceiver};
+use recurring_swaps_scheduler::RecurringSwapsScheduler;
+use storage::ConfigCacher;
+use streamer::{StreamProducer, StreamProducerConfig};
+
+use crate::model::WorkerService;
+use crate::worker::context::WorkerContext;
+use crate::worker::jobs::WorkerJob;
+
+pub async fn jobs(ctx: WorkerContext, shutdown_rx: ShutdownReceiver) -> Result<Vec<JobHandle>, Box<dyn Error + Send + Sync>> {
+    let database = ctx.database();
+    let settings = ctx.settings();
+    let config = ConfigCacher::new(database.clone());
+    let retry = streamer::Retry::new(settings.rabbitmq.retry.delay, settings.rabbitmq.retry.timeout);
+    let rabbitmq_config = StreamProducerConfig::new(settings.rabbitmq.url.clone(), retry);
+    let stream_producer = StreamProducer::new(&rabbitmq_config, "swaps_worker", shutdown_rx.clone()).await?;
+    let swapper = ctx.swapper().ok_or("swapper is not configured")?;
+
+    ctx.plan_builder(WorkerService::Swaps, &config, shutdown_rx)
+        .job(WorkerJob::ScheduleRecurringSwaps, {
+            let scheduler = Arc::new(RecurringSwapsScheduler::new(database.clone(), swapper, stream_producer));
+            move |_| {
+                let scheduler = scheduler.clone();
+                async move { scheduler.run().await }
+            }
+        })
+        .finish()
+}
@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use chrono::Utc;
+use gem_tracing::info_with_fields;
+use localizer::LanguageLocalizer;
+use number_formatter::{ValueFormatter, ValueStyle};
+use primitives::{Asset, Device, GorushNotification, PushNotification, RecurringSwap, RecurringSwapExecutionStatus};
+use storage::models::RecurringSwapRow;
+use storage::{AssetsRepository, Database, RecurringSwapsRepository, WalletsRepository};
+use streamer::{NotificationsPayload, StreamProducer, StreamProducerQueue};
+use swapper::swapper::GemSwapper;
+use swapper::{Options, QuoteRequest, SwapperMode, SwapperQuoteAsset};
+
+pub struct RecurringSwapsScheduler {
+    database: Database,
+    swapper: Arc<GemSwapper>,
+    stream_producer: StreamProducer,
+}
+
+impl RecurringSwapsScheduler {
+    pub fn new(database: Database, swapper: Arc<GemSwapper>, stream_producer: StreamProducer) -> Self {
+        Self {
+            database,
+            swapper,
+            stream_producer,
+        }
+    }
+
+    pub async fn run(&self) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let recurring_swaps = self.database.recurring_swaps()?.get_due_recurring_swaps(Utc::now().naive_utc())?;
+
+        let mut notifications = Vec::new();
+        for (row, recurring_swap, device) in &recurring_swaps {
+            match self.schedule(row, recurring_swap, device).await {
+                Ok(notification) => notifications.extend(notification),
+                Err(e) => gem_tracing::error("recurring swaps scheduler", e.as_ref()),
+            }
+        }
+
+        let count = notifications.len();
+        if !notifications.is_empty() {
+            self.stream_producer.publish_notifications_observers(NotificationsPayload::new(notifications)).await?;
+        }
+
+        info_with_fields!("recurring swaps scheduler", due = recurring_swaps.len(), notified = count);
+        Ok(count)
+    }
+
+    async fn schedule(&self, row: &RecurringSwapRow, recurring_swap: &RecurringSwap, device: &Device) -> Result<Option<GorushNotification>, Box<dyn Error + Send + Sync>> {
+        let Some(scheduled_at) = row.next_execution_at else {
+            return Ok(None);
+        };
+        let next_execution_at = recurring_swap.next_execution_after(Utc::now()).map(|x| x.naive_utc());
+
+        let assets = self
+            .database
+            .assets()?
+            .get_assets(vec![recurring_swap.from_asset_id.clone(), recurring_swap.to_asset_id.clone()])?;
+        let from_asset = assets.iter().find(|x| x.id == recurring_swap.from_asset_id).ok_or("from asset not found")?;
+        let to_asset = assets.iter().find(|x| x.id == recurring_swap.to_asset_id).ok_or("to asset not found")?;
+
+        let quote_value = match self.get_quote_value(row, recurring_swap, from_asset, to_asset).await {
+            Ok(value) => value,
+            Err(e) => {
+                gem_tracing::error("recurring swaps scheduler quote", e.as_ref());
+                self.database
+                    .recurring_swaps()?
+                    .schedule_recurring_swap_execution(row.id, scheduled_at, RecurringSwapExecutionStatus::Failed, None, next_execution_at)?;
+                return Ok(None);
+            }
+        };
+
+        self.database
+            .recurring_swaps()?
+            .schedule_recurring_swap_execution(row.id, scheduled_at, RecurringSwapExecutionStatus::Pending, Some(quote_value), next_execution_at)?;
+
+        let value = ValueFormatter::format_with_symbol(ValueStyle::Auto, &recurring_swap.amount, from_asset.decimals, &from_asset.symbol)?;
+        let localizer = LanguageLocalizer::new_with_language(&device.locale);
+        let notification = localizer.notification_recurring_swap(&value, &to_asset.symbol);
+        let push = PushNotification::new_recurring_swap(recurring_swap.wallet_id.clone(), recurring_swap.id.clone());
+        Ok(GorushNotification::from_device(device.clone(), notification.title, notification.description, push))
+    }
+
+    async fn get_quote_value(&self, row: &RecurringSwapRow, recurring_swap: &RecurringSwap, from_asset: &Asset, to_asset: &Asset) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let mut wallets = self.database.wallets()?;
+        let wallet_address = wallets.subscriptions_wallet_address_for_chain(row.device_id, row.wallet_id, from_asset.chain())?.address;
+        let destination_address = wallets.subscriptions_wallet_address_for_chain(row.device_id, row.wallet_id, to_asset.chain())?.address;
+        let request = QuoteRequest {
+            from_asset: quote_asset(from_asset),
+            to_asset: quote_asset(to_asset),
+            wallet_address,
+            destination_address,
+            value: recurring_swap.amount.clone(),
+            mode: SwapperMode::ExactIn,
+            options: Options::default(),
+        };
+        let quotes = self.swapper.get_quote(&request).await?;
+        Ok(quotes.first().ok_or("no quotes")?.to_value.clone())
+    }
+}
+
+fn quote_asset(asset: &Asset) -> SwapperQuoteAsset {
+    SwapperQuoteAsset {
+        id: asset.id.to_string(),
+        symbol: asset.symbol.clone(),
+        decimals: asset.decimals as u32,
+    }
+}
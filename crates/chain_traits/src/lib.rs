@@ -5,7 +5,7 @@ use primitives::chart::ChartCandleStick;
 use primitives::perpetual::{PerpetualData, PerpetualPositionsSummary};
 use primitives::portfolio::PerpetualPortfolio;
 use primitives::{
-    AddressStatus, Asset, AssetBalance, AssetId, BroadcastOptions, Chain, ChainRequest, ChainRequestType, ChartPeriod, DelegationBase, DelegationValidator, FeeRate,
+    AddressStatus, Asset, AssetBalance, AssetId, BlockHeader, BroadcastOptions, Chain, ChainRequest, ChainRequestType, ChartPeriod, DelegationBase, DelegationValidator, FeeRate,
     NodeSyncStatus, Transaction, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput,
     TransactionStateRequest, TransactionUpdate, UTXO,
 };
@@ -118,6 +118,9 @@ pub trait ChainTransactions: Send + Sync {
     async fn get_transactions_by_block(&self, _block: u64) -> Result<Vec<Transaction>, Box<dyn Error + Sync + Send>> {
         Ok(vec![])
     }
+    async fn get_block_transactions(&self, block: u64) -> Result<(Option<BlockHeader>, Vec<Transaction>), Box<dyn Error + Sync + Send>> {
+        Ok((None, self.get_transactions_by_block(block).await?))
+    }
     async fn get_transactions_by_address(&self, _request: TransactionsRequest) -> Result<Vec<Transaction>, Box<dyn Error + Sync + Send>> {
         Ok(vec![])
     }
@@ -147,6 +150,13 @@ pub trait ChainState: Send + Sync {
         Ok(NodeSyncStatus::in_sync())
     }
     async fn get_block_latest_number(&self) -> Result<u64, Box<dyn Error + Sync + Send>>;
+    async fn get_block_header(&self, _block: u64) -> Result<Option<BlockHeader>, Box<dyn Error + Sync + Send>> {
+        Ok(None)
+    }
+    async fn get_block_headers(&self, blocks: Vec<u64>) -> Result<Option<Vec<BlockHeader>>, Box<dyn Error + Sync + Send>> {
+        let headers = futures::future::try_join_all(blocks.into_iter().map(|x| self.get_block_header(x))).await?;
+        Ok(headers.into_iter().collect())
+    }
 }
 
 #[async_trait]
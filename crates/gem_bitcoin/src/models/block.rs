@@ -11,6 +11,12 @@ pub struct BitcoinBlock {
     pub previous_block_hash: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitcoinBlockIndex {
+    pub block_hash: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BitcoinNodeInfo {
     pub blockbook: BitcoinBlockbook,
@@ -55,5 +61,8 @@ pub struct Blockbook {
 pub struct Block {
     pub page: u64,
     pub total_pages: u64,
+    pub hash: String,
+    pub height: u64,
+    pub previous_block_hash: Option<String>,
     pub txs: Vec<Transaction>,
 }
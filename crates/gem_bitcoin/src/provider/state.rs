@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use chain_traits::ChainState;
+use futures::future::try_join_all;
 use gem_client::Client;
-use primitives::NodeSyncStatus;
+use primitives::{BlockHeader, NodeSyncStatus};
+use std::collections::BTreeSet;
 use std::error::Error;
 
 use crate::{provider::state_mapper, rpc::client::BitcoinClient};
@@ -22,6 +24,17 @@ impl<C: Client> ChainState for BitcoinClient<C> {
         let node_info = self.get_node_info().await?;
         Ok(state_mapper::map_latest_block_number(&node_info))
     }
+
+    async fn get_block_header(&self, block: u64) -> Result<Option<BlockHeader>, Box<dyn Error + Sync + Send>> {
+        Ok(self.get_block_headers(vec![block]).await?.and_then(|headers| headers.into_iter().next()))
+    }
+
+    async fn get_block_headers(&self, blocks: Vec<u64>) -> Result<Option<Vec<BlockHeader>>, Box<dyn Error + Sync + Send>> {
+        let numbers: BTreeSet<u64> = blocks.iter().flat_map(|block| [block.saturating_sub(1), *block]).collect();
+        let indexes = try_join_all(numbers.iter().map(|number| self.get_block_hash(*number))).await?;
+        let hashes = numbers.into_iter().zip(indexes.into_iter().map(|x| x.block_hash)).collect();
+        Ok(Some(state_mapper::map_block_headers(&blocks, &hashes)?))
+    }
 }
 
 #[cfg(all(test, feature = "chain_integration_tests"))]
@@ -66,4 +79,16 @@ mod chain_integration_tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_get_bitcoin_block_header() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_bitcoin_test_client();
+        let header = client.get_block_header(850_000).await?.unwrap();
+        let parent = client.get_block_header(849_999).await?.unwrap();
+
+        assert_eq!(header.number, 850_000);
+        assert_eq!(header.parent_hash, parent.hash);
+
+        Ok(())
+    }
 }
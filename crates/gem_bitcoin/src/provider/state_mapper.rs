@@ -1,5 +1,8 @@
-use crate::models::block::BitcoinNodeInfo;
-use primitives::NodeSyncStatus;
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::models::block::{BitcoinNodeInfo, Block};
+use primitives::{BlockHeader, NodeSyncStatus};
 
 pub fn map_node_status(node_info: &BitcoinNodeInfo) -> NodeSyncStatus {
     let latest_block_number = node_info.backend.blocks;
@@ -12,6 +15,23 @@ pub fn map_latest_block_number(node_info: &BitcoinNodeInfo) -> u64 {
     node_info.blockbook.best_height
 }
 
+pub fn map_block_header(block: &Block) -> BlockHeader {
+    BlockHeader::new(block.height, block.hash.clone(), block.previous_block_hash.clone().unwrap_or_default())
+}
+
+pub fn map_block_headers(blocks: &[u64], hashes: &HashMap<u64, String>) -> Result<Vec<BlockHeader>, Box<dyn Error + Sync + Send>> {
+    blocks
+        .iter()
+        .map(|block| {
+            let hash = hashes.get(block).ok_or_else(|| format!("missing hash for block {block}"))?;
+            let parent_hash = hashes
+                .get(&block.saturating_sub(1))
+                .ok_or_else(|| format!("missing hash for block {}", block.saturating_sub(1)))?;
+            Ok(BlockHeader::new(*block, hash.clone(), parent_hash.clone()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +76,36 @@ mod tests {
 
         assert_eq!(map_latest_block_number(&node_info), 1_000);
     }
+
+    #[test]
+    fn test_map_block_header() {
+        let block: Block = serde_json::from_value(serde_json::json!({
+            "page": 1,
+            "totalPages": 1,
+            "itemsOnPage": 1000,
+            "hash": "00000000000000000001e2a8a1c8e5b2e2e7d4f4e0f3c8b0a9d7c6b5a4938271",
+            "previousBlockHash": "000000000000000000023f6c4d1a9b8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c",
+            "height": 850000,
+            "txCount": 0,
+            "txs": []
+        }))
+        .unwrap();
+
+        let header = map_block_header(&block);
+
+        assert_eq!(header.number, 850_000);
+        assert_eq!(header.hash, "00000000000000000001e2a8a1c8e5b2e2e7d4f4e0f3c8b0a9d7c6b5a4938271");
+        assert_eq!(header.parent_hash, "000000000000000000023f6c4d1a9b8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c");
+    }
+
+    #[test]
+    fn test_map_block_headers() {
+        let hashes = HashMap::from([(9, "a".to_string()), (10, "b".to_string()), (11, "c".to_string())]);
+
+        assert_eq!(
+            map_block_headers(&[10, 11], &hashes).unwrap(),
+            vec![BlockHeader::new(10, "b", "a"), BlockHeader::new(11, "c", "b")]
+        );
+        assert!(map_block_headers(&[12], &hashes).is_err());
+    }
 }
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 use chain_traits::{ChainTransactions, TransactionsRequest};
-use primitives::Transaction;
+use primitives::{BlockHeader, Transaction};
 use std::error::Error;
 
 use gem_client::Client;
 
 use crate::{
     models::Address,
+    provider::state_mapper::map_block_header,
     provider::transactions_mapper::{map_transaction, map_transactions},
     rpc::client::BitcoinClient,
 };
@@ -14,22 +15,23 @@ use crate::{
 #[async_trait]
 impl<C: Client> ChainTransactions for BitcoinClient<C> {
     async fn get_transactions_by_block(&self, block: u64) -> Result<Vec<Transaction>, Box<dyn Error + Sync + Send>> {
-        let mut transactions = Vec::new();
-        let mut page = 1;
-
-        loop {
-            let block = self.get_block(block, page).await?;
+        Ok(self.get_block_transactions(block).await?.1)
+    }
 
-            transactions.extend(map_transactions(self.get_chain(), block.txs));
+    async fn get_block_transactions(&self, block: u64) -> Result<(Option<BlockHeader>, Vec<Transaction>), Box<dyn Error + Sync + Send>> {
+        let first_page = self.get_block(block, 1).await?;
+        let header = map_block_header(&first_page);
+        let mut transactions = map_transactions(self.get_chain(), first_page.txs);
 
-            if block.total_pages == block.page {
-                break;
+        for page in 2..=first_page.total_pages as usize {
+            let next_page = self.get_block(block, page).await?;
+            if next_page.hash != header.hash {
+                return Err(format!("block {block} changed while loading transactions").into());
             }
-
-            page += 1;
+            transactions.extend(map_transactions(self.get_chain(), next_page.txs));
         }
 
-        Ok(transactions)
+        Ok((Some(header), transactions))
     }
 
     async fn get_transaction_by_hash(&self, hash: String) -> Result<Option<Transaction>, Box<dyn Error + Sync + Send>> {
@@ -0,0 +1,168 @@
+use primitives::{BitcoinChain, ChainSigner, SignerError, SignerInput};
+
+use super::signing::{TransferOutput, sign_transfer};
+
+pub struct BitcoinChainSigner {
+    chain: BitcoinChain,
+}
+
+impl BitcoinChainSigner {
+    pub fn new(chain: BitcoinChain) -> Self {
+        Self { chain }
+    }
+}
+
+impl ChainSigner for BitcoinChainSigner {
+    fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        let output = TransferOutput {
+            destination: &input.destination_address,
+            value: &input.value,
+            memo: input.memo.as_deref(),
+        };
+        sign_transfer(self.chain, input, output, private_key)
+    }
+
+    fn sign_swap(&self, input: &SignerInput, private_key: &[u8]) -> Result<Vec<String>, SignerError> {
+        let swap_data = input.input_type.get_swap_data().map_err(SignerError::invalid_input)?;
+        let output = TransferOutput {
+            destination: &swap_data.data.to,
+            value: &swap_data.data.value,
+            memo: swap_data.data.memo.as_deref(),
+        };
+        Ok(vec![sign_transfer(self.chain, input, output, private_key)?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use primitives::{Asset, TransactionFee, TransactionLoadInput, TransactionLoadMetadata, UTXO};
+
+    const PRIVATE_KEY: &str = "eb696a065ef48a2192da5b28b694f87544b30fae8327c4510137a922f32c6dcf";
+    const TX_A: &str = "fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f";
+    const TX_B: &str = "8ac60eb9575db5b2d987e29f301b5b819ea83a5c6579d282d189cc04b8e151ef";
+
+    fn utxo(transaction_id: &str, vout: i32, value: u64, address: &str) -> UTXO {
+        UTXO {
+            transaction_id: transaction_id.to_string(),
+            vout,
+            value: value.to_string(),
+            address: address.to_string(),
+        }
+    }
+
+    fn sign(chain: BitcoinChain, sender: &str, destination: &str, value: &str, fee_rate: u64, memo: Option<&str>, metadata: TransactionLoadMetadata) -> String {
+        let asset = Asset::from_chain(chain.get_chain());
+        let input = TransactionLoadInput::mock_transfer(asset, sender, destination, value, fee_rate, memo, metadata);
+        let input = SignerInput::new(input, TransactionFee::new_from_fee(BigInt::from(fee_rate)));
+        BitcoinChainSigner::new(chain).sign_transfer(&input, &hex::decode(PRIVATE_KEY).unwrap()).unwrap()
+    }
+
+    // Expected transactions below were produced independently with rust-bitcoin 0.32 (and, for Bitcoin Cash and
+    // Zcash, its secp256k1 signer over the FORKID / ZIP-243 preimages) for the same inputs, outputs and key.
+
+    #[test]
+    fn test_sign_transfer_bitcoin_p2wpkh() {
+        let sender = "bc1q0yy3juscd3zfavw76g4h3eqdqzda7qyf58rj4m";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_A, 0, 100_000, sender)],
+        };
+
+        assert_eq!(
+            sign(BitcoinChain::Bitcoin, sender, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", "50000", 10, None, metadata),
+            "020000000001019f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff0000000000ffffffff0250c3000000000000160014751e76e8199196d454941c45d1b3a323f1433bd6cebd00000000000016001479091972186c449eb1ded22b78e40d009bdf008902473044022077751c3b5ab33efc3650f4aa7594cfe897f4738795d5e5bba08ac5ccd35a858502206478a7303bc173b8b1878b2bd8fcd27fe98ec46cb9deb031dc6b66c787306174012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a2687300000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_bitcoin_p2tr_with_memo() {
+        let sender = "bc1plxlu33ukhehnvwj48ecarkm2xnv7a58amwkt57tdx9fe5vc0n8pspdxpju";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_B, 0, 20_000, sender), utxo(TX_A, 1, 30_000, sender)],
+        };
+
+        assert_eq!(
+            sign(BitcoinChain::Bitcoin, sender, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", "40000", 5, Some("gem"), metadata),
+            "020000000001029f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff0100000000ffffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0000000000ffffffff03409c0000000000001976a91477bff20c60e522dfaa3350c39b030a5d004e839a88ac0000000000000000056a0367656dce22000000000000225120f9bfc8c796be6f363a553e71d1db6a34d9eed0fddbacba796d31539a330f99c30140651e5fc9b93bee4cff153d246776bcd8693fd5809ce8bde8577395bb98ae046805f2f6aeff296707a027903846d143fe3e91ac8fc2a823975d14395ad5b9e3780140f4c054ad1e6b4a0d4f66878a4c4284820c03d150cf1de348fc5fa4aaf1588d6d5c44877f270bcc148604763f7818d362399e3578895e57afe6d769bff8cde8a700000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_litecoin_p2sh_p2wpkh() {
+        let sender = "MEPeSgFnEZNFZAMmdjPxrboMFUykxX7Jwd";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_B, 2, 2_000_000, sender)],
+        };
+
+        assert_eq!(
+            sign(BitcoinChain::Litecoin, sender, "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9", "1000000", 2, None, metadata),
+            "02000000000101ef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a020000001716001479091972186c449eb1ded22b78e40d009bdf0089ffffffff0240420f0000000000160014751e76e8199196d454941c45d1b3a323f1433bd6f6400f000000000017a9144733f37cf4db86fbc2efed2500b4f4e49f3120238702483045022100daf2a75c73bf745aade85753d3b310836ac6c19fd209052100d15c19e529fcc0022051e55fb01c57b704ea76ffc860a8b7ef8b4c46e1b52452132d4ba7442789daaf012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a2687300000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_doge() {
+        let sender = "DGB5Chy1faHfpC289YwjwgrWb1mF2TgLLi";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_A, 0, 1_000_000_000, sender)],
+        };
+
+        assert_eq!(
+            sign(BitcoinChain::Doge, sender, "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE", "500000000", 1000, None, metadata),
+            "01000000019f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff000000006a47304402203f7518caa6fe46f00820daa402d0ca11dc13ff170c055d15d586c249d46a5f5e022006809220e8f22a23dda6be5a9fe0ca8922a53bfc9246e236d47d1d19016b5dfd012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a26873ffffffff020065cd1d000000001976a914751e76e8199196d454941c45d1b3a323f1433bd688ac30f2c91d000000001976a91479091972186c449eb1ded22b78e40d009bdf008988ac00000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_bitcoin_cash() {
+        let sender = "bitcoincash:qpusjxtjrpkyf843mmfzk78yp5qfhhcq3yv38ma5lm";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_A, 0, 50_000, sender)],
+        };
+
+        assert_eq!(
+            sign(
+                BitcoinChain::BitcoinCash,
+                sender,
+                "bitcoincash:qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h",
+                "10000",
+                1,
+                None,
+                metadata
+            ),
+            "02000000019f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff000000006b483045022100bf8760c03c91c6eae8b0cf017633f2e45273848310cbe5af06e8ed2a8eee857c022043a902808bbf0607a6592bcfa3a7aa56dcc00677c69545cf51bfbf055b91a0de412103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a26873ffffffff0210270000000000001976a914751e76e8199196d454941c45d1b3a323f1433bd688ac5e9b0000000000001976a91479091972186c449eb1ded22b78e40d009bdf008988ac00000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_zcash() {
+        let sender = "t1UuafnSWLVAysptRMPmJXjnpxYE2SN6Pnm";
+        let metadata = TransactionLoadMetadata::Zcash {
+            utxos: vec![utxo(TX_A, 0, 1_000_000, sender)],
+            branch_id: "c2d6d0b4".to_string(),
+        };
+
+        assert_eq!(
+            sign(BitcoinChain::Zcash, sender, "t1UYsZVJkLPeMjxEtACvSxfWuNmddpWfxzs", "100000", 1, None, metadata),
+            "0400008085202f89019f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff000000006b483045022100c583100ebe3ed5b7ec87856219de32ec25f51e05bffe02be4ca96dcdc62df1460220417d8f8b28d169f482b9cddf6a462b661e63ee2101a42f4b4ef716a863f07e71012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a26873ffffffff02a0860100000000001976a914751e76e8199196d454941c45d1b3a323f1433bd688ac90940d00000000001976a91479091972186c449eb1ded22b78e40d009bdf008988ac00000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_rejects_foreign_sender() {
+        let sender = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        let metadata = TransactionLoadMetadata::Bitcoin {
+            utxos: vec![utxo(TX_A, 0, 100_000, sender)],
+        };
+        let asset = Asset::from_chain(primitives::Chain::Bitcoin);
+        let input = TransactionLoadInput::mock_transfer(asset, sender, sender, "50000", 10, None, metadata);
+        let input = SignerInput::new(input, TransactionFee::new_from_fee(BigInt::from(10)));
+
+        assert!(
+            BitcoinChainSigner::new(BitcoinChain::Bitcoin)
+                .sign_transfer(&input, &hex::decode(PRIVATE_KEY).unwrap())
+                .is_err()
+        );
+    }
+}
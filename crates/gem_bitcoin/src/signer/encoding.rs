@@ -1,3 +1,5 @@
+use primitives::SignerError;
+
 pub fn encode_varint(n: usize) -> Vec<u8> {
     if n < 0xfd {
         vec![n as u8]
@@ -13,6 +15,64 @@ pub fn encode_varint(n: usize) -> Vec<u8> {
     }
 }
 
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn peek_u8(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    pub fn read(&mut self, len: usize) -> Result<&'a [u8], SignerError> {
+        if len > self.data.len() {
+            return SignerError::invalid_input_err("Unexpected end of data");
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], SignerError> {
+        self.read(N)?.try_into().map_err(|_| SignerError::invalid_input("Unexpected end of data"))
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, SignerError> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, SignerError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, SignerError> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_varint(&mut self) -> Result<usize, SignerError> {
+        let value = match self.read_u8()? {
+            0xfd => u16::from_le_bytes(self.read_array()?) as u64,
+            0xfe => u32::from_le_bytes(self.read_array()?) as u64,
+            0xff => u64::from_le_bytes(self.read_array()?),
+            value => value as u64,
+        };
+        usize::try_from(value).map_err(|_| SignerError::invalid_input("Length overflow"))
+    }
+
+    pub fn read_var_bytes(&mut self) -> Result<&'a [u8], SignerError> {
+        let len = self.read_varint()?;
+        self.read(len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,4 +93,21 @@ mod tests {
     fn test_encode_varint_large() {
         assert_eq!(encode_varint(0x10000), vec![0xfe, 0, 0, 1, 0]);
     }
+
+    #[test]
+    fn test_byte_reader_varint_round_trip() {
+        for value in [0, 252, 253, 0xffff, 0x10000, 0xffff_ffff + 1] {
+            let encoded = encode_varint(value);
+            let mut reader = ByteReader::new(&encoded);
+            assert_eq!(reader.read_varint().unwrap(), value);
+            assert!(reader.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_byte_reader_rejects_truncated_data() {
+        let mut reader = ByteReader::new(&[0x05, 0x01, 0x02]);
+        assert!(reader.read_var_bytes().is_err());
+        assert!(ByteReader::new(&[0xfd, 0x01]).read_varint().is_err());
+    }
 }
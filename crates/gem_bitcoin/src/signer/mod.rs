@@ -1,6 +1,19 @@
+mod chain_signer;
 mod encoding;
+mod planner;
+mod psbt;
+mod script;
+mod sighash;
 mod signature;
+mod signing;
+#[cfg(test)]
+pub(crate) mod testkit;
+mod transaction;
 mod types;
 
+pub use chain_signer::BitcoinChainSigner;
+pub use planner::{FeeCalculator, TransactionPlan, plan_transaction};
+pub use psbt::{Psbt, PsbtInputSummary, PsbtOutputSummary, PsbtSummary};
+pub use script::{OutputScript, ScriptType};
 pub use signature::sign_personal;
 pub use types::{BitcoinSignDataResponse, BitcoinSignMessageData};
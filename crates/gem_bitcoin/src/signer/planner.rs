@@ -0,0 +1,235 @@
+use primitives::{BitcoinChain, SignerError, UTXO};
+use signer::InvalidInput;
+
+use super::script::ScriptType;
+
+// version(4) + input count(1) + output count(1) + lock time(4)
+const TRANSACTION_OVERHEAD_VBYTES: u64 = 10;
+// segwit marker and flag weigh 2 WU, rounded up to a full vbyte
+const SEGWIT_OVERHEAD_VBYTES: u64 = 1;
+// value(8) + script length(1)
+const OUTPUT_OVERHEAD_BYTES: u64 = 9;
+
+// ZIP-317 conventional fee
+const ZCASH_MARGINAL_FEE: u64 = 5_000;
+const ZCASH_GRACE_ACTIONS: u64 = 2;
+const ZCASH_P2PKH_STANDARD_OUTPUT_SIZE: u64 = 34;
+
+#[derive(Debug, Clone)]
+pub struct TransactionPlan {
+    pub utxos: Vec<UTXO>,
+    pub amount: u64,
+    pub fee: u64,
+    pub change: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FeeCalculator {
+    pub chain: BitcoinChain,
+    pub input_type: ScriptType,
+    pub fee_rate: u64,
+}
+
+impl FeeCalculator {
+    pub fn new(chain: BitcoinChain, input_type: ScriptType, fee_rate: u64) -> Self {
+        Self { chain, input_type, fee_rate }
+    }
+
+    pub fn fee(&self, inputs: usize, output_scripts: &[usize]) -> u64 {
+        if let BitcoinChain::Zcash = self.chain {
+            return zcash_conventional_fee(inputs, output_scripts);
+        }
+        let outputs: u64 = output_scripts.iter().map(|len| OUTPUT_OVERHEAD_BYTES + *len as u64).sum();
+        let segwit = if self.is_segwit_input() { SEGWIT_OVERHEAD_VBYTES } else { 0 };
+        let vbytes = TRANSACTION_OVERHEAD_VBYTES + segwit + input_vbytes(self.input_type) * inputs as u64 + outputs;
+        vbytes.saturating_mul(self.fee_rate)
+    }
+
+    fn is_segwit_input(&self) -> bool {
+        match self.input_type {
+            ScriptType::P2pkh => false,
+            ScriptType::P2sh | ScriptType::P2wpkh | ScriptType::P2wsh | ScriptType::P2tr => true,
+        }
+    }
+}
+
+fn input_vbytes(input_type: ScriptType) -> u64 {
+    match input_type {
+        ScriptType::P2pkh => 148,
+        ScriptType::P2sh => 91,
+        ScriptType::P2wpkh => 68,
+        ScriptType::P2wsh => 105,
+        ScriptType::P2tr => 58,
+    }
+}
+
+fn zcash_conventional_fee(inputs: usize, output_scripts: &[usize]) -> u64 {
+    let outputs_size: u64 = output_scripts.iter().map(|len| OUTPUT_OVERHEAD_BYTES + *len as u64).sum();
+    let logical_actions = (inputs as u64).max(outputs_size.div_ceil(ZCASH_P2PKH_STANDARD_OUTPUT_SIZE));
+    ZCASH_MARGINAL_FEE * logical_actions.max(ZCASH_GRACE_ACTIONS)
+}
+
+pub fn dust_threshold(chain: BitcoinChain) -> u64 {
+    match chain {
+        BitcoinChain::Doge => 1_000_000,
+        BitcoinChain::Bitcoin | BitcoinChain::BitcoinCash | BitcoinChain::Litecoin | BitcoinChain::Zcash => 546,
+    }
+}
+
+pub fn plan_transaction(
+    calculator: FeeCalculator,
+    utxos: &[UTXO],
+    output_scripts: &[usize],
+    change_script: usize,
+    amount: u64,
+    use_max_amount: bool,
+) -> Result<TransactionPlan, SignerError> {
+    let dust = dust_threshold(calculator.chain);
+    let mut sorted = utxos
+        .iter()
+        .map(|utxo| Ok((utxo.value.parse::<u64>().invalid_input("invalid UTXO value")?, utxo)))
+        .collect::<Result<Vec<_>, SignerError>>()?;
+    sorted.sort_by_key(|(value, _)| std::cmp::Reverse(*value));
+
+    if sorted.is_empty() {
+        return SignerError::invalid_input_err("No UTXOs available");
+    }
+
+    let with_change = [output_scripts, &[change_script]].concat();
+
+    if use_max_amount {
+        let total = sorted
+            .iter()
+            .try_fold(0u64, |total, (value, _)| total.checked_add(*value))
+            .ok_or_else(|| SignerError::invalid_input("UTXO values overflow"))?;
+        let fee = calculator.fee(sorted.len(), output_scripts);
+        let amount = total
+            .checked_sub(fee)
+            .filter(|amount| *amount >= dust)
+            .ok_or_else(|| SignerError::invalid_input("Insufficient funds"))?;
+        return Ok(TransactionPlan {
+            utxos: sorted.into_iter().map(|(_, utxo)| utxo.clone()).collect(),
+            amount,
+            fee,
+            change: 0,
+        });
+    }
+
+    if amount < dust {
+        return SignerError::invalid_input_err("Amount is below dust threshold");
+    }
+
+    let mut total = 0u64;
+    let mut selected = Vec::new();
+    for (value, utxo) in sorted {
+        total = total.checked_add(value).ok_or_else(|| SignerError::invalid_input("UTXO values overflow"))?;
+        selected.push(utxo.clone());
+
+        let fee_with_change = calculator.fee(selected.len(), &with_change);
+        if let Some(change) = amount
+            .checked_add(fee_with_change)
+            .and_then(|required| total.checked_sub(required))
+            .filter(|change| *change >= dust)
+        {
+            return Ok(TransactionPlan {
+                utxos: selected,
+                amount,
+                fee: fee_with_change,
+                change,
+            });
+        }
+
+        if amount.checked_add(calculator.fee(selected.len(), output_scripts)).is_some_and(|required| total >= required) {
+            return Ok(TransactionPlan {
+                utxos: selected,
+                amount,
+                fee: total - amount,
+                change: 0,
+            });
+        }
+    }
+
+    SignerError::invalid_input_err("Insufficient funds")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P2WPKH_SCRIPT: usize = 22;
+
+    fn utxos(values: &[u64]) -> Vec<UTXO> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| UTXO {
+                transaction_id: format!("{i:064x}"),
+                vout: 0,
+                value: value.to_string(),
+                address: "bc1q".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fee_calculator() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        assert_eq!(calculator.fee(1, &[P2WPKH_SCRIPT, P2WPKH_SCRIPT]), 1410);
+
+        let calculator = FeeCalculator::new(BitcoinChain::Doge, ScriptType::P2pkh, 1000);
+        assert_eq!(calculator.fee(2, &[25, 25]), 374_000);
+
+        let calculator = FeeCalculator::new(BitcoinChain::Zcash, ScriptType::P2pkh, 1);
+        assert_eq!(calculator.fee(1, &[25, 25]), 10_000);
+        assert_eq!(calculator.fee(3, &[25, 25]), 15_000);
+    }
+
+    #[test]
+    fn test_plan_transaction_with_change() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        let plan = plan_transaction(calculator, &utxos(&[5_000, 100_000, 20_000]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 50_000, false).unwrap();
+
+        assert_eq!(plan.utxos.len(), 1);
+        assert_eq!(plan.utxos[0].value, "100000");
+        assert_eq!(plan.fee, 1410);
+        assert_eq!(plan.change, 100_000 - 50_000 - 1410);
+    }
+
+    #[test]
+    fn test_plan_transaction_dust_change_goes_to_fee() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        let plan = plan_transaction(calculator, &utxos(&[51_500]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 50_000, false).unwrap();
+
+        assert_eq!(plan.change, 0);
+        assert_eq!(plan.fee, 1_500);
+    }
+
+    #[test]
+    fn test_plan_transaction_max_amount() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        let plan = plan_transaction(calculator, &utxos(&[10_000, 20_000]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 0, true).unwrap();
+
+        assert_eq!(plan.utxos.len(), 2);
+        assert_eq!(plan.fee, 1780);
+        assert_eq!(plan.amount, 30_000 - 1780);
+        assert_eq!(plan.change, 0);
+    }
+
+    #[test]
+    fn test_plan_transaction_insufficient_funds() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        assert!(plan_transaction(calculator, &utxos(&[10_000]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 10_000, false).is_err());
+        assert!(plan_transaction(calculator, &[], &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 10_000, false).is_err());
+        assert!(plan_transaction(calculator, &utxos(&[10_000]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 100, false).is_err());
+    }
+
+    #[test]
+    fn test_plan_transaction_overflow() {
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, 10);
+        assert!(plan_transaction(calculator, &utxos(&[u64::MAX, 1]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, u64::MAX, false).is_err());
+        assert!(plan_transaction(calculator, &utxos(&[u64::MAX, 1]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 0, true).is_err());
+
+        let calculator = FeeCalculator::new(BitcoinChain::Bitcoin, ScriptType::P2wpkh, u64::MAX);
+        assert!(plan_transaction(calculator, &utxos(&[100_000]), &[P2WPKH_SCRIPT], P2WPKH_SCRIPT, 10_000, false).is_err());
+    }
+}
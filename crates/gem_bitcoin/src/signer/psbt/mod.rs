@@ -0,0 +1,488 @@
+mod sign;
+mod summary;
+
+use gem_encoding::{decode_base64, encode_base64};
+use primitives::{BitcoinChain, SignerError};
+
+use super::encoding::{ByteReader, encode_varint};
+use super::transaction::{BitcoinTransaction, OutPoint, TransactionInput, TransactionOutput, encode_script};
+
+pub use summary::{PsbtInputSummary, PsbtOutputSummary, PsbtSummary};
+
+const PSBT_MAGIC: &[u8] = b"psbt\xff";
+
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_GLOBAL_TX_VERSION: u8 = 0x02;
+const PSBT_GLOBAL_FALLBACK_LOCKTIME: u8 = 0x03;
+const PSBT_GLOBAL_INPUT_COUNT: u8 = 0x04;
+const PSBT_GLOBAL_OUTPUT_COUNT: u8 = 0x05;
+const PSBT_GLOBAL_VERSION: u8 = 0xfb;
+
+const PSBT_IN_NON_WITNESS_UTXO: u8 = 0x00;
+const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+const PSBT_IN_PARTIAL_SIG: u8 = 0x02;
+const PSBT_IN_SIGHASH_TYPE: u8 = 0x03;
+const PSBT_IN_REDEEM_SCRIPT: u8 = 0x04;
+const PSBT_IN_WITNESS_SCRIPT: u8 = 0x05;
+const PSBT_IN_BIP32_DERIVATION: u8 = 0x06;
+const PSBT_IN_FINAL_SCRIPTSIG: u8 = 0x07;
+const PSBT_IN_FINAL_SCRIPTWITNESS: u8 = 0x08;
+const PSBT_IN_PREVIOUS_TXID: u8 = 0x0e;
+const PSBT_IN_OUTPUT_INDEX: u8 = 0x0f;
+const PSBT_IN_SEQUENCE: u8 = 0x10;
+const PSBT_IN_REQUIRED_TIME_LOCKTIME: u8 = 0x11;
+const PSBT_IN_REQUIRED_HEIGHT_LOCKTIME: u8 = 0x12;
+const PSBT_IN_TAP_KEY_SIG: u8 = 0x13;
+const PSBT_IN_TAP_SCRIPT_SIG: u8 = 0x14;
+const PSBT_IN_TAP_LEAF_SCRIPT: u8 = 0x15;
+const PSBT_IN_TAP_BIP32_DERIVATION: u8 = 0x16;
+const PSBT_IN_TAP_INTERNAL_KEY: u8 = 0x17;
+const PSBT_IN_TAP_MERKLE_ROOT: u8 = 0x18;
+
+const PSBT_OUT_AMOUNT: u8 = 0x03;
+const PSBT_OUT_SCRIPT: u8 = 0x04;
+
+const LOCKTIME_THRESHOLD: u32 = 500_000_000;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct KeyValueMap(Vec<(Vec<u8>, Vec<u8>)>);
+
+impl KeyValueMap {
+    fn decode(reader: &mut ByteReader) -> Result<Self, SignerError> {
+        let mut pairs: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+        loop {
+            let key = reader.read_var_bytes()?;
+            if key.is_empty() {
+                return Ok(Self(pairs));
+            }
+            if pairs.iter().any(|(existing, _)| existing == key) {
+                return SignerError::invalid_input_err("Duplicate PSBT key");
+            }
+            pairs.push((key.to_vec(), reader.read_var_bytes()?.to_vec()));
+        }
+    }
+
+    fn encode(&self, data: &mut Vec<u8>) {
+        for (key, value) in &self.0 {
+            data.extend(encode_script(key));
+            data.extend(encode_script(value));
+        }
+        data.push(0x00);
+    }
+
+    fn get(&self, key_type: u8) -> Option<&[u8]> {
+        self.0.iter().find(|(key, _)| key.as_slice() == [key_type]).map(|(_, value)| value.as_slice())
+    }
+
+    fn entries(&self, key_type: u8) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.0
+            .iter()
+            .filter(move |(key, _)| key.first() == Some(&key_type))
+            .map(|(key, value)| (&key[1..], value.as_slice()))
+    }
+
+    fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        match self.0.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    fn remove_types(&mut self, remove: &[u8]) {
+        self.0.retain(|(key, _)| !key.first().is_some_and(|key_type| remove.contains(key_type)));
+    }
+
+    fn get_u32(&self, key_type: u8) -> Result<Option<u32>, SignerError> {
+        self.get(key_type)
+            .map(|value| {
+                value
+                    .try_into()
+                    .map(u32::from_le_bytes)
+                    .map_err(|_| SignerError::invalid_input("Invalid PSBT integer field"))
+            })
+            .transpose()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Psbt {
+    chain: BitcoinChain,
+    version: u32,
+    global: KeyValueMap,
+    inputs: Vec<KeyValueMap>,
+    outputs: Vec<KeyValueMap>,
+}
+
+impl Psbt {
+    pub fn from_string(chain: BitcoinChain, value: &str) -> Result<Self, SignerError> {
+        let value = value.trim();
+        let data = match hex::decode(value) {
+            Ok(data) if data.starts_with(PSBT_MAGIC) => data,
+            _ => decode_base64(value).map_err(|_| SignerError::invalid_input("PSBT must be base64 or hex encoded"))?,
+        };
+        Self::decode(chain, &data)
+    }
+
+    pub fn decode(chain: BitcoinChain, data: &[u8]) -> Result<Self, SignerError> {
+        match chain {
+            BitcoinChain::Bitcoin | BitcoinChain::Litecoin => {}
+            BitcoinChain::BitcoinCash | BitcoinChain::Doge | BitcoinChain::Zcash => {
+                return SignerError::invalid_input_err(format!("PSBT is not supported on {}", chain.as_ref()));
+            }
+        }
+        let mut reader = ByteReader::new(data);
+        if reader.read(PSBT_MAGIC.len())? != PSBT_MAGIC {
+            return SignerError::invalid_input_err("Invalid PSBT magic");
+        }
+
+        let global = KeyValueMap::decode(&mut reader)?;
+        let version = global.get_u32(PSBT_GLOBAL_VERSION)?.unwrap_or(0);
+        let (input_count, output_count) = match version {
+            0 => {
+                let transaction = Self::decode_unsigned_transaction(chain, &global)?;
+                (transaction.inputs.len(), transaction.outputs.len())
+            }
+            2 => {
+                if global.get(PSBT_GLOBAL_UNSIGNED_TX).is_some() {
+                    return SignerError::invalid_input_err("PSBTv2 must not contain an unsigned transaction");
+                }
+                (
+                    Self::global_count(&global, PSBT_GLOBAL_INPUT_COUNT)?,
+                    Self::global_count(&global, PSBT_GLOBAL_OUTPUT_COUNT)?,
+                )
+            }
+            _ => return SignerError::invalid_input_err(format!("Unsupported PSBT version {version}")),
+        };
+
+        let inputs = (0..input_count).map(|_| KeyValueMap::decode(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        let outputs = (0..output_count).map(|_| KeyValueMap::decode(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        if !reader.is_empty() {
+            return SignerError::invalid_input_err("Unexpected trailing PSBT data");
+        }
+
+        let psbt = Self {
+            chain,
+            version,
+            global,
+            inputs,
+            outputs,
+        };
+        psbt.unsigned_transaction()?;
+        Ok(psbt)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = PSBT_MAGIC.to_vec();
+        self.global.encode(&mut data);
+        for map in self.inputs.iter().chain(&self.outputs) {
+            map.encode(&mut data);
+        }
+        data
+    }
+
+    pub fn to_base64(&self) -> String {
+        encode_base64(&self.encode())
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn decode_unsigned_transaction(chain: BitcoinChain, global: &KeyValueMap) -> Result<BitcoinTransaction, SignerError> {
+        let data = global
+            .get(PSBT_GLOBAL_UNSIGNED_TX)
+            .ok_or_else(|| SignerError::invalid_input("PSBT is missing the unsigned transaction"))?;
+        let transaction = BitcoinTransaction::decode(chain, data)?;
+        if transaction.inputs.iter().any(|input| !input.script_sig.is_empty() || !input.witness.is_empty()) {
+            return SignerError::invalid_input_err("PSBT unsigned transaction must not contain signatures");
+        }
+        Ok(transaction)
+    }
+
+    fn global_count(global: &KeyValueMap, key_type: u8) -> Result<usize, SignerError> {
+        let value = global.get(key_type).ok_or_else(|| SignerError::invalid_input("PSBTv2 is missing input or output count"))?;
+        let mut reader = ByteReader::new(value);
+        let count = reader.read_varint()?;
+        if !reader.is_empty() {
+            return SignerError::invalid_input_err("Invalid PSBTv2 count");
+        }
+        Ok(count)
+    }
+
+    pub(crate) fn unsigned_transaction(&self) -> Result<BitcoinTransaction, SignerError> {
+        let mut transaction = match self.version {
+            0 => Self::decode_unsigned_transaction(self.chain, &self.global)?,
+            _ => self.build_v2_transaction()?,
+        };
+        for (index, input) in transaction.inputs.iter_mut().enumerate() {
+            if let Some(spent) = self.spent_output(index, &input.previous_output)? {
+                input.value = spent.value;
+                input.script_pubkey = spent.script_pubkey;
+            }
+        }
+        Ok(transaction)
+    }
+
+    fn build_v2_transaction(&self) -> Result<BitcoinTransaction, SignerError> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|map| {
+                let hash: [u8; 32] = map
+                    .get(PSBT_IN_PREVIOUS_TXID)
+                    .and_then(|value| value.try_into().ok())
+                    .ok_or_else(|| SignerError::invalid_input("PSBTv2 input is missing the previous txid"))?;
+                let index = map
+                    .get_u32(PSBT_IN_OUTPUT_INDEX)?
+                    .ok_or_else(|| SignerError::invalid_input("PSBTv2 input is missing the output index"))?;
+                Ok(TransactionInput {
+                    previous_output: OutPoint { hash, index },
+                    value: 0,
+                    script_pubkey: vec![],
+                    sequence: map.get_u32(PSBT_IN_SEQUENCE)?.unwrap_or(u32::MAX),
+                    script_sig: vec![],
+                    witness: vec![],
+                })
+            })
+            .collect::<Result<Vec<_>, SignerError>>()?;
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|map| {
+                let value = map
+                    .get(PSBT_OUT_AMOUNT)
+                    .and_then(|value| value.try_into().ok())
+                    .map(i64::from_le_bytes)
+                    .and_then(|value| u64::try_from(value).ok())
+                    .ok_or_else(|| SignerError::invalid_input("PSBTv2 output is missing the amount"))?;
+                let script = map.get(PSBT_OUT_SCRIPT).ok_or_else(|| SignerError::invalid_input("PSBTv2 output is missing the script"))?;
+                Ok(TransactionOutput::new(value, script.to_vec()))
+            })
+            .collect::<Result<Vec<_>, SignerError>>()?;
+
+        let mut transaction = BitcoinTransaction::new(self.chain, inputs, outputs);
+        transaction.version = self
+            .global
+            .get_u32(PSBT_GLOBAL_TX_VERSION)?
+            .ok_or_else(|| SignerError::invalid_input("PSBTv2 is missing the transaction version"))?;
+        transaction.lock_time = self.v2_lock_time()?;
+        Ok(transaction)
+    }
+
+    fn v2_lock_time(&self) -> Result<u32, SignerError> {
+        let mut heights = vec![];
+        let mut times = vec![];
+        let mut any_height_only = false;
+        let mut any_time_only = false;
+        for map in &self.inputs {
+            let height = map.get_u32(PSBT_IN_REQUIRED_HEIGHT_LOCKTIME)?;
+            let time = map.get_u32(PSBT_IN_REQUIRED_TIME_LOCKTIME)?;
+            any_height_only |= height.is_some() && time.is_none();
+            any_time_only |= time.is_some() && height.is_none();
+            heights.extend(height);
+            times.extend(time);
+        }
+        match (any_height_only, any_time_only) {
+            (true, true) => SignerError::invalid_input_err("PSBTv2 inputs require incompatible locktimes"),
+            (_, false) if !heights.is_empty() => Ok(heights.into_iter().max().unwrap_or_default()),
+            _ if !times.is_empty() => Ok(times.into_iter().max().unwrap_or(LOCKTIME_THRESHOLD)),
+            _ => Ok(self.global.get_u32(PSBT_GLOBAL_FALLBACK_LOCKTIME)?.unwrap_or(0)),
+        }
+    }
+
+    fn spent_output(&self, index: usize, previous_output: &OutPoint) -> Result<Option<TransactionOutput>, SignerError> {
+        let map = &self.inputs[index];
+        if let Some(data) = map.get(PSBT_IN_NON_WITNESS_UTXO) {
+            let previous = BitcoinTransaction::decode(self.chain, data)?;
+            if previous.hash() != previous_output.hash {
+                return SignerError::invalid_input_err(format!("PSBT input {index} UTXO does not match its previous txid"));
+            }
+            return previous
+                .outputs
+                .get(previous_output.index as usize)
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| SignerError::invalid_input(format!("PSBT input {index} spends a missing output")));
+        }
+        if let Some(data) = map.get(PSBT_IN_WITNESS_UTXO) {
+            let mut reader = ByteReader::new(data);
+            let value = reader.read_u64()?;
+            let script_pubkey = reader.read_var_bytes()?.to_vec();
+            return Ok(Some(TransactionOutput::new(value, script_pubkey)));
+        }
+        Ok(None)
+    }
+
+    fn is_input_finalized(&self, index: usize) -> bool {
+        let map = &self.inputs[index];
+        map.get(PSBT_IN_FINAL_SCRIPTSIG).is_some() || map.get(PSBT_IN_FINAL_SCRIPTWITNESS).is_some()
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        (0..self.inputs.len()).all(|index| self.is_input_finalized(index))
+    }
+
+    pub fn extract(&self) -> Result<String, SignerError> {
+        Ok(hex::encode(self.extract_transaction()?.encode()))
+    }
+
+    pub(crate) fn extract_transaction(&self) -> Result<BitcoinTransaction, SignerError> {
+        let mut transaction = self.unsigned_transaction()?;
+        for (index, input) in transaction.inputs.iter_mut().enumerate() {
+            if !self.is_input_finalized(index) {
+                return SignerError::invalid_input_err(format!("PSBT input {index} is not finalized"));
+            }
+            let map = &self.inputs[index];
+            input.script_sig = map.get(PSBT_IN_FINAL_SCRIPTSIG).unwrap_or_default().to_vec();
+            input.witness = map.get(PSBT_IN_FINAL_SCRIPTWITNESS).map(decode_witness).transpose()?.unwrap_or_default();
+        }
+        Ok(transaction)
+    }
+}
+
+fn encode_witness(items: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = encode_varint(items.len());
+    for item in items {
+        data.extend(encode_script(item));
+    }
+    data
+}
+
+fn decode_witness(data: &[u8]) -> Result<Vec<Vec<u8>>, SignerError> {
+    let mut reader = ByteReader::new(data);
+    let items = (0..reader.read_varint()?)
+        .map(|_| Ok(reader.read_var_bytes()?.to_vec()))
+        .collect::<Result<Vec<_>, SignerError>>()?;
+    if !reader.is_empty() {
+        return SignerError::invalid_input_err("Invalid PSBT final witness");
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: &str = "eb696a065ef48a2192da5b28b694f87544b30fae8327c4510137a922f32c6dcf";
+    const P2WPKH_ADDRESS: &str = "bc1q0yy3juscd3zfavw76g4h3eqdqzda7qyf58rj4m";
+    const P2TR_ADDRESS: &str = "bc1plxlu33ukhehnvwj48ecarkm2xnv7a58amwkt57tdx9fe5vc0n8pspdxpju";
+    const P2PKH_ADDRESS: &str = "1C2yfT2NNAPPHBqXQxxBPvguht2whJWRSi";
+
+    // BIP-174 test vectors
+    const BIP174_VALID_PSBT: &str = "cHNidP8BAHUCAAAAASaBcTce3/KF6Tet7qSze3gADAVmy7OtZGQXE8pCFxv2AAAAAAD+////AtPf9QUAAAAAGXapFNDFmQPFusKGh2DpD9UhpGZap2UgiKwA4fUFAAAAABepFDVF5uM7gyxHBQ8k0+65PJwDlIvHh7MuEwAAAQD9pQEBAAAAAAECiaPHHqtNIOA3G7ukzGmPopXJRjr6Ljl/hTPMti+VZ+UBAAAAFxYAFL4Y0VKpsBIDna89p95PUzSe7LmF/////4b4qkOnHf8USIk6UwpyN+9rRgi7st0tAXHmOuxqSJC0AQAAABcWABT+Pp7xp0XpdNkCxDVZQ6vLNL1TU/////8CAMLrCwAAAAAZdqkUhc/xCX/Z4Ai7NK9wnGIZeziXikiIrHL++E4sAAAAF6kUM5cluiHv1irHU6m80GfWx6ajnQWHAkcwRAIgJxK+IuAnDzlPVoMR3HyppolwuAJf3TskAinwf4pfOiQCIAGLONfc0xTnNMkna9b7QPZzMlvEuqFEyADS8vAtsnZcASED0uFWdJQbrUqZY3LLh+GFbTZSYG2YVi/jnF6efkE/IQUCSDBFAiEA0SuFLYXc2WHS9fSrZgZU327tzHlMDDPOXMMJ/7X85Y0CIGczio4OFyXBl/saiK9Z9R5E5CVbIBZ8hoQDHAXR8lkqASECI7cr7vCWXRC+B3jv7NYfysb3mk6haTkzgHNEZPhPKrMAAAAAAAAA";
+    const BIP174_SIGNED_UNSIGNED_TX: &str = "cHNidP8BAP0KAQIAAAACqwlJoIxa98SbghL0F+LxWrP1wz3PFTghqBOfh3pbe+QAAAAAakcwRAIgR1lmF5fAGwNrJZKJSGhiGDR9iYZLcZ4ff89X0eURZYcCIFMJ6r9Wqk2Ikf/REf3xM286KdqGbX+EhtdVRs7tr5MZASEDXNxh/HupccC1AaZGoqg7ECy0OIEhfKaC3Ibi1z+ogpL+////qwlJoIxa98SbghL0F+LxWrP1wz3PFTghqBOfh3pbe+QBAAAAAP7///8CYDvqCwAAAAAZdqkUdopAu9dAy+gdmI5x3ipNXHE5ax2IrI4kAAAAAAAAGXapFG9GILVT+glechue4O/p+gOcykWXiKwAAAAAAAABASAA4fUFAAAAABepFDVF5uM7gyxHBQ8k0+65PJwDlIvHhwEEFgAUhdE1N/LiZUBaNNuvqePdoB+4IwgAAAA=";
+    const BIP174_MISSING_UNSIGNED_TX: &str = "cHNidP8AAQD9pQEBAAAAAAECiaPHHqtNIOA3G7ukzGmPopXJRjr6Ljl/hTPMti+VZ+UBAAAAFxYAFL4Y0VKpsBIDna89p95PUzSe7LmF/////4b4qkOnHf8USIk6UwpyN+9rRgi7st0tAXHmOuxqSJC0AQAAABcWABT+Pp7xp0XpdNkCxDVZQ6vLNL1TU/////8CAMLrCwAAAAAZdqkUhc/xCX/Z4Ai7NK9wnGIZeziXikiIrHL++E4sAAAAF6kUM5cluiHv1irHU6m80GfWx6ajnQWHAkcwRAIgJxK+IuAnDzlPVoMR3HyppolwuAJf3TskAinwf4pfOiQCIAGLONfc0xTnNMkna9b7QPZzMlvEuqFEyADS8vAtsnZcASED0uFWdJQbrUqZY3LLh+GFbTZSYG2YVi/jnF6efkE/IQUCSDBFAiEA0SuFLYXc2WHS9fSrZgZU327tzHlMDDPOXMMJ/7X85Y0CIGczio4OFyXBl/saiK9Z9R5E5CVbIBZ8hoQDHAXR8lkqASECI7cr7vCWXRC+B3jv7NYfysb3mk6haTkzgHNEZPhPKrMAAAAAAA==";
+    const BIP174_DUPLICATE_KEY: &str = "cHNidP8BAHUCAAAAASaBcTce3/KF6Tet7qSze3gADAVmy7OtZGQXE8pCFxv2AAAAAAD+////AtPf9QUAAAAAGXapFNDFmQPFusKGh2DpD9UhpGZap2UgiKwA4fUFAAAAABepFDVF5uM7gyxHBQ8k0+65PJwDlIvHh7MuEwAAAQD9pQEBAAAAAAECiaPHHqtNIOA3G7ukzGmPopXJRjr6Ljl/hTPMti+VZ+UBAAAAFxYAFL4Y0VKpsBIDna89p95PUzSe7LmF/////4b4qkOnHf8USIk6UwpyN+9rRgi7st0tAXHmOuxqSJC0AQAAABcWABT+Pp7xp0XpdNkCxDVZQ6vLNL1TU/////8CAMLrCwAAAAAZdqkUhc/xCX/Z4Ai7NK9wnGIZeziXikiIrHL++E4sAAAAF6kUM5cluiHv1irHU6m80GfWx6ajnQWHAkcwRAIgJxK+IuAnDzlPVoMR3HyppolwuAJf3TskAinwf4pfOiQCIAGLONfc0xTnNMkna9b7QPZzMlvEuqFEyADS8vAtsnZcASED0uFWdJQbrUqZY3LLh+GFbTZSYG2YVi/jnF6efkE/IQUCSDBFAiEA0SuFLYXc2WHS9fSrZgZU327tzHlMDDPOXMMJ/7X85Y0CIGczio4OFyXBl/saiK9Z9R5E5CVbIBZ8hoQDHAXR8lkqASECI7cr7vCWXRC+B3jv7NYfysb3mk6haTkzgHNEZPhPKrMAAAAAAQA/AgAAAAH//////////////////////////////////////////wAAAAAA/////wEAAAAAAAAAAANqAQAAAAAAAAAA";
+
+    // Spends a P2WPKH, a P2TR and a P2PKH output of PRIVATE_KEY into a payment, an OP_RETURN "gem" and change.
+    // PSBT_V0 and SIGNED_TRANSACTION were produced with rust-bitcoin 0.32; PSBT_V2 is the same transaction in BIP-370 form.
+    const PSBT_V0: &str = "cHNidP8BANECAAAAA5+WreS0HVQz9O2jHhc47Cs29ufRQg2Upq+ZgBqI9/f/AAAAAAD9////71HhuATMidGC0nllXDqonoFbGzCf4ofZsrVdV7kOxooBAAAAAP3///85+bWX/YUYPmF+wsc34Et0l3UTAraN0i/giZg5W7MedQEAAAAA/f///wNAnAAAAAAAABYAFHUedugZkZbUVJQcRdGzoyPxQzvWAAAAAAAAAAAFagNnZW0gTgAAAAAAABYAFHkJGXIYbESesd7SK3jkDQCb3wCJUPgMAAABAR8wdQAAAAAAABYAFHkJGXIYbESesd7SK3jkDQCb3wCJAAEBKyBOAAAAAAAAIlEg+b/Ix5a+bzY6VT5x0dtqNNnu0P3brLp5bTFTmjMPmcMAAQB0AQAAAAGflq3ktB1UM/Ttox4XOOwrNvbn0UINlKavmYAaiPf3/wMAAAAA/f///wLoAwAAAAAAABYAFHUedugZkZbUVJQcRdGzoyPxQzvWmDoAAAAAAAAZdqkUeQkZchhsRJ6x3tIreOQNAJvfAImIrAAAAAAAAAAA";
+    const PSBT_V2: &str = "cHNidP8BAgQCAAAAAQMEUPgMAAEEAQMBBQEDAfsEAgAAAAABAR8wdQAAAAAAABYAFHkJGXIYbESesd7SK3jkDQCb3wCJAQ4gn5at5LQdVDP07aMeFzjsKzb259FCDZSmr5mAGoj39/8BDwQAAAAAARAE/f///wABASsgTgAAAAAAACJRIPm/yMeWvm82OlU+cdHbajTZ7tD926y6eW0xU5ozD5nDAQ4g71HhuATMidGC0nllXDqonoFbGzCf4ofZsrVdV7kOxooBDwQBAAAAARAE/f///wABAHQBAAAAAZ+WreS0HVQz9O2jHhc47Cs29ufRQg2Upq+ZgBqI9/f/AwAAAAD9////AugDAAAAAAAAFgAUdR526BmRltRUlBxF0bOjI/FDO9aYOgAAAAAAABl2qRR5CRlyGGxEnrHe0it45A0Am98AiYisAAAAAAEOIDn5tZf9hRg+YX7CxzfgS3SXdRMCto3SL+CJmDlbsx51AQ8EAQAAAAEQBP3///8AAQMIQJwAAAAAAAABBBYAFHUedugZkZbUVJQcRdGzoyPxQzvWAAEDCAAAAAAAAAAAAQQFagNnZW0AAQMIIE4AAAAAAAABBBYAFHkJGXIYbESesd7SK3jkDQCb3wCJAA==";
+    const SIGNED_TRANSACTION: &str = "020000000001039f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff0000000000fdffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000fdffffff39f9b597fd85183e617ec2c737e04b7497751302b68dd22fe08998395bb31e75010000006b483045022100da0b7356b39080e22a67efadb85dff75a74695d29379e71bf03c61611e16b8f8022039ea0d06af7d1eb38855bda13869739e39b0ee577cf3cc332eddc069ad3b8865012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a26873fdffffff03409c000000000000160014751e76e8199196d454941c45d1b3a323f1433bd60000000000000000056a0367656d204e00000000000016001479091972186c449eb1ded22b78e40d009bdf008902483045022100bcc04363eb60ff31b9e00f63cbb9aca676c07f2c06e7bcc7a27f08c87211178702205f0bb9ab2acbc86feb722894b1a774e55c2d2c52aea02b527174a822a96f3f28012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a2687301408b57b6c170c5e99aeb3561a01b83a01a01142d953a926973e653f8b69a95e3e2a505ad49061382046481a930fc5e36980d3b1b008f5802f713c99e31e41e26580050f80c00";
+
+    fn sign_all(psbt: &mut Psbt) {
+        let private_key = hex::decode(PRIVATE_KEY).unwrap();
+        for address in [P2WPKH_ADDRESS, P2TR_ADDRESS, P2PKH_ADDRESS] {
+            assert_eq!(psbt.sign(address, &private_key).unwrap(), 1);
+        }
+    }
+
+    #[test]
+    fn test_parse_bip174_valid_vector() {
+        let psbt = Psbt::from_string(BitcoinChain::Bitcoin, BIP174_VALID_PSBT).unwrap();
+        assert_eq!(psbt.version(), 0);
+        assert_eq!(psbt.to_base64(), BIP174_VALID_PSBT);
+
+        let hex_psbt = Psbt::from_string(BitcoinChain::Bitcoin, &hex::encode(psbt.encode())).unwrap();
+        assert_eq!(hex_psbt.to_base64(), BIP174_VALID_PSBT);
+
+        let summary = psbt.summary("36YhUacEtcnkfhSbxwm11wDCexLGBLgJF6").unwrap();
+        assert_eq!(summary.inputs[0].transaction_id, "f61b1742ca13176464adb3cb66050c00787bb3a4eead37e985f2df1e37718126");
+        assert_eq!(summary.inputs[0].value, Some(200_000_000));
+        assert!(!summary.inputs[0].is_mine);
+        assert_eq!(summary.outputs[0].address.as_deref(), Some("1L2tGENeoh4mSoiUZrSbs1J3jazSdJH9QS"));
+        assert!(summary.outputs[1].is_change);
+        assert_eq!(summary.sent, 99_999_699);
+        assert_eq!(summary.fee, Some(301));
+    }
+
+    #[test]
+    fn test_parse_bip174_invalid_vectors() {
+        for psbt in [BIP174_SIGNED_UNSIGNED_TX, BIP174_MISSING_UNSIGNED_TX, BIP174_DUPLICATE_KEY] {
+            assert!(Psbt::from_string(BitcoinChain::Bitcoin, psbt).is_err());
+        }
+        assert!(Psbt::from_string(BitcoinChain::Doge, BIP174_VALID_PSBT).is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        let psbt = Psbt::from_string(BitcoinChain::Bitcoin, PSBT_V0).unwrap();
+        let summary = psbt.summary(P2WPKH_ADDRESS).unwrap();
+
+        assert_eq!(summary.inputs.iter().map(|input| input.is_mine).collect::<Vec<_>>(), vec![true, false, false]);
+        assert_eq!(summary.inputs[1].address.as_deref(), Some(P2TR_ADDRESS));
+        assert_eq!(summary.inputs[2].address.as_deref(), Some(P2PKH_ADDRESS));
+        assert!(summary.inputs.iter().all(|input| !input.is_signed));
+        assert_eq!(summary.outputs[0].address.as_deref(), Some("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"));
+        assert_eq!(summary.outputs[1].address, None);
+        assert!(summary.outputs[2].is_change);
+        assert_eq!(summary.total_input, Some(65_000));
+        assert_eq!(summary.total_output, 60_000);
+        assert_eq!(summary.sent, 40_000);
+        assert_eq!(summary.fee, Some(5_000));
+    }
+
+    #[test]
+    fn test_sign_finalize_extract() {
+        let mut psbt = Psbt::from_string(BitcoinChain::Bitcoin, PSBT_V0).unwrap();
+        sign_all(&mut psbt);
+
+        assert!(psbt.summary(P2WPKH_ADDRESS).unwrap().inputs.iter().all(|input| input.is_signed));
+        assert!(psbt.extract_transaction().is_err());
+
+        // Signatures survive serialization
+        let mut psbt = Psbt::from_string(BitcoinChain::Bitcoin, &psbt.to_base64()).unwrap();
+        psbt.finalize().unwrap();
+        assert!(psbt.is_finalized());
+        assert!(psbt.inputs.iter().all(|map| map.entries(PSBT_IN_PARTIAL_SIG).next().is_none()));
+
+        let transaction = psbt.extract_transaction().unwrap();
+        assert_eq!(hex::encode(transaction.encode()), SIGNED_TRANSACTION);
+        assert_eq!(
+            OutPoint {
+                hash: transaction.hash(),
+                index: 0
+            }
+            .transaction_id(),
+            "2e54554a0e08d7c9b7f90160fc9768fe6b327bbb747bbc2518f50fd1e850833c"
+        );
+    }
+
+    #[test]
+    fn test_sign_psbt_v2() {
+        let mut psbt = Psbt::from_string(BitcoinChain::Bitcoin, PSBT_V2).unwrap();
+        assert_eq!(psbt.version(), 2);
+        assert_eq!(
+            hex::encode(psbt.unsigned_transaction().unwrap().encode()),
+            hex::encode(Psbt::from_string(BitcoinChain::Bitcoin, PSBT_V0).unwrap().unsigned_transaction().unwrap().encode())
+        );
+
+        sign_all(&mut psbt);
+        psbt.finalize().unwrap();
+
+        assert_eq!(hex::encode(psbt.extract_transaction().unwrap().encode()), SIGNED_TRANSACTION);
+    }
+
+    #[test]
+    fn test_sign_skips_foreign_inputs() {
+        let mut psbt = Psbt::from_string(BitcoinChain::Bitcoin, PSBT_V0).unwrap();
+        let private_key = hex::decode(PRIVATE_KEY).unwrap();
+
+        assert!(psbt.sign("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", &private_key).is_err());
+        assert_eq!(psbt.sign(P2WPKH_ADDRESS, &private_key).unwrap(), 1);
+
+        psbt.finalize().unwrap();
+        assert!(!psbt.is_finalized());
+        assert!(psbt.extract_transaction().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use gem_hash::ripemd::hash160;
+use primitives::SignerError;
+use signer::{secp256k1_public_key, sign_taproot_digest};
+
+use super::{
+    PSBT_IN_BIP32_DERIVATION, PSBT_IN_FINAL_SCRIPTSIG, PSBT_IN_FINAL_SCRIPTWITNESS, PSBT_IN_PARTIAL_SIG, PSBT_IN_REDEEM_SCRIPT, PSBT_IN_SIGHASH_TYPE, PSBT_IN_TAP_BIP32_DERIVATION,
+    PSBT_IN_TAP_INTERNAL_KEY, PSBT_IN_TAP_KEY_SIG, PSBT_IN_TAP_LEAF_SCRIPT, PSBT_IN_TAP_MERKLE_ROOT, PSBT_IN_TAP_SCRIPT_SIG, PSBT_IN_WITNESS_SCRIPT, Psbt, encode_witness,
+};
+use crate::signer::script::{OutputScript, ScriptType, p2pkh_script, p2sh_p2wpkh_redeem_script, push_data};
+use crate::signer::sighash::{SIGHASH_ALL, SIGHASH_DEFAULT, legacy_sighash, taproot_key_spend_sighash, witness_v0_sighash};
+use crate::signer::signing::{sign_ecdsa, verify_sender};
+
+// Signature-construction fields a finalizer clears (BIP-174), including the hash preimages 0x0a-0x0d
+const PSBT_IN_CLEARED_ON_FINALIZE: &[u8] = &[
+    PSBT_IN_PARTIAL_SIG,
+    PSBT_IN_SIGHASH_TYPE,
+    PSBT_IN_REDEEM_SCRIPT,
+    PSBT_IN_WITNESS_SCRIPT,
+    PSBT_IN_BIP32_DERIVATION,
+    0x0a,
+    0x0b,
+    0x0c,
+    0x0d,
+    PSBT_IN_TAP_KEY_SIG,
+    PSBT_IN_TAP_SCRIPT_SIG,
+    PSBT_IN_TAP_LEAF_SCRIPT,
+    PSBT_IN_TAP_BIP32_DERIVATION,
+    PSBT_IN_TAP_INTERNAL_KEY,
+    PSBT_IN_TAP_MERKLE_ROOT,
+];
+
+impl Psbt {
+    pub fn sign(&mut self, address: &str, private_key: &[u8]) -> Result<usize, SignerError> {
+        let public_key = secp256k1_public_key(private_key)?;
+        let sender = OutputScript::from_address(self.chain, address)?;
+        verify_sender(self.chain, &sender, &public_key, private_key)?;
+        let owner = sender.script_pubkey();
+
+        let transaction = self.unsigned_transaction()?;
+        let mut signed = 0;
+        for index in 0..transaction.inputs.len() {
+            if self.is_input_finalized(index) || self.spent_output(index, &transaction.inputs[index].previous_output)?.is_none() || transaction.inputs[index].script_pubkey != owner
+            {
+                continue;
+            }
+            let sighash_type = self.inputs[index].get_u32(PSBT_IN_SIGHASH_TYPE)?;
+            let map = &mut self.inputs[index];
+            match sender.script_type {
+                ScriptType::P2pkh | ScriptType::P2wpkh | ScriptType::P2sh => {
+                    if sighash_type.is_some_and(|sighash_type| sighash_type != SIGHASH_ALL) {
+                        return SignerError::invalid_input_err(format!("PSBT input {index} requests an unsupported sighash type"));
+                    }
+                    let script_code = p2pkh_script(&hash160(&public_key));
+                    let sighash = match sender.script_type {
+                        ScriptType::P2pkh => legacy_sighash(&transaction, index, &script_code),
+                        _ => witness_v0_sighash(&transaction, index, &script_code, SIGHASH_ALL),
+                    };
+                    let signature = sign_ecdsa(&sighash, private_key, SIGHASH_ALL)?;
+                    map.insert([&[PSBT_IN_PARTIAL_SIG][..], &public_key].concat(), signature);
+                    if let ScriptType::P2sh = sender.script_type {
+                        map.insert(vec![PSBT_IN_REDEEM_SCRIPT], p2sh_p2wpkh_redeem_script(&public_key));
+                    }
+                }
+                ScriptType::P2tr => {
+                    if sighash_type.is_some_and(|sighash_type| sighash_type != SIGHASH_DEFAULT as u32) {
+                        return SignerError::invalid_input_err(format!("PSBT input {index} requests an unsupported sighash type"));
+                    }
+                    if map.get(PSBT_IN_TAP_MERKLE_ROOT).is_some() {
+                        return SignerError::invalid_input_err(format!("PSBT input {index} commits to a taproot script tree"));
+                    }
+                    // BIP-341 commits to every spent output, so all of them must be known
+                    for (other, input) in transaction.inputs.iter().enumerate() {
+                        if self.spent_output(other, &input.previous_output)?.is_none() {
+                            return SignerError::invalid_input_err(format!("PSBT input {other} is missing its UTXO"));
+                        }
+                    }
+                    let sighash = taproot_key_spend_sighash(&transaction, index);
+                    self.inputs[index].insert(vec![PSBT_IN_TAP_KEY_SIG], sign_taproot_digest(&sighash, private_key)?);
+                }
+                ScriptType::P2wsh => return SignerError::invalid_input_err("P2WSH inputs are not supported"),
+            }
+            signed += 1;
+        }
+        Ok(signed)
+    }
+
+    pub fn finalize(&mut self) -> Result<(), SignerError> {
+        let transaction = self.unsigned_transaction()?;
+        for index in 0..transaction.inputs.len() {
+            if self.is_input_finalized(index) || self.spent_output(index, &transaction.inputs[index].previous_output)?.is_none() {
+                continue;
+            }
+            let Some(script) = OutputScript::from_script_pubkey(&transaction.inputs[index].script_pubkey) else {
+                continue;
+            };
+            let map = &mut self.inputs[index];
+            let partial_signature = |key_hash: &[u8]| {
+                map.entries(PSBT_IN_PARTIAL_SIG)
+                    .find(|(public_key, _)| hash160(public_key) == key_hash)
+                    .map(|(public_key, signature)| (public_key.to_vec(), signature.to_vec()))
+            };
+            let (script_sig, witness) = match script.script_type {
+                ScriptType::P2pkh => match partial_signature(&script.program) {
+                    Some((public_key, signature)) => ([push_data(&signature), push_data(&public_key)].concat(), vec![]),
+                    None => continue,
+                },
+                ScriptType::P2wpkh => match partial_signature(&script.program) {
+                    Some((public_key, signature)) => (vec![], vec![signature, public_key]),
+                    None => continue,
+                },
+                ScriptType::P2sh => {
+                    let Some(redeem_script) = map.get(PSBT_IN_REDEEM_SCRIPT).map(<[u8]>::to_vec) else {
+                        continue;
+                    };
+                    let nested = OutputScript::from_script_pubkey(&redeem_script).filter(|nested| nested.script_type == ScriptType::P2wpkh);
+                    match nested
+                        .filter(|_| hash160(&redeem_script) == script.program.as_slice())
+                        .and_then(|nested| partial_signature(&nested.program))
+                    {
+                        Some((public_key, signature)) => (push_data(&redeem_script), vec![signature, public_key]),
+                        None => continue,
+                    }
+                }
+                ScriptType::P2tr => match map.get(PSBT_IN_TAP_KEY_SIG) {
+                    Some(signature) => (vec![], vec![signature.to_vec()]),
+                    None => continue,
+                },
+                ScriptType::P2wsh => continue,
+            };
+
+            map.remove_types(PSBT_IN_CLEARED_ON_FINALIZE);
+            if !script_sig.is_empty() {
+                map.insert(vec![PSBT_IN_FINAL_SCRIPTSIG], script_sig);
+            }
+            if !witness.is_empty() {
+                map.insert(vec![PSBT_IN_FINAL_SCRIPTWITNESS], encode_witness(&witness));
+            }
+        }
+        Ok(())
+    }
+}
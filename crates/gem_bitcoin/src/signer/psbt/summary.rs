@@ -0,0 +1,93 @@
+use primitives::SignerError;
+
+use super::{PSBT_IN_PARTIAL_SIG, PSBT_IN_TAP_KEY_SIG, Psbt};
+use crate::signer::script::OutputScript;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsbtInputSummary {
+    pub transaction_id: String,
+    pub vout: u32,
+    pub address: Option<String>,
+    pub value: Option<u64>,
+    pub is_mine: bool,
+    pub is_signed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsbtOutputSummary {
+    pub address: Option<String>,
+    pub value: u64,
+    pub is_change: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsbtSummary {
+    pub inputs: Vec<PsbtInputSummary>,
+    pub outputs: Vec<PsbtOutputSummary>,
+    pub total_input: Option<u64>,
+    pub total_output: u64,
+    pub sent: u64,
+    pub fee: Option<u64>,
+}
+
+impl Psbt {
+    pub fn summary(&self, address: &str) -> Result<PsbtSummary, SignerError> {
+        let owner = OutputScript::from_address(self.chain, address)?.script_pubkey();
+        let transaction = self.unsigned_transaction()?;
+
+        let mut inputs = vec![];
+        for (index, input) in transaction.inputs.iter().enumerate() {
+            let known = self.spent_output(index, &input.previous_output)?.is_some();
+            let map = &self.inputs[index];
+            inputs.push(PsbtInputSummary {
+                transaction_id: input.previous_output.transaction_id(),
+                vout: input.previous_output.index,
+                address: known.then(|| self.script_address(&input.script_pubkey)).flatten(),
+                value: known.then_some(input.value),
+                is_mine: known && input.script_pubkey == owner,
+                is_signed: self.is_input_finalized(index) || map.entries(PSBT_IN_PARTIAL_SIG).next().is_some() || map.get(PSBT_IN_TAP_KEY_SIG).is_some(),
+            });
+        }
+
+        let outputs: Vec<PsbtOutputSummary> = transaction
+            .outputs
+            .iter()
+            .map(|output| PsbtOutputSummary {
+                address: self.script_address(&output.script_pubkey),
+                value: output.value,
+                is_change: output.script_pubkey == owner,
+            })
+            .collect();
+
+        let total_input = inputs.iter().try_fold(0u64, |total, input| input.value.and_then(|value| total.checked_add(value)));
+        let total_output = checked_sum(outputs.iter().map(|output| output.value))?;
+        let sent = checked_sum(outputs.iter().filter(|output| !output.is_change).map(|output| output.value))?;
+        let fee = match total_input {
+            Some(total_input) => Some(
+                total_input
+                    .checked_sub(total_output)
+                    .ok_or_else(|| SignerError::invalid_input("PSBT outputs exceed inputs"))?,
+            ),
+            None => None,
+        };
+
+        Ok(PsbtSummary {
+            inputs,
+            outputs,
+            total_input,
+            total_output,
+            sent,
+            fee,
+        })
+    }
+
+    fn script_address(&self, script_pubkey: &[u8]) -> Option<String> {
+        OutputScript::from_script_pubkey(script_pubkey).and_then(|script| script.address(self.chain).ok())
+    }
+}
+
+fn checked_sum(mut values: impl Iterator<Item = u64>) -> Result<u64, SignerError> {
+    values
+        .try_fold(0u64, |total, value| total.checked_add(value))
+        .ok_or_else(|| SignerError::invalid_input("PSBT output values overflow"))
+}
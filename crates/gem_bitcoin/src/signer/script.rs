@@ -0,0 +1,328 @@
+use gem_hash::ripemd::hash160;
+use primitives::{BitcoinChain, SignerError};
+
+use crate::models::Address;
+
+const OP_0: u8 = 0x00;
+const OP_1: u8 = 0x51;
+const OP_PUSHDATA1: u8 = 0x4c;
+const OP_RETURN: u8 = 0x6a;
+const OP_DUP: u8 = 0x76;
+const OP_EQUAL: u8 = 0x87;
+const OP_EQUALVERIFY: u8 = 0x88;
+const OP_HASH160: u8 = 0xa9;
+const OP_CHECKSIG: u8 = 0xac;
+
+const MAX_OP_RETURN_SIZE: usize = 80;
+
+const CASHADDR_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CASHADDR_PREFIX: &str = "bitcoincash";
+const CASHADDR_CHECKSUM_LENGTH: usize = 8;
+const CASHADDR_TYPE_P2PKH: u8 = 0;
+const CASHADDR_TYPE_P2SH: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptType {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    P2tr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputScript {
+    pub script_type: ScriptType,
+    pub program: Vec<u8>,
+}
+
+struct AddressPrefixes {
+    bech32_hrp: Option<&'static str>,
+    p2pkh: &'static [u8],
+    p2sh: &'static [&'static [u8]],
+}
+
+fn address_prefixes(chain: BitcoinChain) -> AddressPrefixes {
+    match chain {
+        BitcoinChain::Bitcoin => AddressPrefixes {
+            bech32_hrp: Some("bc"),
+            p2pkh: &[0x00],
+            p2sh: &[&[0x05]],
+        },
+        BitcoinChain::Litecoin => AddressPrefixes {
+            bech32_hrp: Some("ltc"),
+            p2pkh: &[0x30],
+            p2sh: &[&[0x32], &[0x05]],
+        },
+        BitcoinChain::Doge => AddressPrefixes {
+            bech32_hrp: None,
+            p2pkh: &[0x1e],
+            p2sh: &[&[0x16]],
+        },
+        BitcoinChain::BitcoinCash => AddressPrefixes {
+            bech32_hrp: None,
+            p2pkh: &[0x00],
+            p2sh: &[&[0x05]],
+        },
+        BitcoinChain::Zcash => AddressPrefixes {
+            bech32_hrp: None,
+            p2pkh: &[0x1c, 0xb8],
+            p2sh: &[&[0x1c, 0xbd]],
+        },
+    }
+}
+
+impl OutputScript {
+    pub fn new(script_type: ScriptType, program: Vec<u8>) -> Self {
+        Self { script_type, program }
+    }
+
+    pub fn from_address(chain: BitcoinChain, address: &str) -> Result<Self, SignerError> {
+        let prefixes = address_prefixes(chain);
+
+        if let Some(hrp) = prefixes.bech32_hrp
+            && let Ok((address_hrp, version, program)) = bech32::segwit::decode(address)
+        {
+            if address_hrp.to_lowercase() != hrp {
+                return SignerError::invalid_input_err(format!("Invalid {} address: {}", chain.as_ref(), address));
+            }
+            return Self::from_witness_program(version.to_u8(), program);
+        }
+
+        let cashaddr = match chain {
+            BitcoinChain::BitcoinCash => decode_cashaddr(address)?,
+            BitcoinChain::Bitcoin | BitcoinChain::Litecoin | BitcoinChain::Doge | BitcoinChain::Zcash => None,
+        };
+        if let Some(script) = cashaddr {
+            return Ok(script);
+        }
+
+        let decoded = bs58::decode(address)
+            .with_check(None)
+            .into_vec()
+            .map_err(|_| SignerError::invalid_input(format!("Invalid {} address: {}", chain.as_ref(), address)))?;
+
+        if let Some(hash) = decoded.strip_prefix(prefixes.p2pkh)
+            && hash.len() == 20
+        {
+            return Ok(Self::new(ScriptType::P2pkh, hash.to_vec()));
+        }
+        if let Some(hash) = prefixes.p2sh.iter().find_map(|prefix| decoded.strip_prefix(*prefix))
+            && hash.len() == 20
+        {
+            return Ok(Self::new(ScriptType::P2sh, hash.to_vec()));
+        }
+        SignerError::invalid_input_err(format!("Unsupported {} address: {}", chain.as_ref(), address))
+    }
+
+    fn from_witness_program(version: u8, program: Vec<u8>) -> Result<Self, SignerError> {
+        match (version, program.len()) {
+            (0, 20) => Ok(Self::new(ScriptType::P2wpkh, program)),
+            (0, 32) => Ok(Self::new(ScriptType::P2wsh, program)),
+            (1, 32) => Ok(Self::new(ScriptType::P2tr, program)),
+            _ => SignerError::invalid_input_err(format!("Unsupported witness program version {version}")),
+        }
+    }
+
+    pub fn from_script_pubkey(script: &[u8]) -> Option<Self> {
+        match script {
+            [OP_DUP, OP_HASH160, 0x14, hash @ .., OP_EQUALVERIFY, OP_CHECKSIG] if hash.len() == 20 => Some(Self::new(ScriptType::P2pkh, hash.to_vec())),
+            [OP_HASH160, 0x14, hash @ .., OP_EQUAL] if hash.len() == 20 => Some(Self::new(ScriptType::P2sh, hash.to_vec())),
+            [OP_0, 0x14, hash @ ..] if hash.len() == 20 => Some(Self::new(ScriptType::P2wpkh, hash.to_vec())),
+            [OP_0, 0x20, hash @ ..] if hash.len() == 32 => Some(Self::new(ScriptType::P2wsh, hash.to_vec())),
+            [OP_1, 0x20, key @ ..] if key.len() == 32 => Some(Self::new(ScriptType::P2tr, key.to_vec())),
+            _ => None,
+        }
+    }
+
+    pub fn address(&self, chain: BitcoinChain) -> Result<String, SignerError> {
+        let prefixes = address_prefixes(chain);
+        match self.script_type {
+            ScriptType::P2pkh => Ok(encode_base58_check(prefixes.p2pkh, &self.program)),
+            ScriptType::P2sh => Ok(encode_base58_check(prefixes.p2sh[0], &self.program)),
+            ScriptType::P2wpkh | ScriptType::P2wsh | ScriptType::P2tr => {
+                let hrp = prefixes
+                    .bech32_hrp
+                    .and_then(|hrp| bech32::Hrp::parse(hrp).ok())
+                    .ok_or_else(|| SignerError::invalid_input(format!("{} does not support segwit addresses", chain.as_ref())))?;
+                let version = if let ScriptType::P2tr = self.script_type {
+                    bech32::segwit::VERSION_1
+                } else {
+                    bech32::segwit::VERSION_0
+                };
+                bech32::segwit::encode(hrp, version, &self.program).map_err(SignerError::from_display)
+            }
+        }
+    }
+
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        match self.script_type {
+            ScriptType::P2pkh => p2pkh_script(&self.program),
+            ScriptType::P2sh => [&[OP_HASH160, 0x14][..], &self.program, &[OP_EQUAL]].concat(),
+            ScriptType::P2wpkh | ScriptType::P2wsh => [&[OP_0, self.program.len() as u8][..], &self.program].concat(),
+            ScriptType::P2tr => [&[OP_1, 0x20][..], &self.program].concat(),
+        }
+    }
+}
+
+pub fn p2pkh_script(public_key_hash: &[u8]) -> Vec<u8> {
+    [&[OP_DUP, OP_HASH160, 0x14][..], public_key_hash, &[OP_EQUALVERIFY, OP_CHECKSIG]].concat()
+}
+
+pub fn p2sh_p2wpkh_redeem_script(public_key: &[u8]) -> Vec<u8> {
+    [&[OP_0, 0x14][..], &hash160(public_key)].concat()
+}
+
+pub fn op_return_script(data: &[u8]) -> Result<Vec<u8>, SignerError> {
+    if data.len() > MAX_OP_RETURN_SIZE {
+        return SignerError::invalid_input_err(format!("Memo exceeds {MAX_OP_RETURN_SIZE} bytes"));
+    }
+    Ok([vec![OP_RETURN], push_data(data)].concat())
+}
+
+pub fn push_data(data: &[u8]) -> Vec<u8> {
+    let prefix = if data.len() < OP_PUSHDATA1 as usize {
+        vec![data.len() as u8]
+    } else {
+        vec![OP_PUSHDATA1, data.len() as u8]
+    };
+    [prefix, data.to_vec()].concat()
+}
+
+fn encode_base58_check(prefix: &[u8], hash: &[u8]) -> String {
+    bs58::encode([prefix, hash].concat()).with_check().into_string()
+}
+
+fn decode_cashaddr(address: &str) -> Result<Option<OutputScript>, SignerError> {
+    let payload = Address::new(address.to_lowercase(), primitives::Chain::BitcoinCash).short().to_string();
+    if !payload.starts_with(['q', 'p']) {
+        return Ok(None);
+    }
+    let Some(values) = payload
+        .bytes()
+        .map(|c| CASHADDR_CHARSET.iter().position(|x| *x == c).map(|x| x as u8))
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return Ok(None);
+    };
+    if values.len() <= CASHADDR_CHECKSUM_LENGTH {
+        return Ok(None);
+    }
+
+    let checksum_input = [CASHADDR_PREFIX.bytes().map(|c| c & 0x1f).collect(), vec![0], values.clone()].concat();
+    if cashaddr_polymod(&checksum_input) != 0 {
+        return SignerError::invalid_input_err(format!("Invalid cashaddr checksum: {address}"));
+    }
+
+    let data = convert_bits(&values[..values.len() - CASHADDR_CHECKSUM_LENGTH], 5, 8).ok_or_else(|| SignerError::invalid_input(format!("Invalid cashaddr: {address}")))?;
+    let (version, hash) = data.split_first().ok_or_else(|| SignerError::invalid_input(format!("Invalid cashaddr: {address}")))?;
+    if hash.len() != 20 {
+        return SignerError::invalid_input_err(format!("Unsupported cashaddr hash size: {address}"));
+    }
+    match version >> 3 {
+        CASHADDR_TYPE_P2PKH => Ok(Some(OutputScript::new(ScriptType::P2pkh, hash.to_vec()))),
+        CASHADDR_TYPE_P2SH => Ok(Some(OutputScript::new(ScriptType::P2sh, hash.to_vec()))),
+        _ => SignerError::invalid_input_err(format!("Unsupported cashaddr type: {address}")),
+    }
+}
+
+fn cashaddr_polymod(values: &[u8]) -> u64 {
+    const GENERATORS: [u64; 5] = [0x98f2bc8e61, 0x79b76d99e2, 0xf33e5fb3c4, 0xae2eabe2a8, 0x1e4f43e470];
+
+    let mut checksum: u64 = 1;
+    for value in values {
+        let top = checksum >> 35;
+        checksum = ((checksum & 0x07ffffffff) << 5) ^ (*value as u64);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum ^ 1
+}
+
+fn convert_bits(data: &[u8], from: u32, to: u32) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max = (1u32 << to) - 1;
+    let mut result = Vec::with_capacity(data.len() * from as usize / to as usize);
+    for value in data {
+        acc = (acc << from) | *value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return None;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_script_from_address() {
+        let script = OutputScript::from_address(BitcoinChain::Bitcoin, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "0014751e76e8199196d454941c45d1b3a323f1433bd6");
+
+        let script = OutputScript::from_address(BitcoinChain::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "76a91477bff20c60e522dfaa3350c39b030a5d004e839a88ac");
+
+        let script = OutputScript::from_address(BitcoinChain::Bitcoin, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "a914b472a266d0bd89c13706a4132ccfb16f7c3b9fcb87");
+
+        let script = OutputScript::from_address(BitcoinChain::Bitcoin, "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "5120a37c3903c8d0db6512e2b40b0dffa05e5a3ab73603ce8c9c4b7771e5412328f9");
+
+        let script = OutputScript::from_address(BitcoinChain::BitcoinCash, "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "76a91476a04053bda0a88bda5177b86a15c3b29f55987388ac");
+
+        let script = OutputScript::from_address(BitcoinChain::BitcoinCash, "ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "a91476a04053bda0a88bda5177b86a15c3b29f55987387");
+
+        let script = OutputScript::from_address(BitcoinChain::Litecoin, "MQMHBtvnBfxTzt3K2bdxgSE7qZPHSXWsGM").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "a914b472a266d0bd89c13706a4132ccfb16f7c3b9fcb87");
+
+        let script = OutputScript::from_address(BitcoinChain::Litecoin, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").unwrap();
+        assert_eq!(hex::encode(script.script_pubkey()), "a914b472a266d0bd89c13706a4132ccfb16f7c3b9fcb87");
+    }
+
+    #[test]
+    fn test_output_script_address_round_trip() {
+        for (chain, address) in [
+            (BitcoinChain::Bitcoin, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            (BitcoinChain::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"),
+            (BitcoinChain::Bitcoin, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+            (BitcoinChain::Bitcoin, "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297"),
+            (BitcoinChain::Litecoin, "MQMHBtvnBfxTzt3K2bdxgSE7qZPHSXWsGM"),
+            (BitcoinChain::Doge, "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE"),
+        ] {
+            let script = OutputScript::from_address(chain, address).unwrap();
+            let parsed = OutputScript::from_script_pubkey(&script.script_pubkey()).unwrap();
+            assert_eq!(parsed, script);
+            assert_eq!(parsed.address(chain).unwrap(), address);
+        }
+
+        assert!(OutputScript::from_script_pubkey(&op_return_script(b"memo").unwrap()).is_none());
+        assert!(OutputScript::new(ScriptType::P2wpkh, vec![0; 20]).address(BitcoinChain::Doge).is_err());
+    }
+
+    #[test]
+    fn test_output_script_rejects_wrong_network() {
+        assert!(OutputScript::from_address(BitcoinChain::Litecoin, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err());
+        assert!(OutputScript::from_address(BitcoinChain::Doge, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_err());
+        assert!(OutputScript::from_address(BitcoinChain::BitcoinCash, "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b").is_err());
+    }
+
+    #[test]
+    fn test_op_return_script() {
+        assert_eq!(hex::encode(op_return_script(b"memo").unwrap()), "6a046d656d6f");
+        assert_eq!(&op_return_script(&[0u8; 80]).unwrap()[..3], &[OP_RETURN, OP_PUSHDATA1, 80]);
+        assert!(op_return_script(&[0u8; 81]).is_err());
+    }
+}
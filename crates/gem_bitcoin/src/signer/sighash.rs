@@ -0,0 +1,204 @@
+use gem_hash::blake2::blake2b_256_personal;
+use gem_hash::sha2::sha256;
+use signer::tagged_hash;
+
+use super::transaction::{BitcoinTransaction, ZCASH_SAPLING_VERSION_GROUP_ID, encode_script};
+
+pub const SIGHASH_ALL: u32 = 0x01;
+pub const SIGHASH_FORKID: u32 = 0x40;
+pub const SIGHASH_DEFAULT: u8 = 0x00;
+
+const TAP_SIGHASH_TAG: &[u8] = b"TapSighash";
+const TAP_SIGHASH_EPOCH: u8 = 0x00;
+
+const ZCASH_SIGHASH_PERSONALIZATION: &[u8] = b"ZcashSigHash";
+const ZCASH_PREVOUTS_PERSONALIZATION: &[u8] = b"ZcashPrevoutHash";
+const ZCASH_SEQUENCE_PERSONALIZATION: &[u8] = b"ZcashSequencHash";
+const ZCASH_OUTPUTS_PERSONALIZATION: &[u8] = b"ZcashOutputsHash";
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    sha256(&sha256(data))
+}
+
+fn prevouts(transaction: &BitcoinTransaction) -> Vec<u8> {
+    transaction.inputs.iter().flat_map(|input| input.previous_output.encode()).collect()
+}
+
+fn sequences(transaction: &BitcoinTransaction) -> Vec<u8> {
+    transaction.inputs.iter().flat_map(|input| input.sequence.to_le_bytes()).collect()
+}
+
+fn outputs(transaction: &BitcoinTransaction) -> Vec<u8> {
+    transaction.outputs.iter().flat_map(|output| output.encode()).collect()
+}
+
+pub fn legacy_sighash(transaction: &BitcoinTransaction, index: usize, script_code: &[u8]) -> [u8; 32] {
+    let mut unsigned = transaction.clone();
+    for (i, input) in unsigned.inputs.iter_mut().enumerate() {
+        input.script_sig = if i == index { script_code.to_vec() } else { vec![] };
+        input.witness = vec![];
+    }
+    let mut data = unsigned.encode_without_witness();
+    data.extend_from_slice(&SIGHASH_ALL.to_le_bytes());
+    double_sha256(&data)
+}
+
+pub fn witness_v0_sighash(transaction: &BitcoinTransaction, index: usize, script_code: &[u8], sighash_type: u32) -> [u8; 32] {
+    let input = &transaction.inputs[index];
+
+    let mut data = transaction.version.to_le_bytes().to_vec();
+    data.extend_from_slice(&double_sha256(&prevouts(transaction)));
+    data.extend_from_slice(&double_sha256(&sequences(transaction)));
+    data.extend(input.previous_output.encode());
+    data.extend(encode_script(script_code));
+    data.extend_from_slice(&input.value.to_le_bytes());
+    data.extend_from_slice(&input.sequence.to_le_bytes());
+    data.extend_from_slice(&double_sha256(&outputs(transaction)));
+    data.extend_from_slice(&transaction.lock_time.to_le_bytes());
+    data.extend_from_slice(&sighash_type.to_le_bytes());
+    double_sha256(&data)
+}
+
+pub fn taproot_key_spend_sighash(transaction: &BitcoinTransaction, index: usize) -> [u8; 32] {
+    let amounts: Vec<u8> = transaction.inputs.iter().flat_map(|input| input.value.to_le_bytes()).collect();
+    let script_pubkeys: Vec<u8> = transaction.inputs.iter().flat_map(|input| encode_script(&input.script_pubkey)).collect();
+
+    let mut data = vec![TAP_SIGHASH_EPOCH, SIGHASH_DEFAULT];
+    data.extend_from_slice(&transaction.version.to_le_bytes());
+    data.extend_from_slice(&transaction.lock_time.to_le_bytes());
+    data.extend_from_slice(&sha256(&prevouts(transaction)));
+    data.extend_from_slice(&sha256(&amounts));
+    data.extend_from_slice(&sha256(&script_pubkeys));
+    data.extend_from_slice(&sha256(&sequences(transaction)));
+    data.extend_from_slice(&sha256(&outputs(transaction)));
+    data.push(0x00); // spend_type: key path, no annex
+    data.extend_from_slice(&(index as u32).to_le_bytes());
+    tagged_hash(TAP_SIGHASH_TAG, &data)
+}
+
+pub fn zcash_sighash(transaction: &BitcoinTransaction, index: usize, script_code: &[u8], branch_id: u32) -> [u8; 32] {
+    let input = &transaction.inputs[index];
+    let empty_hash = [0u8; 32];
+
+    let mut data = transaction.version.to_le_bytes().to_vec();
+    data.extend_from_slice(&ZCASH_SAPLING_VERSION_GROUP_ID.to_le_bytes());
+    data.extend_from_slice(&blake2b_256_personal(&prevouts(transaction), ZCASH_PREVOUTS_PERSONALIZATION));
+    data.extend_from_slice(&blake2b_256_personal(&sequences(transaction), ZCASH_SEQUENCE_PERSONALIZATION));
+    data.extend_from_slice(&blake2b_256_personal(&outputs(transaction), ZCASH_OUTPUTS_PERSONALIZATION));
+    data.extend_from_slice(&empty_hash); // hashJoinSplits
+    data.extend_from_slice(&empty_hash); // hashShieldedSpends
+    data.extend_from_slice(&empty_hash); // hashShieldedOutputs
+    data.extend_from_slice(&transaction.lock_time.to_le_bytes());
+    data.extend_from_slice(&transaction.expiry_height.to_le_bytes());
+    data.extend_from_slice(&0i64.to_le_bytes()); // valueBalance
+    data.extend_from_slice(&SIGHASH_ALL.to_le_bytes());
+    data.extend(input.previous_output.encode());
+    data.extend(encode_script(script_code));
+    data.extend_from_slice(&input.value.to_le_bytes());
+    data.extend_from_slice(&input.sequence.to_le_bytes());
+
+    let personalization = [ZCASH_SIGHASH_PERSONALIZATION, &branch_id.to_le_bytes()].concat();
+    blake2b_256_personal(&data, &personalization)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::script::p2pkh_script;
+    use crate::signer::testkit::decode_transaction;
+    use crate::signer::transaction::{OutPoint, TransactionInput, TransactionOutput};
+    use primitives::BitcoinChain;
+
+    #[test]
+    fn test_witness_v0_sighash_bip143_p2wpkh() {
+        let transaction = decode_transaction(
+            BitcoinChain::Bitcoin,
+            "0100000002fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f0000000000eeffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac11000000",
+            &[(625_000_000, ""), (600_000_000, "00141d0f172a0ecb48aee1be1f2687d2963ae33f71a1")],
+        );
+        let script_code = p2pkh_script(&hex::decode("1d0f172a0ecb48aee1be1f2687d2963ae33f71a1").unwrap());
+
+        assert_eq!(
+            hex::encode(double_sha256(&prevouts(&transaction))),
+            "96b827c8483d4e9b96712b6713a7b68d6e8003a781feba36c31143470b4efd37"
+        );
+        assert_eq!(
+            hex::encode(double_sha256(&sequences(&transaction))),
+            "52b0a642eea2fb7ae638c36f6252b6750293dbe574a806984b8e4d8548339a3b"
+        );
+        assert_eq!(
+            hex::encode(double_sha256(&outputs(&transaction))),
+            "863ef3e1a92afbfdb97f31ad0fc7683ee943e9abcf2501590ff8f6551f47e5e5"
+        );
+        assert_eq!(
+            hex::encode(witness_v0_sighash(&transaction, 1, &script_code, SIGHASH_ALL)),
+            "c37af31116d1b27caf68aae9e3ac82f1477929014d5b917657d0eb49478cb670"
+        );
+    }
+
+    #[test]
+    fn test_witness_v0_sighash_bip143_p2sh_p2wpkh() {
+        let transaction = decode_transaction(
+            BitcoinChain::Bitcoin,
+            "0100000001db6b1b20aa0fd7b23880be2ecbd4a98130974cf4748fb66092ac4d3ceb1a54770100000000feffffff02b8b4eb0b000000001976a914a457b684d7f0d539a46a45bbc043f35b59d0d96388ac0008af2f000000001976a914fd270b1ee6abcaea97fea7ad0402e8bd8ad6d77c88ac92040000",
+            &[(1_000_000_000, "a9144733f37cf4db86fbc2efed2500b4f4e49f31202387")],
+        );
+        let script_code = p2pkh_script(&hex::decode("79091972186c449eb1ded22b78e40d009bdf0089").unwrap());
+
+        assert_eq!(
+            hex::encode(witness_v0_sighash(&transaction, 0, &script_code, SIGHASH_ALL)),
+            "64f3b0f4dd2bb3aa1ce8566d220cc74dda9df97d8490cc81d89d735c92e59fb6"
+        );
+    }
+
+    #[test]
+    fn test_taproot_key_spend_sighash_bip341() {
+        // BIP-341 wallet test vectors, keyPathSpending input 4 (SIGHASH_DEFAULT)
+        let transaction = decode_transaction(
+            BitcoinChain::Bitcoin,
+            "02000000097de20cbff686da83a54981d2b9bab3586f4ca7e48f57f5b55963115f3b334e9c010000000000000000d7b7cab57b1393ace2d064f4d4a2cb8af6def61273e127517d44759b6dafdd990000000000fffffffff8e1f583384333689228c5d28eac13366be082dc57441760d957275419a418420000000000fffffffff0689180aa63b30cb162a73c6d2a38b7eeda2a83ece74310fda0843ad604853b0100000000feffffffaa5202bdf6d8ccd2ee0f0202afbbb7461d9264a25e5bfd3c5a52ee1239e0ba6c0000000000feffffff956149bdc66faa968eb2be2d2faa29718acbfe3941215893a2a3446d32acd050000000000000000000e664b9773b88c09c32cb70a2a3e4da0ced63b7ba3b22f848531bbb1d5d5f4c94010000000000000000e9aa6b8e6c9de67619e6a3924ae25696bb7b694bb677a632a74ef7eadfd4eabf0000000000ffffffffa778eb6a263dc090464cd125c466b5a99667720b1c110468831d058aa1b82af10100000000ffffffff0200ca9a3b000000001976a91406afd46bcdfd22ef94ac122aa11f241244a37ecc88ac807840cb0000000020ac9a87f5594be208f8532db38cff670c450ed2fea8fcdefcc9a663f78bab962b0065cd1d",
+            &[
+                (420_000_000, "512053a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"),
+                (462_000_000, "5120147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"),
+                (294_000_000, "76a914751e76e8199196d454941c45d1b3a323f1433bd688ac"),
+                (504_000_000, "5120e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e"),
+                (630_000_000, "512091b64d5324723a985170e4dc5a0f84c041804f2cd12660fa5dec09fc21783605"),
+                (378_000_000, "00147dd65592d0ab2fe0d0257d571abf032cd9db93dc"),
+                (672_000_000, "512075169f4001aa68f15bbed28b218df1d0a62cbbcf1188c6665110c293c907b831"),
+                (546_000_000, "5120712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5"),
+                (588_000_000, "512077e30a5522dd9f894c3f8b8bd4c4b2cf82ca7da8a3ea6a239655c39c050ab220"),
+            ],
+        );
+
+        assert_eq!(
+            hex::encode(taproot_key_spend_sighash(&transaction, 4)),
+            "4f900a0bae3f1446fd48490c2958b5a023228f01661cda3496a11da502a7f7ef"
+        );
+    }
+
+    #[test]
+    fn test_zcash_sighash_zip243() {
+        // ZIP-243 test vector 3 (transparent input, Sapling branch)
+        let script_code = p2pkh_script(&hex::decode("507173527b4c3318a2aecd793bf1cfed705950cf").unwrap());
+        let input = TransactionInput {
+            previous_output: OutPoint::new("d9042195d9a1b65b2f1f79d68ceb1a5ea6459c9651a6ad4dc1f465824785c6a8", 1).unwrap(),
+            value: 50_000_000,
+            script_pubkey: script_code.clone(),
+            sequence: 0xffff_fffe,
+            script_sig: vec![],
+            witness: vec![],
+        };
+        let outputs = vec![
+            TransactionOutput::new(40_000_000, hex::decode("76a9148132712c3ff19f3a151234616777420a6d7ef22688ac").unwrap()),
+            TransactionOutput::new(9_999_755, hex::decode("76a9145453e4698f02a38abdaa521cd1ff2dee6fac187188ac").unwrap()),
+        ];
+        let mut transaction = BitcoinTransaction::new(BitcoinChain::Zcash, vec![input], outputs);
+        transaction.lock_time = 0x0004_b029;
+        transaction.expiry_height = 0x0004_b048;
+
+        assert_eq!(
+            hex::encode(zcash_sighash(&transaction, 0, &script_code, 0x76b8_09bb)),
+            "f3148f80dfab5e573d5edfe7a850f5fd39234f80b5429d3a57edcc11e34c585b"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use gem_hash::ripemd::hash160;
+use num_traits::ToPrimitive;
+use primitives::{BitcoinChain, SignerError, SignerInput, TransactionLoadMetadata};
+use signer::{InvalidInput, RECOVERY_ID_INDEX, SignatureScheme, Signer, encode_der_signature, secp256k1_public_key, sign_taproot_digest, taproot_output_key};
+
+use super::planner::{FeeCalculator, plan_transaction};
+use super::script::{OutputScript, ScriptType, op_return_script, p2pkh_script, p2sh_p2wpkh_redeem_script, push_data};
+use super::sighash::{SIGHASH_ALL, SIGHASH_FORKID, legacy_sighash, taproot_key_spend_sighash, witness_v0_sighash, zcash_sighash};
+use super::transaction::{BitcoinTransaction, OutPoint, SEQUENCE_FINAL, TransactionInput, TransactionOutput};
+
+pub(crate) struct TransferOutput<'a> {
+    pub destination: &'a str,
+    pub value: &'a str,
+    pub memo: Option<&'a str>,
+}
+
+pub(crate) fn sign_transfer(chain: BitcoinChain, input: &SignerInput, output: TransferOutput, private_key: &[u8]) -> Result<String, SignerError> {
+    let transaction = build_transaction(chain, input, output, private_key)?;
+    Ok(hex::encode(transaction.encode()))
+}
+
+pub(crate) fn build_transaction(chain: BitcoinChain, input: &SignerInput, output: TransferOutput, private_key: &[u8]) -> Result<BitcoinTransaction, SignerError> {
+    let public_key = secp256k1_public_key(private_key)?;
+    let sender = OutputScript::from_address(chain, &input.sender_address)?;
+    verify_sender(chain, &sender, &public_key, private_key)?;
+
+    let destination = OutputScript::from_address(chain, output.destination)?;
+    let memo = output.memo.filter(|memo| !memo.is_empty()).map(|memo| op_return_script(memo.as_bytes())).transpose()?;
+    let amount = output.value.parse::<u64>().invalid_input("invalid Bitcoin amount")?;
+    let fee_rate = input.fee.gas_price_type.gas_price().to_u64().invalid_input("invalid Bitcoin fee rate")?;
+    let utxos = input.metadata.get_utxos()?;
+
+    let change_script = sender.script_pubkey();
+    let output_scripts: Vec<usize> = [Some(destination.script_pubkey().len()), memo.as_ref().map(Vec::len)].into_iter().flatten().collect();
+    let calculator = FeeCalculator::new(chain, sender.script_type, fee_rate);
+    let plan = plan_transaction(calculator, &utxos, &output_scripts, change_script.len(), amount, input.is_max_value)?;
+
+    let inputs = plan
+        .utxos
+        .iter()
+        .map(|utxo| {
+            Ok(TransactionInput {
+                previous_output: OutPoint::new(&utxo.transaction_id, utxo.vout as u32)?,
+                value: utxo.value.parse::<u64>().invalid_input("invalid UTXO value")?,
+                script_pubkey: change_script.clone(),
+                sequence: SEQUENCE_FINAL,
+                script_sig: vec![],
+                witness: vec![],
+            })
+        })
+        .collect::<Result<Vec<_>, SignerError>>()?;
+
+    let mut outputs = vec![TransactionOutput::new(plan.amount, destination.script_pubkey())];
+    if let Some(memo) = memo {
+        outputs.push(TransactionOutput::new(0, memo));
+    }
+    if plan.change > 0 {
+        outputs.push(TransactionOutput::new(plan.change, change_script));
+    }
+
+    let mut transaction = BitcoinTransaction::new(chain, inputs, outputs);
+    sign_inputs(&mut transaction, &sender, &public_key, private_key, zcash_branch_id(&input.metadata)?)?;
+    Ok(transaction)
+}
+
+pub(crate) fn verify_sender(chain: BitcoinChain, sender: &OutputScript, public_key: &[u8], private_key: &[u8]) -> Result<(), SignerError> {
+    let expected = match sender.script_type {
+        ScriptType::P2pkh | ScriptType::P2wpkh => hash160(public_key).to_vec(),
+        // P2SH senders are signed as P2SH-P2WPKH, which needs segwit
+        ScriptType::P2sh if !is_segwit_chain(chain) => return SignerError::invalid_input_err(format!("P2SH inputs are not supported on {}", chain.as_ref())),
+        ScriptType::P2sh => hash160(&p2sh_p2wpkh_redeem_script(public_key)).to_vec(),
+        ScriptType::P2tr => taproot_output_key(private_key)?.to_vec(),
+        ScriptType::P2wsh => return SignerError::invalid_input_err("P2WSH inputs are not supported"),
+    };
+    if expected != sender.program {
+        return SignerError::invalid_input_err("Private key does not match sender address");
+    }
+    Ok(())
+}
+
+fn is_segwit_chain(chain: BitcoinChain) -> bool {
+    match chain {
+        BitcoinChain::Bitcoin | BitcoinChain::Litecoin => true,
+        BitcoinChain::BitcoinCash | BitcoinChain::Doge | BitcoinChain::Zcash => false,
+    }
+}
+
+fn zcash_branch_id(metadata: &TransactionLoadMetadata) -> Result<Option<u32>, SignerError> {
+    match metadata {
+        TransactionLoadMetadata::Zcash { branch_id, .. } => Ok(Some(u32::from_str_radix(branch_id, 16).invalid_input("invalid Zcash branch id")?)),
+        _ => Ok(None),
+    }
+}
+
+fn sign_inputs(transaction: &mut BitcoinTransaction, sender: &OutputScript, public_key: &[u8], private_key: &[u8], branch_id: Option<u32>) -> Result<(), SignerError> {
+    let script_code = p2pkh_script(&hash160(public_key));
+
+    for index in 0..transaction.inputs.len() {
+        match sender.script_type {
+            ScriptType::P2pkh => {
+                let (sighash, sighash_type) = match transaction.chain {
+                    BitcoinChain::BitcoinCash => {
+                        let sighash_type = SIGHASH_ALL | SIGHASH_FORKID;
+                        (witness_v0_sighash(transaction, index, &script_code, sighash_type), sighash_type)
+                    }
+                    BitcoinChain::Zcash => {
+                        let branch_id = branch_id.ok_or_else(|| SignerError::invalid_input("Zcash branch id is required"))?;
+                        (zcash_sighash(transaction, index, &script_code, branch_id), SIGHASH_ALL)
+                    }
+                    BitcoinChain::Bitcoin | BitcoinChain::Litecoin | BitcoinChain::Doge => (legacy_sighash(transaction, index, &script_code), SIGHASH_ALL),
+                };
+                let signature = sign_ecdsa(&sighash, private_key, sighash_type)?;
+                transaction.inputs[index].script_sig = [push_data(&signature), push_data(public_key)].concat();
+            }
+            ScriptType::P2wpkh | ScriptType::P2sh => {
+                let sighash = witness_v0_sighash(transaction, index, &script_code, SIGHASH_ALL);
+                let signature = sign_ecdsa(&sighash, private_key, SIGHASH_ALL)?;
+                let input = &mut transaction.inputs[index];
+                if sender.script_type == ScriptType::P2sh {
+                    input.script_sig = push_data(&p2sh_p2wpkh_redeem_script(public_key));
+                }
+                input.witness = vec![signature, public_key.to_vec()];
+            }
+            ScriptType::P2tr => {
+                let sighash = taproot_key_spend_sighash(transaction, index);
+                transaction.inputs[index].witness = vec![sign_taproot_digest(&sighash, private_key)?];
+            }
+            ScriptType::P2wsh => return SignerError::invalid_input_err("P2WSH inputs are not supported"),
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn sign_ecdsa(sighash: &[u8], private_key: &[u8], sighash_type: u32) -> Result<Vec<u8>, SignerError> {
+    let signature = Signer::sign_digest(SignatureScheme::Secp256k1, sighash.to_vec(), private_key.to_vec())?;
+    let mut encoded = encode_der_signature(&signature[..RECOVERY_ID_INDEX]);
+    encoded.push(sighash_type as u8);
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::testkit::decode_transaction;
+
+    #[test]
+    fn test_sign_bip143_p2wpkh_and_legacy_p2pk() {
+        let mut transaction = decode_transaction(
+            BitcoinChain::Bitcoin,
+            "0100000002fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f0000000000eeffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac11000000",
+            &[(625_000_000, ""), (600_000_000, "00141d0f172a0ecb48aee1be1f2687d2963ae33f71a1")],
+        );
+        let p2pk_script = hex::decode("2103c9f4836b9a4f77fc0d81f7bcb01b7f1b35916864b9476c241ce9fc198bd25432ac").unwrap();
+        let p2pk_key = hex::decode("bbc27228ddcb9209d7fd6f36b02f7dfa6252af40bb2f1cbc7a557da8027ff866").unwrap();
+        let p2wpkh_key = hex::decode("619c335025c7f4012e556c2a58b2506e30b8511b53ade95ea316fd8c3286feb9").unwrap();
+        let p2wpkh_public_key = secp256k1_public_key(&p2wpkh_key).unwrap();
+
+        let sighash = witness_v0_sighash(&transaction, 1, &p2pkh_script(&hash160(&p2wpkh_public_key)), SIGHASH_ALL);
+        transaction.inputs[1].witness = vec![sign_ecdsa(&sighash, &p2wpkh_key, SIGHASH_ALL).unwrap(), p2wpkh_public_key];
+        let sighash = legacy_sighash(&transaction, 0, &p2pk_script);
+        transaction.inputs[0].script_sig = push_data(&sign_ecdsa(&sighash, &p2pk_key, SIGHASH_ALL).unwrap());
+
+        assert_eq!(
+            hex::encode(transaction.encode()),
+            "01000000000102fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f00000000494830450221008b9d1dc26ba6a9cb62127b02742fa9d754cd3bebf337f7a55d114c8e5cdd30be022040529b194ba3f9281a99f2b1c0a19c0489bc22ede944ccf4ecbab4cc618ef3ed01eeffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac000247304402203609e17b84f6a7d30c80bfa610b5b4542f32a8a0d5447a12fb1366d7f01cc44a0220573a954c4518331561406f90300e8f3358f51928d43c212a8caed02de67eebee0121025476c2e83188368da1ff3e292e7acafcdb3566bb0ad253f62fc70f07aeee635711000000"
+        );
+    }
+
+    #[test]
+    fn test_sign_inputs_bip143_p2sh_p2wpkh() {
+        let private_key = hex::decode("eb696a065ef48a2192da5b28b694f87544b30fae8327c4510137a922f32c6dcf").unwrap();
+        let public_key = secp256k1_public_key(&private_key).unwrap();
+        let sender = OutputScript::new(ScriptType::P2sh, hash160(&p2sh_p2wpkh_redeem_script(&public_key)).to_vec());
+        let mut transaction = decode_transaction(
+            BitcoinChain::Bitcoin,
+            "0100000001db6b1b20aa0fd7b23880be2ecbd4a98130974cf4748fb66092ac4d3ceb1a54770100000000feffffff02b8b4eb0b000000001976a914a457b684d7f0d539a46a45bbc043f35b59d0d96388ac0008af2f000000001976a914fd270b1ee6abcaea97fea7ad0402e8bd8ad6d77c88ac92040000",
+            &[(1_000_000_000, "a9144733f37cf4db86fbc2efed2500b4f4e49f31202387")],
+        );
+
+        verify_sender(BitcoinChain::Bitcoin, &sender, &public_key, &private_key).unwrap();
+        sign_inputs(&mut transaction, &sender, &public_key, &private_key, None).unwrap();
+
+        assert_eq!(
+            hex::encode(transaction.encode()),
+            "01000000000101db6b1b20aa0fd7b23880be2ecbd4a98130974cf4748fb66092ac4d3ceb1a5477010000001716001479091972186c449eb1ded22b78e40d009bdf0089feffffff02b8b4eb0b000000001976a914a457b684d7f0d539a46a45bbc043f35b59d0d96388ac0008af2f000000001976a914fd270b1ee6abcaea97fea7ad0402e8bd8ad6d77c88ac02473044022047ac8e878352d3ebbde1c94ce3a10d057c24175747116f8288e5d794d12d482f0220217f36a485cae903c713331d877c1f64677e3622ad4010726870540656fe9dcb012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a2687392040000"
+        );
+    }
+
+    #[test]
+    fn test_sign_inputs_bitcoin_cash_forkid() {
+        // https://blockchair.com/bitcoin-cash/transaction/96ee20002b34e468f9d3c5ee54f6a8ddaa61c118889c4f35395c2cd93ba5bbb4
+        let private_key = hex::decode("7fdafb9db5bc501f2096e7d13d331dc7a75d9594af3d251313ba8b6200f4e384").unwrap();
+        let public_key = secp256k1_public_key(&private_key).unwrap();
+        let sender = OutputScript::new(ScriptType::P2pkh, hash160(&public_key).to_vec());
+        let mut transaction = decode_transaction(
+            BitcoinChain::BitcoinCash,
+            "0100000001e28c2b955293159898e34c6840d99bf4d390e2ee1c6f606939f18ee1e2000d050200000000ffffffff0258020000000000001976a914769bdff96a02f9135a1d19b749db6a78fe07dc9088ace5100000000000001976a9149e089b6889e032d46e3b915a3392edfd616fb1c488ac00000000",
+            &[(5151, "76a914aff1e0789e5fe316b729577665aa0a04d5b0f8c788ac")],
+        );
+
+        sign_inputs(&mut transaction, &sender, &public_key, &private_key, None).unwrap();
+
+        assert_eq!(
+            hex::encode(transaction.encode()),
+            "0100000001e28c2b955293159898e34c6840d99bf4d390e2ee1c6f606939f18ee1e2000d05020000006b483045022100b70d158b43cbcded60e6977e93f9a84966bc0cec6f2dfd1463d1223a90563f0d02207548d081069de570a494d0967ba388ff02641d91cadb060587ead95a98d4e3534121038eab72ec78e639d02758e7860cdec018b49498c307791f785aa3019622f4ea5bffffffff0258020000000000001976a914769bdff96a02f9135a1d19b749db6a78fe07dc9088ace5100000000000001976a9149e089b6889e032d46e3b915a3392edfd616fb1c488ac00000000"
+        );
+    }
+
+    #[test]
+    fn test_verify_sender_rejects_p2sh_without_segwit() {
+        let private_key = hex::decode("eb696a065ef48a2192da5b28b694f87544b30fae8327c4510137a922f32c6dcf").unwrap();
+        let public_key = secp256k1_public_key(&private_key).unwrap();
+        let sender = OutputScript::new(ScriptType::P2sh, hash160(&p2sh_p2wpkh_redeem_script(&public_key)).to_vec());
+
+        assert!(verify_sender(BitcoinChain::Litecoin, &sender, &public_key, &private_key).is_ok());
+        for chain in [BitcoinChain::BitcoinCash, BitcoinChain::Doge, BitcoinChain::Zcash] {
+            assert!(verify_sender(chain, &sender, &public_key, &private_key).is_err());
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use primitives::BitcoinChain;
+
+use super::transaction::BitcoinTransaction;
+
+pub fn decode_transaction(chain: BitcoinChain, raw: &str, spent: &[(u64, &str)]) -> BitcoinTransaction {
+    let mut transaction = BitcoinTransaction::decode(chain, &hex::decode(raw).unwrap()).unwrap();
+    for (input, (value, script_pubkey)) in transaction.inputs.iter_mut().zip(spent) {
+        input.value = *value;
+        input.script_pubkey = hex::decode(script_pubkey).unwrap();
+    }
+    transaction
+}
@@ -0,0 +1,253 @@
+use gem_hash::sha2::sha256;
+use primitives::{BitcoinChain, SignerError};
+
+use super::encoding::{ByteReader, encode_varint};
+
+pub const SEQUENCE_FINAL: u32 = 0xffff_ffff;
+
+const SEGWIT_MARKER: u8 = 0x00;
+const SEGWIT_FLAG: u8 = 0x01;
+
+const ZCASH_OVERWINTERED_FLAG: u32 = 1 << 31;
+const ZCASH_SAPLING_VERSION: u32 = 4;
+pub const ZCASH_SAPLING_VERSION_GROUP_ID: u32 = 0x892f_2085;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+impl OutPoint {
+    pub fn new(transaction_id: &str, index: u32) -> Result<Self, SignerError> {
+        let mut hash: [u8; 32] = hex::decode(transaction_id)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| SignerError::invalid_input(format!("Invalid transaction id: {transaction_id}")))?;
+        hash.reverse();
+        Ok(Self { hash, index })
+    }
+
+    pub fn transaction_id(&self) -> String {
+        hex::encode(self.hash.iter().rev().copied().collect::<Vec<_>>())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        [self.hash.as_slice(), &self.index.to_le_bytes()].concat()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionInput {
+    pub previous_output: OutPoint,
+    pub value: u64,
+    pub script_pubkey: Vec<u8>,
+    pub sequence: u32,
+    pub script_sig: Vec<u8>,
+    pub witness: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOutput {
+    pub value: u64,
+    pub script_pubkey: Vec<u8>,
+}
+
+impl TransactionOutput {
+    pub fn new(value: u64, script_pubkey: Vec<u8>) -> Self {
+        Self { value, script_pubkey }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        [self.value.to_le_bytes().as_slice(), &encode_script(&self.script_pubkey)].concat()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BitcoinTransaction {
+    pub chain: BitcoinChain,
+    pub version: u32,
+    pub inputs: Vec<TransactionInput>,
+    pub outputs: Vec<TransactionOutput>,
+    pub lock_time: u32,
+    pub expiry_height: u32,
+}
+
+impl BitcoinTransaction {
+    pub fn new(chain: BitcoinChain, inputs: Vec<TransactionInput>, outputs: Vec<TransactionOutput>) -> Self {
+        Self {
+            chain,
+            version: default_version(chain),
+            inputs,
+            outputs,
+            lock_time: 0,
+            expiry_height: 0,
+        }
+    }
+
+    pub fn decode(chain: BitcoinChain, data: &[u8]) -> Result<Self, SignerError> {
+        if let BitcoinChain::Zcash = chain {
+            return SignerError::invalid_input_err("Zcash transaction decoding is not supported");
+        }
+        let mut reader = ByteReader::new(data);
+        let version = reader.read_u32()?;
+        let with_witness = reader.peek_u8() == Some(SEGWIT_MARKER);
+        if with_witness {
+            let [_, flag] = reader.read_array::<2>()?;
+            if flag != SEGWIT_FLAG {
+                return SignerError::invalid_input_err("Invalid segwit flag");
+            }
+        }
+
+        let mut inputs = (0..reader.read_varint()?)
+            .map(|_| {
+                let hash = reader.read_array()?;
+                let index = reader.read_u32()?;
+                let script_sig = reader.read_var_bytes()?.to_vec();
+                Ok(TransactionInput {
+                    previous_output: OutPoint { hash, index },
+                    value: 0,
+                    script_pubkey: vec![],
+                    sequence: reader.read_u32()?,
+                    script_sig,
+                    witness: vec![],
+                })
+            })
+            .collect::<Result<Vec<_>, SignerError>>()?;
+        let outputs = (0..reader.read_varint()?)
+            .map(|_| {
+                let value = reader.read_u64()?;
+                Ok(TransactionOutput::new(value, reader.read_var_bytes()?.to_vec()))
+            })
+            .collect::<Result<Vec<_>, SignerError>>()?;
+        if with_witness {
+            for input in &mut inputs {
+                input.witness = (0..reader.read_varint()?)
+                    .map(|_| Ok(reader.read_var_bytes()?.to_vec()))
+                    .collect::<Result<Vec<_>, SignerError>>()?;
+            }
+        }
+        let lock_time = reader.read_u32()?;
+        if !reader.is_empty() {
+            return SignerError::invalid_input_err("Unexpected trailing transaction data");
+        }
+
+        let mut transaction = Self::new(chain, inputs, outputs);
+        transaction.version = version;
+        transaction.lock_time = lock_time;
+        Ok(transaction)
+    }
+
+    pub fn hash(&self) -> [u8; 32] {
+        sha256(&sha256(&self.encode_without_witness()))
+    }
+
+    pub fn has_witness(&self) -> bool {
+        self.inputs.iter().any(|input| !input.witness.is_empty())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self.chain {
+            BitcoinChain::Zcash => self.encode_zcash(),
+            _ => self.encode_bitcoin(self.has_witness()),
+        }
+    }
+
+    pub fn encode_without_witness(&self) -> Vec<u8> {
+        match self.chain {
+            BitcoinChain::Zcash => self.encode_zcash(),
+            _ => self.encode_bitcoin(false),
+        }
+    }
+
+    fn encode_bitcoin(&self, with_witness: bool) -> Vec<u8> {
+        let mut data = self.version.to_le_bytes().to_vec();
+        if with_witness {
+            data.extend_from_slice(&[SEGWIT_MARKER, SEGWIT_FLAG]);
+        }
+        self.encode_inputs_outputs(&mut data);
+        if with_witness {
+            for input in &self.inputs {
+                data.extend(encode_varint(input.witness.len()));
+                for item in &input.witness {
+                    data.extend(encode_script(item));
+                }
+            }
+        }
+        data.extend_from_slice(&self.lock_time.to_le_bytes());
+        data
+    }
+
+    fn encode_zcash(&self) -> Vec<u8> {
+        let mut data = self.version.to_le_bytes().to_vec();
+        data.extend_from_slice(&ZCASH_SAPLING_VERSION_GROUP_ID.to_le_bytes());
+        self.encode_inputs_outputs(&mut data);
+        data.extend_from_slice(&self.lock_time.to_le_bytes());
+        data.extend_from_slice(&self.expiry_height.to_le_bytes());
+        data.extend_from_slice(&0i64.to_le_bytes()); // valueBalance
+        data.extend(encode_varint(0)); // vShieldedSpend
+        data.extend(encode_varint(0)); // vShieldedOutput
+        data.extend(encode_varint(0)); // vJoinSplit
+        data
+    }
+
+    fn encode_inputs_outputs(&self, data: &mut Vec<u8>) {
+        data.extend(encode_varint(self.inputs.len()));
+        for input in &self.inputs {
+            data.extend(input.previous_output.encode());
+            data.extend(encode_script(&input.script_sig));
+            data.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        data.extend(encode_varint(self.outputs.len()));
+        for output in &self.outputs {
+            data.extend(output.encode());
+        }
+    }
+}
+
+fn default_version(chain: BitcoinChain) -> u32 {
+    match chain {
+        BitcoinChain::Bitcoin | BitcoinChain::Litecoin | BitcoinChain::BitcoinCash => 2,
+        BitcoinChain::Doge => 1,
+        BitcoinChain::Zcash => ZCASH_SAPLING_VERSION | ZCASH_OVERWINTERED_FLAG,
+    }
+}
+
+pub fn encode_script(script: &[u8]) -> Vec<u8> {
+    [encode_varint(script.len()), script.to_vec()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_point_reverses_transaction_id() {
+        let out_point = OutPoint::new("fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f", 1).unwrap();
+        assert_eq!(hex::encode(out_point.encode()), "9f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff01000000");
+        assert!(OutPoint::new("abcd", 0).is_err());
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        // BIP-143 native P2WPKH example, signed
+        let raw = "01000000000102fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f00000000494830450221008b9d1dc26ba6a9cb62127b02742fa9d754cd3bebf337f7a55d114c8e5cdd30be022040529b194ba3f9281a99f2b1c0a19c0489bc22ede944ccf4ecbab4cc618ef3ed01eeffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac000247304402203609e17b84f6a7d30c80bfa610b5b4542f32a8a0d5447a12fb1366d7f01cc44a0220573a954c4518331561406f90300e8f3358f51928d43c212a8caed02de67eebee0121025476c2e83188368da1ff3e292e7acafcdb3566bb0ad253f62fc70f07aeee635711000000";
+        let transaction = BitcoinTransaction::decode(BitcoinChain::Bitcoin, &hex::decode(raw).unwrap()).unwrap();
+
+        assert_eq!(transaction.version, 1);
+        assert_eq!(transaction.lock_time, 17);
+        assert_eq!(transaction.inputs[1].witness.len(), 2);
+        assert_eq!(hex::encode(transaction.encode()), raw);
+        assert_eq!(
+            transaction.inputs[0].previous_output.transaction_id(),
+            "9f96ade4b41d5433f4eda31e1738ec2b36f6e7d1420d94a6af99801a88f7f7ff"
+        );
+        assert!(BitcoinTransaction::decode(BitcoinChain::Bitcoin, &hex::decode(&raw[..raw.len() - 2]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_encode_zcash_header() {
+        let transaction = BitcoinTransaction::new(BitcoinChain::Zcash, vec![], vec![]);
+        assert_eq!(hex::encode(&transaction.encode()[..8]), "0400008085202f89");
+    }
+}
@@ -0,0 +1,98 @@
+use primitives::SignerError;
+
+const MAINNET_HRP: &str = "addr";
+const STAKE_HRP: &str = "stake";
+const POOL_HRP: &str = "pool";
+const MAINNET_NETWORK_ID: u8 = 1;
+const KEY_HASH_LENGTH: usize = 28;
+const BYRON_ADDRESS_PREFIX: u8 = 0x82;
+const REWARD_ADDRESS_HEADER: u8 = 0xe0 | MAINNET_NETWORK_ID;
+
+pub type KeyHash = [u8; KEY_HASH_LENGTH];
+
+pub fn decode_address(address: &str) -> Result<Vec<u8>, SignerError> {
+    if let Ok((hrp, bytes)) = bech32::decode(address) {
+        if hrp.as_str() != MAINNET_HRP || bytes.len() <= KEY_HASH_LENGTH || bytes[0] & 0x0f != MAINNET_NETWORK_ID {
+            return SignerError::invalid_input_err("invalid Cardano address");
+        }
+        return Ok(bytes);
+    }
+    match bs58::decode(address).into_vec() {
+        Ok(bytes) if bytes.first() == Some(&BYRON_ADDRESS_PREFIX) => Ok(bytes),
+        _ => SignerError::invalid_input_err("invalid Cardano address"),
+    }
+}
+
+pub fn payment_key_hash(address: &[u8]) -> Option<&[u8]> {
+    match address.first()? >> 4 {
+        0 | 2 | 4 | 6 => address.get(1..1 + KEY_HASH_LENGTH),
+        _ => None,
+    }
+}
+
+pub fn stake_key_hash(address: &[u8]) -> Option<KeyHash> {
+    match address.first()? >> 4 {
+        0 | 1 => address.get(1 + KEY_HASH_LENGTH..1 + 2 * KEY_HASH_LENGTH)?.try_into().ok(),
+        _ => None,
+    }
+}
+
+pub fn reward_address(stake_key_hash: &KeyHash) -> Vec<u8> {
+    [&[REWARD_ADDRESS_HEADER][..], stake_key_hash].concat()
+}
+
+pub fn stake_address(address: &str) -> Result<String, SignerError> {
+    let hash = stake_key_hash(&decode_address(address)?).ok_or_else(|| SignerError::invalid_input("Cardano address has no stake key"))?;
+    bech32::encode::<bech32::Bech32>(bech32::Hrp::parse_unchecked(STAKE_HRP), &reward_address(&hash)).map_err(SignerError::from_display)
+}
+
+pub fn decode_pool_id(pool_id: &str) -> Result<KeyHash, SignerError> {
+    match bech32::decode(pool_id) {
+        Ok((hrp, bytes)) if hrp.as_str() == POOL_HRP => bytes.try_into().map_err(|_| SignerError::invalid_input("invalid Cardano pool id")),
+        _ => SignerError::invalid_input_err("invalid Cardano pool id"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_address() {
+        let address = decode_address("addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23").unwrap();
+
+        assert_eq!(
+            hex::encode(&address),
+            "01df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b"
+        );
+        assert_eq!(hex::encode(payment_key_hash(&address).unwrap()), "df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804");
+
+        let byron = decode_address("Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo").unwrap();
+        assert_eq!(byron[0], BYRON_ADDRESS_PREFIX);
+        assert_eq!(payment_key_hash(&byron), None);
+
+        assert!(decode_address("addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8").is_err());
+        assert!(decode_address("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").is_err());
+    }
+
+    #[test]
+    fn test_stake_address() {
+        let address = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+
+        assert_eq!(
+            hex::encode(stake_key_hash(&decode_address(address).unwrap()).unwrap()),
+            "df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b"
+        );
+        assert_eq!(stake_address(address).unwrap(), "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx");
+        assert!(stake_address("Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo").is_err());
+    }
+
+    #[test]
+    fn test_decode_pool_id() {
+        assert_eq!(
+            hex::encode(decode_pool_id("pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy").unwrap()),
+            "0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735"
+        );
+        assert!(decode_pool_id("stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx").is_err());
+    }
+}
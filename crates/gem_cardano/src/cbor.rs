@@ -0,0 +1,80 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cbor {
+    Uint(u64),
+    Bytes(Vec<u8>),
+    Array(Vec<Cbor>),
+    Map(Vec<(Cbor, Cbor)>),
+    Bool(bool),
+    Null,
+}
+
+impl Cbor {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![];
+        self.write(&mut data);
+        data
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        match self {
+            Self::Uint(value) => write_header(data, 0, *value),
+            Self::Bytes(bytes) => {
+                write_header(data, 2, bytes.len() as u64);
+                data.extend_from_slice(bytes);
+            }
+            Self::Array(items) => {
+                write_header(data, 4, items.len() as u64);
+                items.iter().for_each(|item| item.write(data));
+            }
+            Self::Map(entries) => {
+                write_header(data, 5, entries.len() as u64);
+                for (key, value) in entries {
+                    key.write(data);
+                    value.write(data);
+                }
+            }
+            Self::Bool(value) => data.push(if *value { 0xf5 } else { 0xf4 }),
+            Self::Null => data.push(0xf6),
+        }
+    }
+}
+
+fn write_header(data: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => data.push(major | value as u8),
+        24..=0xff => data.extend([major | 24, value as u8]),
+        0x100..=0xffff => {
+            data.push(major | 25);
+            data.extend((value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            data.push(major | 26);
+            data.extend((value as u32).to_be_bytes());
+        }
+        _ => {
+            data.push(major | 27);
+            data.extend(value.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(hex::encode(Cbor::Uint(0).encode()), "00");
+        assert_eq!(hex::encode(Cbor::Uint(23).encode()), "17");
+        assert_eq!(hex::encode(Cbor::Uint(24).encode()), "1818");
+        assert_eq!(hex::encode(Cbor::Uint(1000).encode()), "1903e8");
+        assert_eq!(hex::encode(Cbor::Uint(1_000_000).encode()), "1a000f4240");
+        assert_eq!(hex::encode(Cbor::Uint(1_000_000_000_000).encode()), "1b000000e8d4a51000");
+        assert_eq!(hex::encode(Cbor::Bytes(vec![1, 2, 3]).encode()), "43010203");
+        assert_eq!(
+            hex::encode(Cbor::Map(vec![(Cbor::Uint(0), Cbor::Array(vec![Cbor::Bool(true), Cbor::Null]))]).encode()),
+            "a10082f5f6"
+        );
+    }
+}
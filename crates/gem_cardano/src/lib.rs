@@ -1,6 +1,11 @@
+pub mod address;
+mod cbor;
 pub mod models;
 pub mod provider;
 pub mod rpc;
+#[cfg(feature = "signer")]
+pub mod signer;
+pub mod transaction;
 
 pub use provider::map_transaction;
 pub use rpc::client::CardanoClient;
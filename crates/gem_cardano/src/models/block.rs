@@ -37,3 +37,37 @@ pub struct Genesis {
 pub struct GenesisShelley {
     pub network_magic: i32,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersData {
+    pub cardano: ProtocolParametersTip,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersTip {
+    pub tip: SlotTip,
+    pub current_epoch: CurrentEpoch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotTip {
+    pub slot_no: UInt64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentEpoch {
+    pub protocol_params: ProtocolParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParams {
+    pub min_fee_a: UInt64,
+    pub min_fee_b: UInt64,
+    pub coins_per_utxo_byte: UInt64,
+    pub key_deposit: UInt64,
+}
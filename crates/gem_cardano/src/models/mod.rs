@@ -1,13 +1,18 @@
 pub mod account;
 pub mod block;
 pub mod rpc;
+pub mod staking;
 pub mod transaction;
 pub mod utxo;
 
 pub type UInt64 = u64;
 
 pub use account::{Balance, BalanceAggregate, BalanceResponse, BalanceSum, BalanceSumValue};
-pub use block::{Block, BlockData, BlockTip, Genesis, GenesisData, GenesisShelley};
+pub use block::{Block, BlockData, BlockTip, CurrentEpoch, Genesis, GenesisData, GenesisShelley, ProtocolParametersData, ProtocolParametersTip, ProtocolParams, SlotTip};
 pub use rpc::{Block as RpcBlock, Blocks, Data, Input, Output, Transaction as RpcTransaction};
+pub use staking::{
+    ActiveStake, AdaPots, AmountAggregate, AmountSum, AmountSumValue, StakeAccount, StakeBlock, StakeCertificate, StakeDelegation, StakeDelegationPool, StakePool, StakePools,
+    StakeTransaction, StakingEpoch, StakingParametersData, StakingParametersEpoch, StakingProtocolParams,
+};
 pub use transaction::{SubmitTransactionHash, Transaction as ModelTransaction, TransactionBroadcast};
-pub use utxo::{UTXO, UTXOS};
+pub use utxo::{UTXO, UTXOAsset, UTXOS, UTXOToken};
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use super::UInt64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountAggregate {
+    pub aggregate: AmountSum,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountSum {
+    pub sum: AmountSumValue,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountSumValue {
+    pub amount: Option<String>,
+}
+
+impl AmountAggregate {
+    pub fn amount(&self) -> u64 {
+        self.aggregate.sum.amount.as_deref().and_then(|amount| amount.parse().ok()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingParametersData {
+    pub cardano: StakingParametersEpoch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingParametersEpoch {
+    pub current_epoch: StakingEpoch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingEpoch {
+    pub protocol_params: StakingProtocolParams,
+    pub ada_pots: AdaPots,
+    #[serde(rename = "activeStake_aggregate")]
+    pub active_stake_aggregate: AmountAggregate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingProtocolParams {
+    pub rho: f64,
+    pub tau: f64,
+    pub n_opt: UInt64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaPots {
+    pub reserves: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakePools {
+    pub stake_pools: Vec<StakePool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakePool {
+    pub id: String,
+    pub margin: f64,
+    pub active_stake: Vec<ActiveStake>,
+    pub metadata: Option<StakePoolMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakePoolMetadata {
+    pub ticker: Option<String>,
+    pub name: Option<String>,
+}
+
+impl StakePool {
+    pub fn name(&self) -> String {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.ticker.clone().or_else(|| metadata.name.clone()))
+            .unwrap_or_else(|| self.id.clone())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveStake {
+    pub amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAccount {
+    pub delegations: Vec<StakeDelegation>,
+    pub stake_registrations: Vec<StakeCertificate>,
+    pub stake_deregistrations: Vec<StakeCertificate>,
+    #[serde(rename = "rewards_aggregate")]
+    pub rewards_aggregate: AmountAggregate,
+    #[serde(rename = "withdrawals_aggregate")]
+    pub withdrawals_aggregate: AmountAggregate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDelegation {
+    pub stake_pool: StakeDelegationPool,
+    pub transaction: StakeTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDelegationPool {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeCertificate {
+    pub transaction: StakeTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeTransaction {
+    pub block: StakeBlock,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeBlock {
+    pub slot_no: UInt64,
+}
+
+impl StakeAccount {
+    fn last_slot(certificates: &[StakeCertificate]) -> Option<UInt64> {
+        certificates.iter().map(|certificate| certificate.transaction.block.slot_no).max()
+    }
+
+    pub fn is_registered(&self) -> bool {
+        match (Self::last_slot(&self.stake_registrations), Self::last_slot(&self.stake_deregistrations)) {
+            (Some(registered), Some(deregistered)) => registered > deregistered,
+            (registered, _) => registered.is_some(),
+        }
+    }
+
+    pub fn delegated_pool(&self) -> Option<&str> {
+        if !self.is_registered() {
+            return None;
+        }
+        let registered = Self::last_slot(&self.stake_registrations)?;
+        self.delegations
+            .iter()
+            .filter(|delegation| delegation.transaction.block.slot_no >= registered)
+            .max_by_key(|delegation| delegation.transaction.block.slot_no)
+            .map(|delegation| delegation.stake_pool.id.as_str())
+    }
+
+    pub fn available_rewards(&self) -> u64 {
+        self.rewards_aggregate.amount().saturating_sub(self.withdrawals_aggregate.amount())
+    }
+}
@@ -13,6 +13,36 @@ pub struct UTXO {
     pub tx_hash: String,
     pub index: i32,
     pub value: String,
+    #[serde(default)]
+    pub tokens: Vec<UTXOToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UTXOToken {
+    pub quantity: String,
+    pub asset: UTXOAsset,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UTXOAsset {
+    pub policy_id: String,
+    pub asset_name: Option<String>,
+}
+
+impl UTXO {
+    pub fn map_tokens(&self) -> Vec<primitives::CardanoUTXOToken> {
+        self.tokens
+            .iter()
+            .map(|token| primitives::CardanoUTXOToken {
+                transaction_id: self.tx_hash.clone(),
+                vout: self.index,
+                token_id: format!("{}{}", token.asset.policy_id, token.asset.asset_name.clone().unwrap_or_default()),
+                quantity: token.quantity.clone(),
+            })
+            .collect()
+    }
 }
 
 impl From<UTXO> for primitives::UTXO {
@@ -6,6 +6,8 @@ use gem_client::Client;
 use primitives::AssetBalance;
 
 use super::balances_mapper::map_balance_coin;
+use super::staking_mapper::map_balance_staking;
+use crate::address::stake_address;
 use crate::rpc::client::CardanoClient;
 
 #[async_trait]
@@ -19,8 +21,9 @@ impl<C: Client> ChainBalances for CardanoClient<C> {
         Ok(vec![])
     }
 
-    async fn get_balance_staking(&self, _address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
-        Ok(None)
+    async fn get_balance_staking(&self, address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let account = self.get_stake_account(&stake_address(&address)?).await?;
+        Ok(Some(map_balance_staking(&account)))
     }
 
     async fn get_balance_assets(&self, _address: String) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
@@ -62,7 +65,7 @@ mod chain_integration_tests {
         let client = create_test_client();
         let balance = client.get_balance_staking(TEST_ADDRESS.to_string()).await?;
 
-        assert!(balance.is_none());
+        assert!(balance.is_some());
         Ok(())
     }
 
@@ -3,6 +3,8 @@ pub mod balances_mapper;
 pub mod preload;
 pub mod preload_mapper;
 pub mod request_classifier;
+pub mod staking;
+pub mod staking_mapper;
 pub mod state;
 #[cfg(all(test, feature = "chain_integration_tests"))]
 pub mod testkit;
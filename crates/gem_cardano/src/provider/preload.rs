@@ -3,21 +3,32 @@ use chain_traits::ChainTransactionLoad;
 use std::error::Error;
 
 use gem_client::Client;
-use primitives::{FeePriority, FeeRate, GasPriceType, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput, UTXO};
+use num_bigint::BigInt;
+use primitives::{
+    FeePriority, FeeRate, GasPriceType, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput, UTXO,
+};
 
 use super::preload_mapper;
+use crate::address::stake_address;
 use crate::rpc::client::CardanoClient;
+use crate::transaction::plan_transaction;
 
 #[async_trait]
 impl<C: Client> ChainTransactionLoad for CardanoClient<C> {
     async fn get_transaction_preload(&self, input: TransactionPreloadInput) -> Result<TransactionLoadMetadata, Box<dyn Error + Sync + Send>> {
         let utxos = self.get_utxos(&input.sender_address).await?;
-        Ok(preload_mapper::map_transaction_preload(utxos, input))
+        let parameters = self.get_protocol_parameters().await?;
+        let stake_account = match &input.input_type {
+            TransactionInputType::Stake(_, _) => Some(self.get_stake_account(&stake_address(&input.sender_address)?).await?),
+            _ => None,
+        };
+        Ok(preload_mapper::map_transaction_preload(utxos, parameters, stake_account, input))
     }
 
     async fn get_transaction_load(&self, input: TransactionLoadInput) -> Result<TransactionLoadData, Box<dyn Error + Sync + Send>> {
+        let fee = plan_transaction(&input)?.fee;
         Ok(TransactionLoadData {
-            fee: input.default_fee(),
+            fee: TransactionFee::new_from_fee(BigInt::from(fee)),
             metadata: input.metadata,
         })
     }
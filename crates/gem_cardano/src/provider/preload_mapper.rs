@@ -1,8 +1,85 @@
+use crate::models::block::ProtocolParametersData;
+use crate::models::staking::StakeAccount;
 use crate::models::utxo::UTXO as CardanoUTXO;
 use primitives::{TransactionLoadMetadata, TransactionPreloadInput};
 
-pub fn map_transaction_preload(utxos: Vec<CardanoUTXO>, _input: TransactionPreloadInput) -> TransactionLoadMetadata {
+// Transactions stay valid for two hours of slots
+const TTL_SLOTS: u64 = 7_200;
+
+pub fn map_transaction_preload(
+    utxos: Vec<CardanoUTXO>,
+    parameters: ProtocolParametersData,
+    stake_account: Option<StakeAccount>,
+    _input: TransactionPreloadInput,
+) -> TransactionLoadMetadata {
+    let protocol_params = parameters.cardano.current_epoch.protocol_params;
     TransactionLoadMetadata::Cardano {
+        tokens: utxos.iter().flat_map(CardanoUTXO::map_tokens).collect(),
         utxos: utxos.into_iter().map(primitives::UTXO::from).collect(),
+        min_fee_a: protocol_params.min_fee_a,
+        min_fee_b: protocol_params.min_fee_b,
+        coins_per_utxo_byte: protocol_params.coins_per_utxo_byte,
+        key_deposit: protocol_params.key_deposit,
+        ttl: parameters.cardano.tip.slot_no + TTL_SLOTS,
+        is_stake_registered: stake_account.as_ref().is_some_and(StakeAccount::is_registered),
+        stake_rewards: stake_account.as_ref().map(StakeAccount::available_rewards).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::rpc::Data;
+    use crate::models::utxo::UTXOS;
+    use primitives::{Asset, Chain, TransactionInputType};
+
+    #[test]
+    fn test_map_transaction_preload() {
+        let utxos: Data<UTXOS<Vec<CardanoUTXO>>> = serde_json::from_value(serde_json::json!({
+            "data": { "utxos": [
+                { "address": "addr1", "txHash": "aa", "index": 1, "value": "2000000", "tokens": [
+                    { "quantity": "150", "asset": { "fingerprint": "asset1", "policyId": "bb", "assetName": "4d494e" } }
+                ] },
+                { "address": "addr1", "txHash": "cc", "index": 0, "value": "5000000", "tokens": [] }
+            ] }
+        }))
+        .unwrap();
+        let parameters: Data<ProtocolParametersData> = serde_json::from_value(serde_json::json!({
+            "data": { "cardano": { "tip": { "slotNo": 140000000 }, "currentEpoch": { "protocolParams": { "minFeeA": 44, "minFeeB": 155381, "coinsPerUtxoByte": 4310, "keyDeposit": 2000000 } } } }
+        }))
+        .unwrap();
+        let input = TransactionPreloadInput {
+            input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Cardano)),
+            sender_address: "addr1".into(),
+            destination_address: "addr2".into(),
+            mev_protection: false,
+        };
+
+        let TransactionLoadMetadata::Cardano {
+            utxos,
+            tokens,
+            min_fee_a,
+            min_fee_b,
+            coins_per_utxo_byte,
+            key_deposit,
+            ttl,
+            is_stake_registered,
+            stake_rewards,
+        } = map_transaction_preload(utxos.data.utxos, parameters.data, None, input)
+        else {
+            panic!("expected Cardano metadata");
+        };
+
+        assert_eq!(utxos.len(), 2);
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].transaction_id, "aa");
+        assert_eq!(tokens[0].vout, 1);
+        assert_eq!(tokens[0].token_id, "bb4d494e");
+        assert_eq!(tokens[0].quantity, "150");
+        assert_eq!((min_fee_a, min_fee_b, coins_per_utxo_byte), (44, 155381, 4310));
+        assert_eq!(key_deposit, 2_000_000);
+        assert_eq!(ttl, 140_007_200);
+        assert!(!is_stake_registered);
+        assert_eq!(stake_rewards, 0);
     }
 }
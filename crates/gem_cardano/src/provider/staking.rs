@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use chain_traits::ChainStaking;
+use num_bigint::BigUint;
+use std::error::Error;
+
+use gem_client::Client;
+use primitives::{DelegationBase, DelegationValidator};
+
+use super::staking_mapper::{map_delegations, map_staking_apy, map_validators};
+use crate::address::stake_address;
+use crate::rpc::client::CardanoClient;
+
+const STAKE_POOLS_LIMIT: u32 = 500;
+
+#[async_trait]
+impl<C: Client> ChainStaking for CardanoClient<C> {
+    async fn get_staking_apy(&self) -> Result<Option<f64>, Box<dyn Error + Sync + Send>> {
+        let parameters = self.get_staking_parameters().await?;
+        Ok(Some(map_staking_apy(&parameters)?))
+    }
+
+    async fn get_staking_validators(&self, apy: Option<f64>) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
+        let parameters = self.get_staking_parameters().await?;
+        let pools = self.get_stake_pools(STAKE_POOLS_LIMIT).await?;
+        let apy = match apy {
+            Some(apy) => apy,
+            None => map_staking_apy(&parameters)?,
+        };
+        map_validators(pools.stake_pools, &parameters, apy)
+    }
+
+    async fn get_staking_delegations(&self, address: String) -> Result<Vec<DelegationBase>, Box<dyn Error + Sync + Send>> {
+        let stake_address = stake_address(&address)?;
+        let account = self.get_stake_account(&stake_address).await?;
+        let balance = self.get_balance(&address).await?.parse::<BigUint>()?;
+        Ok(map_delegations(&account, &stake_address, balance))
+    }
+}
+
+#[cfg(all(test, feature = "chain_integration_tests"))]
+mod chain_integration_tests {
+    use super::*;
+    use crate::provider::testkit::{TEST_ADDRESS, create_test_client};
+
+    #[tokio::test]
+    async fn test_cardano_get_staking_validators() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_test_client();
+        let apy = client.get_staking_apy().await?;
+        let validators = client.get_staking_validators(apy).await?;
+
+        println!("APY: {:?}, validators: {}", apy, validators.len());
+        assert!(!validators.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cardano_get_staking_delegations() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_test_client();
+        let delegations = client.get_staking_delegations(TEST_ADDRESS.to_string()).await?;
+
+        println!("Delegations: {:?}", delegations);
+        Ok(())
+    }
+}
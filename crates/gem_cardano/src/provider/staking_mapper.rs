@@ -0,0 +1,158 @@
+use num_bigint::BigUint;
+use std::error::Error;
+
+use primitives::{AssetBalance, Chain, DelegationBase, DelegationState, DelegationValidator};
+
+use crate::models::staking::{StakeAccount, StakePool, StakingParametersData};
+
+const EPOCHS_PER_YEAR: f64 = 73.0;
+
+pub fn map_staking_apy(parameters: &StakingParametersData) -> Result<f64, Box<dyn Error + Sync + Send>> {
+    let epoch = &parameters.cardano.current_epoch;
+    let reserves = epoch.ada_pots.reserves.parse::<f64>()?;
+    let active_stake = epoch.active_stake_aggregate.amount() as f64;
+    if active_stake == 0.0 {
+        return Ok(0.0);
+    }
+    let epoch_rewards = reserves * epoch.protocol_params.rho * (1.0 - epoch.protocol_params.tau);
+    Ok(epoch_rewards * EPOCHS_PER_YEAR / active_stake * 100.0)
+}
+
+pub fn map_validators(pools: Vec<StakePool>, parameters: &StakingParametersData, apy: f64) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
+    let epoch = &parameters.cardano.current_epoch;
+    let saturation_point = epoch.active_stake_aggregate.amount() as f64 / epoch.protocol_params.n_opt.max(1) as f64;
+
+    pools
+        .into_iter()
+        .map(|pool| {
+            let stake = match pool.active_stake.first() {
+                Some(stake) => stake.amount.parse::<f64>()?,
+                None => 0.0,
+            };
+            let saturation = if saturation_point > 0.0 { stake / saturation_point } else { 0.0 };
+            let apr = apy * (1.0 - pool.margin) * (1.0 / saturation).min(1.0);
+            let name = pool.name();
+            Ok(DelegationValidator::stake(Chain::Cardano, pool.id, name, stake > 0.0, pool.margin * 100.0, apr))
+        })
+        .collect()
+}
+
+pub fn map_delegations(account: &StakeAccount, stake_address: &str, balance: BigUint) -> Vec<DelegationBase> {
+    account
+        .delegated_pool()
+        .map(|pool| DelegationBase {
+            asset_id: Chain::Cardano.as_asset_id(),
+            state: DelegationState::Active,
+            balance,
+            shares: BigUint::from(0u32),
+            rewards: BigUint::from(account.available_rewards()),
+            completion_date: None,
+            delegation_id: stake_address.to_string(),
+            validator_id: pool.to_string(),
+        })
+        .into_iter()
+        .collect()
+}
+
+pub fn map_balance_staking(account: &StakeAccount) -> AssetBalance {
+    AssetBalance::new_staking(
+        Chain::Cardano.as_asset_id(),
+        BigUint::from(0u32),
+        BigUint::from(0u32),
+        BigUint::from(account.available_rewards()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::staking::StakePools;
+
+    fn parameters() -> StakingParametersData {
+        serde_json::from_value(serde_json::json!({
+            "cardano": { "currentEpoch": {
+                "protocolParams": { "rho": 0.003, "tau": 0.2, "nOpt": 500 },
+                "adaPots": { "reserves": "7000000000000000" },
+                "activeStake_aggregate": { "aggregate": { "sum": { "amount": "21000000000000000" } } }
+            } }
+        }))
+        .unwrap()
+    }
+
+    fn account(registrations: &[u64], deregistrations: &[u64], delegations: &[(&str, u64)]) -> StakeAccount {
+        let certificate = |slot: &u64| serde_json::json!({ "transaction": { "block": { "slotNo": slot } } });
+        serde_json::from_value(serde_json::json!({
+            "delegations": delegations.iter().map(|(pool, slot)| serde_json::json!({ "stakePool": { "id": pool }, "transaction": { "block": { "slotNo": slot } } })).collect::<Vec<_>>(),
+            "stakeRegistrations": registrations.iter().map(certificate).collect::<Vec<_>>(),
+            "stakeDeregistrations": deregistrations.iter().map(certificate).collect::<Vec<_>>(),
+            "rewards_aggregate": { "aggregate": { "sum": { "amount": "5000000" } } },
+            "withdrawals_aggregate": { "aggregate": { "sum": { "amount": "1500000" } } }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_map_staking_apy() {
+        let apy = map_staking_apy(&parameters()).unwrap();
+        assert!((apy - 5.84).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_map_validators() {
+        let pools: StakePools = serde_json::from_value(serde_json::json!({
+            "stakePools": [
+                { "id": "pool1a", "margin": 0.01, "activeStake": [{ "amount": "21000000000000" }], "metadata": { "ticker": "GEM", "name": "Gem Pool" } },
+                { "id": "pool1b", "margin": 0.0, "activeStake": [{ "amount": "84000000000000" }], "metadata": { "name": "Pool B" } },
+                { "id": "pool1c", "margin": 0.05, "activeStake": [] }
+            ]
+        }))
+        .unwrap();
+
+        let validators = map_validators(pools.stake_pools, &parameters(), 3.0).unwrap();
+
+        assert_eq!(validators[0].id, "pool1a");
+        assert_eq!(validators[0].name, "GEM");
+        assert_eq!(validators[1].name, "Pool B");
+        assert_eq!(validators[2].name, "pool1c");
+        assert!((validators[0].apr - 2.97).abs() < 1e-9);
+        assert!((validators[0].commission - 1.0).abs() < 1e-9);
+        // 2x saturated
+        assert!((validators[1].apr - 1.5).abs() < 1e-9);
+        assert!(validators[1].is_active);
+        assert!(!validators[2].is_active);
+
+        let invalid: StakePools = serde_json::from_value(serde_json::json!({
+            "stakePools": [{ "id": "pool1a", "margin": 0.01, "activeStake": [{ "amount": "invalid" }] }]
+        }))
+        .unwrap();
+        assert!(map_validators(invalid.stake_pools, &parameters(), 3.0).is_err());
+    }
+
+    #[test]
+    fn test_map_delegations() {
+        let delegated = account(&[100], &[], &[("pool1a", 100), ("pool1b", 200)]);
+        let delegations = map_delegations(&delegated, "stake1u", BigUint::from(10_000_000u32));
+
+        assert_eq!(delegations.len(), 1);
+        assert_eq!(delegations[0].validator_id, "pool1b");
+        assert_eq!(delegations[0].delegation_id, "stake1u");
+        assert_eq!(delegations[0].balance, BigUint::from(10_000_000u32));
+        assert_eq!(delegations[0].rewards, BigUint::from(3_500_000u32));
+
+        let deregistered = account(&[100], &[300], &[("pool1a", 100)]);
+        assert!(!deregistered.is_registered());
+        assert!(map_delegations(&deregistered, "stake1u", BigUint::from(1u32)).is_empty());
+
+        let reregistered = account(&[100, 400], &[300], &[("pool1a", 100)]);
+        assert!(reregistered.is_registered());
+        assert!(map_delegations(&reregistered, "stake1u", BigUint::from(1u32)).is_empty());
+    }
+
+    #[test]
+    fn test_map_balance_staking() {
+        let balance = map_balance_staking(&account(&[100], &[], &[("pool1a", 100)]));
+
+        assert_eq!(balance.balance.rewards, BigUint::from(3_500_000u32));
+        assert_eq!(balance.balance.staked, BigUint::from(0u32));
+    }
+}
@@ -1,13 +1,14 @@
 use std::error::Error;
 
-use chain_traits::{ChainAddressStatus, ChainPerpetual, ChainProvider, ChainStaking, ChainTraits};
+use chain_traits::{ChainAddressStatus, ChainPerpetual, ChainProvider, ChainTraits};
 use gem_client::{Client, ClientExt};
 use primitives::chain::Chain;
 
 use crate::models::{
     account::BalanceResponse,
-    block::{BlockData, GenesisData},
+    block::{BlockData, GenesisData, ProtocolParametersData},
     rpc::{Block, Blocks, Data},
+    staking::{StakeAccount, StakePools, StakingParametersData},
     transaction::TransactionBroadcast,
     utxo::{UTXO, UTXOS},
 };
@@ -32,6 +33,16 @@ impl<C: Client> CardanoClient<C> {
         Ok(response.data.cardano.tip.number as i64)
     }
 
+    pub async fn get_protocol_parameters(&self) -> Result<ProtocolParametersData, Box<dyn Error + Send + Sync>> {
+        let json = serde_json::json!({
+            "operationName": "GetProtocolParameters",
+            "variables": {},
+            "query": "query GetProtocolParameters { cardano { tip { slotNo } currentEpoch { protocolParams { minFeeA minFeeB coinsPerUtxoByte keyDeposit } } } }"
+        });
+        let response: Data<ProtocolParametersData> = self.client.post("/", &json).await?;
+        Ok(response.data)
+    }
+
     pub async fn get_block(&self, block_number: i64) -> Result<Block, Box<dyn Error + Send + Sync>> {
         let json = serde_json::json!({
             "query": "query GetBlockByNumber($blockNumber: Int!) { blocks(where: { number: { _eq: $blockNumber } }) { number hash forgedAt transactions { hash inputs { address value } outputs { address value } fee } } }",
@@ -75,6 +86,36 @@ impl<C: Client> CardanoClient<C> {
         Ok(response.data.utxos)
     }
 
+    pub async fn get_staking_parameters(&self) -> Result<StakingParametersData, Box<dyn Error + Send + Sync>> {
+        let json = serde_json::json!({
+            "operationName": "GetStakingParameters",
+            "variables": {},
+            "query": "query GetStakingParameters { cardano { currentEpoch { protocolParams { rho tau nOpt } adaPots { reserves } activeStake_aggregate { aggregate { sum { amount } } } } } }"
+        });
+        let response: Data<StakingParametersData> = self.client.post("/", &json).await?;
+        Ok(response.data)
+    }
+
+    pub async fn get_stake_pools(&self, limit: u32) -> Result<StakePools, Box<dyn Error + Send + Sync>> {
+        let json = serde_json::json!({
+            "operationName": "GetStakePools",
+            "variables": {"limit": limit},
+            "query": "query GetStakePools($limit: Int!) { stakePools(limit: $limit, where: { _not: { retirements: {} } }) { id margin activeStake(limit: 1, order_by: { epochNo: desc }) { amount } metadata { ticker name } } }"
+        });
+        let response: Data<StakePools> = self.client.post("/", &json).await?;
+        Ok(response.data)
+    }
+
+    pub async fn get_stake_account(&self, stake_address: &str) -> Result<StakeAccount, Box<dyn Error + Send + Sync>> {
+        let json = serde_json::json!({
+            "operationName": "GetStakeAccount",
+            "variables": {"address": stake_address},
+            "query": "query GetStakeAccount($address: String!) { delegations(where: { address: { _eq: $address } }) { stakePool { id } transaction { block { slotNo } } } stakeRegistrations(where: { address: { _eq: $address } }) { transaction { block { slotNo } } } stakeDeregistrations(where: { address: { _eq: $address } }) { transaction { block { slotNo } } } rewards_aggregate(where: { address: { _eq: $address } }) { aggregate { sum { amount } } } withdrawals_aggregate(where: { address: { _eq: $address } }) { aggregate { sum { amount } } } }"
+        });
+        let response: Data<StakeAccount> = self.client.post("/", &json).await?;
+        Ok(response.data)
+    }
+
     pub async fn get_network_magic(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
         let json = serde_json::json!({
             "operationName": "GetNetworkMagic",
@@ -119,8 +160,6 @@ impl<C: Client> CardanoClient<C> {
     }
 }
 
-impl<C: Client> ChainStaking for CardanoClient<C> {}
-
 impl<C: Client> ChainPerpetual for CardanoClient<C> {}
 
 impl<C: Client> ChainAddressStatus for CardanoClient<C> {}
@@ -0,0 +1,321 @@
+use gem_hash::blake2::{blake2b_224, blake2b_256};
+use primitives::{ChainSigner, SignerError, SignerInput};
+use signer::{Ed25519ExtendedKeyPair, Ed25519KeyPair};
+
+use crate::address::{decode_address, payment_key_hash, stake_key_hash};
+use crate::transaction::{Signature, TransactionBody, VerificationKey, encode_transaction, plan_stake, plan_transfer};
+
+// Extended wallet keys are `payment (kL || kR || chain code) || stake (kL || kR || chain code)`
+const EXTENDED_KEY_LENGTH: usize = 96;
+
+#[derive(Default)]
+pub struct CardanoChainSigner;
+
+impl ChainSigner for CardanoChainSigner {
+    fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        sign_transaction(input, &plan_transfer(input)?, private_key)
+    }
+
+    fn sign_token_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        sign_transaction(input, &plan_transfer(input)?, private_key)
+    }
+
+    fn sign_stake(&self, input: &SignerInput, private_key: &[u8]) -> Result<Vec<String>, SignerError> {
+        Ok(vec![sign_transaction(input, &plan_stake(input)?, private_key)?])
+    }
+}
+
+fn sign_transaction(input: &SignerInput, body: &TransactionBody, private_key: &[u8]) -> Result<String, SignerError> {
+    let hash = blake2b_256(&body.encode());
+    let sender = decode_address(&input.sender_address)?;
+
+    let mut witnesses = vec![sign_hash(&hash, payment_private_key(private_key), payment_key_hash(&sender))?];
+    if body.requires_stake_key() {
+        let stake_key = private_key
+            .get(EXTENDED_KEY_LENGTH..2 * EXTENDED_KEY_LENGTH)
+            .ok_or_else(|| SignerError::invalid_input("missing Cardano stake private key"))?;
+        witnesses.push(sign_hash(&hash, stake_key, stake_key_hash(&sender).as_ref().map(|hash| &hash[..]))?);
+    }
+    Ok(hex::encode(encode_transaction(body, &witnesses)))
+}
+
+fn payment_private_key(private_key: &[u8]) -> &[u8] {
+    private_key.get(..EXTENDED_KEY_LENGTH).unwrap_or(private_key)
+}
+
+fn sign_hash(hash: &[u8], private_key: &[u8], key_hash: Option<&[u8]>) -> Result<(VerificationKey, Signature), SignerError> {
+    let (public_key, signature) = if private_key.len() == 32 {
+        let key_pair = Ed25519KeyPair::from_private_key(private_key)?;
+        (key_pair.public_key_bytes, key_pair.sign(hash))
+    } else {
+        let key_pair = Ed25519ExtendedKeyPair::from_private_key(private_key)?;
+        (key_pair.public_key_bytes, key_pair.sign(hash))
+    };
+    if key_hash != Some(&blake2b_224(&public_key)[..]) {
+        return SignerError::invalid_input_err("private key does not match Cardano sender address");
+    }
+    Ok((public_key, signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::ProtocolParameters;
+    use crate::transaction::{Certificate, Withdrawal};
+    use ed25519_dalek::{Signature as DalekSignature, Verifier, VerifyingKey};
+    use primitives::{
+        Asset, AssetId, AssetType, CardanoUTXOToken, Chain, Delegation, DelegationValidator, RedelegateData, StakeType, TransactionFee, TransactionInputType, TransactionLoadInput,
+        TransactionLoadMetadata, UTXO,
+    };
+
+    // wallet-core Cardano test key (kL || kR || chain code || staking key) and its base address
+    const PRIVATE_KEY: &str = "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e";
+    const SENDER: &str = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+    const DESTINATION: &str = "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+    const TOKEN_ID: &str = "29d222ce763455e3d7a09a665ce554f00ac89d2e99a1a83d267170c64d494e";
+    const PARAMETERS: ProtocolParameters = ProtocolParameters {
+        min_fee_a: 44,
+        min_fee_b: 155_381,
+        coins_per_utxo_byte: 4_310,
+        key_deposit: 2_000_000,
+    };
+    const POOL_ID: &str = "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy";
+
+    fn utxo(transaction_id: u8, vout: i32, value: u64) -> UTXO {
+        UTXO {
+            transaction_id: hex::encode([transaction_id; 32]),
+            vout,
+            value: value.to_string(),
+            address: SENDER.into(),
+        }
+    }
+
+    fn metadata(tokens: Vec<CardanoUTXOToken>, is_stake_registered: bool, stake_rewards: u64) -> TransactionLoadMetadata {
+        TransactionLoadMetadata::Cardano {
+            utxos: vec![utxo(1, 0, 1_500_000), utxo(2, 1, 6_000_000), utxo(3, 0, 2_000_000)],
+            tokens,
+            min_fee_a: PARAMETERS.min_fee_a,
+            min_fee_b: PARAMETERS.min_fee_b,
+            coins_per_utxo_byte: PARAMETERS.coins_per_utxo_byte,
+            key_deposit: PARAMETERS.key_deposit,
+            ttl: 140_007_200,
+            is_stake_registered,
+            stake_rewards,
+        }
+    }
+
+    fn input(asset: Asset, value: &str, tokens: Vec<CardanoUTXOToken>) -> SignerInput {
+        let metadata = metadata(tokens, false, 0);
+        SignerInput::new(
+            TransactionLoadInput::mock_transfer(asset, SENDER, DESTINATION, value, 0, None, metadata),
+            TransactionFee::default(),
+        )
+    }
+
+    fn token_input(value: &str) -> SignerInput {
+        let asset = Asset::mock_with_params(Chain::Cardano, Some(TOKEN_ID.into()), "MIN".into(), "MIN".into(), 6, AssetType::TOKEN);
+        let token = CardanoUTXOToken {
+            transaction_id: hex::encode([3u8; 32]),
+            vout: 0,
+            token_id: TOKEN_ID.into(),
+            quantity: "1000".into(),
+        };
+        input(asset, value, vec![token])
+    }
+
+    fn stake_input(stake_type: StakeType, is_stake_registered: bool, stake_rewards: u64) -> SignerInput {
+        let mut input = input(Asset::from_chain(Chain::Cardano), "0", vec![]);
+        input.input.input_type = TransactionInputType::Stake(Asset::from_chain(Chain::Cardano), stake_type);
+        input.input.metadata = metadata(vec![], is_stake_registered, stake_rewards);
+        input
+    }
+
+    fn pool() -> DelegationValidator {
+        DelegationValidator {
+            id: POOL_ID.into(),
+            ..DelegationValidator::mock()
+        }
+    }
+
+    fn assert_signed(signed: &str, body: &TransactionBody) {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let hash = blake2b_256(&body.encode());
+        let keys: &[&[u8]] = if body.requires_stake_key() { &[&key[..64], &key[96..160]] } else { &[&key[..64]] };
+        let witnesses: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                let key_pair = Ed25519ExtendedKeyPair::from_private_key(key).unwrap();
+                let signature = key_pair.sign(&hash);
+                let verifying_key = VerifyingKey::from_bytes(&key_pair.public_key_bytes).unwrap();
+                assert!(verifying_key.verify(&hash, &DalekSignature::from_bytes(&signature)).is_ok());
+                (key_pair.public_key_bytes, signature)
+            })
+            .collect();
+
+        assert_eq!(signed, hex::encode(encode_transaction(body, &witnesses)));
+    }
+
+    fn total_in(input: &SignerInput, body: &TransactionBody) -> u64 {
+        let TransactionLoadMetadata::Cardano { utxos, .. } = &input.metadata else {
+            unreachable!()
+        };
+        body.inputs
+            .iter()
+            .map(|selected| {
+                let utxo = utxos.iter().find(|utxo| utxo.transaction_id == hex::encode(selected.transaction_id)).unwrap();
+                utxo.value.parse::<u64>().unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_sign_transfer() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let input = input(Asset::from_chain(Chain::Cardano), "3000000", vec![]);
+
+        let body = plan_transfer(&input).unwrap();
+        assert_eq!(body.inputs.len(), 1);
+        assert_eq!(body.inputs[0].transaction_id, [2; 32]);
+        assert_eq!(body.outputs.len(), 2);
+        assert_eq!(body.outputs[0].address, decode_address(DESTINATION).unwrap());
+        assert_eq!(body.outputs[0].coin, 3_000_000);
+        assert_eq!(body.outputs[1].address, decode_address(SENDER).unwrap());
+        assert_eq!(body.fee, PARAMETERS.min_fee(&body));
+        assert_eq!(body.outputs.iter().map(|output| output.coin).sum::<u64>() + body.fee, total_in(&input, &body));
+        assert_eq!(body.ttl, 140_007_200);
+
+        assert_signed(&CardanoChainSigner.sign_transfer(&input, &key).unwrap(), &body);
+    }
+
+    #[test]
+    fn test_sign_transfer_max_value() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let mut input = token_input("9500000");
+        input.input.input_type = TransactionInputType::Transfer(Asset::from_chain(Chain::Cardano));
+        input.input.is_max_value = true;
+
+        let body = plan_transfer(&input).unwrap();
+        assert_eq!(body.inputs.len(), 3);
+        assert_eq!(body.outputs.len(), 2);
+        // Tokens stay with the sender
+        assert!(body.outputs[0].tokens.is_empty());
+        assert_eq!(body.outputs[1].tokens.values().sum::<u64>(), 1000);
+        assert_eq!(body.outputs[1].coin, PARAMETERS.min_ada(&body.outputs[1]));
+        assert_eq!(body.outputs[0].coin + body.outputs[1].coin + body.fee, 9_500_000);
+
+        assert_signed(&CardanoChainSigner.sign_transfer(&input, &key).unwrap(), &body);
+    }
+
+    #[test]
+    fn test_sign_token_transfer() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let input = token_input("400");
+
+        let body = plan_transfer(&input).unwrap();
+        assert_eq!(body.inputs[0].transaction_id, [3; 32]);
+        let destination = &body.outputs[0];
+        assert_eq!(destination.tokens.values().copied().collect::<Vec<_>>(), vec![400]);
+        assert_eq!(destination.coin, PARAMETERS.min_ada(destination));
+        let change = &body.outputs[1];
+        assert_eq!(change.tokens.values().copied().collect::<Vec<_>>(), vec![600]);
+        assert!(change.coin >= PARAMETERS.min_ada(change));
+        assert_eq!(body.outputs.iter().map(|output| output.coin).sum::<u64>() + body.fee, total_in(&input, &body));
+
+        assert_signed(&CardanoChainSigner.sign_token_transfer(&input, &key).unwrap(), &body);
+    }
+
+    #[test]
+    fn test_sign_rejects_invalid_input() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        assert!(
+            CardanoChainSigner
+                .sign_transfer(&input(Asset::from_chain(Chain::Cardano), "9500000", vec![]), &key)
+                .is_err()
+        );
+        assert!(CardanoChainSigner.sign_transfer(&input(Asset::from_chain(Chain::Cardano), "500000", vec![]), &key).is_err());
+        assert!(CardanoChainSigner.sign_token_transfer(&token_input("1001"), &key).is_err());
+        assert!(
+            CardanoChainSigner
+                .sign_transfer(&input(Asset::from_chain(Chain::Cardano), "3000000", vec![]), &[7u8; 32])
+                .is_err()
+        );
+
+        let mut token = token_input("1");
+        token.input.input_type = TransactionInputType::Transfer(Asset::new(AssetId::from_token(Chain::Cardano, "abcd"), "X".into(), "X".into(), 0, AssetType::TOKEN));
+        assert!(CardanoChainSigner.sign_token_transfer(&token, &key).is_err());
+    }
+
+    #[test]
+    fn test_stake_key_matches_address() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let stake_key = Ed25519ExtendedKeyPair::from_private_key(&key[96..160]).unwrap();
+
+        assert_eq!(blake2b_224(&stake_key.public_key_bytes), stake_key_hash(&decode_address(SENDER).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_sign_stake_registers_and_delegates() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let input = stake_input(StakeType::Stake(pool()), false, 0);
+        let stake_key = stake_key_hash(&decode_address(SENDER).unwrap()).unwrap();
+        let pool_hash = crate::address::decode_pool_id(POOL_ID).unwrap();
+
+        let body = plan_stake(&input).unwrap();
+        assert_eq!(
+            body.certificates,
+            vec![Certificate::StakeRegistration(stake_key), Certificate::StakeDelegation(stake_key, pool_hash)]
+        );
+        assert!(body.withdrawals.is_empty());
+        assert_eq!(body.outputs.len(), 1);
+        assert_eq!(body.outputs[0].address, decode_address(SENDER).unwrap());
+        assert_eq!(body.outputs[0].coin + body.fee + PARAMETERS.key_deposit, total_in(&input, &body));
+        assert_eq!(body.fee, PARAMETERS.min_fee(&body));
+
+        let signed = CardanoChainSigner.sign_stake(&input, &key).unwrap();
+        assert_eq!(signed.len(), 1);
+        assert_signed(&signed[0], &body);
+
+        let redelegate = StakeType::Redelegate(RedelegateData {
+            delegation: Delegation::mock(),
+            to_validator: pool(),
+        });
+        let body = plan_stake(&stake_input(redelegate, true, 0)).unwrap();
+        assert_eq!(body.certificates, vec![Certificate::StakeDelegation(stake_key, pool_hash)]);
+    }
+
+    #[test]
+    fn test_sign_stake_withdraws_rewards() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let stake_key = stake_key_hash(&decode_address(SENDER).unwrap()).unwrap();
+
+        let input = stake_input(StakeType::Rewards(vec![pool()]), true, 3_500_000);
+        let body = plan_stake(&input).unwrap();
+        assert!(body.certificates.is_empty());
+        assert_eq!(
+            body.withdrawals,
+            vec![Withdrawal {
+                stake_key_hash: stake_key,
+                coin: 3_500_000
+            }]
+        );
+        assert_eq!(body.outputs[0].coin + body.fee, total_in(&input, &body) + 3_500_000);
+        assert_signed(&CardanoChainSigner.sign_stake(&input, &key).unwrap()[0], &body);
+
+        let input = stake_input(StakeType::Unstake(Delegation::mock()), true, 3_500_000);
+        let body = plan_stake(&input).unwrap();
+        assert_eq!(body.certificates, vec![Certificate::StakeDeregistration(stake_key)]);
+        assert_eq!(body.withdrawals.len(), 1);
+        assert_eq!(body.outputs[0].coin + body.fee, total_in(&input, &body) + 3_500_000 + PARAMETERS.key_deposit);
+        assert_signed(&CardanoChainSigner.sign_stake(&input, &key).unwrap()[0], &body);
+    }
+
+    #[test]
+    fn test_sign_stake_rejects_invalid_input() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        assert!(CardanoChainSigner.sign_stake(&stake_input(StakeType::Rewards(vec![]), true, 0), &key).is_err());
+        assert!(CardanoChainSigner.sign_stake(&stake_input(StakeType::Unstake(Delegation::mock()), false, 0), &key).is_err());
+        assert!(CardanoChainSigner.sign_stake(&stake_input(StakeType::Withdraw(Delegation::mock()), true, 0), &key).is_err());
+        // The stake key is required for delegation
+        assert!(CardanoChainSigner.sign_stake(&stake_input(StakeType::Stake(pool()), true, 0), &key[..96]).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+mod chain_signer;
+
+pub use chain_signer::CardanoChainSigner;
@@ -0,0 +1,179 @@
+mod planner;
+mod staking;
+
+use std::collections::BTreeMap;
+
+use primitives::{SignerError, TransactionInputType, TransactionLoadInput};
+
+pub use planner::{ProtocolParameters, plan_transfer};
+pub use staking::plan_stake;
+
+use crate::address::{KeyHash, reward_address};
+use crate::cbor::Cbor;
+
+const BODY_INPUTS: u64 = 0;
+const BODY_OUTPUTS: u64 = 1;
+const BODY_FEE: u64 = 2;
+const BODY_TTL: u64 = 3;
+const BODY_CERTIFICATES: u64 = 4;
+const BODY_WITHDRAWALS: u64 = 5;
+const WITNESS_VKEYS: u64 = 0;
+
+const POLICY_ID_LENGTH: usize = 28;
+
+const CERTIFICATE_STAKE_REGISTRATION: u64 = 0;
+const CERTIFICATE_STAKE_DEREGISTRATION: u64 = 1;
+const CERTIFICATE_STAKE_DELEGATION: u64 = 2;
+const CREDENTIAL_KEY_HASH: u64 = 0;
+
+pub type VerificationKey = [u8; 32];
+pub type Signature = [u8; 64];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenId {
+    pub policy_id: [u8; POLICY_ID_LENGTH],
+    pub asset_name: Vec<u8>,
+}
+
+impl TokenId {
+    pub fn from_hex(token_id: &str) -> Result<Self, SignerError> {
+        let bytes = hex::decode(token_id).map_err(|_| SignerError::invalid_input("invalid Cardano token id"))?;
+        if bytes.len() < POLICY_ID_LENGTH || bytes.len() > POLICY_ID_LENGTH + 32 {
+            return SignerError::invalid_input_err("invalid Cardano token id");
+        }
+        Ok(Self {
+            policy_id: bytes[..POLICY_ID_LENGTH].try_into().unwrap(),
+            asset_name: bytes[POLICY_ID_LENGTH..].to_vec(),
+        })
+    }
+}
+
+pub type TokenBundle = BTreeMap<TokenId, u64>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionInput {
+    pub transaction_id: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOutput {
+    pub address: Vec<u8>,
+    pub coin: u64,
+    pub tokens: TokenBundle,
+}
+
+impl TransactionOutput {
+    fn to_cbor(&self) -> Cbor {
+        let amount = if self.tokens.is_empty() {
+            Cbor::Uint(self.coin)
+        } else {
+            let mut policies: BTreeMap<&[u8; POLICY_ID_LENGTH], Vec<(Cbor, Cbor)>> = BTreeMap::new();
+            for (token, quantity) in &self.tokens {
+                policies
+                    .entry(&token.policy_id)
+                    .or_default()
+                    .push((Cbor::Bytes(token.asset_name.clone()), Cbor::Uint(*quantity)));
+            }
+            let multi_asset = policies
+                .into_iter()
+                .map(|(policy_id, assets)| (Cbor::Bytes(policy_id.to_vec()), Cbor::Map(assets)))
+                .collect();
+            Cbor::Array(vec![Cbor::Uint(self.coin), Cbor::Map(multi_asset)])
+        };
+        Cbor::Array(vec![Cbor::Bytes(self.address.clone()), amount])
+    }
+
+    pub fn encoded_length(&self) -> usize {
+        self.to_cbor().encode().len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Certificate {
+    StakeRegistration(KeyHash),
+    StakeDeregistration(KeyHash),
+    StakeDelegation(KeyHash, KeyHash),
+}
+
+impl Certificate {
+    fn to_cbor(&self) -> Cbor {
+        let credential = |hash: &KeyHash| Cbor::Array(vec![Cbor::Uint(CREDENTIAL_KEY_HASH), Cbor::Bytes(hash.to_vec())]);
+        match self {
+            Self::StakeRegistration(hash) => Cbor::Array(vec![Cbor::Uint(CERTIFICATE_STAKE_REGISTRATION), credential(hash)]),
+            Self::StakeDeregistration(hash) => Cbor::Array(vec![Cbor::Uint(CERTIFICATE_STAKE_DEREGISTRATION), credential(hash)]),
+            Self::StakeDelegation(hash, pool) => Cbor::Array(vec![Cbor::Uint(CERTIFICATE_STAKE_DELEGATION), credential(hash), Cbor::Bytes(pool.to_vec())]),
+        }
+    }
+
+    fn requires_stake_key(&self) -> bool {
+        match self {
+            Self::StakeRegistration(_) => false,
+            Self::StakeDeregistration(_) | Self::StakeDelegation(_, _) => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Withdrawal {
+    pub stake_key_hash: KeyHash,
+    pub coin: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionBody {
+    pub inputs: Vec<TransactionInput>,
+    pub outputs: Vec<TransactionOutput>,
+    pub fee: u64,
+    pub ttl: u64,
+    pub certificates: Vec<Certificate>,
+    pub withdrawals: Vec<Withdrawal>,
+}
+
+impl TransactionBody {
+    pub fn encode(&self) -> Vec<u8> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| Cbor::Array(vec![Cbor::Bytes(input.transaction_id.to_vec()), Cbor::Uint(input.index as u64)]))
+            .collect();
+        let mut entries = vec![
+            (Cbor::Uint(BODY_INPUTS), Cbor::Array(inputs)),
+            (Cbor::Uint(BODY_OUTPUTS), Cbor::Array(self.outputs.iter().map(TransactionOutput::to_cbor).collect())),
+            (Cbor::Uint(BODY_FEE), Cbor::Uint(self.fee)),
+            (Cbor::Uint(BODY_TTL), Cbor::Uint(self.ttl)),
+        ];
+        if !self.certificates.is_empty() {
+            entries.push((Cbor::Uint(BODY_CERTIFICATES), Cbor::Array(self.certificates.iter().map(Certificate::to_cbor).collect())));
+        }
+        if !self.withdrawals.is_empty() {
+            let withdrawals = self
+                .withdrawals
+                .iter()
+                .map(|withdrawal| (Cbor::Bytes(reward_address(&withdrawal.stake_key_hash)), Cbor::Uint(withdrawal.coin)))
+                .collect();
+            entries.push((Cbor::Uint(BODY_WITHDRAWALS), Cbor::Map(withdrawals)));
+        }
+        Cbor::Map(entries).encode()
+    }
+
+    pub fn requires_stake_key(&self) -> bool {
+        !self.withdrawals.is_empty() || self.certificates.iter().any(Certificate::requires_stake_key)
+    }
+}
+
+pub fn plan_transaction(input: &TransactionLoadInput) -> Result<TransactionBody, SignerError> {
+    match input.input_type {
+        TransactionInputType::Stake(_, _) => plan_stake(input),
+        _ => plan_transfer(input),
+    }
+}
+
+pub fn encode_transaction(body: &TransactionBody, witnesses: &[(VerificationKey, Signature)]) -> Vec<u8> {
+    let witnesses = witnesses
+        .iter()
+        .map(|(key, signature)| Cbor::Array(vec![Cbor::Bytes(key.to_vec()), Cbor::Bytes(signature.to_vec())]))
+        .collect();
+    let witness_set = Cbor::Map(vec![(Cbor::Uint(WITNESS_VKEYS), Cbor::Array(witnesses))]);
+    [&[0x84][..], &body.encode(), &witness_set.encode(), &Cbor::Bool(true).encode(), &Cbor::Null.encode()].concat()
+}
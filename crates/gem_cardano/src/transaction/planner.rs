@@ -0,0 +1,286 @@
+use std::cmp::Reverse;
+
+use primitives::{CardanoUTXOToken, SignerError, TransactionLoadInput, TransactionLoadMetadata, UTXO};
+
+use super::{Certificate, TokenBundle, TokenId, TransactionBody, TransactionInput, TransactionOutput, Withdrawal, encode_transaction};
+use crate::address::decode_address;
+
+// Babbage `minUTxO`: (constant overhead + serialized output size) * coinsPerUTxOByte
+const UTXO_ENTRY_OVERHEAD: u64 = 160;
+const MAX_FEE_ITERATIONS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolParameters {
+    pub min_fee_a: u64,
+    pub min_fee_b: u64,
+    pub coins_per_utxo_byte: u64,
+    pub key_deposit: u64,
+}
+
+impl ProtocolParameters {
+    pub fn min_ada(&self, output: &TransactionOutput) -> u64 {
+        let mut output = output.clone();
+        loop {
+            let required = (UTXO_ENTRY_OVERHEAD + output.encoded_length() as u64) * self.coins_per_utxo_byte;
+            if output.coin >= required {
+                return required;
+            }
+            output.coin = required;
+        }
+    }
+
+    pub fn min_fee(&self, body: &TransactionBody) -> u64 {
+        let witnesses = vec![([0; 32], [0; 64]); 1 + body.requires_stake_key() as usize];
+        let size = encode_transaction(body, &witnesses).len() as u64;
+        self.min_fee_a * size + self.min_fee_b
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Utxo {
+    input: TransactionInput,
+    coin: u64,
+    tokens: TokenBundle,
+}
+
+pub fn plan_transfer(input: &TransactionLoadInput) -> Result<TransactionBody, SignerError> {
+    let planner = Planner::new(input, vec![], vec![])?;
+
+    let amount = input.value.parse::<u64>().map_err(|_| SignerError::invalid_input("invalid Cardano amount"))?;
+    let mut output = TransactionOutput {
+        address: decode_address(&input.destination_address)?,
+        coin: amount,
+        tokens: TokenBundle::new(),
+    };
+    match &input.input_type.get_asset().id.token_id {
+        Some(token_id) => {
+            output.tokens.insert(TokenId::from_hex(token_id)?, amount);
+            output.coin = planner.parameters.min_ada(&output);
+            planner.plan(vec![output])
+        }
+        None if input.is_max_value => planner.plan_max(output),
+        None => {
+            if output.coin < planner.parameters.min_ada(&output) {
+                return SignerError::invalid_input_err("Cardano amount is below the minimum UTXO value");
+            }
+            planner.plan(vec![output])
+        }
+    }
+}
+
+pub(super) fn plan_certificates(input: &TransactionLoadInput, certificates: Vec<Certificate>, withdrawals: Vec<Withdrawal>) -> Result<TransactionBody, SignerError> {
+    Planner::new(input, certificates, withdrawals)?.plan(vec![])
+}
+
+struct Planner {
+    parameters: ProtocolParameters,
+    utxos: Vec<Utxo>,
+    change_address: Vec<u8>,
+    ttl: u64,
+    certificates: Vec<Certificate>,
+    withdrawals: Vec<Withdrawal>,
+}
+
+impl Planner {
+    fn new(input: &TransactionLoadInput, certificates: Vec<Certificate>, withdrawals: Vec<Withdrawal>) -> Result<Self, SignerError> {
+        let TransactionLoadMetadata::Cardano {
+            utxos,
+            tokens,
+            min_fee_a,
+            min_fee_b,
+            coins_per_utxo_byte,
+            key_deposit,
+            ttl,
+            ..
+        } = &input.metadata
+        else {
+            return SignerError::invalid_input_err("expected Cardano metadata");
+        };
+        Ok(Self {
+            parameters: ProtocolParameters {
+                min_fee_a: *min_fee_a,
+                min_fee_b: *min_fee_b,
+                coins_per_utxo_byte: *coins_per_utxo_byte,
+                key_deposit: *key_deposit,
+            },
+            utxos: map_utxos(utxos, tokens)?,
+            change_address: decode_address(&input.sender_address)?,
+            ttl: *ttl,
+            certificates,
+            withdrawals,
+        })
+    }
+
+    fn implicit_balance(&self) -> (u64, u64) {
+        let count = |registration: bool| {
+            self.certificates
+                .iter()
+                .filter(|certificate| match certificate {
+                    Certificate::StakeRegistration(_) => registration,
+                    Certificate::StakeDeregistration(_) => !registration,
+                    Certificate::StakeDelegation(..) => false,
+                })
+                .count() as u64
+        };
+        let withdrawn: u64 = self.withdrawals.iter().map(|withdrawal| withdrawal.coin).sum();
+        (withdrawn + count(false) * self.parameters.key_deposit, count(true) * self.parameters.key_deposit)
+    }
+
+    fn plan(&self, outputs: Vec<TransactionOutput>) -> Result<TransactionBody, SignerError> {
+        let mut candidates: Vec<&Utxo> = self.utxos.iter().collect();
+        candidates.sort_by_key(|utxo| {
+            let requested: u64 = outputs
+                .iter()
+                .flat_map(|output| output.tokens.keys())
+                .map(|token| utxo.tokens.get(token).copied().unwrap_or_default())
+                .sum();
+            (Reverse(requested), Reverse(utxo.coin))
+        });
+
+        (1..=candidates.len())
+            .find_map(|count| self.build(&candidates[..count], &outputs))
+            .ok_or_else(|| SignerError::invalid_input("insufficient Cardano balance"))
+    }
+
+    fn plan_max(&self, mut output: TransactionOutput) -> Result<TransactionBody, SignerError> {
+        let selected: Vec<&Utxo> = self.utxos.iter().collect();
+        let (total_coin, total_tokens) = totals(&selected);
+        let change = (!total_tokens.is_empty()).then(|| {
+            let mut change = self.change_output(0, total_tokens);
+            change.coin = self.parameters.min_ada(&change);
+            change
+        });
+        let reserved = change.as_ref().map(|change| change.coin).unwrap_or_default();
+
+        let mut fee = 0;
+        for _ in 0..MAX_FEE_ITERATIONS {
+            output.coin = total_coin.saturating_sub(reserved + fee);
+            if output.coin < self.parameters.min_ada(&output) {
+                break;
+            }
+            let outputs = [Some(output.clone()), change.clone()].into_iter().flatten().collect();
+            let body = self.body(&selected, outputs, fee);
+            let required = self.parameters.min_fee(&body);
+            if required <= fee {
+                return Ok(body);
+            }
+            fee = required;
+        }
+        SignerError::invalid_input_err("insufficient Cardano balance")
+    }
+
+    fn build(&self, selected: &[&Utxo], outputs: &[TransactionOutput]) -> Option<TransactionBody> {
+        let (total_coin, mut change_tokens) = totals(selected);
+        for (token, quantity) in outputs.iter().flat_map(|output| &output.tokens) {
+            let remaining = change_tokens.get(token)?.checked_sub(*quantity)?;
+            if remaining == 0 {
+                change_tokens.remove(token);
+            } else {
+                change_tokens.insert(token.clone(), remaining);
+            }
+        }
+        let (credit, debit) = self.implicit_balance();
+        let spent: u64 = outputs.iter().map(|output| output.coin).sum::<u64>() + debit;
+        let available = (total_coin + credit).checked_sub(spent)?;
+
+        let mut fee = 0;
+        for _ in 0..MAX_FEE_ITERATIONS {
+            let change = self.change_output(available.saturating_sub(fee), change_tokens.clone());
+            if available < fee || change.coin < self.parameters.min_ada(&change) {
+                if !change_tokens.is_empty() || outputs.is_empty() {
+                    return None;
+                }
+                // Change too small to exist on its own goes to the fee
+                let body = self.body(selected, outputs.to_vec(), available);
+                return (body.fee >= self.parameters.min_fee(&body)).then_some(body);
+            }
+            let body = self.body(selected, [outputs, &[change]].concat(), fee);
+            let required = self.parameters.min_fee(&body);
+            if required <= fee {
+                return Some(body);
+            }
+            fee = required;
+        }
+        None
+    }
+
+    fn change_output(&self, coin: u64, tokens: TokenBundle) -> TransactionOutput {
+        TransactionOutput {
+            address: self.change_address.clone(),
+            coin,
+            tokens,
+        }
+    }
+
+    fn body(&self, selected: &[&Utxo], outputs: Vec<TransactionOutput>, fee: u64) -> TransactionBody {
+        TransactionBody {
+            inputs: selected.iter().map(|utxo| utxo.input.clone()).collect(),
+            outputs,
+            fee,
+            ttl: self.ttl,
+            certificates: self.certificates.clone(),
+            withdrawals: self.withdrawals.clone(),
+        }
+    }
+}
+
+fn totals(selected: &[&Utxo]) -> (u64, TokenBundle) {
+    let mut tokens = TokenBundle::new();
+    for utxo in selected {
+        for (token, quantity) in &utxo.tokens {
+            *tokens.entry(token.clone()).or_default() += quantity;
+        }
+    }
+    (selected.iter().map(|utxo| utxo.coin).sum(), tokens)
+}
+
+fn map_utxos(utxos: &[UTXO], tokens: &[CardanoUTXOToken]) -> Result<Vec<Utxo>, SignerError> {
+    utxos
+        .iter()
+        .map(|utxo| {
+            let mut bundle = TokenBundle::new();
+            for token in tokens.iter().filter(|token| token.transaction_id == utxo.transaction_id && token.vout == utxo.vout) {
+                let quantity = token.quantity.parse::<u64>().map_err(|_| SignerError::invalid_input("invalid Cardano token quantity"))?;
+                *bundle.entry(TokenId::from_hex(&token.token_id)?).or_default() += quantity;
+            }
+            Ok(Utxo {
+                input: TransactionInput {
+                    transaction_id: hex::decode(&utxo.transaction_id)
+                        .ok()
+                        .and_then(|bytes| bytes.try_into().ok())
+                        .ok_or_else(|| SignerError::invalid_input("invalid Cardano UTXO hash"))?,
+                    index: u32::try_from(utxo.vout).map_err(|_| SignerError::invalid_input("invalid Cardano UTXO index"))?,
+                },
+                coin: utxo.value.parse::<u64>().map_err(|_| SignerError::invalid_input("invalid Cardano UTXO value"))?,
+                tokens: bundle,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_ada() {
+        let parameters = ProtocolParameters {
+            min_fee_a: 44,
+            min_fee_b: 155_381,
+            coins_per_utxo_byte: 4_310,
+            key_deposit: 2_000_000,
+        };
+        let address = decode_address("addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23").unwrap();
+        let mut output = TransactionOutput {
+            address,
+            coin: 0,
+            tokens: TokenBundle::new(),
+        };
+        assert_eq!(parameters.min_ada(&output), 969_750);
+
+        output
+            .tokens
+            .insert(TokenId::from_hex("29d222ce763455e3d7a09a665ce554f00ac89d2e99a1a83d267170c64d494e").unwrap(), 1);
+        assert_eq!(parameters.min_ada(&output), 1_133_530);
+    }
+}
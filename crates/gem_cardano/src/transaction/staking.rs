@@ -0,0 +1,42 @@
+use primitives::{SignerError, StakeType, TransactionLoadInput, TransactionLoadMetadata};
+
+use super::planner::plan_certificates;
+use super::{Certificate, TransactionBody, Withdrawal};
+use crate::address::{decode_address, decode_pool_id, stake_key_hash};
+
+pub fn plan_stake(input: &TransactionLoadInput) -> Result<TransactionBody, SignerError> {
+    let TransactionLoadMetadata::Cardano {
+        is_stake_registered,
+        stake_rewards,
+        ..
+    } = &input.metadata
+    else {
+        return SignerError::invalid_input_err("expected Cardano metadata");
+    };
+    let stake_key = stake_key_hash(&decode_address(&input.sender_address)?).ok_or_else(|| SignerError::invalid_input("Cardano address has no stake key"))?;
+    let registration = (!is_stake_registered).then_some(Certificate::StakeRegistration(stake_key));
+    let withdrawal = (*stake_rewards > 0).then_some(Withdrawal {
+        stake_key_hash: stake_key,
+        coin: *stake_rewards,
+    });
+
+    let (certificates, withdrawals) = match input.input_type.get_stake_type().map_err(SignerError::invalid_input)? {
+        StakeType::Stake(validator) => {
+            let delegation = Certificate::StakeDelegation(stake_key, decode_pool_id(&validator.id)?);
+            (registration.into_iter().chain([delegation]).collect(), vec![])
+        }
+        StakeType::Redelegate(data) => {
+            let delegation = Certificate::StakeDelegation(stake_key, decode_pool_id(&data.to_validator.id)?);
+            (registration.into_iter().chain([delegation]).collect(), vec![])
+        }
+        StakeType::Rewards(_) => match withdrawal {
+            Some(withdrawal) => (vec![], vec![withdrawal]),
+            None => return SignerError::invalid_input_err("no Cardano rewards to withdraw"),
+        },
+        // Rewards must be withdrawn before the deposit is refunded
+        StakeType::Unstake(_) if *is_stake_registered => (vec![Certificate::StakeDeregistration(stake_key)], withdrawal.into_iter().collect()),
+        StakeType::Unstake(_) => return SignerError::invalid_input_err("Cardano stake key is not registered"),
+        StakeType::Withdraw(_) | StakeType::Freeze(_) | StakeType::Unfreeze(_) => return SignerError::invalid_input_err("unsupported Cardano stake type"),
+    };
+    plan_certificates(input, certificates, withdrawals)
+}
@@ -1,6 +1,9 @@
 use alloy_primitives::{Address, AddressError};
 use std::str::FromStr;
 
+// EIP-7702 delegation designator: account code is `0xef0100 || delegate address`
+const DELEGATION_CODE_PREFIX: &str = "0xef0100";
+
 pub fn ethereum_address_checksum(address: &str) -> Result<String, AddressError> {
     Ok(Address::from_str(address)?.to_checksum(None))
 }
@@ -9,6 +12,14 @@ pub fn ethereum_address_from_topic(topic: &str) -> Option<String> {
     ethereum_address_checksum(topic.trim_start_matches("0x000000000000000000000000")).ok()
 }
 
+pub fn ethereum_address_from_delegation_code(code: &str) -> Option<String> {
+    let address = code.to_lowercase().strip_prefix(DELEGATION_CODE_PREFIX)?.to_string();
+    if address.len() != 40 {
+        return None;
+    }
+    ethereum_address_checksum(&address).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +40,15 @@ mod tests {
             Some("0x5615E8AB93b9d695b6d4d6545f7792aA59e1069a".to_string())
         );
     }
+
+    #[test]
+    fn test_ethereum_address_from_delegation_code() {
+        assert_eq!(
+            ethereum_address_from_delegation_code("0xef010063c0c19a282a1b52b07dd5a65b58948a07dae32b"),
+            Some("0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B".to_string())
+        );
+        assert_eq!(ethereum_address_from_delegation_code("0x"), None);
+        assert_eq!(ethereum_address_from_delegation_code("0x6080604052"), None);
+        assert_eq!(ethereum_address_from_delegation_code("0xef010063c0c19a282a1b52b07dd5a65b58948a07dae32b00"), None);
+    }
 }
@@ -0,0 +1,25 @@
+use alloy_sol_types::sol;
+
+// https://github.com/circlefin/evm-cctp-contracts/blob/master/src/v2/TokenMessengerV2.sol
+// https://github.com/circlefin/evm-cctp-contracts/blob/master/src/v2/MessageTransmitterV2.sol
+sol! {
+    #[derive(Debug, PartialEq)]
+    interface ITokenMessengerV2 {
+        function depositForBurn(
+            uint256 amount,
+            uint32 destinationDomain,
+            bytes32 mintRecipient,
+            address burnToken,
+            bytes32 destinationCaller,
+            uint256 maxFee,
+            uint32 minFinalityThreshold
+        ) external;
+    }
+
+    #[derive(Debug, PartialEq)]
+    interface IMessageTransmitterV2 {
+        function receiveMessage(bytes calldata message, bytes calldata attestation) external returns (bool success);
+
+        function usedNonces(bytes32 nonce) external view returns (uint256);
+    }
+}
@@ -0,0 +1,73 @@
+use primitives::{Chain, asset_constants::*};
+
+pub const TOKEN_MESSENGER_V2: &str = "0x28b5a0e9C621a5BadaA536219b3a228C8168cf5d";
+pub const MESSAGE_TRANSMITTER_V2: &str = "0x81D40F21F12A8F0E3252Bccb954D722d4c464B64";
+
+pub const FINALITY_THRESHOLD_STANDARD: u32 = 2000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CctpDeployment {
+    pub chain: Chain,
+    pub domain: u32,
+    pub usdc: &'static str,
+}
+
+const DEPLOYMENTS: [CctpDeployment; 9] = [
+    CctpDeployment {
+        chain: Chain::Ethereum,
+        domain: 0,
+        usdc: ETHEREUM_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::AvalancheC,
+        domain: 1,
+        usdc: AVALANCHE_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Optimism,
+        domain: 2,
+        usdc: OPTIMISM_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Arbitrum,
+        domain: 3,
+        usdc: ARBITRUM_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Solana,
+        domain: 5,
+        usdc: SOLANA_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Base,
+        domain: 6,
+        usdc: BASE_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Polygon,
+        domain: 7,
+        usdc: POLYGON_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Unichain,
+        domain: 10,
+        usdc: UNICHAIN_USDC_TOKEN_ID,
+    },
+    CctpDeployment {
+        chain: Chain::Hyperliquid,
+        domain: 19,
+        usdc: HYPEREVM_USDC_TOKEN_ID,
+    },
+];
+
+pub fn deployment_by_chain(chain: &Chain) -> Option<CctpDeployment> {
+    DEPLOYMENTS.iter().find(|x| x.chain == *chain).cloned()
+}
+
+pub fn deployment_by_domain(domain: u32) -> Option<CctpDeployment> {
+    DEPLOYMENTS.iter().find(|x| x.domain == domain).cloned()
+}
+
+pub fn deployments() -> Vec<CctpDeployment> {
+    DEPLOYMENTS.to_vec()
+}
@@ -90,6 +90,23 @@ impl FeeCalculator {
 
         Ok(result)
     }
+
+    pub fn calculate_gas_prices(&self, gas_price: &BigInt, priorities: &[FeePriority]) -> Vec<PriorityFeeValue> {
+        priorities
+            .iter()
+            .map(|&priority| {
+                let percent = match priority {
+                    FeePriority::Slow => 100,
+                    FeePriority::Normal => 110,
+                    FeePriority::Fast => 125,
+                };
+                PriorityFeeValue {
+                    priority,
+                    value: gas_price * BigInt::from(percent) / BigInt::from(100),
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -187,6 +204,16 @@ mod tests {
         assert!(result[1].value < result[2].value);
     }
 
+    #[test]
+    fn test_calculate_gas_prices() {
+        let result = FeeCalculator::new().calculate_gas_prices(&BigInt::from(20_000_000_000u64), &[FeePriority::Slow, FeePriority::Normal, FeePriority::Fast]);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].value, BigInt::from(20_000_000_000u64));
+        assert_eq!(result[1].value, BigInt::from(22_000_000_000u64));
+        assert_eq!(result[2].value, BigInt::from(25_000_000_000u64));
+    }
+
     #[test]
     fn test_calculate_priority_fees_errors() {
         let calculator = FeeCalculator::new();
@@ -5,6 +5,7 @@ pub mod across;
 pub mod address;
 pub mod address_deserializer;
 pub mod call_decoder;
+pub mod cctp;
 pub mod chainlink;
 pub mod constants;
 pub mod contracts;
@@ -1,4 +1,6 @@
+use num_bigint::BigInt;
 use serde::{Deserialize, Serialize};
+use serde_serializers::deserialize_bigint_from_str;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -9,3 +11,19 @@ pub struct EthereumTransactionReciept {
     #[serde(rename = "l1Fee")]
     pub l1_fee: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthereumAccessList {
+    pub access_list: Vec<EthereumAccessListItem>,
+    #[serde(deserialize_with = "deserialize_bigint_from_str")]
+    pub gas_used: BigInt,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthereumAccessListItem {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
@@ -1,8 +1,12 @@
 #[cfg(feature = "rpc")]
+use async_trait::async_trait;
+#[cfg(feature = "rpc")]
 use chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainProvider, ChainTraits};
 use gem_client::Client;
-use primitives::Chain;
+use primitives::{AddressStatus, Chain};
+use std::error::Error;
 
+use crate::address::ethereum_address_from_delegation_code;
 use crate::rpc::client::EthereumClient;
 
 #[cfg(feature = "rpc")]
@@ -22,4 +26,31 @@ impl<C: Client + Clone> ChainAccount for EthereumClient<C> {}
 impl<C: Client + Clone> ChainPerpetual for EthereumClient<C> {}
 
 #[cfg(feature = "rpc")]
-impl<C: Client + Clone> ChainAddressStatus for EthereumClient<C> {}
+#[async_trait]
+impl<C: Client + Clone> ChainAddressStatus for EthereumClient<C> {
+    async fn get_address_status(&self, address: String) -> Result<Vec<AddressStatus>, Box<dyn Error + Sync + Send>> {
+        let code = self.get_code(&address).await?;
+        Ok(map_address_status(&code))
+    }
+}
+
+fn map_address_status(code: &str) -> Vec<AddressStatus> {
+    match ethereum_address_from_delegation_code(code) {
+        Some(_) => vec![AddressStatus::Eip7702Delegation],
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_address_status() {
+        assert_eq!(map_address_status("0x"), vec![]);
+        assert_eq!(
+            map_address_status("0xef010063c0c19a282a1b52b07dd5a65b58948a07dae32b"),
+            vec![AddressStatus::Eip7702Delegation]
+        );
+    }
+}
@@ -2,8 +2,8 @@
 use super::preload_optimism::OptimismGasOracle;
 use crate::fee_calculator::{get_fee_history_blocks, get_reward_percentiles};
 use crate::provider::preload_mapper::{
-    bigint_to_hex_string, bytes_to_hex_string, calculate_gas_limit_with_increase, get_extra_fee_gas_limit, get_transaction_params, map_transaction_fee_rates,
-    map_transaction_preload,
+    bigint_to_hex_string, bytes_to_hex_string, calculate_gas_limit_with_increase, get_extra_fee_gas_limit, get_transaction_params, map_access_list, map_legacy_fee_rates,
+    map_transaction_fee_rates, map_transaction_preload,
 };
 use crate::rpc::client::EthereumClient;
 #[cfg(feature = "rpc")]
@@ -15,7 +15,7 @@ use gem_client::Client;
 use num_bigint::BigInt;
 #[cfg(feature = "rpc")]
 use primitives::ContractCallData;
-use primitives::GasPriceType;
+use primitives::{EvmTransactionType, GasPriceType};
 #[cfg(feature = "rpc")]
 use primitives::{FeeRate, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput};
 #[cfg(feature = "rpc")]
@@ -34,6 +34,9 @@ impl<C: Client + Clone> ChainTransactionLoad for EthereumClient<C> {
     }
 
     async fn get_transaction_fee_rates(&self, _input_type: TransactionInputType) -> Result<Vec<FeeRate>, Box<dyn Error + Sync + Send>> {
+        if self.chain.transaction_type() == EvmTransactionType::Legacy {
+            return Ok(map_legacy_fee_rates(&self.gas_price().await?));
+        }
         let fee_history = self.get_fee_history(get_fee_history_blocks(self.chain), get_reward_percentiles().to_vec()).await?;
 
         map_transaction_fee_rates(self.chain, &fee_history)
@@ -48,37 +51,49 @@ impl<C: Client + Clone> ChainTransactionLoad for EthereumClient<C> {
 impl<C: Client + Clone> EthereumClient<C> {
     pub async fn map_transaction_load(&self, input: TransactionLoadInput) -> Result<TransactionLoadData, Box<dyn Error + Sync + Send>> {
         let params = get_transaction_params(self.chain, &input)?;
+        let value = bigint_to_hex_string(&params.value);
+        let data = bytes_to_hex_string(&params.data);
 
         let gas_estimate = {
-            let estimate = self
-                .estimate_gas(
-                    Some(&input.sender_address),
-                    &params.to,
-                    Some(&bigint_to_hex_string(&params.value)),
-                    Some(&bytes_to_hex_string(&params.data)),
-                )
-                .await?;
+            let estimate = self.estimate_gas(Some(&input.sender_address), &params.to, Some(&value), Some(&data)).await?;
             bigint_from_hex_str(&estimate)?
         };
+        let access_list = match &input.input_type {
+            TransactionInputType::Swap(_, _, swap_data) if swap_data.data.approval.is_none() => self
+                .create_access_list(&input.sender_address, &params.to, Some(&value), Some(&data))
+                .await
+                .ok()
+                .and_then(|result| map_access_list(&gas_estimate, &result)),
+            _ => None,
+        };
+        let (gas_estimate, access_list) = match access_list {
+            Some((access_list, gas_used)) => (gas_used, access_list),
+            None => (gas_estimate, vec![]),
+        };
         let gas_limit = calculate_gas_limit_with_increase(gas_estimate);
         let fee = self.calculate_fee(&input, &gas_limit).await?;
 
-        let metadata = if let TransactionInputType::Stake(_, _) = &input.input_type {
-            match input.metadata {
-                TransactionLoadMetadata::Evm { nonce, chain_id, .. } => TransactionLoadMetadata::Evm {
-                    nonce,
-                    chain_id,
-                    contract_call: Some(ContractCallData {
+        let metadata = match input.metadata {
+            TransactionLoadMetadata::Evm {
+                nonce, chain_id, contract_call, ..
+            } => {
+                let contract_call = match &input.input_type {
+                    TransactionInputType::Stake(_, _) => Some(ContractCallData {
                         contract_address: params.to,
                         call_data: hex::encode(&params.data),
                         approval: None,
                         gas_limit: None,
                     }),
-                },
-                _ => input.metadata,
+                    _ => contract_call,
+                };
+                TransactionLoadMetadata::Evm {
+                    nonce,
+                    chain_id,
+                    contract_call,
+                    access_list,
+                }
             }
-        } else {
-            input.metadata
+            metadata => metadata,
         };
 
         Ok(TransactionLoadData { fee, metadata })
@@ -90,13 +105,12 @@ impl<C: Client + Clone> EthereumClient<C> {
         } else {
             let gas_limit = gas_limit + get_extra_fee_gas_limit(input)?;
             let fee = input.gas_price.total_fee() * &gas_limit;
+            let gas_price_type = match self.chain.transaction_type() {
+                EvmTransactionType::Legacy | EvmTransactionType::Eip2930 => GasPriceType::regular(input.gas_price.total_fee()),
+                EvmTransactionType::Eip1559 => GasPriceType::eip1559(input.gas_price.total_fee(), input.gas_price.priority_fee()),
+            };
 
-            Ok(TransactionFee::new_gas_price_type(
-                GasPriceType::eip1559(input.gas_price.total_fee(), input.gas_price.priority_fee()),
-                fee,
-                gas_limit.clone(),
-                HashMap::new(),
-            ))
+            Ok(TransactionFee::new_gas_price_type(gas_price_type, fee, gas_limit.clone(), HashMap::new()))
         }
     }
 }
@@ -115,6 +129,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Ethereum)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(input).await?;
@@ -134,6 +149,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::SmartChain)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(input).await?;
@@ -212,6 +228,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Ethereum)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
         let metadata = client.get_transaction_preload(preload_input.clone()).await?;
 
@@ -252,6 +269,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::mock_erc20()),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
         let metadata = client.get_transaction_preload(preload_input.clone()).await?;
 
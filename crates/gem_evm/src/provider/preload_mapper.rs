@@ -8,14 +8,15 @@ use num_bigint::BigInt;
 use num_traits::Num;
 use primitives::swap::SwapQuoteDataType;
 use primitives::{
-    AssetSubtype, Chain, EVMChain, FeeRate, NFTType, StakeType, TransactionInputType, TransactionLoadInput, TransactionLoadMetadata, decode_hex, fee::FeePriority,
-    fee::GasPriceType,
+    AssetSubtype, Chain, EVMChain, EvmAccessListItem, FeeRate, NFTType, StakeType, TransactionInputType, TransactionLoadInput, TransactionLoadMetadata, decode_hex,
+    fee::FeePriority, fee::GasPriceType,
 };
 
 use crate::encode::{encode_erc20_approve, encode_erc20_transfer, encode_erc721_transfer, encode_erc1155_transfer};
 use crate::everstake::{DEFAULT_ALLOWED_INTERCHANGE_NUM, EVERSTAKE_ACCOUNTING_ADDRESS, EVERSTAKE_POOL_ADDRESS, EVERSTAKE_SOURCE, IAccounting, IPool};
 use crate::fee_calculator::FeeCalculator;
 use crate::models::fee::EthereumFeeHistory;
+use crate::models::transaction::EthereumAccessList;
 use crate::monad::{STAKING_CONTRACT, encode_monad_staking};
 
 const GAS_LIMIT_PERCENT_INCREASE: u32 = 50;
@@ -51,6 +52,7 @@ pub fn map_transaction_preload(nonce_hex: String, chain_id: String) -> Result<Tr
         nonce,
         chain_id: chain_id.parse::<u64>()?,
         contract_call: None,
+        access_list: vec![],
     })
 }
 
@@ -68,6 +70,29 @@ pub fn map_transaction_fee_rates(chain: EVMChain, fee_history: &EthereumFeeHisto
         .collect())
 }
 
+pub fn map_legacy_fee_rates(gas_price: &BigInt) -> Vec<FeeRate> {
+    FeeCalculator::new()
+        .calculate_gas_prices(gas_price, &[FeePriority::Slow, FeePriority::Normal, FeePriority::Fast])
+        .into_iter()
+        .map(|x| FeeRate::new(x.priority, GasPriceType::regular(x.value)))
+        .collect()
+}
+
+pub fn map_access_list(gas_estimate: &BigInt, result: &EthereumAccessList) -> Option<(Vec<EvmAccessListItem>, BigInt)> {
+    if result.error.is_some() || result.access_list.is_empty() || &result.gas_used >= gas_estimate {
+        return None;
+    }
+    let access_list = result
+        .access_list
+        .iter()
+        .map(|item| EvmAccessListItem {
+            address: item.address.clone(),
+            storage_keys: item.storage_keys.clone(),
+        })
+        .collect();
+    Some((access_list, result.gas_used.clone()))
+}
+
 pub fn get_transaction_params(chain: EVMChain, input: &TransactionLoadInput) -> Result<TransactionParams, Box<dyn Error + Send + Sync>> {
     let value = BigInt::from_str_radix(&input.value, 10)?;
 
@@ -107,6 +132,7 @@ pub fn get_transaction_params(chain: EVMChain, input: &TransactionLoadInput) ->
                             let data = encode_erc20_transfer(&swap_data.data.to.clone(), &BigInt::from_str_radix(&input.value, 10)?)?;
                             Ok(TransactionParams::new(to, data, BigInt::ZERO))
                         }
+                        SwapQuoteDataType::Eip712 => Err("Swap order is signed off-chain".into()),
                     },
                 }
             }
@@ -284,10 +310,16 @@ mod tests {
         let result = map_transaction_preload(nonce_hex, chain_id)?;
 
         match result {
-            TransactionLoadMetadata::Evm { nonce, chain_id, contract_call } => {
+            TransactionLoadMetadata::Evm {
+                nonce,
+                chain_id,
+                contract_call,
+                access_list,
+            } => {
                 assert_eq!(nonce, 10);
                 assert_eq!(chain_id, 1);
                 assert!(contract_call.is_none());
+                assert!(access_list.is_empty());
             }
             _ => panic!("Expected Evm variant"),
         }
@@ -295,6 +327,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_map_access_list() {
+        let result: EthereumAccessList = serde_json::from_value(serde_json::json!({
+            "accessList": [{
+                "address": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                "storageKeys": ["0x0000000000000000000000000000000000000000000000000000000000000003"]
+            }],
+            "gasUsed": "0x1d4c0"
+        }))
+        .unwrap();
+
+        let (access_list, gas_used) = map_access_list(&BigInt::from(125_000), &result).unwrap();
+        assert_eq!(gas_used, BigInt::from(120_000));
+        assert_eq!(access_list[0].address, "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+        assert_eq!(access_list[0].storage_keys.len(), 1);
+
+        assert!(map_access_list(&BigInt::from(120_000), &result).is_none());
+        assert!(
+            map_access_list(
+                &BigInt::from(125_000),
+                &EthereumAccessList {
+                    error: Some("execution reverted".to_string()),
+                    ..result
+                }
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_map_legacy_fee_rates() {
+        let rates = map_legacy_fee_rates(&BigInt::from(1_000_000_000u64));
+
+        assert_eq!(rates.len(), 3);
+        assert_eq!(rates[0].gas_price_type.gas_price(), BigInt::from(1_000_000_000u64));
+        assert_eq!(rates[2].gas_price_type.gas_price(), BigInt::from(1_250_000_000u64));
+        assert_eq!(rates[2].gas_price_type.priority_fee(), BigInt::from(0));
+    }
+
     #[test]
     fn test_map_transaction_preload_invalid_nonce() {
         let nonce_hex = "invalid".to_string();
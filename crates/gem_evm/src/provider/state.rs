@@ -10,7 +10,7 @@ use crate::provider::state_mapper;
 use crate::rpc::client::EthereumClient;
 use gem_client::Client;
 #[cfg(feature = "rpc")]
-use primitives::NodeSyncStatus;
+use primitives::{BlockHeader, NodeSyncStatus};
 
 #[cfg(feature = "rpc")]
 #[async_trait]
@@ -30,6 +30,16 @@ impl<C: Client + Clone> ChainState for EthereumClient<C> {
         let block_number = self.get_latest_block().await?;
         Ok(block_number)
     }
+
+    async fn get_block_header(&self, block: u64) -> Result<Option<BlockHeader>, Box<dyn Error + Sync + Send>> {
+        let header = EthereumClient::get_block_header(self, block).await?;
+        Ok(Some(state_mapper::map_block_header(header)))
+    }
+
+    async fn get_block_headers(&self, blocks: Vec<u64>) -> Result<Option<Vec<BlockHeader>>, Box<dyn Error + Sync + Send>> {
+        let headers = EthereumClient::get_block_headers(self, &blocks).await?;
+        Ok(Some(headers.into_iter().map(state_mapper::map_block_header).collect()))
+    }
 }
 
 #[cfg(all(test, feature = "chain_integration_tests"))]
@@ -96,4 +106,17 @@ mod chain_integration_tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_ethereum_get_block_header() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_ethereum_test_client();
+        let latest_block = ChainState::get_block_latest_number(&client).await?;
+        let header = ChainState::get_block_header(&client, latest_block).await?.unwrap();
+        let parent = ChainState::get_block_header(&client, latest_block - 1).await?.unwrap();
+
+        assert_eq!(header.number, latest_block);
+        assert_eq!(header.parent_hash, parent.hash);
+
+        Ok(())
+    }
 }
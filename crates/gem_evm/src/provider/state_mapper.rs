@@ -1,4 +1,4 @@
-use crate::rpc::model::EthSyncingStatus;
+use crate::rpc::model::{BlockHeader, EthSyncingStatus};
 use primitives::NodeSyncStatus;
 use std::error::Error;
 
@@ -13,6 +13,10 @@ pub fn map_node_status(sync_status: &EthSyncingStatus, latest_block: u64) -> Res
     }
 }
 
+pub fn map_block_header(header: BlockHeader) -> primitives::BlockHeader {
+    primitives::BlockHeader::new(header.number, header.hash, header.parent_hash)
+}
+
 #[cfg(test)]
 mod tests {
     use num_bigint::BigUint;
@@ -47,4 +51,21 @@ mod tests {
         assert_eq!(mapped.current_block_number, Some(5));
         assert_eq!(mapped.latest_block_number, Some(10));
     }
+
+    #[test]
+    fn test_map_block_header() {
+        let header: BlockHeader = serde_json::from_value(serde_json::json!({
+            "number": "0x1312d00",
+            "hash": "0xa917fcc721a5465a484e9be17cda0cc5493933dd3bc70c9adbee192cb419c9d7",
+            "parentHash": "0xd0d5b6e5b8ff4b1d7a1ff7ec0b1c9d0cfd1b1a0e2c9e6e8c3a4f0b6f2d3c4e5f",
+            "timestamp": "0x64b2c5a7"
+        }))
+        .unwrap();
+
+        let mapped = map_block_header(header);
+
+        assert_eq!(mapped.number, 20_000_000);
+        assert_eq!(mapped.hash, "0xa917fcc721a5465a484e9be17cda0cc5493933dd3bc70c9adbee192cb419c9d7");
+        assert_eq!(mapped.parent_hash, "0xd0d5b6e5b8ff4b1d7a1ff7ec0b1c9d0cfd1b1a0e2c9e6e8c3a4f0b6f2d3c4e5f");
+    }
 }
@@ -19,8 +19,15 @@ use gem_client::Client;
 #[cfg(feature = "rpc")]
 #[async_trait]
 impl<C: Client + Clone> ChainTransactionBroadcast for EthereumClient<C> {
-    async fn transaction_broadcast(&self, data: String, _options: BroadcastOptions) -> Result<String, Box<dyn Error + Sync + Send>> {
+    async fn transaction_broadcast(&self, data: String, options: BroadcastOptions) -> Result<String, Box<dyn Error + Sync + Send>> {
         let data = map_transaction_broadcast_request(&data);
+        if options.mev_protection && self.private_client.is_some() {
+            match self.send_private_raw_transaction(&data).await {
+                Ok(hash) => return Ok(hash),
+                Err(err) if !err.is_client_error() => return Err(err.into()),
+                Err(err) => tracing::warn!(chain = self.chain.as_ref(), error = %err, "private relay broadcast failed, broadcasting publicly"),
+            }
+        }
         let response = self.send_raw_transaction(&data).await?;
         Ok(response)
     }
@@ -31,3 +38,59 @@ impl ChainTransactionDecode for BroadcastProvider {
         map_transaction_broadcast_response_from_str(response).ok()
     }
 }
+
+#[cfg(all(test, feature = "rpc"))]
+mod tests {
+    use super::*;
+    use gem_client::ClientError;
+    use gem_jsonrpc::testkit::mock_jsonrpc_client;
+    use primitives::EVMChain;
+
+    fn public_client() -> gem_jsonrpc::client::JsonRpcClient<gem_client::testkit::MockClient> {
+        mock_jsonrpc_client(|method, _| match method {
+            "eth_sendRawTransaction" => Ok(serde_json::json!("0xpublic")),
+            _ => Err(ClientError::Network(method.to_string())),
+        })
+    }
+
+    fn mev_protection_options() -> BroadcastOptions {
+        BroadcastOptions {
+            skip_preflight: false,
+            mev_protection: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection() {
+        let private_client = mock_jsonrpc_client(|_, params| {
+            assert_eq!(params, &serde_json::json!(["0x02f8"]));
+            Ok(serde_json::json!("0xprivate"))
+        });
+        let client = EthereumClient::new(public_client(), EVMChain::Ethereum).with_private_client(private_client);
+
+        assert_eq!(client.transaction_broadcast("02f8".to_string(), BroadcastOptions::new(false)).await.unwrap(), "0xpublic");
+        assert_eq!(client.transaction_broadcast("02f8".to_string(), mev_protection_options()).await.unwrap(), "0xprivate");
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection_fallback() {
+        let private_client = mock_jsonrpc_client(|_, _| Err(ClientError::Timeout));
+        let client = EthereumClient::new(public_client(), EVMChain::Ethereum).with_private_client(private_client);
+        assert_eq!(client.transaction_broadcast("0x02f8".to_string(), mev_protection_options()).await.unwrap(), "0xpublic");
+
+        let client = EthereumClient::new(public_client(), EVMChain::Base);
+        assert_eq!(client.transaction_broadcast("0x02f8".to_string(), mev_protection_options()).await.unwrap(), "0xpublic");
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection_rejected() {
+        let private_client = gem_jsonrpc::client::JsonRpcClient::new(
+            gem_client::testkit::MockClient::new()
+                .with_post(|_, _| Ok(serde_json::to_vec(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "error": {"code": 3, "message": "execution reverted"}})).unwrap())),
+        );
+        let client = EthereumClient::new(public_client(), EVMChain::Ethereum).with_private_client(private_client);
+
+        let error = client.transaction_broadcast("0x02f8".to_string(), mev_protection_options()).await.unwrap_err();
+        assert!(error.to_string().contains("execution reverted"));
+    }
+}
@@ -6,15 +6,28 @@ use async_trait::async_trait;
 use chain_traits::ChainTransactionState;
 use primitives::{TransactionStateRequest, TransactionUpdate};
 
-use crate::{provider::transaction_state_mapper::map_transaction_status, rpc::client::EthereumClient};
+use crate::{
+    provider::transaction_state_mapper::{map_missing_receipt_status, map_transaction_status},
+    rpc::client::EthereumClient,
+};
 use gem_client::Client;
 
 #[cfg(feature = "rpc")]
 #[async_trait]
 impl<C: Client + Clone> ChainTransactionState for EthereumClient<C> {
     async fn get_transaction_status(&self, request: TransactionStateRequest) -> Result<TransactionUpdate, Box<dyn Error + Sync + Send>> {
-        let receipt = self.get_transaction_receipt(&request.id).await?;
-        Ok(map_transaction_status(&receipt))
+        if self.private_client.is_none() {
+            let receipt = self.get_transaction_receipt(&request.id).await?;
+            return Ok(map_transaction_status(&receipt));
+        }
+        match self.get_transaction_receipt_optional(&request.id).await? {
+            Some(receipt) => Ok(map_transaction_status(&receipt)),
+            None => {
+                let is_known = self.get_transaction_by_hash(&request.id).await?.is_some();
+                let now = chrono::Utc::now().timestamp();
+                Ok(map_missing_receipt_status(is_known, request.created_at, now, self.get_chain().block_time()))
+            }
+        }
     }
 }
 
@@ -2,6 +2,9 @@ use crate::rpc::model::TransactionReciept;
 use num_bigint::BigInt;
 use primitives::{TransactionChange, TransactionState, TransactionUpdate};
 
+// Flashbots Protect keeps retrying a private transaction for 25 blocks before dropping it
+const PRIVATE_RELAY_INCLUSION_BLOCKS: i64 = 25;
+
 pub fn map_transaction_status(receipt: &TransactionReciept) -> TransactionUpdate {
     if receipt.status == "0x0" || receipt.status == "0x1" {
         let state = match receipt.status.as_str() {
@@ -18,6 +21,15 @@ pub fn map_transaction_status(receipt: &TransactionReciept) -> TransactionUpdate
     }
 }
 
+pub fn map_missing_receipt_status(is_known: bool, created_at: i64, now: i64, block_time_ms: u32) -> TransactionUpdate {
+    let timeout = PRIVATE_RELAY_INCLUSION_BLOCKS * block_time_ms as i64 / 1000;
+    if !is_known && created_at > 0 && now - created_at > timeout {
+        TransactionUpdate::new_state(TransactionState::Failed)
+    } else {
+        TransactionUpdate::new_state(TransactionState::Pending)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +103,14 @@ mod tests {
         let expected_total = BigInt::from(21000u32) * BigInt::from(20000000000u64) + BigInt::from(5000000000000000u64);
         assert_eq!(result.changes, vec![TransactionChange::NetworkFee(expected_total)]);
     }
+
+    #[test]
+    fn map_missing_receipt_status_private_relay_timeout() {
+        let created_at = 1_700_000_000;
+
+        assert_eq!(map_missing_receipt_status(false, created_at, created_at + 60, 12_000).state, TransactionState::Pending);
+        assert_eq!(map_missing_receipt_status(false, created_at, created_at + 301, 12_000).state, TransactionState::Failed);
+        assert_eq!(map_missing_receipt_status(true, created_at, created_at + 301, 12_000).state, TransactionState::Pending);
+        assert_eq!(map_missing_receipt_status(false, 0, created_at, 12_000).state, TransactionState::Pending);
+    }
 }
@@ -4,8 +4,12 @@ use std::error::Error;
 use async_trait::async_trait;
 #[cfg(feature = "rpc")]
 use chain_traits::{ChainTransactions, TransactionsRequest};
+#[cfg(feature = "rpc")]
+use primitives::BlockHeader;
 use primitives::{NodeType, Transaction};
 
+#[cfg(feature = "rpc")]
+use crate::provider::state_mapper;
 use crate::rpc::{EthereumMapper, client::EthereumClient, mapper::CONTRACT_REGISTRY};
 use gem_client::Client;
 use gem_jsonrpc::types::JsonRpcError;
@@ -60,13 +64,18 @@ impl<C: Client + Clone> ChainTransactions for EthereumClient<C> {
     }
 
     async fn get_transactions_by_block(&self, block_number: u64) -> Result<Vec<Transaction>, Box<dyn Error + Sync + Send>> {
+        Ok(self.get_block_transactions(block_number).await?.1)
+    }
+
+    async fn get_block_transactions(&self, block_number: u64) -> Result<(Option<BlockHeader>, Vec<Transaction>), Box<dyn Error + Sync + Send>> {
         let block = self.get_block(block_number).await?;
-        let receipts = self.get_block_receipts(block_number).await?;
+        let header = state_mapper::map_block_header(block.header.clone());
 
         if block.transactions.is_empty() {
-            return Ok(vec![]);
+            return Ok((Some(header), vec![]));
         }
 
+        let receipts = self.get_block_receipts(&block.header.hash).await?;
         let traces = if self.node_type == NodeType::Archival {
             Some(self.trace_replay_block_transactions(block_number).await?)
         } else {
@@ -74,7 +83,7 @@ impl<C: Client + Clone> ChainTransactions for EthereumClient<C> {
         };
 
         let chain = self.get_chain();
-        Ok(block
+        let transactions = block
             .transactions
             .into_iter()
             .zip(receipts)
@@ -83,7 +92,8 @@ impl<C: Client + Clone> ChainTransactions for EthereumClient<C> {
                 let trace = traces.as_ref().and_then(|entries| entries.get(index));
                 EthereumMapper::map_transaction(chain, &tx, &receipt, trace, &block.timestamp, Some(&CONTRACT_REGISTRY))
             })
-            .collect())
+            .collect();
+        Ok((Some(header), transactions))
     }
 
     async fn get_transaction_by_hash(&self, hash: String) -> Result<Option<Transaction>, Box<dyn Error + Sync + Send>> {
@@ -12,10 +12,11 @@ use std::str::FromStr;
 
 use super::{
     ankr::AnkrClient,
-    model::{Block, BlockTransactionsIds, EthSyncingStatus, Log, Transaction, TransactionReciept, TransactionReplayTrace},
+    model::{Block, BlockHeader, BlockTransactionsIds, CallFrame, EthSyncingStatus, Log, SimulatedBlock, Transaction, TransactionReciept, TransactionReplayTrace},
 };
 use crate::jsonrpc::BlockParameter;
 use crate::models::fee::EthereumFeeHistory;
+use crate::models::transaction::EthereumAccessList;
 #[cfg(feature = "rpc")]
 use crate::multicall3::{
     IMulticall3,
@@ -36,6 +37,7 @@ pub struct EthereumClient<C: Client + Clone> {
     pub client: GenericJsonRpcClient<C>,
     pub(crate) node_type: NodeType,
     pub(crate) ankr_client: Option<AnkrClient<C>>,
+    pub(crate) private_client: Option<GenericJsonRpcClient<C>>,
 }
 
 impl<C: Client + Clone> EthereumClient<C> {
@@ -49,6 +51,7 @@ impl<C: Client + Clone> EthereumClient<C> {
             client,
             node_type: NodeType::Default,
             ankr_client: None,
+            private_client: None,
         }
     }
 
@@ -62,6 +65,11 @@ impl<C: Client + Clone> EthereumClient<C> {
         self
     }
 
+    pub fn with_private_client(mut self, private_client: GenericJsonRpcClient<C>) -> Self {
+        self.private_client = Some(private_client);
+        self
+    }
+
     pub fn get_chain(&self) -> Chain {
         self.chain.to_chain()
     }
@@ -101,8 +109,21 @@ impl<C: Client + Clone> EthereumClient<C> {
         self.client.call("eth_getBlockByNumber", params).await
     }
 
-    pub async fn get_block_receipts(&self, block_number: u64) -> Result<Vec<TransactionReciept>, JsonRpcError> {
-        let params = json!([format!("0x{:x}", block_number)]);
+    pub async fn get_block_header(&self, block_number: u64) -> Result<BlockHeader, JsonRpcError> {
+        let params = json!([format!("0x{:x}", block_number), false]);
+        self.client.call("eth_getBlockByNumber", params).await
+    }
+
+    pub async fn get_block_headers(&self, blocks: &[u64]) -> Result<Vec<BlockHeader>, JsonRpcError> {
+        let calls: Vec<(String, serde_json::Value)> = blocks
+            .iter()
+            .map(|block| ("eth_getBlockByNumber".to_string(), json!([format!("0x{:x}", block), false])))
+            .collect();
+        self.client.batch_call::<BlockHeader>(calls).await?.take_all()
+    }
+
+    pub async fn get_block_receipts(&self, block_hash: &str) -> Result<Vec<TransactionReciept>, JsonRpcError> {
+        let params = json!([block_hash]);
         self.client.call("eth_getBlockReceipts", params).await
     }
 
@@ -151,6 +172,14 @@ impl<C: Client + Clone> EthereumClient<C> {
         self.client.call("eth_getTransactionReceipt", params).await
     }
 
+    pub async fn get_transaction_receipt_optional(&self, hash: &str) -> Result<Option<TransactionReciept>, JsonRpcError> {
+        self.client.call("eth_getTransactionReceipt", json!([hash])).await
+    }
+
+    pub async fn get_transaction_by_hash(&self, hash: &str) -> Result<Option<Transaction>, JsonRpcError> {
+        self.client.call("eth_getTransactionByHash", json!([hash])).await
+    }
+
     pub async fn trace_replay_block_transactions(&self, block_number: u64) -> Result<Vec<TransactionReplayTrace>, JsonRpcError> {
         let params = json!([format!("0x{:x}", block_number), json!(["stateDiff"])]);
         self.client.call("trace_replayBlockTransactions", params).await
@@ -205,6 +234,14 @@ impl<C: Client + Clone> EthereumClient<C> {
         self.client.call("eth_sendRawTransaction", params).await
     }
 
+    pub async fn send_private_raw_transaction(&self, data: &str) -> Result<String, JsonRpcError> {
+        let client = self.private_client.as_ref().ok_or_else(|| JsonRpcError {
+            code: ERROR_INTERNAL_ERROR,
+            message: format!("No private relay for {}", self.chain.as_ref()),
+        })?;
+        client.call("eth_sendRawTransaction", json!([data])).await
+    }
+
     pub async fn batch_eth_call<const N: usize>(&self, contract_address: &str, function_selectors: [&str; N]) -> Result<[String; N], Box<dyn std::error::Error + Sync + Send>> {
         let calls: Vec<(String, serde_json::Value)> = function_selectors
             .iter()
@@ -239,6 +276,37 @@ impl<C: Client + Clone> EthereumClient<C> {
     }
 
     pub async fn estimate_gas(&self, from: Option<&str>, to: &str, value: Option<&str>, data: Option<&str>) -> Result<String, JsonRpcError> {
+        let params = json!([Self::call_object(from, to, value, data), Self::latest_block_parameter()]);
+        self.client.call("eth_estimateGas", params).await
+    }
+
+    pub async fn create_access_list(&self, from: &str, to: &str, value: Option<&str>, data: Option<&str>) -> Result<EthereumAccessList, JsonRpcError> {
+        let params = json!([Self::call_object(Some(from), to, value, data), Self::latest_block_parameter()]);
+        self.client.call("eth_createAccessList", params).await
+    }
+
+    pub async fn simulate_v1(&self, from: &str, to: &str, value: Option<&str>, data: Option<&str>) -> Result<Vec<SimulatedBlock>, JsonRpcError> {
+        let params = json!([
+            {
+                "blockStateCalls": [{ "calls": [Self::call_object(Some(from), to, value, data)] }],
+                "traceTransfers": true,
+                "validation": false
+            },
+            Self::latest_block_parameter()
+        ]);
+        self.client.call("eth_simulateV1", params).await
+    }
+
+    pub async fn debug_trace_call(&self, from: &str, to: &str, value: Option<&str>, data: Option<&str>) -> Result<CallFrame, JsonRpcError> {
+        let params = json!([
+            Self::call_object(Some(from), to, value, data),
+            Self::latest_block_parameter(),
+            { "tracer": "callTracer", "tracerConfig": { "withLog": true } }
+        ]);
+        self.client.call("debug_traceCall", params).await
+    }
+
+    fn call_object(from: Option<&str>, to: &str, value: Option<&str>, data: Option<&str>) -> serde_json::Value {
         let mut params_obj = json!({
             "to": to
         });
@@ -253,9 +321,7 @@ impl<C: Client + Clone> EthereumClient<C> {
         if let Some(data) = data {
             params_obj["data"] = json!(data);
         }
-
-        let params = json!([params_obj, Self::latest_block_parameter()]);
-        self.client.call("eth_estimateGas", params).await
+        params_obj
     }
 
     #[cfg(feature = "rpc")]
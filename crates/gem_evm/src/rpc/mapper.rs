@@ -216,7 +216,7 @@ impl EthereumMapper {
         } else if is_native_transfer_with_data {
             let memo = decode_hex_utf8(&transaction.input).filter(|m| !m.is_empty());
             (TransactionType::Transfer, memo, Some(transaction.input.clone()))
-        } else if is_smart_contract_call {
+        } else if is_smart_contract_call || transaction.is_set_code() {
             (TransactionType::SmartContractCall, None, None)
         } else {
             return None;
@@ -276,6 +276,22 @@ mod tests {
         assert_eq!(transaction.to, "0xB907Dcc926b5991A149d04Cb7C0a4a25dC2D8f9a");
     }
 
+    #[test]
+    fn test_map_set_code_transaction() {
+        let transaction = load_json_rpc_result::<Transaction>(include_str!("../../testdata/set_code_tx.json"));
+        let receipt = load_json_rpc_result::<TransactionReciept>(include_str!("../../testdata/set_code_tx_receipt.json"));
+
+        assert!(transaction.is_set_code());
+        assert_eq!(transaction.authorization_list.as_ref().unwrap()[0].address, "0x63c0c19a282a1b52b07dd5a65b58948a07dae32b");
+
+        let result = EthereumMapper::map_transaction(Chain::Ethereum, &transaction, &receipt, None, &BigUint::from(1735671600u64), None).unwrap();
+
+        assert_eq!(result.transaction_type, TransactionType::SmartContractCall);
+        assert_eq!(result.from, "0xBA4D1d35bCe0e8F28E5a3403e7a0b996c5d50AC4");
+        assert_eq!(result.to, "0xBA4D1d35bCe0e8F28E5a3403e7a0b996c5d50AC4");
+        assert_eq!(result.fee, "33500000000000");
+    }
+
     #[test]
     fn test_erc20_transfer() {
         let erc20_transfer_tx = serde_json::from_value::<JsonRpcResult<Transaction>>(serde_json::from_str(include_str!("../../testdata/transfer_erc20.json")).unwrap())
@@ -423,6 +439,7 @@ mod tests {
             gas: 280395,
             input: "0x982ef0a7000000000000000000000000d34403249b2d82aaddb14e778422c966265e5fb50000000000000000000000000000000000000000000000000000000000000000".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let receipt = TransactionReciept {
@@ -495,6 +512,7 @@ mod tests {
             gas: 50000,
             input: input.clone(),
             block_number: BigUint::from(1000u32),
+            authorization_list: None,
         };
 
         let receipt = TransactionReciept {
@@ -4,8 +4,10 @@ pub mod client;
 pub mod mapper;
 pub mod model;
 mod parsers;
+pub mod private_relay;
 pub mod staking_mapper;
 
 pub use client::EthereumClient;
 pub use mapper::EthereumMapper;
+pub use private_relay::private_relay_url;
 pub use staking_mapper::StakingMapper;
@@ -23,11 +23,22 @@ pub struct EthSyncingInfo {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Block {
+    #[serde(flatten)]
+    pub header: BlockHeader,
     pub transactions: Vec<Transaction>,
     #[serde(deserialize_with = "deserialize_biguint_from_hex_str")]
     pub timestamp: BigUint,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    #[serde(deserialize_with = "deserialize_u64_from_str_or_int")]
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTransactionsIds {
@@ -54,6 +65,22 @@ pub struct Transaction {
     pub value: BigUint,
     // #[serde(rename = "type")]
     // pub transaction_type: String,
+    #[serde(default)]
+    pub authorization_list: Option<Vec<TransactionAuthorization>>,
+}
+
+impl Transaction {
+    pub fn is_set_code(&self) -> bool {
+        self.authorization_list.as_ref().is_some_and(|list| !list.is_empty())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionAuthorization {
+    pub chain_id: String,
+    pub address: String,
+    pub nonce: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -146,6 +173,49 @@ pub struct FromTo<T> {
     pub to: T,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimulatedBlock {
+    pub calls: Vec<SimulatedCall>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedCall {
+    pub status: String,
+    pub return_data: String,
+    #[serde(default)]
+    pub logs: Vec<Log>,
+    pub error: Option<SimulatedCallError>,
+}
+
+impl SimulatedCall {
+    pub fn is_success(&self) -> bool {
+        self.status == "0x1"
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimulatedCallError {
+    pub message: String,
+    pub data: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    pub from: String,
+    pub to: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_biguint_from_option_hex_str")]
+    pub value: Option<BigUint>,
+    pub output: Option<String>,
+    pub error: Option<String>,
+    pub revert_reason: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<Log>,
+    #[serde(default)]
+    pub calls: Vec<CallFrame>,
+}
+
 #[cfg(test)]
 mod tests {
     use primitives::testkit::json_rpc::load_json_rpc_result;
@@ -158,4 +228,20 @@ mod tests {
 
         assert!(trace_replay_transaction.state_diff.len() > 1);
     }
+
+    #[test]
+    fn test_decode_call_frame() {
+        let frame: CallFrame = serde_json::from_value(serde_json::json!({
+            "from": "0x52a07c930157d07d9effd147ecf41c5cbbc6000c",
+            "to": "0xd0ec028a3d21533fdd200838f39c85b03679285d",
+            "value": "0x0",
+            "error": "execution reverted",
+            "calls": [{ "from": "0xd0ec028a3d21533fdd200838f39c85b03679285d", "to": "0x0000000000000000000000000000000000000001", "value": "0x64" }]
+        }))
+        .unwrap();
+
+        assert_eq!(frame.error.as_deref(), Some("execution reverted"));
+        assert_eq!(frame.calls[0].value, Some(BigUint::from(100u32)));
+        assert!(frame.logs.is_empty());
+    }
 }
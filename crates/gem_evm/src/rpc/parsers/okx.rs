@@ -249,6 +249,7 @@ mod tests {
             to: Some("0x4409921ae43a39a11d90f7b7f96cfd0b8093d9fc".to_string()),
             block_number: BigUint::from(1u32),
             value: BigUint::from(0u8),
+            authorization_list: None,
         };
         let transfer_receipt = TransactionReciept {
             gas_used: BigUint::from(318420u32),
@@ -300,6 +301,7 @@ mod tests {
             to: Some("0x5e1f62dac767b0491e3ce72469c217365d5b48cc".to_string()),
             block_number: BigUint::from(24717134u32),
             value: BigUint::from(0u8),
+            authorization_list: None,
         };
         let uniswap_v3_swap_to_receipt = TransactionReciept {
             gas_used: BigUint::from(203405u32),
@@ -340,6 +342,7 @@ mod tests {
             to: Some("0x5e1f62dac767b0491e3ce72469c217365d5b48cc".to_string()),
             block_number: BigUint::from(24717121u32),
             value: BigUint::from(0u8),
+            authorization_list: None,
         };
         let unxswap_by_order_id_receipt = TransactionReciept {
             gas_used: BigUint::from(176410u32),
@@ -0,0 +1,21 @@
+use primitives::EVMChain;
+
+pub fn private_relay_url(chain: EVMChain) -> Option<&'static str> {
+    match chain {
+        EVMChain::Ethereum => Some("https://rpc.flashbots.net/fast"),
+        EVMChain::SmartChain => Some("https://bscrpc.pancakeswap.finance"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_relay_url() {
+        assert_eq!(private_relay_url(EVMChain::Ethereum), Some("https://rpc.flashbots.net/fast"));
+        assert!(private_relay_url(EVMChain::SmartChain).is_some());
+        assert_eq!(private_relay_url(EVMChain::Base), None);
+    }
+}
@@ -366,6 +366,7 @@ mod tests {
             gas: 280395,
             input: "0x".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
@@ -404,6 +405,7 @@ mod tests {
             gas: 384404,
             input: "0x".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
@@ -442,6 +444,7 @@ mod tests {
             gas: 485626,
             input: "0x".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
@@ -481,6 +484,7 @@ mod tests {
             gas: 150000,
             input: "0x".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
@@ -518,6 +522,7 @@ mod tests {
             gas: 21000,
             input: "0x".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
@@ -547,6 +552,7 @@ mod tests {
             gas: 21000,
             input: "0x982ef0a7000000000000000000000000d34403249b2d82aaddb14e778422c966265e5fb50000000000000000000000000000000000000000000000000000000000000000".to_string(),
             block_number: BigUint::from(0x1234u32),
+            authorization_list: None,
         };
 
         let log = Log {
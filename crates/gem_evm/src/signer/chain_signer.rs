@@ -1,16 +1,15 @@
 use std::str::FromStr;
 
-use alloy_consensus::TxEip1559;
+use alloy_consensus::{TxEip1559, TxEip2930, TxLegacy, TypedTransaction};
 use alloy_primitives::{Address, Bytes, TxKind, U256};
 use num_bigint::BigInt;
 use num_traits::Num;
-use primitives::{ChainSigner, EVMChain, NFTType, SignerError, SignerInput, StakeType, decode_hex, swap::SwapQuoteDataType};
+use primitives::{ChainSigner, EVMChain, EvmTransactionType, NFTType, SignerError, SignerInput, StakeType, decode_hex, swap::SwapQuoteDataType};
 
 use super::model::TransactionParams;
-use super::sign_eip1559_tx;
+use super::sign_typed_tx;
 use crate::encode::{encode_erc20_approve, encode_erc20_transfer, encode_erc721_transfer, encode_erc1155_transfer};
 
-#[allow(dead_code)]
 pub struct EvmChainSigner {
     chain: EVMChain,
 }
@@ -23,22 +22,22 @@ impl EvmChainSigner {
 
 impl ChainSigner for EvmChainSigner {
     fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
-        let params = TransactionParams::from_input(input)?;
+        let params = TransactionParams::from_input(self.chain, input)?;
         sign_and_encode(
-            &build_eip1559_transaction(&params, &input.destination_address, value_u256(&input.value)?, Bytes::new())?,
+            &build_transaction(&params, &input.destination_address, value_u256(&input.value)?, Bytes::new())?,
             private_key,
         )
     }
 
     fn sign_token_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
-        let params = TransactionParams::from_input(input)?;
+        let params = TransactionParams::from_input(self.chain, input)?;
         let token_id = input.input_type.get_asset().id.get_token_id()?;
         let data = encode_erc20_transfer(&input.destination_address, &BigInt::from_str_radix(&input.value, 10)?)?;
-        sign_and_encode(&build_eip1559_transaction(&params, token_id, U256::ZERO, Bytes::from(data))?, private_key)
+        sign_and_encode(&build_transaction(&params, token_id, U256::ZERO, Bytes::from(data))?, private_key)
     }
 
     fn sign_nft_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
-        let params = TransactionParams::from_input(input)?;
+        let params = TransactionParams::from_input(self.chain, input)?;
         let nft_asset = input.input_type.get_nft_asset()?;
         let contract = nft_asset.get_contract_address()?;
         let data = match nft_asset.token_type {
@@ -46,14 +45,14 @@ impl ChainSigner for EvmChainSigner {
             NFTType::ERC1155 => encode_erc1155_transfer(&input.sender_address, &input.destination_address, &nft_asset.token_id),
             _ => return Err(SignerError::invalid_input("unsupported NFT type for EVM")),
         }?;
-        sign_and_encode(&build_eip1559_transaction(&params, contract, U256::ZERO, Bytes::from(data))?, private_key)
+        sign_and_encode(&build_transaction(&params, contract, U256::ZERO, Bytes::from(data))?, private_key)
     }
 
     fn sign_token_approval(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
-        let params = TransactionParams::from_input(input)?;
+        let params = TransactionParams::from_input(self.chain, input)?;
         let approval = input.input_type.get_approval_data()?;
         sign_and_encode(
-            &build_eip1559_transaction(&params, &approval.token, U256::ZERO, Bytes::from(encode_erc20_approve(&approval.spender)?))?,
+            &build_transaction(&params, &approval.token, U256::ZERO, Bytes::from(encode_erc20_approve(&approval.spender)?))?,
             private_key,
         )
     }
@@ -65,18 +64,15 @@ impl ChainSigner for EvmChainSigner {
 
         match swap_data.data_type {
             SwapQuoteDataType::Transfer => {
-                let params = TransactionParams::from_input(input)?;
+                let params = TransactionParams::from_input(self.chain, input)?;
                 if from_asset.id.is_token() {
                     let token_id = from_asset.id.get_token_id()?;
                     let amount = BigInt::from_str_radix(&input.value, 10)?;
                     let data = encode_erc20_transfer(&swap_data.to, &amount)?;
-                    Ok(vec![sign_and_encode(
-                        &build_eip1559_transaction(&params, token_id, U256::ZERO, Bytes::from(data))?,
-                        private_key,
-                    )?])
+                    Ok(vec![sign_and_encode(&build_transaction(&params, token_id, U256::ZERO, Bytes::from(data))?, private_key)?])
                 } else {
                     Ok(vec![sign_and_encode(
-                        &build_eip1559_transaction(&params, &swap_data.to, value_u256(&input.value)?, Bytes::new())?,
+                        &build_transaction(&params, &swap_data.to, value_u256(&input.value)?, Bytes::new())?,
                         private_key,
                     )?])
                 }
@@ -88,7 +84,7 @@ impl ChainSigner for EvmChainSigner {
                     None => input.fee.gas_limit()?,
                 };
                 sign_contract_call(
-                    input,
+                    TransactionParams::from_input(self.chain, input)?,
                     &swap_data.to,
                     decode_hex(&swap_data.data)?,
                     gas_limit,
@@ -97,6 +93,19 @@ impl ChainSigner for EvmChainSigner {
                     private_key,
                 )
             }
+            // The order itself is signed as a message and submitted off-chain, only the allowance needs a transaction.
+            SwapQuoteDataType::Eip712 => match &swap_data.approval {
+                Some(approval) => Ok(vec![sign_and_encode(
+                    &build_transaction(
+                        &TransactionParams::from_input(self.chain, input)?,
+                        &approval.token,
+                        U256::ZERO,
+                        Bytes::from(encode_erc20_approve(&approval.spender)?),
+                    )?,
+                    private_key,
+                )?]),
+                None => Ok(vec![]),
+            },
         }
     }
 
@@ -104,7 +113,7 @@ impl ChainSigner for EvmChainSigner {
         let earn_data = input.input_type.get_earn_data()?;
         let gas_limit = earn_data.gas_limit.as_ref().and_then(|gl| gl.parse().ok()).map_or_else(|| input.fee.gas_limit(), Ok)?;
         sign_contract_call(
-            input,
+            TransactionParams::from_input(self.chain, input)?,
             &earn_data.contract_address,
             decode_hex(&earn_data.call_data)?,
             gas_limit,
@@ -122,7 +131,7 @@ impl ChainSigner for EvmChainSigner {
             _ => U256::ZERO,
         };
         sign_contract_call(
-            input,
+            TransactionParams::from_input(self.chain, input)?,
             &contract_call.contract_address,
             decode_hex(&contract_call.call_data)?,
             input.fee.gas_limit()?,
@@ -134,11 +143,11 @@ impl ChainSigner for EvmChainSigner {
 
     fn sign_data(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
         let extra = input.input_type.get_generic_data()?;
-        let base = TransactionParams::from_input(input)?;
+        let base = TransactionParams::from_input(self.chain, input)?;
         let gas_limit = extra.gas_limit.as_ref().and_then(|gl| gl.to_string().parse().ok()).unwrap_or(base.gas_limit);
         let params = TransactionParams { gas_limit, ..base };
         sign_and_encode(
-            &build_eip1559_transaction(&params, &extra.to, value_u256(&input.value)?, Bytes::from(extra.data.clone().unwrap_or_default()))?,
+            &build_transaction(&params, &extra.to, value_u256(&input.value)?, Bytes::from(extra.data.clone().unwrap_or_default()))?,
             private_key,
         )
     }
@@ -153,30 +162,52 @@ fn value_u256(value: &str) -> Result<U256, SignerError> {
     U256::from_str(value).map_err(SignerError::from_display)
 }
 
-fn build_eip1559_transaction(params: &TransactionParams, to: &str, value: U256, input: Bytes) -> Result<TxEip1559, SignerError> {
+fn build_transaction(params: &TransactionParams, to: &str, value: U256, input: Bytes) -> Result<TypedTransaction, SignerError> {
     let to_address = Address::parse_checksummed(to, None)
         .or_else(|_| to.parse::<Address>())
         .map_err(|_| SignerError::invalid_input("invalid to address"))?;
 
-    Ok(TxEip1559 {
-        chain_id: params.chain_id,
-        nonce: params.nonce,
-        gas_limit: params.gas_limit,
-        max_fee_per_gas: params.max_fee_per_gas,
-        max_priority_fee_per_gas: params.max_priority_fee_per_gas,
-        to: TxKind::Call(to_address),
-        value,
-        access_list: Default::default(),
-        input,
-    })
+    let transaction = match params.transaction_type {
+        EvmTransactionType::Eip1559 => TypedTransaction::Eip1559(TxEip1559 {
+            chain_id: params.chain_id,
+            nonce: params.nonce,
+            gas_limit: params.gas_limit,
+            max_fee_per_gas: params.max_fee_per_gas,
+            max_priority_fee_per_gas: params.max_priority_fee_per_gas,
+            to: TxKind::Call(to_address),
+            value,
+            access_list: params.access_list.clone(),
+            input,
+        }),
+        EvmTransactionType::Legacy if params.access_list.is_empty() => TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(params.chain_id),
+            nonce: params.nonce,
+            gas_price: params.max_fee_per_gas,
+            gas_limit: params.gas_limit,
+            to: TxKind::Call(to_address),
+            value,
+            input,
+        }),
+        EvmTransactionType::Legacy | EvmTransactionType::Eip2930 => TypedTransaction::Eip2930(TxEip2930 {
+            chain_id: params.chain_id,
+            nonce: params.nonce,
+            gas_price: params.max_fee_per_gas,
+            gas_limit: params.gas_limit,
+            to: TxKind::Call(to_address),
+            value,
+            access_list: params.access_list.clone(),
+            input,
+        }),
+    };
+    Ok(transaction)
 }
 
-fn sign_and_encode(transaction: &TxEip1559, private_key: &[u8]) -> Result<String, SignerError> {
-    Ok(hex::encode(sign_eip1559_tx(transaction, private_key)?))
+fn sign_and_encode(transaction: &TypedTransaction, private_key: &[u8]) -> Result<String, SignerError> {
+    Ok(hex::encode(sign_typed_tx(transaction, private_key)?))
 }
 
 fn sign_contract_call(
-    input: &SignerInput,
+    params: TransactionParams,
     contract_address: &str,
     call_data: Vec<u8>,
     gas_limit: u64,
@@ -184,21 +215,19 @@ fn sign_contract_call(
     approval: Option<&primitives::swap::ApprovalData>,
     private_key: &[u8],
 ) -> Result<Vec<String>, SignerError> {
-    let params = TransactionParams::from_input(input)?;
-
     if let Some(approval) = approval {
-        let approval_transaction = build_eip1559_transaction(&params, &approval.token, U256::ZERO, Bytes::from(encode_erc20_approve(&approval.spender)?))?;
+        let approval_transaction = build_transaction(&params, &approval.token, U256::ZERO, Bytes::from(encode_erc20_approve(&approval.spender)?))?;
         let main_params = TransactionParams {
             nonce: params.nonce + 1,
             gas_limit,
             ..params
         };
-        let main_transaction = build_eip1559_transaction(&main_params, contract_address, value, Bytes::from(call_data))?;
+        let main_transaction = build_transaction(&main_params, contract_address, value, Bytes::from(call_data))?;
         Ok(vec![sign_and_encode(&approval_transaction, private_key)?, sign_and_encode(&main_transaction, private_key)?])
     } else {
         let main_params = TransactionParams { gas_limit, ..params };
         Ok(vec![sign_and_encode(
-            &build_eip1559_transaction(&main_params, contract_address, value, Bytes::from(call_data))?,
+            &build_transaction(&main_params, contract_address, value, Bytes::from(call_data))?,
             private_key,
         )?])
     }
@@ -318,6 +347,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sign_swap_eip712() {
+        let signer = EvmChainSigner::new(EVMChain::Ethereum);
+        let swap_data = |approval: Option<ApprovalData>| SwapData {
+            quote: SwapQuote::mock(),
+            data: SwapQuoteData {
+                data_type: SwapQuoteDataType::Eip712,
+                data: "{}".to_string(),
+                approval,
+                ..SwapQuoteData::mock()
+            },
+        };
+        let input = |approval: Option<ApprovalData>| {
+            SignerInput::mock_evm(
+                TransactionInputType::Swap(Asset::from_chain(Chain::Ethereum), Asset::from_chain(Chain::Ethereum), swap_data(approval)),
+                "0",
+                65000,
+            )
+        };
+
+        assert!(signer.sign_swap(&input(None), &TEST_PRIVATE_KEY).unwrap().is_empty());
+
+        let result = signer.sign_swap(&input(Some(ApprovalData::mock())), &TEST_PRIVATE_KEY).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0],
+            "02f8b00180843b9aca008504a817c80082fde894dac17f958d2ee523a2206206994597c13d831ec780b844095ea7b30000000000000000000000002b5ad5c4795c026514f8317c7a215e218dccd6cfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc001a02fde3a01cfa4c2349782fa026932003f5eae7077763db75b31be593e7c15f4a3a048071a05fccf3905cb59be79fc9a0442d801907506193c158858cd6b7ef31fa3"
+        );
+    }
+
     #[test]
     fn test_sign_stake() {
         let signer = EvmChainSigner::new(EVMChain::Ethereum);
@@ -327,6 +386,7 @@ mod tests {
             contract_call: Some(ContractCallData::mock_with_call_data(
                 "3a29dbae0000000000000000000000000000000000000000000000000000000000000017",
             )),
+            access_list: vec![],
         };
         let input = SignerInput::mock_evm_with_metadata(
             TransactionInputType::Stake(Asset::from_chain(Chain::Ethereum), StakeType::Stake(DelegationValidator::mock())),
@@ -402,6 +462,62 @@ mod tests {
         );
     }
 
+    fn decode(encoded: &str) -> alloy_consensus::TxEnvelope {
+        use alloy_network::eip2718::Decodable2718;
+        alloy_consensus::TxEnvelope::decode_2718(&mut hex::decode(encoded).unwrap().as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_sign_legacy_transfer() {
+        let signer = EvmChainSigner::new(EVMChain::Fantom);
+        let input = SignerInput::mock_evm_with_metadata(
+            TransactionInputType::Transfer(Asset::from_chain(Chain::Fantom)),
+            "1000000000000000000",
+            21000,
+            TransactionLoadMetadata::mock_evm(3, 250),
+        );
+
+        let alloy_consensus::TxEnvelope::Legacy(signed) = decode(&signer.sign_transfer(&input, &TEST_PRIVATE_KEY).unwrap()) else {
+            panic!("expected legacy transaction");
+        };
+        assert_eq!(signed.tx().chain_id, Some(250));
+        assert_eq!(signed.tx().nonce, 3);
+        assert_eq!(signed.tx().gas_price, 20_000_000_000);
+        assert_eq!(signed.tx().value, U256::from(1_000_000_000_000_000_000u64));
+    }
+
+    #[test]
+    fn test_sign_access_list() {
+        let access_list = vec![primitives::EvmAccessListItem {
+            address: "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".to_string(),
+            storage_keys: vec!["0x0000000000000000000000000000000000000000000000000000000000000003".to_string()],
+        }];
+        let input_type = TransactionInputType::Generic(
+            Asset::from_chain(Chain::Fantom),
+            WalletConnectionSessionAppMetadata::mock(),
+            TransferDataExtra::mock_encoded_transaction(vec![0xab, 0xcd]),
+        );
+        let metadata = |chain_id| TransactionLoadMetadata::Evm {
+            nonce: 0,
+            chain_id,
+            contract_call: None,
+            access_list: access_list.clone(),
+        };
+
+        let input = SignerInput::mock_evm_with_metadata(input_type.clone(), "0", 100000, metadata(250));
+        let alloy_consensus::TxEnvelope::Eip2930(signed) = decode(&EvmChainSigner::new(EVMChain::Fantom).sign_data(&input, &TEST_PRIVATE_KEY).unwrap()) else {
+            panic!("expected EIP-2930 transaction");
+        };
+        assert_eq!(signed.tx().access_list.len(), 1);
+        assert_eq!(signed.tx().access_list[0].storage_keys.len(), 1);
+
+        let input = SignerInput::mock_evm_with_metadata(input_type, "0", 100000, metadata(1));
+        let alloy_consensus::TxEnvelope::Eip1559(signed) = decode(&EvmChainSigner::new(EVMChain::Ethereum).sign_data(&input, &TEST_PRIVATE_KEY).unwrap()) else {
+            panic!("expected EIP-1559 transaction");
+        };
+        assert_eq!(signed.tx().access_list.len(), 1);
+    }
+
     #[test]
     fn test_invalid_metadata() {
         let signer = EvmChainSigner::new(EVMChain::Ethereum);
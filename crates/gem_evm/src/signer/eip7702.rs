@@ -0,0 +1,106 @@
+use alloy_consensus::{SignableTransaction, TxEip7702};
+use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+use alloy_network::TxSignerSync;
+use alloy_network::eip2718::Encodable2718;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use std::error::Error;
+
+use super::model::TransactionParams;
+
+pub fn sign_authorization(chain_id: u64, delegate: Address, nonce: u64, private_key: &[u8]) -> Result<SignedAuthorization, Box<dyn Error + Send + Sync>> {
+    let signer = PrivateKeySigner::from_slice(private_key)?;
+    let authorization = Authorization {
+        chain_id: U256::from(chain_id),
+        address: delegate,
+        nonce,
+    };
+    let signature = signer.sign_hash_sync(&authorization.signature_hash())?;
+    Ok(authorization.into_signed(signature))
+}
+
+pub fn create_set_code_tx(params: &TransactionParams, to: Address, value: U256, input: Bytes, authorization_list: Vec<SignedAuthorization>) -> TxEip7702 {
+    TxEip7702 {
+        chain_id: params.chain_id,
+        nonce: params.nonce,
+        gas_limit: params.gas_limit,
+        max_fee_per_gas: params.max_fee_per_gas,
+        max_priority_fee_per_gas: params.max_priority_fee_per_gas,
+        to,
+        value,
+        access_list: params.access_list.clone(),
+        authorization_list,
+        input,
+    }
+}
+
+pub fn sign_eip7702_tx(tx: &TxEip7702, private_key: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let signer = PrivateKeySigner::from_slice(private_key)?;
+    let mut tx = tx.clone();
+    let signature = signer.sign_transaction_sync(&mut tx)?;
+    let signed = tx.into_signed(signature);
+    Ok(signed.encoded_2718())
+}
+
+pub fn sign_self_delegation_tx(params: &TransactionParams, delegate: Address, value: U256, input: Bytes, private_key: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let sender = PrivateKeySigner::from_slice(private_key)?.address();
+    let authorization = sign_authorization(params.chain_id, delegate, params.nonce + 1, private_key)?;
+    sign_eip7702_tx(&create_set_code_tx(params, sender, value, input, vec![authorization]), private_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEnvelope;
+    use alloy_network::eip2718::Decodable2718;
+    use primitives::EvmTransactionType;
+    use primitives::testkit::signer_mock::TEST_PRIVATE_KEY;
+
+    const DELEGATE: Address = alloy_primitives::address!("0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B");
+
+    fn recover_authority(authorization: &SignedAuthorization) -> Address {
+        authorization.signature().unwrap().recover_address_from_prehash(&authorization.signature_hash()).unwrap()
+    }
+
+    fn params() -> TransactionParams {
+        TransactionParams {
+            transaction_type: EvmTransactionType::Eip1559,
+            nonce: 7,
+            chain_id: 1,
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            gas_limit: 100_000,
+            access_list: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_sign_authorization() {
+        let sender = PrivateKeySigner::from_slice(&TEST_PRIVATE_KEY).unwrap().address();
+        let authorization = sign_authorization(1, DELEGATE, 3, &TEST_PRIVATE_KEY).unwrap();
+
+        assert_eq!(authorization.address, DELEGATE);
+        assert_eq!(authorization.nonce, 3);
+        assert_eq!(recover_authority(&authorization), sender);
+    }
+
+    #[test]
+    fn test_sign_self_delegation_tx() {
+        let sender = PrivateKeySigner::from_slice(&TEST_PRIVATE_KEY).unwrap().address();
+        let encoded = sign_self_delegation_tx(&params(), DELEGATE, U256::ZERO, Bytes::from(vec![0xde, 0xad]), &TEST_PRIVATE_KEY).unwrap();
+
+        assert_eq!(encoded[0], 0x04);
+        let TxEnvelope::Eip7702(signed) = TxEnvelope::decode_2718(&mut encoded.as_slice()).unwrap() else {
+            panic!("expected EIP-7702 transaction");
+        };
+        let tx = signed.tx();
+        assert_eq!(signed.signature().recover_address_from_prehash(&signed.signature_hash()).unwrap(), sender);
+        assert_eq!(tx.to, sender);
+        assert_eq!(tx.nonce, 7);
+        assert_eq!(tx.input, Bytes::from(vec![0xde, 0xad]));
+        assert_eq!(tx.authorization_list.len(), 1);
+        assert_eq!(tx.authorization_list[0].nonce, 8);
+        assert_eq!(recover_authority(&tx.authorization_list[0]), sender);
+    }
+}
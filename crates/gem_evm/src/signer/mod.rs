@@ -1,10 +1,16 @@
 mod chain_signer;
 mod eip1559;
+mod eip7702;
 mod model;
 mod transaction;
+mod typed;
 
 pub use chain_signer::EvmChainSigner;
 pub use eip1559::sign_eip1559_tx;
+pub use eip7702::{create_set_code_tx, sign_authorization, sign_eip7702_tx, sign_self_delegation_tx};
+pub use model::TransactionParams;
 pub use transaction::create_transfer_tx;
+pub use typed::sign_typed_tx;
 
-pub use alloy_consensus::TxEip1559;
+pub use alloy_consensus::{TxEip1559, TxEip2930, TxEip7702, TxLegacy, TypedTransaction};
+pub use alloy_eips::eip7702::SignedAuthorization;
@@ -1,22 +1,51 @@
-use primitives::{SignerError, SignerInput};
+use std::str::FromStr;
 
-#[derive(Clone, Copy)]
+use alloy_eips::eip2930::{AccessList, AccessListItem};
+use alloy_primitives::{Address, B256};
+use primitives::{EVMChain, EvmAccessListItem, EvmTransactionType, SignerError, SignerInput, TransactionLoadMetadata};
+
+#[derive(Clone)]
 pub struct TransactionParams {
+    pub transaction_type: EvmTransactionType,
     pub nonce: u64,
     pub chain_id: u64,
     pub max_fee_per_gas: u128,
     pub max_priority_fee_per_gas: u128,
     pub gas_limit: u64,
+    pub access_list: AccessList,
 }
 
 impl TransactionParams {
-    pub fn from_input(input: &SignerInput) -> Result<Self, SignerError> {
+    pub fn from_input(chain: EVMChain, input: &SignerInput) -> Result<Self, SignerError> {
+        let access_list = match &input.metadata {
+            TransactionLoadMetadata::Evm { access_list, .. } => map_access_list(access_list)?,
+            _ => AccessList::default(),
+        };
         Ok(Self {
+            transaction_type: chain.transaction_type(),
             nonce: input.metadata.get_sequence()?,
             chain_id: input.metadata.get_chain_id_u64()?,
             max_fee_per_gas: input.fee.gas_price_u64()? as u128,
             max_priority_fee_per_gas: input.fee.priority_fee_u64()? as u128,
             gas_limit: input.fee.gas_limit()?,
+            access_list,
         })
     }
 }
+
+fn map_access_list(items: &[EvmAccessListItem]) -> Result<AccessList, SignerError> {
+    items
+        .iter()
+        .map(|item| {
+            Ok(AccessListItem {
+                address: Address::from_str(&item.address).map_err(|_| SignerError::invalid_input("invalid access list address"))?,
+                storage_keys: item
+                    .storage_keys
+                    .iter()
+                    .map(|key| B256::from_str(key).map_err(|_| SignerError::invalid_input("invalid access list storage key")))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(AccessList)
+}
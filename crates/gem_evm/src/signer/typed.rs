@@ -0,0 +1,12 @@
+use alloy_consensus::TypedTransaction;
+use alloy_network::TxSignerSync;
+use alloy_network::eip2718::Encodable2718;
+use alloy_signer_local::PrivateKeySigner;
+use std::error::Error;
+
+pub fn sign_typed_tx(tx: &TypedTransaction, private_key: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let signer = PrivateKeySigner::from_slice(private_key)?;
+    let mut tx = tx.clone();
+    let signature = signer.sign_transaction_sync(&mut tx)?;
+    Ok(tx.into_envelope(signature).encoded_2718())
+}
@@ -33,7 +33,7 @@ pub enum UniversalRouterCommand {
     SWEEP(Sweep),
     TRANSFER(Transfer),
     PAY_PORTION(PayPortion),
-    V2_SWAP_EXACT_IN,
+    V2_SWAP_EXACT_IN(V2SwapExactIn),
     V2_SWAP_EXACT_OUT,
     PERMIT2_PERMIT(Permit2Permit),
     WRAP_ETH(WrapEth),
@@ -54,7 +54,7 @@ impl UniversalRouterCommand {
             Self::SWEEP(_) => SWEEP_COMMAND,
             Self::TRANSFER(_) => TRANSFER_COMMAND,
             Self::PAY_PORTION(_) => PAY_PORTION_COMMAND,
-            Self::V2_SWAP_EXACT_IN => V2_SWAP_EXACT_IN_COMMAND,
+            Self::V2_SWAP_EXACT_IN(_) => V2_SWAP_EXACT_IN_COMMAND,
             // COMMAND_PLACEHOLDER = 0x07;
             Self::V2_SWAP_EXACT_OUT => V2_SWAP_EXACT_OUT_COMMAND,
             Self::PERMIT2_PERMIT(_) => PERMIT2_PERMIT_COMMAND,
@@ -70,6 +70,7 @@ impl UniversalRouterCommand {
         match self {
             Self::V3_SWAP_EXACT_IN(payload) => payload.abi_encode(),
             Self::V3_SWAP_EXACT_OUT(payload) => payload.abi_encode(),
+            Self::V2_SWAP_EXACT_IN(payload) => payload.abi_encode(),
             Self::SWEEP(payload) => payload.abi_encode(),
             Self::TRANSFER(payload) => payload.abi_encode(),
             Self::PAY_PORTION(payload) => payload.abi_encode(),
@@ -78,12 +79,19 @@ impl UniversalRouterCommand {
             Self::PERMIT2_PERMIT(payload) => payload.abi_encode(),
             Self::PERMIT2_TRANSFER_FROM(payload) => payload.abi_encode(),
             Self::V4_SWAP { actions } => actions::encode_actions(actions),
-            Self::PERMIT2_PERMIT_BATCH | Self::PERMIT2_TRANSFER_FROM_BATCH | Self::V2_SWAP_EXACT_IN | Self::V2_SWAP_EXACT_OUT => todo!(),
+            Self::PERMIT2_PERMIT_BATCH | Self::PERMIT2_TRANSFER_FROM_BATCH | Self::V2_SWAP_EXACT_OUT => todo!(),
         }
     }
 }
 
 type V3SwapExactType = (sol_data::Address, sol_data::Uint<256>, sol_data::Uint<256>, sol_data::Bytes, sol_data::Bool);
+type V2SwapExactType = (
+    sol_data::Address,
+    sol_data::Uint<256>,
+    sol_data::Uint<256>,
+    sol_data::Array<sol_data::Address>,
+    sol_data::Bool,
+);
 type SweepType = (sol_data::Address, sol_data::Address, sol_data::Uint<256>);
 type PayPortionType = (sol_data::Address, sol_data::Address, sol_data::Uint<256>);
 type TransferType = PayPortionType;
@@ -134,6 +142,33 @@ impl V3SwapExactOut {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct V2SwapExactIn {
+    pub recipient: Address,
+    pub amount_in: U256,
+    pub amount_out_min: U256,
+    pub path: Vec<Address>,
+    pub payer_is_user: bool,
+}
+
+impl V2SwapExactIn {
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let data = (self.recipient, self.amount_in, self.amount_out_min, self.path.clone(), self.payer_is_user);
+        V2SwapExactType::abi_encode_sequence(&data)
+    }
+
+    pub fn abi_decode(data: &[u8]) -> Result<Self, alloy_sol_types::Error> {
+        let (recipient, amount_in, amount_out_min, path, payer_is_user) = V2SwapExactType::abi_decode_sequence(data)?;
+        Ok(Self {
+            recipient,
+            amount_in,
+            amount_out_min,
+            path,
+            payer_is_user,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Sweep {
     pub token: Address,
@@ -452,4 +487,19 @@ mod tests {
 
         assert_eq!(HexEncode(encoded), expected);
     }
+
+    #[test]
+    fn test_v2_swap_exact_in_round_trip() {
+        let swap = V2SwapExactIn {
+            recipient: Address::from_str(MSG_SENDER).unwrap(),
+            amount_in: U256::from(1000u64),
+            amount_out_min: U256::from(900u64),
+            path: vec![Address::from_str(OP_WETH).unwrap(), Address::from_str(OP_USDC).unwrap()],
+            payer_is_user: true,
+        };
+        let command = UniversalRouterCommand::V2_SWAP_EXACT_IN(V2SwapExactIn::abi_decode(&swap.abi_encode()).unwrap());
+
+        assert_eq!(command.raw_value(), V2_SWAP_EXACT_IN_COMMAND);
+        assert_eq!(V2SwapExactIn::abi_decode(&command.encode()).unwrap(), swap);
+    }
 }
@@ -1,5 +1,6 @@
 use alloy_sol_types::sol;
 
+pub mod v2;
 pub mod v3;
 pub mod v4;
 
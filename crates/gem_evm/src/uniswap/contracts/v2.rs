@@ -0,0 +1,72 @@
+use alloy_sol_types::sol;
+
+// https://github.com/Uniswap/v2-core/blob/master/contracts/interfaces/IUniswapV2Factory.sol
+// https://github.com/Uniswap/v2-core/blob/master/contracts/interfaces/IUniswapV2Pair.sol
+// https://github.com/Uniswap/v2-periphery/blob/master/contracts/interfaces/IUniswapV2Router02.sol
+// https://github.com/CamelotLabs/periphery/blob/main/contracts/interfaces/ICamelotRouter.sol
+sol! {
+    #[derive(Debug, PartialEq)]
+    interface IUniswapV2Factory {
+        function getPair(address tokenA, address tokenB) external view returns (address pair);
+    }
+
+    #[derive(Debug, PartialEq)]
+    interface IUniswapV2Pair {
+        function getReserves() external view returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+    }
+
+    #[derive(Debug, PartialEq)]
+    interface IUniswapV2Router02 {
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(
+            uint amountIn,
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            uint deadline
+        ) external;
+
+        function swapExactETHForTokensSupportingFeeOnTransferTokens(
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            uint deadline
+        ) external payable;
+
+        function swapExactTokensForETHSupportingFeeOnTransferTokens(
+            uint amountIn,
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            uint deadline
+        ) external;
+    }
+
+    #[derive(Debug, PartialEq)]
+    interface ICamelotRouter {
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(
+            uint amountIn,
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            address referrer,
+            uint deadline
+        ) external;
+
+        function swapExactETHForTokensSupportingFeeOnTransferTokens(
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            address referrer,
+            uint deadline
+        ) external payable;
+
+        function swapExactTokensForETHSupportingFeeOnTransferTokens(
+            uint amountIn,
+            uint amountOutMin,
+            address[] calldata path,
+            address to,
+            address referrer,
+            uint deadline
+        ) external;
+    }
+}
@@ -1,3 +1,4 @@
+pub mod v2;
 pub mod v3;
 pub mod v4;
 
@@ -63,5 +64,16 @@ pub fn get_provider_by_chain_contract(chain: &Chain, contract: &str) -> Option<S
     {
         return Some(SwapProvider::Aerodrome.id().to_string());
     }
-    None
+    let v2_deployments = [
+        (SwapProvider::UniswapV2, v2::get_uniswap_v2_deployment_by_chain(chain)),
+        (SwapProvider::PancakeswapV2, v2::get_pancakeswap_v2_deployment_by_chain(chain)),
+        (SwapProvider::SushiswapV2, v2::get_sushiswap_v2_deployment_by_chain(chain)),
+        (SwapProvider::QuickswapV2, v2::get_quickswap_v2_deployment_by_chain(chain)),
+        (SwapProvider::CamelotV2, v2::get_camelot_v2_deployment_by_chain(chain)),
+        (SwapProvider::SpookyswapV2, v2::get_spookyswap_v2_deployment_by_chain(chain)),
+    ];
+    v2_deployments
+        .into_iter()
+        .find(|(_, deployment)| deployment.as_ref().is_some_and(|x| x.router.to_lowercase() == contract))
+        .map(|(provider, _)| provider.id().to_string())
 }
@@ -0,0 +1,230 @@
+use super::{v3, v4};
+use alloy_primitives::{Address, B256, keccak256};
+use primitives::Chain;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum V2RouterType {
+    UniswapV2,
+    // Camelot router swaps take an extra referrer argument.
+    Camelot,
+}
+
+// Universal router that executes this deployment's V2 pools and can take a referral fee in the same transaction.
+pub struct V2UniversalRouter {
+    pub address: &'static str,
+    pub permit2: &'static str,
+}
+
+pub struct V2Deployment {
+    pub router: &'static str,
+    pub factory: &'static str,
+    // Pairs are derived with CREATE2 when the hash is known, otherwise looked up through the factory.
+    pub init_code_hash: Option<&'static str>,
+    pub fee_bps: u32,
+    pub router_type: V2RouterType,
+    pub universal_router: Option<V2UniversalRouter>,
+}
+
+impl V2Deployment {
+    pub fn pair_address(&self, token_a: Address, token_b: Address) -> Option<Address> {
+        let factory: Address = self.factory.parse().ok()?;
+        let init_code_hash: B256 = self.init_code_hash?.parse().ok()?;
+        let (token0, token1) = if token_a < token_b { (token_a, token_b) } else { (token_b, token_a) };
+        let salt = keccak256([token0.as_slice(), token1.as_slice()].concat());
+        Some(factory.create2(salt, init_code_hash))
+    }
+}
+
+fn uniswap_universal_router(chain: &Chain) -> Option<V2UniversalRouter> {
+    v4::get_uniswap_deployment_by_chain(chain).map(|deployment| V2UniversalRouter {
+        address: deployment.universal_router,
+        permit2: deployment.permit2,
+    })
+}
+
+fn pancakeswap_universal_router(chain: &Chain) -> Option<V2UniversalRouter> {
+    v3::get_pancakeswap_router_deployment_by_chain(chain).map(|deployment| V2UniversalRouter {
+        address: deployment.universal_router,
+        permit2: deployment.permit2,
+    })
+}
+
+const UNISWAP_V2_INIT_CODE_HASH: &str = "0x96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f";
+
+pub fn get_uniswap_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    // https://docs.uniswap.org/contracts/v2/reference/smart-contracts/v2-deployments
+    match chain {
+        Chain::Ethereum => Some(V2Deployment {
+            router: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+            factory: "0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: uniswap_universal_router(chain),
+        }),
+        Chain::Arbitrum => Some(V2Deployment {
+            router: "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24",
+            factory: "0xf1D7CC64Fb4452F05c498126312eBE29f30Fbcf9",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: uniswap_universal_router(chain),
+        }),
+        Chain::Base => Some(V2Deployment {
+            router: "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24",
+            factory: "0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: uniswap_universal_router(chain),
+        }),
+        Chain::SmartChain => Some(V2Deployment {
+            router: "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24",
+            factory: "0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: uniswap_universal_router(chain),
+        }),
+        Chain::Polygon => Some(V2Deployment {
+            router: "0xedf6066a2b290C185783862C7F4776A2C8077AD1",
+            factory: "0x9e5A52f57b3038F1B8EeE45F28b3C1967e22799C",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: uniswap_universal_router(chain),
+        }),
+        _ => None,
+    }
+}
+
+pub fn get_pancakeswap_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    // https://developer.pancakeswap.finance/contracts/v2/addresses
+    match chain {
+        Chain::SmartChain => Some(V2Deployment {
+            router: "0x10ED43C718714eb63d5aA57B78B54704E256024E",
+            factory: "0xcA143Ce32Fe78f1f7019d7d551a6402fC5350c73",
+            init_code_hash: Some("0x00fb7f630766e6a796048ea87d01acd3068e8ff67d078148a3fa3f4a84f69bd5"),
+            fee_bps: 25,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: pancakeswap_universal_router(chain),
+        }),
+        _ => None,
+    }
+}
+
+pub fn get_sushiswap_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    // https://docs.sushi.com/contracts/cpamm
+    match chain {
+        Chain::Arbitrum | Chain::Polygon | Chain::SmartChain | Chain::AvalancheC => Some(V2Deployment {
+            router: "0x1b02dA8Cb0d097eB8D57A175b88c7D8b47997506",
+            factory: "0xc35DADB65012eC5796536bD9864eD8773aBc74C4",
+            init_code_hash: None,
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: None,
+        }),
+        Chain::Base => Some(V2Deployment {
+            router: "0x6BDED42c6DA8FBf0d2bA55B2fa120C5e0c8D7891",
+            factory: "0x71524B4f93c58fcbF659783284E38825f0622859",
+            init_code_hash: None,
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: None,
+        }),
+        _ => None,
+    }
+}
+
+pub fn get_quickswap_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    // https://docs.quickswap.exchange/overview/contracts-and-addresses
+    match chain {
+        Chain::Polygon => Some(V2Deployment {
+            router: "0xa5E0829CaCEd8fFDD4De3c43696c57F7D7A678ff",
+            factory: "0x5757371414417b8C6CAad45bAeF941aBc7d3Ab32",
+            init_code_hash: Some(UNISWAP_V2_INIT_CODE_HASH),
+            fee_bps: 30,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: None,
+        }),
+        _ => None,
+    }
+}
+
+pub fn get_camelot_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    match chain {
+        Chain::Arbitrum => Some(V2Deployment {
+            router: "0xc873fEcbd354f5A56E00E710B90EF4201db2448d",
+            factory: "0x6EcCab422D763aC031210895C81787E87B43A652",
+            init_code_hash: Some("0xa856464ae65f7619087bc369daaf7e387dae1e5af69cfa7935850ebf754b04c1"),
+            fee_bps: 30,
+            router_type: V2RouterType::Camelot,
+            universal_router: None,
+        }),
+        _ => None,
+    }
+}
+
+pub fn get_spookyswap_v2_deployment_by_chain(chain: &Chain) -> Option<V2Deployment> {
+    match chain {
+        Chain::Sonic => Some(V2Deployment {
+            router: "0xa6AD18C2aC47803E193F75c3677b14BF19B94883",
+            factory: "0xEE4bC42157cf65291Ba2FE839AE127e3Cc76f741",
+            init_code_hash: Some("0xcdf2deca40a0bd56de8e3ce5c7df6727e5b1bf2ac96f283fa9c4b3e6b42ea9d2"),
+            fee_bps: 20,
+            router_type: V2RouterType::UniswapV2,
+            universal_router: None,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair_address(deployment: Option<V2Deployment>, token_a: &str, token_b: &str) -> Address {
+        deployment.unwrap().pair_address(token_a.parse().unwrap(), token_b.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_pair_address() {
+        let weth = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+        let usdc = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+        let pair: Address = "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc".parse().unwrap();
+
+        assert_eq!(pair_address(get_uniswap_v2_deployment_by_chain(&Chain::Ethereum), weth, usdc), pair);
+        assert_eq!(pair_address(get_uniswap_v2_deployment_by_chain(&Chain::Ethereum), usdc, weth), pair);
+
+        assert_eq!(
+            pair_address(
+                get_pancakeswap_v2_deployment_by_chain(&Chain::SmartChain),
+                "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c",
+                "0xe9e7CEA3DedcA5984780Bafc599bD69ADd087D56"
+            ),
+            "0x58F876857a02D6762E0101bb5C46A8c1ED44Dc16".parse::<Address>().unwrap()
+        );
+        assert_eq!(
+            pair_address(
+                get_quickswap_v2_deployment_by_chain(&Chain::Polygon),
+                "0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270",
+                "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"
+            ),
+            "0x6e7a5FAFcec6BB1e78bAE2A1F0B612012BF14827".parse::<Address>().unwrap()
+        );
+        assert_eq!(
+            pair_address(
+                get_camelot_v2_deployment_by_chain(&Chain::Arbitrum),
+                "0x82aF49447D8a07e3bd95BD0d56f35241523fBab1",
+                "0xFF970A61A04b1cA14834A43f5dE4533eBDDB5CC8"
+            ),
+            "0x84652bb2539513BAf36e225c930Fdd8eaa63CE27".parse::<Address>().unwrap()
+        );
+        assert!(
+            get_sushiswap_v2_deployment_by_chain(&Chain::Arbitrum)
+                .unwrap()
+                .pair_address(weth.parse().unwrap(), usdc.parse().unwrap())
+                .is_none()
+        );
+    }
+}
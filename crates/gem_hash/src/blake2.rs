@@ -0,0 +1,53 @@
+use blake2b_simd::Params;
+
+pub fn blake2b_256_personal(bytes: &[u8], personal: &[u8]) -> [u8; 32] {
+    let hash = Params::new().hash_length(32).personal(personal).hash(bytes);
+
+    let mut result = [0u8; 32];
+    result.copy_from_slice(hash.as_bytes());
+    result
+}
+
+pub fn blake2b_224(bytes: &[u8]) -> [u8; 28] {
+    let hash = Params::new().hash_length(28).hash(bytes);
+
+    let mut result = [0u8; 28];
+    result.copy_from_slice(hash.as_bytes());
+    result
+}
+
+pub fn blake2b_256(bytes: &[u8]) -> [u8; 32] {
+    blake2b_256_personal(bytes, b"")
+}
+
+pub fn blake2b_512(bytes: &[u8]) -> [u8; 64] {
+    let hash = Params::new().hash_length(64).hash(bytes);
+
+    let mut result = [0u8; 64];
+    result.copy_from_slice(hash.as_bytes());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake2b_256_personal() {
+        assert_eq!(
+            hex::encode(blake2b_256_personal(b"", b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_ne!(blake2b_256_personal(b"", b"ZcashPrevoutHash"), blake2b_256_personal(b"", b""));
+    }
+
+    #[test]
+    fn test_blake2b() {
+        assert_eq!(hex::encode(blake2b_224(b"")), "836cc68931c2e4e3e838602eca1902591d216837bafddfe6f0c8cb07");
+        assert_eq!(blake2b_256(b""), blake2b_256_personal(b"", b""));
+        assert_eq!(
+            hex::encode(blake2b_512(b"")),
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        );
+    }
+}
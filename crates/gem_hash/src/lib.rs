@@ -1,4 +1,8 @@
+#[cfg(feature = "blake2")]
+pub mod blake2;
 pub mod keccak;
 pub mod message;
+#[cfg(feature = "ripemd")]
+pub mod ripemd;
 pub mod sha2;
 pub mod sha3;
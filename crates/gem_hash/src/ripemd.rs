@@ -0,0 +1,27 @@
+use ripemd::{Digest, Ripemd160};
+
+use crate::sha2::sha256;
+
+pub fn ripemd160(bytes: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(bytes).into()
+}
+
+pub fn hash160(bytes: &[u8]) -> [u8; 20] {
+    ripemd160(&sha256(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ripemd160() {
+        assert_eq!(hex::encode(ripemd160(b"")), "9c1185a5c5e9fc54612808977ee8f548b2258d31");
+    }
+
+    #[test]
+    fn test_hash160() {
+        let public_key = hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+        assert_eq!(hex::encode(hash160(&public_key)), "751e76e8199196d454941c45d1b3a323f1433bd6");
+    }
+}
@@ -1,4 +1,4 @@
-use sha2::{Digest, Sha256, Sha512_256};
+use sha2::{Digest, Sha256, Sha512, Sha512_256};
 
 pub fn sha256(bytes: &[u8]) -> [u8; 32] {
     let mut hasher = Sha256::new();
@@ -19,3 +19,13 @@ pub fn sha512_256(bytes: &[u8]) -> [u8; 32] {
     hash.copy_from_slice(&result);
     hash
 }
+
+pub fn sha512(bytes: &[u8]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(bytes);
+    let result = hasher.finalize();
+
+    let mut hash = [0u8; 64];
+    hash.copy_from_slice(&result);
+    hash
+}
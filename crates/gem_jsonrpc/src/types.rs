@@ -40,6 +40,12 @@ pub struct JsonRpcError {
     pub message: String,
 }
 
+impl JsonRpcError {
+    pub fn is_client_error(&self) -> bool {
+        self.code == ERROR_CLIENT_ERROR
+    }
+}
+
 impl Display for JsonRpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let original = self.message.trim();
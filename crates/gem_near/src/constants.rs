@@ -1,3 +1,15 @@
 pub const TRANSACTION_STATUS_FINAL: &str = "FINAL";
 pub const TRANSACTION_STATUS_EXECUTED: &str = "EXECUTED";
 pub const TRANSACTION_STATUS_EXECUTED_OPTIMISTIC: &str = "EXECUTED_OPTIMISTIC";
+
+// Prepaid gas for staking pool calls (125 TGas)
+pub const STAKING_GAS: u64 = 125_000_000_000_000;
+
+// Prepaid gas for `ft_transfer` and `storage_deposit` calls (30 TGas each)
+pub const TOKEN_TRANSFER_GAS: u64 = 30_000_000_000_000;
+pub const STORAGE_DEPOSIT_GAS: u64 = 30_000_000_000_000;
+
+// NEP-141 requires exactly 1 yoctoNEAR attached to `ft_transfer`
+pub const TOKEN_TRANSFER_DEPOSIT: u128 = 1;
+// NEP-145 storage registration cost of a fungible token account (0.00125 NEAR)
+pub const STORAGE_DEPOSIT_AMOUNT: u128 = 1_250_000_000_000_000_000_000;
@@ -1,4 +1,6 @@
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use serde_serializers::deserialize_biguint_from_str;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
@@ -9,6 +11,8 @@ pub struct Block {
 pub struct BlockHeader {
     pub hash: String,
     pub height: u64,
+    #[serde(deserialize_with = "deserialize_biguint_from_str")]
+    pub total_supply: BigUint,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
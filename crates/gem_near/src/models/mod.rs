@@ -2,9 +2,13 @@ pub mod account;
 pub mod block;
 pub mod fee;
 pub mod rpc;
+pub mod staking;
+pub mod token;
 pub mod transaction;
 
 pub use account::*;
 pub use block::*;
 pub use fee::*;
+pub use staking::*;
+pub use token::*;
 pub use transaction::*;
@@ -0,0 +1,50 @@
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_serializers::deserialize_biguint_from_str;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochValidators {
+    pub current_validators: Vec<EpochValidator>,
+    pub epoch_start_height: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochValidator {
+    pub account_id: String,
+    #[serde(deserialize_with = "deserialize_biguint_from_str")]
+    pub stake: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCallResult {
+    pub result: Vec<u8>,
+}
+
+impl FunctionCallResult {
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.result)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardFeeFraction {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl RewardFeeFraction {
+    pub fn value(&self) -> f64 {
+        if self.denominator == 0 {
+            return 0.0;
+        }
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PoolPosition {
+    pub pool_id: String,
+    pub staked: BigUint,
+    pub unstaked: BigUint,
+    pub can_withdraw: bool,
+}
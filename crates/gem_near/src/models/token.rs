@@ -0,0 +1,16 @@
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use serde_serializers::deserialize_biguint_from_str;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FungibleTokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageBalance {
+    #[serde(deserialize_with = "deserialize_biguint_from_str")]
+    pub total: BigUint,
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use chain_traits::ChainBalances;
+use futures::future::try_join_all;
 use std::error::Error;
 
 use gem_client::Client;
@@ -7,6 +8,7 @@ use gem_jsonrpc::types::JsonRpcError;
 use primitives::{AssetBalance, Chain};
 
 use super::balances_mapper;
+use super::token_mapper::map_token_balance;
 use crate::rpc::client::NearClient;
 
 const ACCOUNT_NOT_FOUND_ERROR_CODE: i32 = -32000;
@@ -22,8 +24,9 @@ impl<C: Client + Clone> ChainBalances for NearClient<C> {
         balances_mapper::map_native_balance(&account)
     }
 
-    async fn get_balance_tokens(&self, _address: String, _token_ids: Vec<String>) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
-        Ok(vec![])
+    async fn get_balance_tokens(&self, address: String, token_ids: Vec<String>) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let balances = try_join_all(token_ids.iter().map(|token_id| self.get_ft_balance(token_id, &address))).await?;
+        Ok(token_ids.iter().zip(balances).map(|(token_id, balance)| map_token_balance(token_id, balance)).collect())
     }
 
     async fn get_balance_staking(&self, _address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
@@ -43,6 +46,7 @@ fn is_account_missing(error: &JsonRpcError) -> bool {
 mod chain_integration_tests {
     use crate::provider::testkit::{TEST_ADDRESS, create_near_test_client};
     use chain_traits::ChainBalances;
+    use primitives::asset_constants::{NEAR_USDC_TOKEN_ID, NEAR_USDT_TOKEN_ID};
 
     #[tokio::test]
     async fn test_near_get_balance_coin() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -54,6 +58,17 @@ mod chain_integration_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_near_get_balance_tokens() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_near_test_client()?;
+        let token_ids = vec![NEAR_USDT_TOKEN_ID.to_string(), NEAR_USDC_TOKEN_ID.to_string()];
+        let balances = client.get_balance_tokens(TEST_ADDRESS.to_string(), token_ids).await?;
+
+        assert_eq!(balances.len(), 2);
+        println!("Token balances: {:?}", balances);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_near_get_balance_assets() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let client = create_near_test_client()?;
@@ -3,9 +3,13 @@ pub mod balances_mapper;
 pub mod preload;
 pub mod preload_mapper;
 pub mod request_classifier;
+pub mod staking;
+pub mod staking_mapper;
 pub mod state;
 pub mod state_mapper;
 pub mod testkit;
+pub mod token;
+pub mod token_mapper;
 pub mod transaction_broadcast;
 pub mod transaction_broadcast_mapper;
 pub mod transaction_state;
@@ -1,33 +1,42 @@
 use async_trait::async_trait;
 use chain_traits::ChainTransactionLoad;
-use futures::try_join;
-use num_bigint::BigInt;
-use std::{error::Error, str::FromStr};
+use futures::join;
+use std::error::Error;
 
 use gem_client::Client;
-use primitives::{FeeRate, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput};
+use primitives::{FeeRate, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput};
 
 use crate::{
     provider::{
-        preload_mapper::{address_to_public_key, map_transaction_preload},
+        preload_mapper::{address_to_public_key, map_transaction_load, map_transaction_preload},
         state_mapper::map_gas_price_to_priorities,
     },
     rpc::client::NearClient,
 };
 
+impl<C: Client + Clone> NearClient<C> {
+    async fn is_storage_registered(&self, input_type: &TransactionInputType, account_id: &str) -> Result<bool, Box<dyn Error + Sync + Send>> {
+        match (input_type, &input_type.get_asset().id.token_id) {
+            (TransactionInputType::Transfer(_), Some(token_id)) => Ok(self.get_storage_balance(token_id, account_id).await?.is_some()),
+            _ => Ok(true),
+        }
+    }
+}
+
 #[async_trait]
 impl<C: Client + Clone> ChainTransactionLoad for NearClient<C> {
     async fn get_transaction_preload(&self, input: TransactionPreloadInput) -> Result<TransactionLoadMetadata, Box<dyn Error + Sync + Send>> {
         let public_key = address_to_public_key(&input.sender_address)?;
-        let (access_key, block) = try_join!(self.get_account_access_key(&input.sender_address, &public_key), self.get_latest_block(),)?;
-        Ok(map_transaction_preload(&access_key, &block))
+        let (access_key, block, is_storage_registered) = join!(
+            self.get_account_access_key(&input.sender_address, &public_key),
+            self.get_latest_block(),
+            self.is_storage_registered(&input.input_type, &input.destination_address),
+        );
+        Ok(map_transaction_preload(&access_key?, &block?, is_storage_registered?))
     }
 
     async fn get_transaction_load(&self, input: TransactionLoadInput) -> Result<TransactionLoadData, Box<dyn Error + Sync + Send>> {
-        Ok(TransactionLoadData {
-            fee: TransactionFee::new_from_gas_price_and_limit(input.gas_price.gas_price(), BigInt::from_str("9000000000000")?), // "4174947687500" * 2
-            metadata: input.metadata,
-        })
+        map_transaction_load(input)
     }
 
     async fn get_transaction_fee_rates(&self, _input_type: TransactionInputType) -> Result<Vec<FeeRate>, Box<dyn Error + Sync + Send>> {
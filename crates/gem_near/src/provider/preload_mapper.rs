@@ -1,24 +1,62 @@
-use crate::models::{AccountAccessKey, Block};
-use primitives::TransactionLoadMetadata;
+use std::collections::HashMap;
 use std::error::Error;
 
+use num_bigint::BigInt;
+use primitives::{FeeOption, GasPriceType, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata};
+
+use crate::constants::{STAKING_GAS, STORAGE_DEPOSIT_AMOUNT, STORAGE_DEPOSIT_GAS, TOKEN_TRANSFER_GAS};
+use crate::models::{AccountAccessKey, Block};
+
+// "4174947687500" * 2
+const TRANSFER_GAS: u64 = 9_000_000_000_000;
+
 pub fn address_to_public_key(address: &str) -> Result<String, Box<dyn Error + Sync + Send>> {
     let address_bytes = hex::decode(address)?;
     let encoded = bs58::encode(address_bytes).into_string();
     Ok(format!("ed25519:{}", encoded))
 }
 
-pub fn map_transaction_preload(access_key: &AccountAccessKey, block: &Block) -> TransactionLoadMetadata {
+pub fn map_transaction_preload(access_key: &AccountAccessKey, block: &Block, is_destination_storage_registered: bool) -> TransactionLoadMetadata {
     TransactionLoadMetadata::Near {
         sequence: (access_key.nonce + 1) as u64,
         block_hash: block.header.hash.clone(),
+        is_destination_storage_registered,
     }
 }
 
+pub fn map_transaction_load(input: TransactionLoadInput) -> Result<TransactionLoadData, Box<dyn Error + Sync + Send>> {
+    let gas_price = input.gas_price.gas_price();
+    let fee = match &input.input_type {
+        TransactionInputType::Stake(_, _) => TransactionFee::new_from_gas_price_and_limit(gas_price, BigInt::from(STAKING_GAS)),
+        TransactionInputType::Transfer(asset) if asset.id.token_id.is_some() => {
+            if input.metadata.get_is_destination_storage_registered()? {
+                TransactionFee::new_from_gas_price_and_limit(gas_price, BigInt::from(TOKEN_TRANSFER_GAS))
+            } else {
+                let gas_limit = BigInt::from(TOKEN_TRANSFER_GAS + STORAGE_DEPOSIT_GAS);
+                let options = HashMap::from([(FeeOption::TokenAccountCreation, BigInt::from(STORAGE_DEPOSIT_AMOUNT))]);
+                TransactionFee::new_gas_price_type(GasPriceType::regular(gas_price.clone()), gas_price * &gas_limit, gas_limit, options)
+            }
+        }
+        TransactionInputType::Transfer(_)
+        | TransactionInputType::Deposit(_)
+        | TransactionInputType::Swap(_, _, _)
+        | TransactionInputType::TokenApprove(_, _)
+        | TransactionInputType::Generic(_, _, _)
+        | TransactionInputType::TransferNft(_, _)
+        | TransactionInputType::Account(_, _)
+        | TransactionInputType::Perpetual(_, _)
+        | TransactionInputType::Earn(_, _, _) => TransactionFee::new_from_gas_price_and_limit(gas_price, BigInt::from(TRANSFER_GAS)),
+    };
+    Ok(TransactionLoadData { fee, metadata: input.metadata })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::{AccountAccessKey, Block, BlockHeader};
+    use num_bigint::BigUint;
+    use primitives::asset_constants::NEAR_USDT_TOKEN_ID;
+    use primitives::{Asset, AssetType, Chain};
 
     #[test]
     fn test_address_to_public_key() {
@@ -35,17 +73,66 @@ mod tests {
             header: BlockHeader {
                 hash: "F45xbjXiyHn5noj1692RVqeuNC6X232qhKpvvPrv92iz".to_string(),
                 height: 12345,
+                total_supply: BigUint::from(0u32),
             },
         };
 
-        let result = map_transaction_preload(&access_key, &block);
+        let result = map_transaction_preload(&access_key, &block, false);
 
         match result {
-            TransactionLoadMetadata::Near { sequence, block_hash } => {
+            TransactionLoadMetadata::Near {
+                sequence,
+                block_hash,
+                is_destination_storage_registered,
+            } => {
                 assert_eq!(sequence, 116479371000027);
                 assert_eq!(block_hash, "F45xbjXiyHn5noj1692RVqeuNC6X232qhKpvvPrv92iz");
+                assert!(!is_destination_storage_registered);
             }
             _ => panic!("Expected Near metadata"),
         }
     }
+
+    fn token_input(is_destination_storage_registered: bool) -> TransactionLoadInput {
+        let asset = Asset::mock_with_params(
+            Chain::Near,
+            Some(NEAR_USDT_TOKEN_ID.to_string()),
+            "Tether USD".to_string(),
+            "USDt".to_string(),
+            6,
+            AssetType::TOKEN,
+        );
+        let metadata = TransactionLoadMetadata::Near {
+            sequence: 1,
+            block_hash: "F45xbjXiyHn5noj1692RVqeuNC6X232qhKpvvPrv92iz".to_string(),
+            is_destination_storage_registered,
+        };
+        TransactionLoadInput::mock_transfer(asset, "sender.near", "receiver.near", "1000000", 100_000_000, None, metadata)
+    }
+
+    #[test]
+    fn test_map_transaction_load() {
+        let input = TransactionLoadInput::mock_near("sender.near", "receiver.near", "1", 1, "hash");
+        let result = map_transaction_load(TransactionLoadInput {
+            gas_price: GasPriceType::regular(100_000_000),
+            ..input
+        })
+        .unwrap();
+        assert_eq!(result.fee.gas_limit, BigInt::from(TRANSFER_GAS));
+        assert_eq!(result.fee.fee, BigInt::from(900_000_000_000_000_000_000u128));
+
+        let result = map_transaction_load(token_input(true)).unwrap();
+        assert_eq!(result.fee.gas_limit, BigInt::from(TOKEN_TRANSFER_GAS));
+        assert_eq!(result.fee.fee, BigInt::from(3_000_000_000_000_000_000_000u128));
+        assert!(result.fee.options.is_empty());
+    }
+
+    #[test]
+    fn test_map_transaction_load_storage_deposit() {
+        let result = map_transaction_load(token_input(false)).unwrap();
+
+        assert_eq!(result.fee.gas_limit, BigInt::from(60_000_000_000_000u64));
+        assert_eq!(result.fee.options[&FeeOption::TokenAccountCreation], BigInt::from(STORAGE_DEPOSIT_AMOUNT));
+        assert_eq!(result.fee.fee, BigInt::from(6_000_000_000_000_000_000_000u128 + STORAGE_DEPOSIT_AMOUNT));
+    }
 }
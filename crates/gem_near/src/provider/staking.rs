@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chain_traits::ChainStaking;
+use chrono::Utc;
+use futures::{StreamExt, stream, try_join};
+use num_bigint::BigUint;
+use std::error::Error;
+
+use gem_client::Client;
+use primitives::{DelegationBase, DelegationValidator};
+
+use super::staking_mapper::{map_delegations, map_staking_apy, map_unlock_date, map_validators};
+use crate::models::PoolPosition;
+use crate::rpc::client::NearClient;
+
+const POOL_REQUESTS_CONCURRENCY: usize = 10;
+
+impl<C: Client + Clone> NearClient<C> {
+    async fn get_pool_position(&self, pool_id: String, account_id: &str) -> Result<PoolPosition, Box<dyn Error + Sync + Send>> {
+        let (staked, unstaked) = try_join!(
+            self.get_account_staked_balance(&pool_id, account_id),
+            self.get_account_unstaked_balance(&pool_id, account_id)
+        )?;
+        let can_withdraw = unstaked > BigUint::ZERO && self.is_account_unstaked_balance_available(&pool_id, account_id).await?;
+        Ok(PoolPosition {
+            pool_id,
+            staked,
+            unstaked,
+            can_withdraw,
+        })
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainStaking for NearClient<C> {
+    async fn get_staking_apy(&self) -> Result<Option<f64>, Box<dyn Error + Sync + Send>> {
+        let (validators, block) = try_join!(self.get_validators(), self.get_latest_block())?;
+        Ok(Some(map_staking_apy(&block.header.total_supply, &validators.current_validators)))
+    }
+
+    async fn get_staking_validators(&self, apy: Option<f64>) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
+        let apy = match apy {
+            Some(apy) => apy,
+            None => self.get_staking_apy().await?.unwrap_or_default(),
+        };
+        let validators = self.get_validators().await?.current_validators;
+        let validators = stream::iter(validators)
+            .map(|validator| async move {
+                let fee = self.get_reward_fee_fraction(&validator.account_id).await.ok();
+                (validator, fee)
+            })
+            .buffered(POOL_REQUESTS_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+        Ok(map_validators(validators, apy))
+    }
+
+    async fn get_staking_delegations(&self, address: String) -> Result<Vec<DelegationBase>, Box<dyn Error + Sync + Send>> {
+        let (validators, block) = try_join!(self.get_validators(), self.get_latest_block())?;
+        let positions = stream::iter(validators.current_validators)
+            .map(|validator| self.get_pool_position(validator.account_id, &address))
+            .buffered(POOL_REQUESTS_CONCURRENCY)
+            .filter_map(|position| async move { position.ok() })
+            .collect::<Vec<_>>()
+            .await;
+        let unlock_date = map_unlock_date(validators.epoch_start_height, block.header.height, Utc::now());
+        Ok(map_delegations(positions, unlock_date))
+    }
+}
+
+#[cfg(all(test, feature = "chain_integration_tests"))]
+mod chain_integration_tests {
+    use super::*;
+    use crate::provider::testkit::{TEST_ADDRESS, create_near_test_client};
+
+    #[tokio::test]
+    async fn test_near_get_staking_validators() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_near_test_client()?;
+        let apy = client.get_staking_apy().await?;
+        let validators = client.get_staking_validators(apy).await?;
+
+        println!("APY: {:?}, validators: {}", apy, validators.len());
+        assert!(!validators.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_near_get_staking_delegations() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_near_test_client()?;
+        let delegations = client.get_staking_delegations(TEST_ADDRESS.to_string()).await?;
+
+        println!("Delegations: {:?}", delegations);
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Duration, Utc};
+use num_bigint::BigUint;
+use primitives::{Chain, DelegationBase, DelegationState, DelegationValidator};
+
+use crate::models::{EpochValidator, PoolPosition, RewardFeeFraction};
+
+pub const EPOCH_LENGTH: u64 = 43_200;
+pub const NUM_EPOCHS_TO_UNLOCK: u64 = 4;
+// Protocol inflation is capped at 5% a year, 10% of it goes to the treasury
+const MAX_INFLATION_RATE: f64 = 0.05;
+const VALIDATORS_REWARD_SHARE: f64 = 0.9;
+
+fn to_f64(value: &BigUint) -> f64 {
+    value.to_string().parse::<f64>().unwrap_or_default()
+}
+
+pub fn map_staking_apy(total_supply: &BigUint, validators: &[EpochValidator]) -> f64 {
+    let total_stake: f64 = validators.iter().map(|validator| to_f64(&validator.stake)).sum();
+    if total_stake == 0.0 {
+        return 0.0;
+    }
+    to_f64(total_supply) * MAX_INFLATION_RATE * VALIDATORS_REWARD_SHARE / total_stake * 100.0
+}
+
+pub fn map_validators(validators: Vec<(EpochValidator, Option<RewardFeeFraction>)>, apy: f64) -> Vec<DelegationValidator> {
+    validators
+        .into_iter()
+        .filter_map(|(validator, fee)| {
+            let fee = fee?.value();
+            Some(DelegationValidator::stake(
+                Chain::Near,
+                validator.account_id.clone(),
+                validator.account_id,
+                true,
+                fee * 100.0,
+                apy * (1.0 - fee),
+            ))
+        })
+        .collect()
+}
+
+pub fn map_unlock_date(epoch_start_height: u64, height: u64, now: DateTime<Utc>) -> DateTime<Utc> {
+    let remaining_blocks = (epoch_start_height + EPOCH_LENGTH * NUM_EPOCHS_TO_UNLOCK).saturating_sub(height);
+    now + Duration::milliseconds((remaining_blocks * Chain::Near.block_time() as u64) as i64)
+}
+
+pub fn map_delegations(positions: Vec<PoolPosition>, unlock_date: DateTime<Utc>) -> Vec<DelegationBase> {
+    positions
+        .into_iter()
+        .flat_map(|position| {
+            let unstaked_state = if position.can_withdraw {
+                (DelegationState::AwaitingWithdrawal, position.unstaked, None)
+            } else {
+                (DelegationState::Deactivating, position.unstaked, Some(unlock_date))
+            };
+            [(DelegationState::Active, position.staked, None), unstaked_state]
+                .into_iter()
+                .filter(|(_, balance, _)| *balance > BigUint::ZERO)
+                .map(|(state, balance, completion_date)| DelegationBase {
+                    asset_id: Chain::Near.as_asset_id(),
+                    state,
+                    balance,
+                    shares: BigUint::from(0u32),
+                    rewards: BigUint::from(0u32),
+                    completion_date,
+                    delegation_id: format!("{}-{}", position.pool_id, state.as_ref()),
+                    validator_id: position.pool_id.clone(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EpochValidators;
+
+    fn validators() -> Vec<EpochValidator> {
+        serde_json::from_value::<EpochValidators>(serde_json::json!({
+            "current_validators": [
+                { "account_id": "figment.poolv1.near", "stake": "200000000000000000000000000000000" },
+                { "account_id": "custom.near", "stake": "100000000000000000000000000000000" }
+            ],
+            "epoch_start_height": 1000
+        }))
+        .unwrap()
+        .current_validators
+    }
+
+    fn position(staked: u128, unstaked: u128, can_withdraw: bool) -> PoolPosition {
+        PoolPosition {
+            pool_id: "figment.poolv1.near".to_string(),
+            staked: BigUint::from(staked),
+            unstaked: BigUint::from(unstaked),
+            can_withdraw,
+        }
+    }
+
+    #[test]
+    fn test_map_staking_apy() {
+        let total_supply = BigUint::from(1_200_000_000u64) * BigUint::from(10u64).pow(24);
+        let apy = map_staking_apy(&total_supply, &validators());
+
+        assert!((apy - 18.0).abs() < 1e-9);
+        assert_eq!(map_staking_apy(&total_supply, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_map_validators() {
+        let fee = RewardFeeFraction { numerator: 10, denominator: 100 };
+        let validators: Vec<_> = validators().into_iter().zip([Some(fee), None]).collect();
+        let result = map_validators(validators, 10.0);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "figment.poolv1.near");
+        assert_eq!(result[0].commission, 10.0);
+        assert!((result[0].apr - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_map_unlock_date() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        assert_eq!(map_unlock_date(1000, 1000, now), now + Duration::seconds(172_800));
+        assert_eq!(map_unlock_date(1000, 44_200, now), now + Duration::seconds(129_600));
+        assert_eq!(map_unlock_date(0, 1_000_000, now), now);
+    }
+
+    #[test]
+    fn test_map_delegations() {
+        let unlock_date = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let delegations = map_delegations(vec![position(5, 2, false), position(0, 3, true), position(0, 0, false)], unlock_date);
+        let states: Vec<_> = delegations.iter().map(|x| (x.state, x.balance.clone(), x.completion_date)).collect();
+
+        assert_eq!(
+            states,
+            vec![
+                (DelegationState::Active, BigUint::from(5u32), None),
+                (DelegationState::Deactivating, BigUint::from(2u32), Some(unlock_date)),
+                (DelegationState::AwaitingWithdrawal, BigUint::from(3u32), None),
+            ]
+        );
+        assert_eq!(delegations[0].validator_id, "figment.poolv1.near");
+        assert_eq!(delegations[1].delegation_id, "figment.poolv1.near-deactivating");
+    }
+}
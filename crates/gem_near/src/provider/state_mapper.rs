@@ -21,7 +21,7 @@ pub fn map_node_status(block: &Block) -> Result<NodeSyncStatus, Box<dyn Error +
 mod tests {
     use super::*;
     use crate::models::fee::GasPrice;
-    use num_bigint::BigInt;
+    use num_bigint::{BigInt, BigUint};
     use primitives::GasPriceType;
 
     #[test]
@@ -52,6 +52,7 @@ mod tests {
             header: BlockHeader {
                 hash: String::new(),
                 height: 123456789,
+                total_supply: BigUint::from(0u32),
             },
         };
         let mapped = map_node_status(&block).unwrap();
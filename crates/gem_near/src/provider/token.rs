@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chain_traits::ChainToken;
+use std::error::Error;
+
+use gem_client::Client;
+use primitives::Asset;
+
+use super::token_mapper::{is_token_address, map_token_data};
+use crate::rpc::client::NearClient;
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for NearClient<C> {
+    async fn get_token_data(&self, token_id: String) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let metadata = self.get_ft_metadata(&token_id).await?;
+        Ok(map_token_data(&token_id, metadata))
+    }
+
+    fn get_is_token_address(&self, token_id: &str) -> bool {
+        is_token_address(token_id)
+    }
+}
+
+#[cfg(all(test, feature = "chain_integration_tests"))]
+mod chain_integration_tests {
+    use super::*;
+    use crate::provider::testkit::create_near_test_client;
+    use primitives::asset_constants::NEAR_USDT_TOKEN_ID;
+
+    #[tokio::test]
+    async fn test_near_get_token_data() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_near_test_client()?;
+        let asset = client.get_token_data(NEAR_USDT_TOKEN_ID.to_string()).await?;
+
+        assert_eq!(asset.symbol, "USDt");
+        assert_eq!(asset.decimals, 6);
+        Ok(())
+    }
+}
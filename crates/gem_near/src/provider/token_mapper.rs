@@ -0,0 +1,60 @@
+use num_bigint::BigUint;
+use primitives::{Asset, AssetBalance, AssetId, AssetType, Chain};
+
+use crate::models::FungibleTokenMetadata;
+
+const IMPLICIT_ACCOUNT_LENGTH: usize = 64;
+const ACCOUNT_SEPARATORS: [char; 3] = ['.', '-', '_'];
+
+pub fn map_token_data(token_id: &str, metadata: FungibleTokenMetadata) -> Asset {
+    Asset::new(
+        AssetId::from_token(Chain::Near, token_id),
+        metadata.name,
+        metadata.symbol,
+        metadata.decimals,
+        AssetType::TOKEN,
+    )
+}
+
+pub fn map_token_balance(token_id: &str, balance: BigUint) -> AssetBalance {
+    AssetBalance::new(AssetId::from_token(Chain::Near, token_id), balance)
+}
+
+pub fn is_token_address(token_id: &str) -> bool {
+    let is_valid_account = (2..=64).contains(&token_id.len())
+        && token_id.split(ACCOUNT_SEPARATORS).all(|part| !part.is_empty())
+        && token_id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || ACCOUNT_SEPARATORS.contains(&c));
+    let is_implicit = token_id.len() == IMPLICIT_ACCOUNT_LENGTH && token_id.chars().all(|c| c.is_ascii_hexdigit());
+    is_valid_account && (token_id.contains('.') || is_implicit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::{NEAR_USDC_TOKEN_ID, NEAR_USDT_TOKEN_ID};
+
+    #[test]
+    fn test_map_token_data() {
+        let metadata: FungibleTokenMetadata = serde_json::from_value(serde_json::json!({
+            "spec": "ft-1.0.0", "name": "Tether USD", "symbol": "USDt", "icon": null, "decimals": 6
+        }))
+        .unwrap();
+        let asset = map_token_data(NEAR_USDT_TOKEN_ID, metadata);
+
+        assert_eq!(asset.id, AssetId::from_token(Chain::Near, NEAR_USDT_TOKEN_ID));
+        assert_eq!(asset.symbol, "USDt");
+        assert_eq!(asset.decimals, 6);
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+    }
+
+    #[test]
+    fn test_is_token_address() {
+        assert!(is_token_address(NEAR_USDT_TOKEN_ID));
+        assert!(is_token_address(NEAR_USDC_TOKEN_ID));
+        assert!(is_token_address("wrap.near"));
+        assert!(!is_token_address("near"));
+        assert!(!is_token_address("Wrap.near"));
+        assert!(!is_token_address("wrap..near"));
+        assert!(!is_token_address("0x17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1"));
+    }
+}
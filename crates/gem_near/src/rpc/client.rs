@@ -1,9 +1,14 @@
-use crate::models::{Account, AccountAccessKey, Block, BroadcastResult, GasPrice, NodeStatus};
-use chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainProvider, ChainStaking, ChainToken, ChainTraits};
+use crate::models::{
+    Account, AccountAccessKey, Block, BroadcastResult, EpochValidators, FunctionCallResult, FungibleTokenMetadata, GasPrice, NodeStatus, RewardFeeFraction, StorageBalance,
+};
+use chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainProvider, ChainTraits};
 use gem_client::Client;
+use gem_encoding::encode_base64;
 use gem_jsonrpc::{client::JsonRpcClient, types::JsonRpcError};
+use num_bigint::BigUint;
 use primitives::Chain;
-use serde_json::json;
+use serde_json::{Value, json};
+use std::error::Error;
 
 #[derive(Debug)]
 pub struct NearClient<C: Client + Clone> {
@@ -55,6 +60,62 @@ impl<C: Client + Clone> NearClient<C> {
         self.client.call("send_tx", params).await
     }
 
+    pub async fn get_validators(&self) -> Result<EpochValidators, JsonRpcError> {
+        let params = json!([null]);
+        self.client.call("validators", params).await
+    }
+
+    pub async fn call_function(&self, contract_id: &str, method_name: &str, args: Value) -> Result<FunctionCallResult, JsonRpcError> {
+        let params = json!({
+            "request_type": "call_function",
+            "finality": "final",
+            "account_id": contract_id,
+            "method_name": method_name,
+            "args_base64": encode_base64(args.to_string().as_bytes())
+        });
+        self.client.call("query", params).await
+    }
+
+    pub async fn get_reward_fee_fraction(&self, pool_id: &str) -> Result<RewardFeeFraction, Box<dyn Error + Send + Sync>> {
+        Ok(self.call_function(pool_id, "get_reward_fee_fraction", json!({})).await?.decode()?)
+    }
+
+    pub async fn get_account_staked_balance(&self, pool_id: &str, account_id: &str) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let balance: String = self
+            .call_function(pool_id, "get_account_staked_balance", json!({"account_id": account_id}))
+            .await?
+            .decode()?;
+        Ok(balance.parse()?)
+    }
+
+    pub async fn get_account_unstaked_balance(&self, pool_id: &str, account_id: &str) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let balance: String = self
+            .call_function(pool_id, "get_account_unstaked_balance", json!({"account_id": account_id}))
+            .await?
+            .decode()?;
+        Ok(balance.parse()?)
+    }
+
+    pub async fn is_account_unstaked_balance_available(&self, pool_id: &str, account_id: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .call_function(pool_id, "is_account_unstaked_balance_available", json!({"account_id": account_id}))
+            .await?
+            .decode()?)
+    }
+
+    pub async fn get_ft_metadata(&self, token_id: &str) -> Result<FungibleTokenMetadata, Box<dyn Error + Send + Sync>> {
+        Ok(self.call_function(token_id, "ft_metadata", json!({})).await?.decode()?)
+    }
+
+    pub async fn get_ft_balance(&self, token_id: &str, account_id: &str) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let balance: String = self.call_function(token_id, "ft_balance_of", json!({"account_id": account_id})).await?.decode()?;
+        Ok(balance.parse()?)
+    }
+
+    pub async fn get_storage_balance(&self, token_id: &str, account_id: &str) -> Result<Option<StorageBalance>, Box<dyn Error + Send + Sync>> {
+        Ok(self.call_function(token_id, "storage_balance_of", json!({"account_id": account_id})).await?.decode()?)
+    }
+
     pub async fn get_transaction_status(&self, tx_hash: &str, sender_account_id: &str) -> Result<BroadcastResult, JsonRpcError> {
         let params = json!({
             "tx_hash": tx_hash,
@@ -71,9 +132,7 @@ impl<C: Client + Clone> ChainProvider for NearClient<C> {
     }
 }
 
-impl<C: Client + Clone> ChainStaking for NearClient<C> {}
 impl<C: Client + Clone> ChainPerpetual for NearClient<C> {}
 impl<C: Client + Clone> ChainAddressStatus for NearClient<C> {}
 impl<C: Client + Clone> ChainAccount for NearClient<C> {}
-impl<C: Client + Clone> ChainToken for NearClient<C> {}
 impl<C: Client + Clone> ChainTraits for NearClient<C> {}
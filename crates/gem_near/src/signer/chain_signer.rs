@@ -1,4 +1,4 @@
-use crate::signer::models::NearTransfer;
+use crate::signer::models::NearTransaction;
 use crate::signer::signing;
 use primitives::{ChainSigner, SignerError, SignerInput};
 
@@ -7,33 +7,163 @@ pub struct NearChainSigner;
 
 impl ChainSigner for NearChainSigner {
     fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
-        let transaction = NearTransfer::from_input(input)?;
-        signing::sign_transfer(&transaction, private_key)
+        let transaction = NearTransaction::transfer(input)?;
+        signing::sign_transaction(&transaction, private_key)
+    }
+
+    fn sign_token_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        let transaction = NearTransaction::token_transfer(input)?;
+        signing::sign_transaction(&transaction, private_key)
+    }
+
+    fn sign_stake(&self, input: &SignerInput, private_key: &[u8]) -> Result<Vec<String>, SignerError> {
+        let transaction = NearTransaction::stake(input)?;
+        Ok(vec![signing::sign_transaction(&transaction, private_key)?])
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use primitives::{TransactionFee, TransactionLoadInput};
+    use crate::signer::models::NearAction;
+    use primitives::asset_constants::NEAR_USDT_TOKEN_ID;
+    use primitives::{Asset, AssetType, Chain, Delegation, DelegationValidator, StakeType, TransactionFee, TransactionInputType, TransactionLoadInput, TransactionLoadMetadata};
+
+    const PRIVATE_KEY: &str = "3hoMW1HvnRLSFCLZnvPzWeoGwtdHzke34B2cTHM8rhcbG3TbuLKtShTv3DvyejnXKXKBiV7YPkLeqUHN1ghnqpFv";
+    const BLOCK_HASH: &str = "244ZQ9cgj3CQ6bWBdytfrJMuMQ1jdXLFGnr4HhvtCTnM";
+    const POOL: &str = "figment.poolv1.near";
+
+    fn private_key() -> Vec<u8> {
+        bs58::decode(PRIVATE_KEY).into_vec().unwrap()[..32].to_vec()
+    }
+
+    fn stake_input(stake_type: StakeType, value: &str) -> SignerInput {
+        let mut input = TransactionLoadInput::mock_near("test.near", POOL, value, 1, BLOCK_HASH);
+        input.input_type = TransactionInputType::Stake(Asset::from_chain(Chain::Near), stake_type);
+        SignerInput::new(input, TransactionFee::new_from_fee(0.into()))
+    }
+
+    fn token_input(is_destination_storage_registered: bool, memo: Option<&str>) -> SignerInput {
+        let asset = Asset::mock_with_params(
+            Chain::Near,
+            Some(NEAR_USDT_TOKEN_ID.to_string()),
+            "Tether USD".to_string(),
+            "USDt".to_string(),
+            6,
+            AssetType::TOKEN,
+        );
+        let metadata = TransactionLoadMetadata::Near {
+            sequence: 1,
+            block_hash: BLOCK_HASH.to_string(),
+            is_destination_storage_registered,
+        };
+        let input = TransactionLoadInput::mock_transfer(asset, "test.near", "whatever.near", "1000000", 0, memo, metadata);
+        SignerInput::new(input, TransactionFee::new_from_fee(0.into()))
+    }
+
+    fn pool_delegation() -> Delegation {
+        let mut delegation = Delegation::mock();
+        delegation.base.validator_id = POOL.to_string();
+        delegation
+    }
 
     // Tests taken from https://github.com/trustwallet/wallet-core/blob/master/tests/chains/NEAR/SignerTests.cpp
     #[test]
     fn test_sign_near_transfer() {
-        let private_key = bs58::decode("3hoMW1HvnRLSFCLZnvPzWeoGwtdHzke34B2cTHM8rhcbG3TbuLKtShTv3DvyejnXKXKBiV7YPkLeqUHN1ghnqpFv")
-            .into_vec()
-            .unwrap();
-
         let input = SignerInput::new(
-            TransactionLoadInput::mock_near("test.near", "whatever.near", "1", 1, "244ZQ9cgj3CQ6bWBdytfrJMuMQ1jdXLFGnr4HhvtCTnM"),
+            TransactionLoadInput::mock_near("test.near", "whatever.near", "1", 1, BLOCK_HASH),
             TransactionFee::new_from_fee(0.into()),
         );
 
-        let signed = NearChainSigner.sign_transfer(&input, &private_key[..32]).unwrap();
+        let signed = NearChainSigner.sign_transfer(&input, &private_key()).unwrap();
 
         assert_eq!(
             signed,
             "CQAAAHRlc3QubmVhcgCRez0mjUtY9/7BsVC9aNab4+5dTMOYVeNBU4Rlu3eGDQEAAAAAAAAADQAAAHdoYXRldmVyLm5lYXIPpHP9JpAd8pa+atxMxN800EDvokNSJLaYaRDmMML+9gEAAAADAQAAAAAAAAAAAAAAAAAAAACWmoMzIYbul1Xkg5MlUlgG4Ymj0tK7S0dg6URD6X4cTyLe7vAFmo6XExAO2m4ZFE2n6KDvflObIHCLodjQIb0B"
         );
     }
+
+    #[test]
+    fn test_stake_transactions() {
+        let validator = DelegationValidator::stake(Chain::Near, POOL.to_string(), POOL.to_string(), true, 10.0, 9.0);
+        let cases = [
+            (StakeType::Stake(validator), "deposit_and_stake", "{}", 1_000u128),
+            (StakeType::Unstake(pool_delegation()), "unstake", r#"{"amount":"1000"}"#, 0),
+            (StakeType::Withdraw(pool_delegation()), "withdraw_all", "{}", 0),
+        ];
+
+        for (stake_type, expected_method, expected_args, expected_deposit) in cases {
+            let transaction = NearTransaction::stake(&stake_input(stake_type, "1000")).unwrap();
+            assert_eq!(transaction.receiver_id, POOL);
+            let [NearAction::FunctionCall { method_name, args, gas, deposit }] = transaction.actions.as_slice() else {
+                panic!("expected a single function call");
+            };
+            assert_eq!(method_name, expected_method);
+            assert_eq!(String::from_utf8(args.clone()).unwrap(), expected_args);
+            assert_eq!(*gas, 125_000_000_000_000);
+            assert_eq!(*deposit, expected_deposit);
+        }
+    }
+
+    #[test]
+    fn test_sign_near_stake() {
+        let validator = DelegationValidator::stake(Chain::Near, POOL.to_string(), POOL.to_string(), true, 10.0, 9.0);
+        let signed = NearChainSigner.sign_stake(&stake_input(StakeType::Stake(validator), "1000"), &private_key()).unwrap();
+
+        assert_eq!(signed.len(), 1);
+        let bytes = gem_encoding::decode_base64(&signed[0]).unwrap();
+        assert!(bytes.windows(POOL.len()).any(|window| window == POOL.as_bytes()));
+        assert!(bytes.windows(17).any(|window| window == b"deposit_and_stake"));
+    }
+
+    #[test]
+    fn test_token_transfer_transaction() {
+        let transaction = NearTransaction::token_transfer(&token_input(true, Some("invoice"))).unwrap();
+
+        assert_eq!(transaction.receiver_id, NEAR_USDT_TOKEN_ID);
+        let [NearAction::FunctionCall { method_name, args, gas, deposit }] = transaction.actions.as_slice() else {
+            panic!("expected a single function call");
+        };
+        assert_eq!(method_name, "ft_transfer");
+        assert_eq!(
+            String::from_utf8(args.clone()).unwrap(),
+            r#"{"receiver_id":"whatever.near","amount":"1000000","memo":"invoice"}"#
+        );
+        assert_eq!(*gas, 30_000_000_000_000);
+        assert_eq!(*deposit, 1);
+    }
+
+    #[test]
+    fn test_token_transfer_transaction_storage_deposit() {
+        let transaction = NearTransaction::token_transfer(&token_input(false, None)).unwrap();
+        let methods: Vec<_> = transaction
+            .actions
+            .iter()
+            .map(|action| match action {
+                NearAction::FunctionCall { method_name, args, deposit, .. } => (method_name.as_str(), String::from_utf8(args.clone()).unwrap(), *deposit),
+                NearAction::Transfer { .. } => panic!("expected function calls"),
+            })
+            .collect();
+
+        assert_eq!(
+            methods,
+            vec![
+                (
+                    "storage_deposit",
+                    r#"{"account_id":"whatever.near","registration_only":true}"#.to_string(),
+                    1_250_000_000_000_000_000_000
+                ),
+                ("ft_transfer", r#"{"receiver_id":"whatever.near","amount":"1000000"}"#.to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sign_near_token_transfer() {
+        let signed = NearChainSigner.sign_token_transfer(&token_input(true, None), &private_key()).unwrap();
+        let bytes = gem_encoding::decode_base64(&signed).unwrap();
+
+        assert!(bytes.windows(NEAR_USDT_TOKEN_ID.len()).any(|window| window == NEAR_USDT_TOKEN_ID.as_bytes()));
+        assert!(bytes.windows(11).any(|window| window == b"ft_transfer"));
+    }
 }
@@ -1,15 +1,23 @@
-use primitives::{SignerError, SignerInput};
+use primitives::{SignerError, SignerInput, StakeType};
+use serde_json::json;
 
-pub struct NearTransfer {
+use crate::constants::{STAKING_GAS, STORAGE_DEPOSIT_AMOUNT, STORAGE_DEPOSIT_GAS, TOKEN_TRANSFER_DEPOSIT, TOKEN_TRANSFER_GAS};
+
+pub enum NearAction {
+    FunctionCall { method_name: String, args: Vec<u8>, gas: u64, deposit: u128 },
+    Transfer { deposit: u128 },
+}
+
+pub struct NearTransaction {
     pub signer_id: String,
     pub receiver_id: String,
     pub nonce: u64,
     pub block_hash: [u8; 32],
-    pub deposit: [u8; 16],
+    pub actions: Vec<NearAction>,
 }
 
-impl NearTransfer {
-    pub fn from_input(input: &SignerInput) -> Result<Self, SignerError> {
+impl NearTransaction {
+    pub fn new(input: &SignerInput, receiver_id: &str, actions: Vec<NearAction>) -> Result<Self, SignerError> {
         let block_hash: [u8; 32] = bs58::decode(input.metadata.get_block_hash().map_err(SignerError::from_display)?)
             .into_vec()
             .map_err(|e| SignerError::invalid_input(format!("invalid NEAR block hash: {e}")))?
@@ -18,10 +26,59 @@ impl NearTransfer {
 
         Ok(Self {
             signer_id: input.sender_address.clone(),
-            receiver_id: input.destination_address.clone(),
+            receiver_id: receiver_id.to_string(),
             nonce: input.metadata.get_sequence().map_err(SignerError::from_display)?,
             block_hash,
-            deposit: input.value.parse::<u128>().map_err(|_| SignerError::invalid_input("invalid NEAR amount"))?.to_le_bytes(),
+            actions,
         })
     }
+
+    pub fn transfer(input: &SignerInput) -> Result<Self, SignerError> {
+        let deposit = parse_amount(&input.value)?;
+        Self::new(input, &input.destination_address, vec![NearAction::Transfer { deposit }])
+    }
+
+    pub fn token_transfer(input: &SignerInput) -> Result<Self, SignerError> {
+        let token_id = input.input_type.get_asset().id.get_token_id()?;
+        let mut actions = Vec::new();
+        if !input.metadata.get_is_destination_storage_registered()? {
+            actions.push(NearAction::FunctionCall {
+                method_name: "storage_deposit".to_string(),
+                args: json!({ "account_id": input.destination_address, "registration_only": true }).to_string().into_bytes(),
+                gas: STORAGE_DEPOSIT_GAS,
+                deposit: STORAGE_DEPOSIT_AMOUNT,
+            });
+        }
+        let mut args = json!({ "receiver_id": input.destination_address, "amount": parse_amount(&input.value)?.to_string() });
+        if let Some(memo) = &input.memo {
+            args["memo"] = json!(memo);
+        }
+        actions.push(NearAction::FunctionCall {
+            method_name: "ft_transfer".to_string(),
+            args: args.to_string().into_bytes(),
+            gas: TOKEN_TRANSFER_GAS,
+            deposit: TOKEN_TRANSFER_DEPOSIT,
+        });
+        Self::new(input, token_id, actions)
+    }
+
+    pub fn stake(input: &SignerInput) -> Result<Self, SignerError> {
+        let (pool_id, method_name, args, deposit) = match input.input_type.get_stake_type()? {
+            StakeType::Stake(validator) => (&validator.id, "deposit_and_stake", json!({}), parse_amount(&input.value)?),
+            StakeType::Unstake(delegation) => (&delegation.base.validator_id, "unstake", json!({ "amount": input.value }), 0),
+            StakeType::Withdraw(delegation) => (&delegation.base.validator_id, "withdraw_all", json!({}), 0),
+            _ => return SignerError::invalid_input_err("unsupported NEAR stake type"),
+        };
+        let action = NearAction::FunctionCall {
+            method_name: method_name.to_string(),
+            args: args.to_string().into_bytes(),
+            gas: STAKING_GAS,
+            deposit,
+        };
+        Self::new(input, pool_id, vec![action])
+    }
+}
+
+pub fn parse_amount(value: &str) -> Result<u128, SignerError> {
+    value.parse::<u128>().map_err(|_| SignerError::invalid_input("invalid NEAR amount"))
 }
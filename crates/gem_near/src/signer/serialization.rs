@@ -1,24 +1,75 @@
-use super::models::NearTransfer;
+use super::models::{NearAction, NearTransaction};
 use signer::ED25519_KEY_TYPE;
 
+const FUNCTION_CALL_ACTION: u8 = 2;
 const TRANSFER_ACTION: u8 = 3;
 
-pub fn encode_transfer(transfer: &NearTransfer, public_key: &[u8; 32]) -> Vec<u8> {
+pub fn encode_transaction(transaction: &NearTransaction, public_key: &[u8; 32]) -> Vec<u8> {
     let mut buf = Vec::with_capacity(128);
-    write_string(&mut buf, &transfer.signer_id);
+    write_string(&mut buf, &transaction.signer_id);
     buf.push(ED25519_KEY_TYPE);
     buf.extend_from_slice(public_key);
-    buf.extend_from_slice(&transfer.nonce.to_le_bytes());
-    write_string(&mut buf, &transfer.receiver_id);
-    buf.extend_from_slice(&transfer.block_hash);
-    // 1 action
-    buf.extend_from_slice(&1u32.to_le_bytes());
-    buf.push(TRANSFER_ACTION);
-    buf.extend_from_slice(&transfer.deposit);
+    buf.extend_from_slice(&transaction.nonce.to_le_bytes());
+    write_string(&mut buf, &transaction.receiver_id);
+    buf.extend_from_slice(&transaction.block_hash);
+    buf.extend_from_slice(&(transaction.actions.len() as u32).to_le_bytes());
+    for action in &transaction.actions {
+        write_action(&mut buf, action);
+    }
     buf
 }
 
+fn write_action(buf: &mut Vec<u8>, action: &NearAction) {
+    match action {
+        NearAction::FunctionCall { method_name, args, gas, deposit } => {
+            buf.push(FUNCTION_CALL_ACTION);
+            write_string(buf, method_name);
+            write_bytes(buf, args);
+            buf.extend_from_slice(&gas.to_le_bytes());
+            buf.extend_from_slice(&deposit.to_le_bytes());
+        }
+        NearAction::Transfer { deposit } => {
+            buf.push(TRANSFER_ACTION);
+            buf.extend_from_slice(&deposit.to_le_bytes());
+        }
+    }
+}
+
 fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_bytes(buf, value.as_bytes());
+}
+
+fn write_bytes(buf: &mut Vec<u8>, value: &[u8]) {
     buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
-    buf.extend_from_slice(value.as_bytes());
+    buf.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_function_call() {
+        let transaction = NearTransaction {
+            signer_id: "a".to_string(),
+            receiver_id: "b".to_string(),
+            nonce: 1,
+            block_hash: [0u8; 32],
+            actions: vec![NearAction::FunctionCall {
+                method_name: "m".to_string(),
+                args: b"{}".to_vec(),
+                gas: 2,
+                deposit: 3,
+            }],
+        };
+        let encoded = encode_transaction(&transaction, &[0u8; 32]);
+        let action = &encoded[encoded.len() - 36..];
+
+        assert_eq!(&encoded[encoded.len() - 40..encoded.len() - 36], &1u32.to_le_bytes());
+        assert_eq!(action[0], FUNCTION_CALL_ACTION);
+        assert_eq!(&action[1..6], &[1, 0, 0, 0, b'm']);
+        assert_eq!(&action[6..12], &[2, 0, 0, 0, b'{', b'}']);
+        assert_eq!(&action[12..20], &2u64.to_le_bytes());
+        assert_eq!(&action[20..36], &3u128.to_le_bytes());
+    }
 }
@@ -1,2 +1,24 @@
 pub const TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE: &str = "transferKeepAlive";
 pub const TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH: &str = "transferAllowDeath";
+
+pub const PALLET_BALANCES: &str = "balances";
+pub const PALLET_STAKING: &str = "staking";
+pub const PALLET_UTILITY: &str = "utility";
+
+pub const CALL_BOND: &str = "bond";
+pub const CALL_NOMINATE: &str = "nominate";
+pub const CALL_CHILL: &str = "chill";
+pub const CALL_UNBOND: &str = "unbond";
+pub const CALL_WITHDRAW_UNBONDED: &str = "withdrawUnbonded";
+pub const CALL_BATCH_ALL: &str = "batchAll";
+
+pub const SIGNER_CALLS: &[(&str, &str)] = &[
+    (PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE),
+    (PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH),
+    (PALLET_STAKING, CALL_BOND),
+    (PALLET_STAKING, CALL_NOMINATE),
+    (PALLET_STAKING, CALL_CHILL),
+    (PALLET_STAKING, CALL_UNBOND),
+    (PALLET_STAKING, CALL_WITHDRAW_UNBONDED),
+    (PALLET_UTILITY, CALL_BATCH_ALL),
+];
@@ -6,6 +6,8 @@ pub mod provider;
 
 pub mod constants;
 pub mod models;
+#[cfg(feature = "signer")]
+pub mod signer;
 
 #[cfg(feature = "rpc")]
 pub use rpc::client::PolkadotClient;
@@ -1,11 +1,13 @@
 pub mod account;
 pub mod block;
 pub mod fee;
+pub mod pallet;
 pub mod rpc;
 pub mod transaction;
 
 pub use account::*;
 pub use block::*;
 pub use fee::*;
+pub use pallet::*;
 pub use rpc::*;
 pub use transaction::*;
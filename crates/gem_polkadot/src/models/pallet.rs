@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use serde_serializers::deserialize_u64_from_str_or_int;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolkadotPalletDispatchables {
+    pub pallet: String,
+    #[serde(deserialize_with = "deserialize_u64_from_str_or_int")]
+    pub pallet_index: u64,
+    pub items: Vec<PolkadotDispatchable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolkadotDispatchable {
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_u64_from_str_or_int")]
+    pub index: u64,
+}
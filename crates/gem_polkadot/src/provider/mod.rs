@@ -1,6 +1,7 @@
 pub mod balances;
 pub mod balances_mapper;
 pub mod preload;
+pub mod preload_mapper;
 pub mod request_classifier;
 pub mod staking;
 pub mod state;
@@ -8,6 +8,7 @@ use primitives::{
     FeePriority, FeeRate, GasPriceType, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata, TransactionPreloadInput,
 };
 
+use crate::provider::preload_mapper::{map_call_indices, map_pallets};
 use crate::rpc::client::PolkadotClient;
 
 #[async_trait]
@@ -15,6 +16,10 @@ impl<C: Client> ChainTransactionLoad for PolkadotClient<C> {
     async fn get_transaction_preload(&self, input: TransactionPreloadInput) -> Result<TransactionLoadMetadata, Box<dyn Error + Sync + Send>> {
         let material = self.get_transaction_material().await?;
         let sender_balance = self.get_balance(input.sender_address).await?;
+        let mut pallets = vec![];
+        for pallet in map_pallets(&input.input_type) {
+            pallets.push(self.get_pallet_dispatchables(pallet).await?);
+        }
 
         Ok(TransactionLoadMetadata::Polkadot {
             sequence: sender_balance.nonce,
@@ -24,6 +29,7 @@ impl<C: Client> ChainTransactionLoad for PolkadotClient<C> {
             spec_version: material.spec_version,
             transaction_version: material.tx_version,
             period: 64,
+            call_indices: map_call_indices(pallets)?,
         })
     }
 
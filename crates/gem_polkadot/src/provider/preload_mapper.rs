@@ -0,0 +1,89 @@
+use std::error::Error;
+
+use primitives::{PolkadotCallIndex, TransactionInputType};
+
+use crate::constants::{PALLET_BALANCES, PALLET_STAKING, PALLET_UTILITY, SIGNER_CALLS};
+use crate::models::pallet::PolkadotPalletDispatchables;
+
+pub fn map_pallets(input_type: &TransactionInputType) -> Vec<&'static str> {
+    match input_type {
+        TransactionInputType::Transfer(_) => vec![PALLET_BALANCES],
+        TransactionInputType::Stake(_, _) => vec![PALLET_STAKING, PALLET_UTILITY],
+        _ => vec![],
+    }
+}
+
+pub fn map_call_indices(pallets: Vec<PolkadotPalletDispatchables>) -> Result<Vec<PolkadotCallIndex>, Box<dyn Error + Sync + Send>> {
+    let mut call_indices = vec![];
+    for pallet in pallets {
+        let pallet_index = u8::try_from(pallet.pallet_index)?;
+        for item in pallet.items {
+            let Some((pallet_name, call)) = SIGNER_CALLS.iter().find(|(name, call)| name.eq_ignore_ascii_case(&pallet.pallet) && *call == item.name) else {
+                continue;
+            };
+            call_indices.push(PolkadotCallIndex {
+                pallet: pallet_name.to_string(),
+                call: call.to_string(),
+                pallet_index,
+                call_index: u8::try_from(item.index)?,
+            });
+        }
+    }
+    Ok(call_indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::pallet::PolkadotDispatchable;
+
+    #[test]
+    fn test_map_call_indices() {
+        let pallets: Vec<PolkadotPalletDispatchables> = vec![
+            serde_json::from_value(serde_json::json!({
+                "pallet": "balances",
+                "palletIndex": "10",
+                "items": [
+                    { "name": "transferAllowDeath", "index": "0" },
+                    { "name": "forceTransfer", "index": "2" },
+                    { "name": "transferKeepAlive", "index": "3" }
+                ]
+            }))
+            .unwrap(),
+        ];
+
+        let result = map_call_indices(pallets).unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                PolkadotCallIndex {
+                    pallet: "balances".into(),
+                    call: "transferAllowDeath".into(),
+                    pallet_index: 10,
+                    call_index: 0,
+                },
+                PolkadotCallIndex {
+                    pallet: "balances".into(),
+                    call: "transferKeepAlive".into(),
+                    pallet_index: 10,
+                    call_index: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_call_indices_rejects_out_of_range_index() {
+        let pallets = vec![PolkadotPalletDispatchables {
+            pallet: "utility".into(),
+            pallet_index: 256,
+            items: vec![PolkadotDispatchable {
+                name: "batchAll".into(),
+                index: 2,
+            }],
+        }];
+
+        assert!(map_call_indices(pallets).is_err());
+    }
+}
@@ -7,6 +7,7 @@ use primitives::Chain;
 use crate::models::account::PolkadotAccountBalance;
 use crate::models::block::PolkadotNodeVersion;
 use crate::models::fee::PolkadotEstimateFee;
+use crate::models::pallet::PolkadotPalletDispatchables;
 use crate::models::rpc::{Block, BlockHeader};
 use crate::models::transaction::{PolkadotTransactionBroadcastResponse, PolkadotTransactionMaterial};
 
@@ -53,6 +54,10 @@ impl<C: Client> PolkadotClient<C> {
         Ok(self.client.get(&format!("/blocks/{}/header", block)).await?)
     }
 
+    pub async fn get_pallet_dispatchables(&self, pallet: &str) -> Result<PolkadotPalletDispatchables, Box<dyn Error + Send + Sync>> {
+        Ok(self.client.get(&format!("/pallets/{}/dispatchables", pallet)).await?)
+    }
+
     pub async fn get_block(&self, block_number: i64) -> Result<Block, Box<dyn Error + Send + Sync>> {
         Ok(self.client.get(&format!("/blocks/{}", block_number)).await?)
     }
@@ -0,0 +1,40 @@
+use gem_hash::blake2::blake2b_512;
+use primitives::SignerError;
+
+pub type AccountId = [u8; 32];
+
+const SS58_CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+const SS58_CHECKSUM_LENGTH: usize = 2;
+
+pub fn decode_address(address: &str) -> Result<AccountId, SignerError> {
+    let data = bs58::decode(address).into_vec().map_err(|_| SignerError::invalid_input("invalid Polkadot address"))?;
+    let prefix_length = match data.first() {
+        Some(0..=63) => 1,
+        Some(64..=127) => 2,
+        _ => return SignerError::invalid_input_err("invalid Polkadot address"),
+    };
+    if data.len() != prefix_length + 32 + SS58_CHECKSUM_LENGTH {
+        return SignerError::invalid_input_err("invalid Polkadot address");
+    }
+
+    let (payload, checksum) = data.split_at(prefix_length + 32);
+    if blake2b_512(&[SS58_CHECKSUM_PREFIX, payload].concat())[..SS58_CHECKSUM_LENGTH] != *checksum {
+        return SignerError::invalid_input_err("invalid Polkadot address checksum");
+    }
+    Ok(payload[prefix_length..].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_address() {
+        let alice = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+        assert_eq!(hex::encode(decode_address("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap()), alice);
+        assert_eq!(hex::encode(decode_address("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap()), alice);
+        assert!(decode_address("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6").is_err());
+        assert!(decode_address("0x1234").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use primitives::{PolkadotCallIndex, SignerError};
+
+use super::address::AccountId;
+use super::scale::encode_compact;
+use crate::constants::{
+    CALL_BATCH_ALL, CALL_BOND, CALL_CHILL, CALL_NOMINATE, CALL_UNBOND, CALL_WITHDRAW_UNBONDED, PALLET_BALANCES, PALLET_STAKING, PALLET_UTILITY,
+    TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE,
+};
+
+const MULTI_ADDRESS_ID: u8 = 0x00;
+const REWARD_DESTINATION_STAKED: u8 = 0x00;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolkadotCall {
+    Transfer { destination: AccountId, value: u128, keep_alive: bool },
+    Bond { value: u128 },
+    Nominate { targets: Vec<AccountId> },
+    Chill,
+    Unbond { value: u128 },
+    WithdrawUnbonded,
+    BatchAll(Vec<PolkadotCall>),
+}
+
+impl PolkadotCall {
+    fn name(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Transfer { keep_alive: true, .. } => (PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE),
+            Self::Transfer { keep_alive: false, .. } => (PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH),
+            Self::Bond { .. } => (PALLET_STAKING, CALL_BOND),
+            Self::Nominate { .. } => (PALLET_STAKING, CALL_NOMINATE),
+            Self::Chill => (PALLET_STAKING, CALL_CHILL),
+            Self::Unbond { .. } => (PALLET_STAKING, CALL_UNBOND),
+            Self::WithdrawUnbonded => (PALLET_STAKING, CALL_WITHDRAW_UNBONDED),
+            Self::BatchAll(_) => (PALLET_UTILITY, CALL_BATCH_ALL),
+        }
+    }
+
+    pub fn encode(&self, call_indices: &[PolkadotCallIndex]) -> Result<Vec<u8>, SignerError> {
+        let (pallet, call) = self.name();
+        let index = call_indices
+            .iter()
+            .find(|index| index.pallet == pallet && index.call == call)
+            .ok_or_else(|| SignerError::invalid_input(format!("missing call index for {pallet}.{call}")))?;
+
+        let mut data = vec![index.pallet_index, index.call_index];
+        match self {
+            Self::Transfer { destination, value, .. } => {
+                data.push(MULTI_ADDRESS_ID);
+                data.extend_from_slice(destination);
+                data.extend(encode_compact(*value));
+            }
+            Self::Bond { value } => {
+                data.extend(encode_compact(*value));
+                data.push(REWARD_DESTINATION_STAKED);
+            }
+            Self::Nominate { targets } => {
+                data.extend(encode_compact(targets.len() as u128));
+                for target in targets {
+                    data.push(MULTI_ADDRESS_ID);
+                    data.extend_from_slice(target);
+                }
+            }
+            Self::Chill => {}
+            Self::Unbond { value } => data.extend(encode_compact(*value)),
+            // num_slashing_spans: zero for nominators that were never slashed
+            Self::WithdrawUnbonded => data.extend(0u32.to_le_bytes()),
+            Self::BatchAll(calls) => {
+                data.extend(encode_compact(calls.len() as u128));
+                for call in calls {
+                    data.extend(call.encode(call_indices)?);
+                }
+            }
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call_index(pallet: &str, call: &str, pallet_index: u8, call_index: u8) -> PolkadotCallIndex {
+        PolkadotCallIndex {
+            pallet: pallet.into(),
+            call: call.into(),
+            pallet_index,
+            call_index,
+        }
+    }
+
+    #[test]
+    fn test_encode_transfer() {
+        let call_indices = vec![call_index(PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE, 10, 3)];
+        let call = PolkadotCall::Transfer {
+            destination: [0x11; 32],
+            value: 10_000_000_000,
+            keep_alive: true,
+        };
+
+        assert_eq!(hex::encode(call.encode(&call_indices).unwrap()), format!("0a0300{}0700e40b5402", "11".repeat(32)));
+
+        let call = PolkadotCall::Transfer {
+            destination: [0x11; 32],
+            value: 1,
+            keep_alive: false,
+        };
+        assert!(call.encode(&call_indices).is_err());
+    }
+
+    #[test]
+    fn test_encode_staking_batch() {
+        let call_indices = vec![
+            call_index(PALLET_STAKING, CALL_BOND, 89, 0),
+            call_index(PALLET_STAKING, CALL_NOMINATE, 89, 5),
+            call_index(PALLET_UTILITY, CALL_BATCH_ALL, 40, 2),
+        ];
+        let call = PolkadotCall::BatchAll(vec![PolkadotCall::Bond { value: 64 }, PolkadotCall::Nominate { targets: vec![[0x22; 32]] }]);
+
+        assert_eq!(hex::encode(call.encode(&call_indices).unwrap()), format!("280208590001010059050400{}", "22".repeat(32)));
+    }
+}
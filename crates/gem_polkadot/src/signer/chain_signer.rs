@@ -0,0 +1,211 @@
+use primitives::{ChainSigner, SignerError, SignerInput, StakeType, TransactionLoadMetadata};
+use signer::InvalidInput;
+
+use super::address::decode_address;
+use super::call::PolkadotCall;
+use super::extrinsic::Extrinsic;
+use super::signing::sign_extrinsic;
+
+#[derive(Default)]
+pub struct PolkadotChainSigner;
+
+impl ChainSigner for PolkadotChainSigner {
+    fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        // Sending the whole balance must be allowed to reap the account
+        let call = PolkadotCall::Transfer {
+            destination: decode_address(&input.destination_address)?,
+            value: parse_value(&input.value)?,
+            keep_alive: !input.is_max_value,
+        };
+        sign_call(input, &call, private_key)
+    }
+
+    fn sign_stake(&self, input: &SignerInput, private_key: &[u8]) -> Result<Vec<String>, SignerError> {
+        let call = match input.input_type.get_stake_type().map_err(SignerError::invalid_input)? {
+            StakeType::Stake(validator) => PolkadotCall::BatchAll(vec![
+                PolkadotCall::Bond {
+                    value: parse_value(&input.value)?,
+                },
+                PolkadotCall::Nominate {
+                    targets: vec![decode_address(&validator.id)?],
+                },
+            ]),
+            StakeType::Redelegate(data) => PolkadotCall::Nominate {
+                targets: vec![decode_address(&data.to_validator.id)?],
+            },
+            StakeType::Unstake(_) => PolkadotCall::BatchAll(vec![
+                PolkadotCall::Chill,
+                PolkadotCall::Unbond {
+                    value: parse_value(&input.value)?,
+                },
+            ]),
+            StakeType::Withdraw(_) => PolkadotCall::WithdrawUnbonded,
+            StakeType::Rewards(_) | StakeType::Freeze(_) | StakeType::Unfreeze(_) => return SignerError::invalid_input_err("unsupported Polkadot stake type"),
+        };
+        Ok(vec![sign_call(input, &call, private_key)?])
+    }
+}
+
+fn sign_call(input: &SignerInput, call: &PolkadotCall, private_key: &[u8]) -> Result<String, SignerError> {
+    let TransactionLoadMetadata::Polkadot { call_indices, .. } = &input.metadata else {
+        return SignerError::invalid_input_err("expected Polkadot metadata");
+    };
+    let extrinsic = Extrinsic::new(input, call.encode(call_indices)?)?;
+    sign_extrinsic(&extrinsic, &input.sender_address, private_key)
+}
+
+fn parse_value(value: &str) -> Result<u128, SignerError> {
+    value.parse::<u128>().ok().invalid_input("invalid Polkadot amount")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        CALL_BATCH_ALL, CALL_BOND, CALL_CHILL, CALL_NOMINATE, CALL_UNBOND, CALL_WITHDRAW_UNBONDED, PALLET_BALANCES, PALLET_STAKING, PALLET_UTILITY,
+        TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE,
+    };
+    use crate::signer::scale::encode_compact;
+    use primitives::{Asset, Chain, DelegationValidator, PolkadotCallIndex, TransactionFee, TransactionInputType, TransactionLoadInput};
+    use signer::{Ed25519KeyPair, SR25519_SIGNING_CONTEXT, verify_sr25519};
+
+    // Substrate `//Alice` sr25519 dev account
+    const ALICE_PRIVATE_KEY: &str = "e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a";
+    const ALICE: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+    const BOB: &str = "14E5nqKAp3oAJcmzgZhUD2RcptBeUBScxKHgJKU4HPNcKVf3";
+    const GENESIS_HASH: &str = "0x68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f";
+    const BLOCK_HASH: &str = "0x5d2143bb808626d63ad7e1cda70fa8697059d670a992e82cd440fbb95ea40351";
+
+    fn metadata() -> TransactionLoadMetadata {
+        let call_index = |pallet: &str, call: &str, pallet_index, call_index| PolkadotCallIndex {
+            pallet: pallet.into(),
+            call: call.into(),
+            pallet_index,
+            call_index,
+        };
+        TransactionLoadMetadata::Polkadot {
+            sequence: 3,
+            genesis_hash: GENESIS_HASH.into(),
+            block_hash: BLOCK_HASH.into(),
+            block_number: 9_000_042,
+            spec_version: 1_006_000,
+            transaction_version: 15,
+            period: 64,
+            call_indices: vec![
+                call_index(PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_ALLOW_DEATH, 10, 0),
+                call_index(PALLET_BALANCES, TRANSACTION_TYPE_TRANSFER_KEEP_ALIVE, 10, 3),
+                call_index(PALLET_STAKING, CALL_BOND, 89, 0),
+                call_index(PALLET_STAKING, CALL_UNBOND, 89, 2),
+                call_index(PALLET_STAKING, CALL_WITHDRAW_UNBONDED, 89, 3),
+                call_index(PALLET_STAKING, CALL_NOMINATE, 89, 5),
+                call_index(PALLET_STAKING, CALL_CHILL, 89, 6),
+                call_index(PALLET_UTILITY, CALL_BATCH_ALL, 40, 2),
+            ],
+        }
+    }
+
+    fn transfer_input(sender: &str, is_max_value: bool) -> SignerInput {
+        let mut input = TransactionLoadInput::mock_transfer(Asset::from_chain(Chain::Polkadot), sender, BOB, "10000000000", 1, None, metadata());
+        input.is_max_value = is_max_value;
+        SignerInput::new(input, TransactionFee::default())
+    }
+
+    fn decode_signed(signed: &str) -> Vec<u8> {
+        let bytes = hex::decode(signed.trim_start_matches("0x")).unwrap();
+        let prefix = encode_compact((bytes.len() - 2) as u128);
+        assert_eq!(bytes[..2], prefix[..]);
+        bytes[2..].to_vec()
+    }
+
+    #[test]
+    fn test_sign_transfer_sr25519() {
+        let key = hex::decode(ALICE_PRIVATE_KEY).unwrap();
+        let input = transfer_input(ALICE, false);
+        let signed = PolkadotChainSigner.sign_transfer(&input, &key).unwrap();
+        let body = decode_signed(&signed);
+
+        let alice = decode_address(ALICE).unwrap();
+        let call = [&[0x0a, 0x03, 0x00][..], &decode_address(BOB).unwrap(), &encode_compact(10_000_000_000)].concat();
+        // era (64, 9000042), nonce 3, tip 0, no fee asset, metadata hash disabled
+        let extra = hex::decode("a5020c000000").unwrap();
+
+        assert_eq!(body[..2], [0x84, 0x00]);
+        assert_eq!(body[2..34], alice);
+        assert_eq!(body[34], 0x01);
+        assert_eq!(body[99..105], extra[..]);
+        assert_eq!(body[105..], call[..]);
+
+        let extrinsic = Extrinsic::new(&input, call).unwrap();
+        assert!(verify_sr25519(SR25519_SIGNING_CONTEXT, &extrinsic.signing_payload(), &body[35..99], &alice));
+        assert_eq!(
+            hex::encode(&extrinsic.signing_payload()[extrinsic.call.len() + extra.len()..]),
+            format!("{}0f000000{}{}00", hex::encode(1_006_000u32.to_le_bytes()), &GENESIS_HASH[2..], &BLOCK_HASH[2..])
+        );
+    }
+
+    #[test]
+    fn test_sign_transfer_ed25519_max_value() {
+        let key = hex::decode(ALICE_PRIVATE_KEY).unwrap();
+        let key_pair = Ed25519KeyPair::from_private_key(&key).unwrap();
+        let sender = bs58_address(&key_pair.public_key_bytes);
+
+        let input = transfer_input(&sender, true);
+        let body = decode_signed(&PolkadotChainSigner.sign_transfer(&input, &key).unwrap());
+
+        let call = [&[0x0a, 0x00, 0x00][..], &decode_address(BOB).unwrap(), &encode_compact(10_000_000_000)].concat();
+        let extrinsic = Extrinsic::new(&input, call.clone()).unwrap();
+
+        assert_eq!(body[34], 0x00);
+        assert_eq!(body[35..99], key_pair.sign(&extrinsic.signing_payload()));
+        assert_eq!(body[105..], call[..]);
+    }
+
+    #[test]
+    fn test_sign_stake() {
+        let key = hex::decode(ALICE_PRIVATE_KEY).unwrap();
+        let validator = DelegationValidator {
+            id: BOB.into(),
+            ..DelegationValidator::mock()
+        };
+        let mut input = transfer_input(ALICE, false);
+        input.input.input_type = TransactionInputType::Stake(Asset::from_chain(Chain::Polkadot), StakeType::Stake(validator));
+
+        let signed = PolkadotChainSigner.sign_stake(&input, &key).unwrap();
+        assert_eq!(signed.len(), 1);
+
+        let body = decode_signed(&signed[0]);
+        let call = [
+            &[0x28, 0x02, 0x08, 0x59, 0x00][..],
+            &encode_compact(10_000_000_000),
+            &[0x00, 0x59, 0x05, 0x04, 0x00],
+            &decode_address(BOB).unwrap(),
+        ]
+        .concat();
+        assert_eq!(body[105..], call[..]);
+    }
+
+    #[test]
+    fn test_sign_rejects_wrong_key_and_missing_call_index() {
+        let key = hex::decode(ALICE_PRIVATE_KEY).unwrap();
+        assert!(PolkadotChainSigner.sign_transfer(&transfer_input(BOB, false), &key).is_err());
+
+        let mut input = transfer_input(ALICE, false);
+        input.input.metadata = TransactionLoadMetadata::Polkadot {
+            sequence: 0,
+            genesis_hash: GENESIS_HASH.into(),
+            block_hash: BLOCK_HASH.into(),
+            block_number: 0,
+            spec_version: 1,
+            transaction_version: 1,
+            period: 64,
+            call_indices: vec![],
+        };
+        assert!(PolkadotChainSigner.sign_transfer(&input, &key).is_err());
+    }
+
+    fn bs58_address(public_key: &[u8; 32]) -> String {
+        let payload = [&[0u8][..], public_key].concat();
+        let checksum = gem_hash::blake2::blake2b_512(&[&b"SS58PRE"[..], &payload].concat());
+        bs58::encode([&payload[..], &checksum[..2]].concat()).into_string()
+    }
+}
@@ -0,0 +1,132 @@
+use gem_hash::blake2::blake2b_256;
+use primitives::{SignerError, SignerInput, TransactionLoadMetadata};
+use signer::InvalidInput;
+
+use super::address::AccountId;
+use super::scale::encode_compact;
+
+const EXTRINSIC_VERSION_SIGNED: u8 = 0x84;
+const MULTI_ADDRESS_ID: u8 = 0x00;
+const MAX_PAYLOAD_LENGTH: usize = 256;
+
+// ChargeAssetTxPayment with the native asset and CheckMetadataHash disabled
+const ASSET_ID_NONE: u8 = 0x00;
+const METADATA_HASH_MODE_DISABLED: u8 = 0x00;
+const METADATA_HASH_NONE: u8 = 0x00;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureType {
+    Ed25519 = 0x00,
+    Sr25519 = 0x01,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extrinsic {
+    pub call: Vec<u8>,
+    pub era: [u8; 2],
+    pub nonce: u64,
+    pub tip: u128,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    pub genesis_hash: [u8; 32],
+    pub block_hash: [u8; 32],
+}
+
+impl Extrinsic {
+    pub fn new(input: &SignerInput, call: Vec<u8>) -> Result<Self, SignerError> {
+        let TransactionLoadMetadata::Polkadot {
+            sequence,
+            genesis_hash,
+            block_hash,
+            block_number,
+            spec_version,
+            transaction_version,
+            period,
+            ..
+        } = &input.metadata
+        else {
+            return SignerError::invalid_input_err("expected Polkadot metadata");
+        };
+
+        Ok(Self {
+            call,
+            era: encode_mortal_era(*period, *block_number),
+            nonce: *sequence,
+            tip: 0,
+            spec_version: u32::try_from(*spec_version).ok().invalid_input("invalid Polkadot spec version")?,
+            transaction_version: u32::try_from(*transaction_version).ok().invalid_input("invalid Polkadot transaction version")?,
+            genesis_hash: decode_hash(genesis_hash)?,
+            block_hash: decode_hash(block_hash)?,
+        })
+    }
+
+    fn extra(&self) -> Vec<u8> {
+        [
+            &self.era[..],
+            &encode_compact(self.nonce as u128),
+            &encode_compact(self.tip),
+            &[ASSET_ID_NONE, METADATA_HASH_MODE_DISABLED],
+        ]
+        .concat()
+    }
+
+    fn additional_signed(&self) -> Vec<u8> {
+        [
+            &self.spec_version.to_le_bytes()[..],
+            &self.transaction_version.to_le_bytes(),
+            &self.genesis_hash,
+            &self.block_hash,
+            &[METADATA_HASH_NONE],
+        ]
+        .concat()
+    }
+
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let payload = [&self.call[..], &self.extra(), &self.additional_signed()].concat();
+        if payload.len() > MAX_PAYLOAD_LENGTH {
+            return blake2b_256(&payload).to_vec();
+        }
+        payload
+    }
+
+    pub fn encode_signed(&self, signer: &AccountId, signature_type: SignatureType, signature: &[u8]) -> Vec<u8> {
+        let body = [
+            &[EXTRINSIC_VERSION_SIGNED, MULTI_ADDRESS_ID][..],
+            signer,
+            &[signature_type as u8],
+            signature,
+            &self.extra(),
+            &self.call,
+        ]
+        .concat();
+        [encode_compact(body.len() as u128), body].concat()
+    }
+}
+
+pub fn encode_mortal_era(period: u64, block_number: u64) -> [u8; 2] {
+    let period = period.checked_next_power_of_two().unwrap_or(1 << 16).clamp(4, 1 << 16);
+    let quantize_factor = (period >> 12).max(1);
+    let phase = block_number % period / quantize_factor * quantize_factor;
+    let encoded = (period.trailing_zeros() - 1).clamp(1, 15) as u64 | (phase / quantize_factor) << 4;
+    (encoded as u16).to_le_bytes()
+}
+
+fn decode_hash(value: &str) -> Result<[u8; 32], SignerError> {
+    hex::decode(value.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .invalid_input("invalid Polkadot block hash")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_mortal_era() {
+        assert_eq!(encode_mortal_era(64, 42), [0xa5, 0x02]);
+        assert_eq!(encode_mortal_era(64, 42 + 64 * 1000), [0xa5, 0x02]);
+        assert_eq!(encode_mortal_era(32768, 20000), [0x4e, 0x9c]);
+        assert_eq!(encode_mortal_era(50, 0), [0x05, 0x00]);
+    }
+}
@@ -0,0 +1,8 @@
+mod address;
+mod call;
+mod chain_signer;
+mod extrinsic;
+mod scale;
+mod signing;
+
+pub use chain_signer::PolkadotChainSigner;
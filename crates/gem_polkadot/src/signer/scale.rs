@@ -0,0 +1,31 @@
+pub fn encode_compact(value: u128) -> Vec<u8> {
+    match value {
+        0..=0x3f => vec![(value as u8) << 2],
+        0x40..=0x3fff => ((value as u16) << 2 | 0b01).to_le_bytes().to_vec(),
+        0x4000..=0x3fff_ffff => ((value as u32) << 2 | 0b10).to_le_bytes().to_vec(),
+        _ => {
+            let bytes = value.to_le_bytes();
+            let length = bytes.len() - value.leading_zeros() as usize / 8;
+            [&[((length - 4) as u8) << 2 | 0b11][..], &bytes[..length]].concat()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_compact() {
+        assert_eq!(hex::encode(encode_compact(0)), "00");
+        assert_eq!(hex::encode(encode_compact(1)), "04");
+        assert_eq!(hex::encode(encode_compact(63)), "fc");
+        assert_eq!(hex::encode(encode_compact(64)), "0101");
+        assert_eq!(hex::encode(encode_compact(16383)), "fdff");
+        assert_eq!(hex::encode(encode_compact(16384)), "02000100");
+        assert_eq!(hex::encode(encode_compact(1073741823)), "feffffff");
+        assert_eq!(hex::encode(encode_compact(1073741824)), "0300000040");
+        assert_eq!(hex::encode(encode_compact(10_000_000_000)), "0700e40b5402");
+        assert_eq!(hex::encode(encode_compact(u128::MAX)), format!("33{}", "ff".repeat(16)));
+    }
+}
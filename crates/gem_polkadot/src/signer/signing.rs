@@ -0,0 +1,37 @@
+use primitives::SignerError;
+use signer::{Ed25519KeyPair, SR25519_SIGNING_CONTEXT, Sr25519KeyPair};
+
+use super::address::{AccountId, decode_address};
+use super::extrinsic::{Extrinsic, SignatureType};
+
+enum SigningKey {
+    Ed25519(Box<Ed25519KeyPair>),
+    Sr25519(Box<Sr25519KeyPair>),
+}
+
+impl SigningKey {
+    fn for_account(private_key: &[u8], account: &AccountId) -> Result<Self, SignerError> {
+        let key_pair = Ed25519KeyPair::from_private_key(private_key)?;
+        if key_pair.public_key_bytes == *account {
+            return Ok(Self::Ed25519(Box::new(key_pair)));
+        }
+        let key_pair = Sr25519KeyPair::from_private_key(private_key)?;
+        if key_pair.public_key_bytes == *account {
+            return Ok(Self::Sr25519(Box::new(key_pair)));
+        }
+        SignerError::invalid_input_err("Private key does not match sender address")
+    }
+
+    fn sign(&self, payload: &[u8]) -> (SignatureType, [u8; 64]) {
+        match self {
+            Self::Ed25519(key_pair) => (SignatureType::Ed25519, key_pair.sign(payload)),
+            Self::Sr25519(key_pair) => (SignatureType::Sr25519, key_pair.sign(SR25519_SIGNING_CONTEXT, payload)),
+        }
+    }
+}
+
+pub(crate) fn sign_extrinsic(extrinsic: &Extrinsic, sender_address: &str, private_key: &[u8]) -> Result<String, SignerError> {
+    let sender = decode_address(sender_address)?;
+    let (signature_type, signature) = SigningKey::for_account(private_key, &sender)?.sign(&extrinsic.signing_payload());
+    Ok(format!("0x{}", hex::encode(extrinsic.encode_signed(&sender, signature_type, &signature))))
+}
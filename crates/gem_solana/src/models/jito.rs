@@ -1,3 +1,19 @@
+use serde::Deserialize;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JitoTipFloor {
+    pub landed_tips_50th_percentile: f64,
+    pub landed_tips_75th_percentile: f64,
+}
+
+impl JitoTipFloor {
+    pub fn tip_lamports(&self) -> u64 {
+        (self.landed_tips_75th_percentile * LAMPORTS_PER_SOL) as u64
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct FeeStats {
     pub median: i64,
@@ -34,3 +50,18 @@ pub fn calculate_fee_stats(fees: &[i64]) -> FeeStats {
         count,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tip_lamports() {
+        let floors: Vec<JitoTipFloor> = serde_json::from_str(
+            r#"[{"time":"2025-10-01T00:00:00Z","landed_tips_25th_percentile":0.000001,"landed_tips_50th_percentile":0.000005,"landed_tips_75th_percentile":0.0000125,"landed_tips_95th_percentile":0.0001,"landed_tips_99th_percentile":0.001,"ema_landed_tips_50th_percentile":0.000006}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(floors[0].tip_lamports(), 12_500);
+    }
+}
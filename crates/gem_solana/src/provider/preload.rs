@@ -18,6 +18,7 @@ impl<C: Client + Clone> ChainTransactionLoad for SolanaClient<C> {
             input_type,
             sender_address,
             destination_address,
+            mev_protection,
         } = input;
         let (sender_lookup_address, recipient_lookup_address) = match &input_type {
             TransactionInputType::Transfer(_) | TransactionInputType::TransferNft(_, _) => (&sender_address, &destination_address),
@@ -48,7 +49,22 @@ impl<C: Client + Clone> ChainTransactionLoad for SolanaClient<C> {
             Ok(None)
         };
 
-        let (block_hash, sender_token_address, recipient_token_address) = futures::try_join!(self.get_latest_blockhash(), sender_token_future, recipient_token_future)?;
+        let jito_tip_future = async {
+            match (&input_type, self.get_jito_client()) {
+                (TransactionInputType::Swap(_, _, _), Some(jito_client)) if mev_protection => {
+                    let floor = jito_client.get_tip_floor().await?.ok_or("Jito tip floor unavailable")?;
+                    Ok::<_, Box<dyn Error + Sync + Send>>(Some(floor.tip_lamports()))
+                }
+                _ => Ok(None),
+            }
+        };
+
+        let (result, jito_tip_lamports) = futures::join!(
+            futures::future::try_join3(self.get_latest_blockhash(), sender_token_future, recipient_token_future),
+            jito_tip_future
+        );
+        let (block_hash, sender_token_address, recipient_token_address) = result?;
+        let jito_tip_lamports = jito_tip_lamports?;
 
         let token_program = match source_asset.asset_type {
             AssetType::SPL => Some(SolanaTokenProgramId::Token),
@@ -61,6 +77,7 @@ impl<C: Client + Clone> ChainTransactionLoad for SolanaClient<C> {
             recipient_token_address,
             token_program,
             block_hash: block_hash.value.blockhash,
+            jito_tip_lamports,
         })
     }
 
@@ -97,6 +114,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::mock_sol()),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
         let result = client.get_transaction_preload(input).await?;
 
@@ -116,6 +134,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::mock_spl_token()),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: "4BgapREafMMprtU6CehRmH8LUY26PRFmGf7K4S44oSMW".to_string(),
+            mev_protection: false,
         };
 
         let result = client.get_transaction_preload(input).await?;
@@ -137,6 +156,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Swap(Asset::mock_spl_token().clone(), Asset::mock_ethereum_usdc().clone(), swap_data),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let result = client.get_transaction_preload(input).await?;
@@ -163,6 +183,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Swap(Asset::mock_spl_token(), Asset::mock_spl_token(), swap_data),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_EMPTY_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let result = client.get_transaction_preload(input).await?;
@@ -13,6 +13,15 @@ use crate::{
 #[async_trait]
 impl<C: Client + Clone> ChainTransactionBroadcast for SolanaClient<C> {
     async fn transaction_broadcast(&self, data: String, options: BroadcastOptions) -> Result<String, Box<dyn Error + Sync + Send>> {
+        if options.mev_protection
+            && let Some(jito_client) = self.get_jito_client()
+        {
+            match jito_client.send_transaction(&data).await {
+                Ok(hash) => return Ok(hash),
+                Err(err) if !err.is_client_error() => return Err(err.into()),
+                Err(err) => tracing::warn!(chain = self.chain.as_ref(), error = %err, "jito bundle broadcast failed, broadcasting publicly"),
+            }
+        }
         let response = self.send_transaction(data, Some(options.skip_preflight)).await?;
         Ok(response)
     }
@@ -23,3 +32,55 @@ impl ChainTransactionDecode for BroadcastProvider {
         map_transaction_broadcast_response_from_str(response).ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::jito::JitoClient;
+    use gem_client::{ClientError, testkit::MockClient};
+    use gem_jsonrpc::testkit::{mock_jsonrpc_client, mock_jsonrpc_transport};
+
+    fn client(jito_client: MockClient) -> SolanaClient<MockClient> {
+        let public_client = mock_jsonrpc_client(|method, _| match method {
+            "sendTransaction" => Ok(serde_json::json!("public")),
+            _ => Err(ClientError::Network(method.to_string())),
+        });
+        SolanaClient::new(public_client).with_jito_client(JitoClient::new(jito_client))
+    }
+
+    fn mev_protection_options() -> BroadcastOptions {
+        BroadcastOptions {
+            skip_preflight: true,
+            mev_protection: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection() {
+        let client = client(mock_jsonrpc_transport(|_, params| {
+            assert_eq!(params, &serde_json::json!(["AQID", {"encoding": "base64"}]));
+            Ok(serde_json::json!("jito"))
+        }));
+
+        assert_eq!(client.transaction_broadcast("AQID".to_string(), mev_protection_options()).await.unwrap(), "jito");
+        assert_eq!(client.transaction_broadcast("AQID".to_string(), BroadcastOptions::new(true)).await.unwrap(), "public");
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection_fallback() {
+        let client = client(mock_jsonrpc_transport(|_, _| Err(ClientError::Timeout)));
+
+        assert_eq!(client.transaction_broadcast("AQID".to_string(), mev_protection_options()).await.unwrap(), "public");
+    }
+
+    #[tokio::test]
+    async fn test_transaction_broadcast_mev_protection_rejected() {
+        let client =
+            client(MockClient::new().with_post(|_, _| {
+                Ok(serde_json::to_vec(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32602, "message": "bundle must contain a tip"}})).unwrap())
+            }));
+
+        let error = client.transaction_broadcast("AQID".to_string(), mev_protection_options()).await.unwrap_err();
+        assert!(error.to_string().contains("bundle must contain a tip"));
+    }
+}
@@ -5,6 +5,8 @@ use crate::models::{
     prioritization_fee::SolanaPrioritizationFee,
     transaction::{BlockTransactions, SolanaTransaction},
 };
+#[cfg(feature = "rpc")]
+use crate::rpc::jito::JitoClient;
 use crate::{COMMITMENT_CONFIRMED, STAKE_PROGRAM_ID};
 use chain_traits::ChainProvider;
 #[cfg(feature = "rpc")]
@@ -19,6 +21,7 @@ use std::error::Error;
 #[cfg(feature = "rpc")]
 pub struct SolanaClient<C: Client + Clone> {
     client: GenericJsonRpcClient<C>,
+    jito_client: Option<JitoClient<C>>,
     pub chain: Chain,
 }
 
@@ -49,7 +52,20 @@ pub fn token_accounts_by_mint_params(owner: &str, mint: &str) -> serde_json::Val
 #[cfg(feature = "rpc")]
 impl<C: Client + Clone> SolanaClient<C> {
     pub fn new(client: GenericJsonRpcClient<C>) -> Self {
-        Self { client, chain: Chain::Solana }
+        Self {
+            client,
+            jito_client: None,
+            chain: Chain::Solana,
+        }
+    }
+
+    pub fn with_jito_client(mut self, jito_client: JitoClient<C>) -> Self {
+        self.jito_client = Some(jito_client);
+        self
+    }
+
+    pub fn get_jito_client(&self) -> Option<&JitoClient<C>> {
+        self.jito_client.as_ref()
     }
 
     pub fn get_client(&self) -> &GenericJsonRpcClient<C> {
@@ -0,0 +1,38 @@
+use gem_client::{Client, ClientExt};
+use gem_jsonrpc::types::{JsonRpcError, JsonRpcRequest, JsonRpcResult};
+
+use crate::models::jito::JitoTipFloor;
+
+pub const JITO_BLOCK_ENGINE_URL: &str = "https://mainnet.block-engine.jito.wtf/api/v1/transactions";
+pub const JITO_TIP_FLOOR_URL: &str = "https://bundles.jito.wtf/api/v1/bundles/tip_floor";
+const JITO_BUNDLE_ONLY_PATH: &str = "?bundleOnly=true";
+
+#[derive(Debug, Clone)]
+pub struct JitoClient<C: Client + Clone> {
+    client: C,
+}
+
+impl<C: Client + Clone> JitoClient<C> {
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    pub async fn send_transaction(&self, data: &str) -> Result<String, JsonRpcError> {
+        let params = serde_json::json!([
+            data,
+            {
+                "encoding": "base64"
+            }
+        ]);
+        let request = JsonRpcRequest::new(1, "sendTransaction", params);
+        match self.client.post(JITO_BUNDLE_ONLY_PATH, &request).await? {
+            JsonRpcResult::Value(value) => Ok(value.result),
+            JsonRpcResult::Error(error) => Err(error.error),
+        }
+    }
+
+    pub async fn get_tip_floor(&self) -> Result<Option<JitoTipFloor>, JsonRpcError> {
+        let floors: Vec<JitoTipFloor> = self.client.get_url(JITO_TIP_FLOOR_URL).await?;
+        Ok(floors.into_iter().next())
+    }
+}
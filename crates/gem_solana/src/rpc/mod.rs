@@ -1,5 +1,9 @@
 pub mod client;
 pub mod constants;
+#[cfg(feature = "rpc")]
+pub mod jito;
 
 pub use client::SolanaClient;
 pub use constants::*;
+#[cfg(feature = "rpc")]
+pub use jito::JitoClient;
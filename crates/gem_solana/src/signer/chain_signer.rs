@@ -1,7 +1,9 @@
 use gem_encoding::{decode_base64, encode_base64};
-use primitives::{ChainSigner, SignerError, SignerInput, TransferDataOutputType};
+use primitives::{ChainSigner, SignerError, SignerInput, TransactionLoadMetadata, TransferDataOutputType};
 use solana_primitives::{VersionedTransaction, sign_message};
 
+use super::jito_tip::add_tip_transfer;
+
 #[derive(Default)]
 pub struct SolanaChainSigner;
 
@@ -12,8 +14,12 @@ impl ChainSigner for SolanaChainSigner {
 
         let unit_price = input.fee.unit_price_u64()?;
         let gas_limit = swap_data.data.gas_limit_as_u32().map_err(SignerError::invalid_input)?;
+        let tip_lamports = match &input.metadata {
+            TransactionLoadMetadata::Solana { jito_tip_lamports, .. } => *jito_tip_lamports,
+            _ => None,
+        };
 
-        let signed = Self::sign_transaction(tx_base64, private_key, unit_price, gas_limit)?;
+        let signed = Self::sign_transaction(tx_base64, private_key, unit_price, gas_limit, tip_lamports)?;
 
         Ok(vec![signed])
     }
@@ -45,7 +51,7 @@ impl ChainSigner for SolanaChainSigner {
 }
 
 impl SolanaChainSigner {
-    fn sign_transaction(tx_base64: &str, private_key: &[u8], unit_price: u64, gas_limit: u32) -> Result<String, SignerError> {
+    fn sign_transaction(tx_base64: &str, private_key: &[u8], unit_price: u64, gas_limit: u32, tip_lamports: Option<u64>) -> Result<String, SignerError> {
         let data = decode_base64(tx_base64).map_err(|_| SignerError::invalid_input("base64 decode failed"))?;
 
         let mut tx = VersionedTransaction::deserialize_with_version(&data).map_err(|e| SignerError::invalid_input(format!("parse transaction: {e}")))?;
@@ -60,6 +66,9 @@ impl SolanaChainSigner {
                 tx.set_compute_unit_limit(gas_limit)
                     .map_err(|e| SignerError::invalid_input(format!("set compute unit limit: {e}")))?;
             }
+            if let Some(tip_lamports) = tip_lamports {
+                add_tip_transfer(&mut tx, tip_lamports)?;
+            }
         }
 
         let message_bytes = tx.serialize_message().map_err(|e| SignerError::signing_error(format!("serialize message: {e}")))?;
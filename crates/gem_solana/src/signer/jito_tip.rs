@@ -0,0 +1,115 @@
+use primitives::SignerError;
+use solana_primitives::{CompiledInstruction, MessageHeader, Pubkey, VersionedTransaction, instructions::system};
+
+// https://docs.jito.wtf/lowlatencytxnsend/#gettipaccounts
+pub const JITO_TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+pub fn add_tip_transfer(tx: &mut VersionedTransaction, lamports: u64) -> Result<(), SignerError> {
+    let tip_account = JITO_TIP_ACCOUNTS[tx.recent_blockhash()[0] as usize % JITO_TIP_ACCOUNTS.len()];
+    let tip_account = Pubkey::from_base58(tip_account).map_err(|e| SignerError::invalid_input(format!("tip account: {e}")))?;
+    let (header, account_keys, instructions) = match tx {
+        VersionedTransaction::Legacy { message, .. } => (&mut message.header, &mut message.account_keys, &mut message.instructions),
+        VersionedTransaction::V0 { message, .. } => (&mut message.header, &mut message.account_keys, &mut message.instructions),
+    };
+    let fee_payer = *account_keys.first().ok_or_else(|| SignerError::invalid_input("missing fee payer"))?;
+    let transfer = system::transfer(&fee_payer, &tip_account, lamports);
+
+    if !account_keys.contains(&tip_account) {
+        let position = account_keys.len() - header.num_readonly_unsigned_accounts as usize;
+        insert_account_key(account_keys, instructions, position, tip_account)?;
+    }
+    if !account_keys.contains(&transfer.program_id) {
+        insert_account_key(account_keys, instructions, account_keys.len(), transfer.program_id)?;
+        header.num_readonly_unsigned_accounts += 1;
+    }
+
+    let program_id_index = account_key_index(account_keys, &transfer.program_id)?;
+    let tip_account_index = account_key_index(account_keys, &tip_account)?;
+    instructions.push(CompiledInstruction {
+        program_id_index,
+        accounts: vec![0, tip_account_index],
+        data: transfer.data,
+    });
+    validate_header(header, account_keys)
+}
+
+// Lookup table accounts are indexed after the static keys, so they shift together with any key inserted before them.
+fn insert_account_key(account_keys: &mut Vec<Pubkey>, instructions: &mut [CompiledInstruction], position: usize, key: Pubkey) -> Result<(), SignerError> {
+    account_keys.insert(position, key);
+    for instruction in instructions.iter_mut() {
+        shift_index(&mut instruction.program_id_index, position)?;
+        for account in instruction.accounts.iter_mut() {
+            shift_index(account, position)?;
+        }
+    }
+    Ok(())
+}
+
+fn shift_index(index: &mut u8, position: usize) -> Result<(), SignerError> {
+    if *index as usize >= position {
+        *index = index.checked_add(1).ok_or_else(|| SignerError::invalid_input("too many accounts for tip transfer"))?;
+    }
+    Ok(())
+}
+
+fn account_key_index(account_keys: &[Pubkey], key: &Pubkey) -> Result<u8, SignerError> {
+    let index = account_keys
+        .iter()
+        .position(|k| k == key)
+        .ok_or_else(|| SignerError::invalid_input("missing account key"))?;
+    u8::try_from(index).map_err(|_| SignerError::invalid_input("too many accounts for tip transfer"))
+}
+
+fn validate_header(header: &MessageHeader, account_keys: &[Pubkey]) -> Result<(), SignerError> {
+    match header.num_required_signatures as usize + header.num_readonly_unsigned_accounts as usize <= account_keys.len() {
+        true => Ok(()),
+        false => Err(SignerError::invalid_input("invalid message header")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::testkit::SINGLE_SIG_TX;
+    use gem_encoding::decode_base64;
+
+    #[test]
+    fn test_add_tip_transfer() {
+        let mut tx = VersionedTransaction::deserialize_with_version(&decode_base64(SINGLE_SIG_TX).unwrap()).unwrap();
+        let original = tx.clone();
+
+        add_tip_transfer(&mut tx, 12_500).unwrap();
+
+        let keys = tx.account_keys();
+        let instruction = tx.instructions().last().unwrap();
+        let tip_account = keys[instruction.accounts[1] as usize];
+        let program_id = keys[instruction.program_id_index as usize];
+
+        assert_eq!(keys.len(), original.account_keys().len() + 1);
+        assert_eq!(tx.instructions().len(), original.instructions().len() + 1);
+        assert_eq!(instruction.accounts[0], 0);
+        assert!(JITO_TIP_ACCOUNTS.contains(&tip_account.to_base58().as_str()));
+        assert_eq!(program_id.to_base58(), "11111111111111111111111111111111");
+        assert_eq!(instruction.data, [vec![2, 0, 0, 0], 12_500u64.to_le_bytes().to_vec()].concat());
+        assert_eq!(tx.num_readonly_unsigned_accounts(), original.num_readonly_unsigned_accounts());
+
+        let position = original.account_keys().len() - original.num_readonly_unsigned_accounts() as usize;
+        for (updated, original) in tx.instructions().iter().zip(original.instructions()) {
+            let shift = |index: u8| if index as usize >= position { index + 1 } else { index };
+            assert_eq!(updated.program_id_index, shift(original.program_id_index));
+            assert_eq!(updated.accounts, original.accounts.iter().map(|index| shift(*index)).collect::<Vec<_>>());
+        }
+
+        let bytes = tx.serialize().unwrap();
+        assert!(VersionedTransaction::deserialize_with_version(&bytes).is_ok());
+    }
+}
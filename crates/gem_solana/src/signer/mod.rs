@@ -1,4 +1,5 @@
 mod chain_signer;
+mod jito_tip;
 #[cfg(test)]
 pub mod testkit;
 
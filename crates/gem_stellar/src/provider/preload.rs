@@ -50,6 +50,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Stellar)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(input).await?;
@@ -68,6 +69,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Stellar)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_EMPTY_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(input).await?;
@@ -86,6 +88,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Stellar)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(preload_input).await?;
@@ -117,6 +120,7 @@ mod chain_integration_tests {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Stellar)),
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_EMPTY_ADDRESS.to_string(),
+            mev_protection: false,
         };
 
         let metadata = client.get_transaction_preload(preload_input).await?;
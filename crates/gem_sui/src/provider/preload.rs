@@ -136,6 +136,7 @@ mod chain_integration_tests {
         let input = TransactionPreloadInput {
             sender_address: TEST_ADDRESS.to_string(),
             destination_address: TEST_ADDRESS.to_string(),
+            mev_protection: false,
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Sui)),
         };
 
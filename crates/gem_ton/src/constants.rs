@@ -1,3 +1,5 @@
+use primitives::TonStakePoolType;
+
 // Transaction opcodes
 pub const JETTON_TRANSFER_OPCODE: u32 = 0x0f8a7ea5;
 
@@ -11,3 +13,28 @@ pub const FAILED_OPERATION_OPCODES: &[&str] = &[
     "0x77d0fee6", // Another failure pattern
     "0x98ce9044", // Failed jetton operation (insufficient funds)
 ];
+
+// Staking pool opcodes
+pub const WHALES_DEPOSIT_OPCODE: u32 = 0x7bcd1fef;
+pub const WHALES_WITHDRAW_OPCODE: u32 = 0xda803efd;
+pub const LIQUID_DEPOSIT_OPCODE: u32 = 0x47d54391;
+pub const JETTON_BURN_OPCODE: u32 = 0x595f07bc;
+
+// TON nominator pools take deposits and withdraw requests as text comments
+pub const NOMINATOR_DEPOSIT_COMMENT: &str = "d";
+pub const NOMINATOR_WITHDRAW_COMMENT: &str = "w";
+
+pub const WHALES_GAS_LIMIT: u64 = 100_000;
+
+// TON attached to withdraw requests to pay for pool processing, the unused part is returned
+pub const WHALES_WITHDRAW_FEE: u64 = 200_000_000;
+pub const NOMINATOR_WITHDRAW_FEE: u64 = 1_000_000_000;
+pub const LIQUID_WITHDRAW_FEE: u64 = 1_000_000_000;
+
+pub fn stake_withdraw_fee(pool_type: TonStakePoolType) -> u64 {
+    match pool_type {
+        TonStakePoolType::Whales => WHALES_WITHDRAW_FEE,
+        TonStakePoolType::Nominator => NOMINATOR_WITHDRAW_FEE,
+        TonStakePoolType::Liquid => LIQUID_WITHDRAW_FEE,
+    }
+}
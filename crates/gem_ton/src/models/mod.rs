@@ -4,6 +4,7 @@ pub mod block;
 pub mod fee;
 pub mod nft;
 pub mod rpc;
+pub mod staking;
 pub mod transaction;
 
 pub use account::*;
@@ -12,4 +13,5 @@ pub use block::*;
 pub use fee::*;
 pub use nft::*;
 pub use rpc::*;
+pub use staking::*;
 pub use transaction::*;
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakingPools {
+    pub pools: Vec<StakingPool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakingPool {
+    pub address: String,
+    pub name: String,
+    pub implementation: StakingPoolImplementation,
+    pub apy: f64,
+    pub min_stake: u64,
+    pub cycle_end: i64,
+    pub verified: bool,
+    pub liquid_jetton_master: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StakingPoolImplementation {
+    #[serde(rename = "whales")]
+    Whales,
+    #[serde(rename = "tf")]
+    Nominator,
+    #[serde(rename = "liquidTF")]
+    Liquid,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NominatorPools {
+    pub pools: Vec<NominatorPool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NominatorPool {
+    pub pool: String,
+    pub amount: u64,
+    pub pending_deposit: u64,
+    pub pending_withdraw: u64,
+    pub ready_withdraw: u64,
+}
@@ -6,6 +6,7 @@ use gem_client::Client;
 use primitives::AssetBalance;
 
 use crate::provider::balances_mapper::{map_balance_assets, map_balance_tokens, map_coin_balance};
+use crate::provider::staking_mapper::map_balance_staking;
 use crate::rpc::client::TonClient;
 
 #[async_trait]
@@ -20,8 +21,9 @@ impl<C: Client> ChainBalances for TonClient<C> {
         Ok(map_balance_tokens(balances, token_ids))
     }
 
-    async fn get_balance_staking(&self, _address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
-        Ok(None)
+    async fn get_balance_staking(&self, address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let positions = self.get_nominator_pools(&address).await?;
+        Ok(Some(map_balance_staking(&positions.pools)))
     }
 
     async fn get_balance_assets(&self, address: String) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
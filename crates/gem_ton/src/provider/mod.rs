@@ -2,6 +2,8 @@ pub mod balances;
 pub mod balances_mapper;
 pub mod preload;
 pub mod request_classifier;
+pub mod staking;
+pub mod staking_mapper;
 pub mod state;
 pub mod state_mapper;
 pub mod testkit;
@@ -4,13 +4,15 @@ use gem_client::Client;
 use num_bigint::BigInt;
 use primitives::FeeOption;
 use primitives::{
-    AssetSubtype, FeePriority, FeeRate, GasPriceType, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata,
+    AssetSubtype, FeePriority, FeeRate, GasPriceType, StakeType, TransactionFee, TransactionInputType, TransactionLoadData, TransactionLoadInput, TransactionLoadMetadata,
     TransactionPreloadInput,
 };
 use std::collections::HashMap;
 use std::error::Error;
 
 use crate::address::base64_to_hex_address;
+use crate::constants::stake_withdraw_fee;
+use crate::provider::staking_mapper::{find_pool, map_stake_pool_type};
 use crate::rpc::client::TonClient;
 
 const TON_BASE_FEE: u64 = 10_000_000;
@@ -43,6 +45,10 @@ pub fn calculate_transaction_fee(input: &TransactionLoadInput, recipient_token_a
             options.insert(FeeOption::TokenAccountCreation, BigInt::from(JETTON_ACCOUNT_CREATION));
             base_fee
         }
+        TransactionInputType::Stake(_, StakeType::Unstake(_) | StakeType::Withdraw(_)) => {
+            let withdraw_fee = input.metadata.get_ton_stake_pool_type().map(stake_withdraw_fee).unwrap_or_default();
+            base_fee + BigInt::from(withdraw_fee)
+        }
         TransactionInputType::TokenApprove(_, _) => base_fee.clone(),
         TransactionInputType::Generic(_, _, _) => base_fee.clone(),
         TransactionInputType::Perpetual(_, _) => base_fee.clone(),
@@ -52,12 +58,49 @@ pub fn calculate_transaction_fee(input: &TransactionLoadInput, recipient_token_a
     TransactionFee::new_gas_price_type(GasPriceType::regular(fee.clone()), fee.clone(), BigInt::from(1), options)
 }
 
+impl<C: Client> TonClient<C> {
+    async fn get_stake_metadata(&self, stake_type: &StakeType, sender_address: &str, sequence: u64) -> Result<TransactionLoadMetadata, Box<dyn Error + Sync + Send>> {
+        let pool_address = match stake_type {
+            StakeType::Stake(validator) => &validator.id,
+            StakeType::Unstake(delegation) | StakeType::Withdraw(delegation) => &delegation.base.validator_id,
+            _ => return Err("Unsupported TON stake type".into()),
+        };
+        let pools = self.get_staking_pools().await?;
+        let pool = find_pool(&pools.pools, pool_address).ok_or("Unknown TON staking pool")?;
+        let stake_pool_type = map_stake_pool_type(pool.implementation).ok_or("Unsupported TON staking pool")?;
+
+        let sender_token_address = match (stake_type, &pool.liquid_jetton_master) {
+            (StakeType::Unstake(_), Some(jetton_master)) => {
+                let jetton_master = jetton_master.to_uppercase();
+                let wallets = self.get_jetton_wallets(sender_address.to_string()).await?;
+                wallets
+                    .jetton_wallets
+                    .into_iter()
+                    .find(|wallet| wallet.jetton == jetton_master)
+                    .map(|wallet| wallet.address)
+            }
+            _ => None,
+        };
+
+        Ok(TransactionLoadMetadata::Ton {
+            sender_token_address,
+            recipient_token_address: None,
+            sequence,
+            stake_pool_type: Some(stake_pool_type),
+        })
+    }
+}
+
 #[async_trait]
 impl<C: Client> ChainTransactionLoad for TonClient<C> {
     async fn get_transaction_preload(&self, input: TransactionPreloadInput) -> Result<TransactionLoadMetadata, Box<dyn Error + Sync + Send>> {
         let wallet_info = self.get_wallet_information(input.sender_address.clone()).await?;
         let sequence = wallet_info.seqno.unwrap_or(0) as u64;
 
+        if let TransactionInputType::Stake(_, stake_type) = &input.input_type {
+            return self.get_stake_metadata(stake_type, &input.sender_address, sequence).await;
+        }
+
         let asset = input.input_type.get_asset();
         return match &asset.id.token_subtype() {
             AssetSubtype::TOKEN => {
@@ -76,12 +119,14 @@ impl<C: Client> ChainTransactionLoad for TonClient<C> {
                     sender_token_address: sender_jetton_wallet_address.map(|x| x.address.clone()),
                     recipient_token_address: recipient_jetton_wallet_address.map(|x| x.address.clone()),
                     sequence,
+                    stake_pool_type: None,
                 })
             }
             AssetSubtype::NATIVE => Ok(TransactionLoadMetadata::Ton {
                 sender_token_address: None,
                 recipient_token_address: None,
                 sequence,
+                stake_pool_type: None,
             }),
         };
     }
@@ -102,8 +147,9 @@ impl<C: Client> ChainTransactionLoad for TonClient<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::WHALES_WITHDRAW_FEE;
     use num_bigint::BigInt;
-    use primitives::{Asset, AssetId, AssetType, Chain, GasPriceType};
+    use primitives::{Asset, AssetId, AssetType, Chain, Delegation, GasPriceType, TonStakePoolType};
 
     fn create_input(asset_type: AssetType, memo: Option<String>) -> TransactionLoadInput {
         let (token_id, name, symbol, decimals) = match asset_type {
@@ -135,6 +181,7 @@ mod tests {
                 sender_token_address: None,
                 recipient_token_address: None,
                 sequence: 0,
+                stake_pool_type: None,
             },
         }
     }
@@ -183,4 +230,15 @@ mod tests {
         assert_eq!(fee.fee, BigInt::from(TON_BASE_FEE + JETTON_ACCOUNT_CREATION));
         assert_eq!(fee.options.get(&FeeOption::TokenAccountCreation), Some(&BigInt::from(JETTON_ACCOUNT_CREATION)));
     }
+
+    #[test]
+    fn test_stake_withdraw_fee() {
+        let input = TransactionLoadInput {
+            input_type: TransactionInputType::Stake(Asset::from_chain(Chain::Ton), StakeType::Unstake(Delegation::mock())),
+            metadata: TransactionLoadMetadata::mock_ton_stake(1, TonStakePoolType::Whales, None),
+            ..create_input(AssetType::NATIVE, None)
+        };
+        let fee = calculate_transaction_fee(&input, None);
+        assert_eq!(fee.fee, BigInt::from(TON_BASE_FEE + WHALES_WITHDRAW_FEE));
+    }
 }
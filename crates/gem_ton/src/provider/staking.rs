@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use chain_traits::ChainStaking;
+use std::error::Error;
+
+use gem_client::Client;
+use primitives::{DelegationBase, DelegationValidator};
+
+use super::staking_mapper::{map_delegations, map_staking_apy, map_validators};
+use crate::rpc::client::TonClient;
+
+#[async_trait]
+impl<C: Client> ChainStaking for TonClient<C> {
+    async fn get_staking_apy(&self) -> Result<Option<f64>, Box<dyn Error + Sync + Send>> {
+        let pools = self.get_staking_pools().await?;
+        Ok(Some(map_staking_apy(&pools.pools)))
+    }
+
+    async fn get_staking_validators(&self, _apy: Option<f64>) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
+        let pools = self.get_staking_pools().await?;
+        Ok(map_validators(pools.pools))
+    }
+
+    async fn get_staking_delegations(&self, address: String) -> Result<Vec<DelegationBase>, Box<dyn Error + Sync + Send>> {
+        let (positions, pools, jetton_wallets) = futures::try_join!(self.get_nominator_pools(&address), self.get_staking_pools(), self.get_jetton_wallets(address.clone()))?;
+        Ok(map_delegations(positions.pools, &pools.pools, &jetton_wallets.jetton_wallets))
+    }
+}
+
+#[cfg(all(test, feature = "chain_integration_tests"))]
+mod chain_integration_tests {
+    use super::*;
+    use crate::provider::testkit::{TEST_ADDRESS, create_ton_test_client};
+
+    #[tokio::test]
+    async fn test_ton_get_staking_validators() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_ton_test_client();
+        let apy = client.get_staking_apy().await?;
+        let validators = client.get_staking_validators(apy).await?;
+
+        println!("APY: {:?}, validators: {}", apy, validators.len());
+        assert!(!validators.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ton_get_staking_delegations() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = create_ton_test_client();
+        let delegations = client.get_staking_delegations(TEST_ADDRESS.to_string()).await?;
+
+        println!("Delegations: {:?}", delegations);
+        Ok(())
+    }
+}
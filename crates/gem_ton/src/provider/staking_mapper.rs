@@ -0,0 +1,174 @@
+use chrono::DateTime;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Chain, DelegationBase, DelegationState, DelegationValidator, TonStakePoolType};
+
+use crate::address::Address;
+use crate::models::{JettonWallet, NominatorPool, StakingPool, StakingPoolImplementation};
+
+pub fn map_stake_pool_type(implementation: StakingPoolImplementation) -> Option<TonStakePoolType> {
+    match implementation {
+        StakingPoolImplementation::Whales => Some(TonStakePoolType::Whales),
+        StakingPoolImplementation::Nominator => Some(TonStakePoolType::Nominator),
+        StakingPoolImplementation::Liquid => Some(TonStakePoolType::Liquid),
+        StakingPoolImplementation::Unknown => None,
+    }
+}
+
+pub fn find_pool<'a>(pools: &'a [StakingPool], address: &str) -> Option<&'a StakingPool> {
+    let address = Address::parse(address).ok()?;
+    pools.iter().find(|pool| Address::parse(&pool.address).ok() == Some(address))
+}
+
+fn is_supported(pool: &StakingPool) -> bool {
+    pool.verified && map_stake_pool_type(pool.implementation).is_some()
+}
+
+pub fn map_staking_apy(pools: &[StakingPool]) -> f64 {
+    pools.iter().filter(|pool| is_supported(pool)).map(|pool| pool.apy).fold(0.0, f64::max)
+}
+
+pub fn map_validators(pools: Vec<StakingPool>) -> Vec<DelegationValidator> {
+    pools
+        .into_iter()
+        .filter(is_supported)
+        .map(|pool| DelegationValidator::stake(Chain::Ton, pool.address, pool.name, true, 0.0, pool.apy))
+        .collect()
+}
+
+pub fn map_delegations(positions: Vec<NominatorPool>, pools: &[StakingPool], jetton_wallets: &[JettonWallet]) -> Vec<DelegationBase> {
+    positions
+        .into_iter()
+        .flat_map(|position| {
+            let pool = find_pool(pools, &position.pool);
+            let cycle_end = pool.and_then(|pool| DateTime::from_timestamp(pool.cycle_end, 0));
+            let shares = pool
+                .and_then(|pool| pool.liquid_jetton_master.as_ref())
+                .and_then(|jetton_master| jetton_wallets.iter().find(|wallet| wallet.jetton == jetton_master.to_uppercase()))
+                .map(|wallet| wallet.balance.clone())
+                .unwrap_or_default();
+            [
+                (DelegationState::Active, position.amount, None),
+                (DelegationState::Activating, position.pending_deposit, cycle_end),
+                (DelegationState::Deactivating, position.pending_withdraw, cycle_end),
+                (DelegationState::AwaitingWithdrawal, position.ready_withdraw, None),
+            ]
+            .into_iter()
+            .filter(|(_, amount, _)| *amount > 0)
+            .map(|(state, amount, completion_date)| DelegationBase {
+                asset_id: Chain::Ton.as_asset_id(),
+                state,
+                balance: BigUint::from(amount),
+                shares: if state == DelegationState::Active { shares.clone() } else { BigUint::from(0u32) },
+                rewards: BigUint::from(0u32),
+                completion_date,
+                delegation_id: format!("{}-{}", position.pool, state.as_ref()),
+                validator_id: position.pool.clone(),
+            })
+            .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn map_balance_staking(positions: &[NominatorPool]) -> AssetBalance {
+    let staked: u64 = positions.iter().map(|position| position.amount).sum();
+    let pending: u64 = positions
+        .iter()
+        .map(|position| position.pending_deposit + position.pending_withdraw + position.ready_withdraw)
+        .sum();
+    AssetBalance::new_staking(Chain::Ton.as_asset_id(), BigUint::from(staked), BigUint::from(pending), BigUint::from(0u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{JettonWalletsResponse, NominatorPools, StakingPools};
+
+    const WHALES_POOL: &str = "0:a45b17f28409229b78360e3290420f13e4fe20f90d7e2bf8c4ac6703259e22fa";
+    const LIQUID_POOL: &str = "0:a45b17f28409229b78360e3290420f13e4fe20f90d7e2bf8c4ac6703259e22fb";
+    const LIQUID_JETTON: &str = "0:bdf3fa8098d129b54b4f73b5bac5d1e1fd91eb054169c3916dfc8ccd536d1000";
+
+    fn pools() -> Vec<StakingPool> {
+        serde_json::from_value::<StakingPools>(serde_json::json!({
+            "pools": [
+                { "address": WHALES_POOL, "name": "Whales Nominators #1", "implementation": "whales", "apy": 4.1, "min_stake": 50000000000u64, "cycle_end": 1_760_000_000, "verified": true },
+                { "address": LIQUID_POOL, "name": "Tonstakers", "implementation": "liquidTF", "apy": 4.4, "min_stake": 1000000000u64, "cycle_end": 1_760_000_000, "verified": true, "liquid_jetton_master": LIQUID_JETTON },
+                { "address": "0:00", "name": "Unverified", "implementation": "tf", "apy": 9.0, "min_stake": 0, "cycle_end": 0, "verified": false },
+                { "address": "0:01", "name": "Future", "implementation": "other", "apy": 8.0, "min_stake": 0, "cycle_end": 0, "verified": true }
+            ]
+        }))
+        .unwrap()
+        .pools
+    }
+
+    fn positions() -> Vec<NominatorPool> {
+        serde_json::from_value::<NominatorPools>(serde_json::json!({
+            "pools": [
+                { "pool": WHALES_POOL, "amount": 60000000000u64, "pending_deposit": 0, "pending_withdraw": 5000000000u64, "ready_withdraw": 0 },
+                { "pool": LIQUID_POOL, "amount": 3000000000u64, "pending_deposit": 2000000000u64, "pending_withdraw": 0, "ready_withdraw": 1000000000u64 }
+            ]
+        }))
+        .unwrap()
+        .pools
+    }
+
+    #[test]
+    fn test_map_validators() {
+        let pools = pools();
+        assert_eq!(map_staking_apy(&pools), 4.4);
+
+        let validators = map_validators(pools);
+        assert_eq!(validators.len(), 2);
+        assert_eq!(validators[0].id, WHALES_POOL);
+        assert_eq!(validators[0].name, "Whales Nominators #1");
+        assert_eq!(validators[1].apr, 4.4);
+    }
+
+    #[test]
+    fn test_find_pool() {
+        let pools = pools();
+        let address = Address::parse(LIQUID_POOL).unwrap().to_string();
+
+        assert_eq!(find_pool(&pools, &address).unwrap().implementation, StakingPoolImplementation::Liquid);
+        assert!(find_pool(&pools, "invalid").is_none());
+    }
+
+    fn jetton_wallets() -> Vec<JettonWallet> {
+        serde_json::from_value::<JettonWalletsResponse>(serde_json::json!({
+            "jetton_wallets": [{ "address": "0:ff", "balance": "2900000000", "jetton": LIQUID_JETTON.to_uppercase() }]
+        }))
+        .unwrap()
+        .jetton_wallets
+    }
+
+    #[test]
+    fn test_map_delegations() {
+        let delegations = map_delegations(positions(), &pools(), &jetton_wallets());
+        let states: Vec<(DelegationState, u64)> = delegations.iter().map(|x| (x.state, x.balance.to_string().parse().unwrap())).collect();
+
+        assert_eq!(
+            states,
+            vec![
+                (DelegationState::Active, 60_000_000_000),
+                (DelegationState::Deactivating, 5_000_000_000),
+                (DelegationState::Active, 3_000_000_000),
+                (DelegationState::Activating, 2_000_000_000),
+                (DelegationState::AwaitingWithdrawal, 1_000_000_000),
+            ]
+        );
+        assert_eq!(delegations[0].validator_id, WHALES_POOL);
+        assert_eq!(delegations[0].completion_date, None);
+        assert_eq!(delegations[1].completion_date, DateTime::from_timestamp(1_760_000_000, 0));
+        assert_eq!(delegations[1].delegation_id, format!("{WHALES_POOL}-deactivating"));
+        assert_eq!(delegations[0].shares, BigUint::from(0u32));
+        assert_eq!(delegations[2].shares, BigUint::from(2_900_000_000u64));
+        assert_eq!(delegations[3].shares, BigUint::from(0u32));
+    }
+
+    #[test]
+    fn test_map_balance_staking() {
+        let balance = map_balance_staking(&positions());
+
+        assert_eq!(balance.balance.staked, BigUint::from(63_000_000_000u64));
+        assert_eq!(balance.balance.pending, BigUint::from(8_000_000_000u64));
+    }
+}
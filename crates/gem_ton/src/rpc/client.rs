@@ -3,14 +3,16 @@ use std::error::Error;
 use primitives::{Asset, AssetId, AssetType, chain::Chain};
 use serde_json;
 
-use chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainStaking, ChainTraits};
+use chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainTraits};
 use gem_client::{Client, ClientExt};
 
 use crate::models::{
     ApiResult, BroadcastTransaction, Chainhead, JettonInfo, JettonOffchainMetadata, JettonWalletsResponse, MessageTransactions, NftCollectionsResponse, NftItemsResponse,
-    SimpleJettonBalance, WalletInfo,
+    NominatorPools, SimpleJettonBalance, StakingPools, WalletInfo,
 };
 
+const TONAPI_URL: &str = "https://tonapi.io";
+
 pub struct TonClient<C: Client> {
     pub client: C,
 }
@@ -82,6 +84,14 @@ impl<C: Client> TonClient<C> {
         Ok(self.client.get(&format!("/api/v3/nft/collections?collection_address={}", collection_address)).await?)
     }
 
+    pub async fn get_staking_pools(&self) -> Result<StakingPools, Box<dyn Error + Send + Sync>> {
+        Ok(self.client.get_url(&format!("{}/v2/staking/pools?include_unverified=false", TONAPI_URL)).await?)
+    }
+
+    pub async fn get_nominator_pools(&self, address: &str) -> Result<NominatorPools, Box<dyn Error + Send + Sync>> {
+        Ok(self.client.get_url(&format!("{}/v2/staking/nominator/{}/pools", TONAPI_URL, address)).await?)
+    }
+
     pub async fn get_token_data(&self, token_id: String) -> Result<Asset, Box<dyn Error + Send + Sync>> {
         let token_info = self.get_token_info(token_id.clone()).await?.result;
         let data = &token_info.jetton_content.data;
@@ -108,7 +118,6 @@ impl<C: Client> ChainTraits for TonClient<C> {}
 impl<C: Client> ChainAccount for TonClient<C> {}
 impl<C: Client> ChainPerpetual for TonClient<C> {}
 impl<C: Client> ChainAddressStatus for TonClient<C> {}
-impl<C: Client> ChainStaking for TonClient<C> {}
 impl<C: Client> chain_traits::ChainProvider for TonClient<C> {
     fn get_chain(&self) -> primitives::Chain {
         Chain::Ton
@@ -1,6 +1,7 @@
 pub(super) mod message;
 pub(super) mod request;
 mod sign;
+mod stake;
 mod wallet;
 
 pub use wallet::WalletV4R2;
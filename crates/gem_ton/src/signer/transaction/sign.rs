@@ -7,6 +7,7 @@ use primitives::{FeeOption, SignerError, SignerInput};
 use super::{
     message::{InternalMessage, build_internal_message},
     request::{JettonTransferRequest, TransferRequest},
+    stake::build_stake_request,
 };
 use crate::{
     address::Address,
@@ -57,6 +58,11 @@ impl TonSigner {
         Ok(vec![self.sign_requests(vec![request], input.metadata.get_sequence()?, expire_at)?])
     }
 
+    pub fn sign_stake(&self, input: &SignerInput, expire_at: Option<u32>) -> Result<Vec<String>, SignerError> {
+        let request = build_stake_request(input)?;
+        Ok(vec![self.sign_requests(vec![request], input.metadata.get_sequence()?, expire_at)?])
+    }
+
     pub(crate) fn sign_requests(&self, requests: Vec<TransferRequest>, sequence: u64, expire_at: Option<u32>) -> Result<String, SignerError> {
         let sequence = u32::try_from(sequence).map_err(|_| SignerError::invalid_input("TON sequence does not fit in u32"))?;
         let expire_at = resolve_expire_at(sequence, expire_at)?;
@@ -0,0 +1,205 @@
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+use primitives::{Delegation, SignerError, SignerInput, StakeType, TonStakePoolType};
+
+use super::request::TransferRequest;
+use crate::{
+    address::Address,
+    constants::{
+        JETTON_BURN_OPCODE, LIQUID_DEPOSIT_OPCODE, NOMINATOR_DEPOSIT_COMMENT, NOMINATOR_WITHDRAW_COMMENT, WHALES_DEPOSIT_OPCODE, WHALES_GAS_LIMIT, WHALES_WITHDRAW_OPCODE,
+        stake_withdraw_fee,
+    },
+    signer::cells::{CellArc, CellBuilder},
+};
+
+pub(super) fn build_stake_request(input: &SignerInput) -> Result<TransferRequest, SignerError> {
+    let pool_type = input.metadata.get_ton_stake_pool_type()?;
+    match input.input_type.get_stake_type()? {
+        StakeType::Stake(validator) => build_deposit_request(pool_type, &validator.id, &input.value),
+        StakeType::Unstake(delegation) => build_withdraw_request(pool_type, input, delegation, &BigUint::from_str(&input.value)?),
+        StakeType::Withdraw(delegation) => match pool_type {
+            TonStakePoolType::Liquid => SignerError::invalid_input_err("liquid staking withdrawals are paid out automatically"),
+            _ => build_withdraw_request(pool_type, input, delegation, &delegation.base.balance),
+        },
+        _ => SignerError::invalid_input_err("unsupported TON stake type"),
+    }
+}
+
+fn build_deposit_request(pool_type: TonStakePoolType, pool: &str, amount: &str) -> Result<TransferRequest, SignerError> {
+    match pool_type {
+        TonStakePoolType::Nominator => TransferRequest::new_with_payload(pool, amount, Some(NOMINATOR_DEPOSIT_COMMENT.to_string()), None, true, None),
+        TonStakePoolType::Whales => {
+            let mut builder = CellBuilder::new();
+            builder
+                .store_u32(32, WHALES_DEPOSIT_OPCODE)?
+                .store_u64(64, 0)?
+                .store_coins(&BigUint::from(WHALES_GAS_LIMIT))?;
+            TransferRequest::new_with_payload(pool, amount, None, Some(builder.build()?.into_arc()), true, None)
+        }
+        TonStakePoolType::Liquid => {
+            let mut builder = CellBuilder::new();
+            builder.store_u32(32, LIQUID_DEPOSIT_OPCODE)?.store_u64(64, 0)?;
+            TransferRequest::new_with_payload(pool, amount, None, Some(builder.build()?.into_arc()), true, None)
+        }
+    }
+}
+
+fn build_withdraw_request(pool_type: TonStakePoolType, input: &SignerInput, delegation: &Delegation, amount: &BigUint) -> Result<TransferRequest, SignerError> {
+    let fee = stake_withdraw_fee(pool_type).to_string();
+    let pool = &delegation.base.validator_id;
+    match pool_type {
+        TonStakePoolType::Nominator => TransferRequest::new_with_payload(pool, &fee, Some(NOMINATOR_WITHDRAW_COMMENT.to_string()), None, true, None),
+        TonStakePoolType::Whales => {
+            let mut builder = CellBuilder::new();
+            builder
+                .store_u32(32, WHALES_WITHDRAW_OPCODE)?
+                .store_u64(64, 0)?
+                .store_coins(&BigUint::from(WHALES_GAS_LIMIT))?
+                .store_coins(amount)?;
+            TransferRequest::new_with_payload(pool, &fee, None, Some(builder.build()?.into_arc()), true, None)
+        }
+        TonStakePoolType::Liquid => {
+            let jetton_wallet = input
+                .metadata
+                .get_sender_token_address()?
+                .ok_or_else(|| SignerError::invalid_input("missing liquid staking jetton wallet"))?;
+            let payload = build_burn_payload(&liquid_shares(delegation, amount)?, &Address::parse(&input.sender_address)?)?;
+            TransferRequest::new_with_payload(&jetton_wallet, &fee, None, Some(payload), true, None)
+        }
+    }
+}
+
+fn liquid_shares(delegation: &Delegation, amount: &BigUint) -> Result<BigUint, SignerError> {
+    let base = &delegation.base;
+    if base.shares == BigUint::ZERO || base.balance == BigUint::ZERO {
+        return SignerError::invalid_input_err("missing liquid staking shares");
+    }
+    Ok((amount * &base.shares / &base.balance).min(base.shares.clone()))
+}
+
+fn build_burn_payload(amount: &BigUint, response_address: &Address) -> Result<CellArc, SignerError> {
+    let mut builder = CellBuilder::new();
+    builder
+        .store_u32(32, JETTON_BURN_OPCODE)?
+        .store_u64(64, 0)?
+        .store_coins(amount)?
+        .store_address(response_address)?
+        .store_bit(false)?;
+    Ok(builder.build()?.into_arc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::{testkit::mock_signer_address, transaction::request::TransferPayload};
+    use primitives::{Asset, Chain, DelegationValidator, TransactionInputType, TransactionLoadMetadata};
+
+    const POOL: &str = "EQCkWxfyhAkim3g2DjKQQg8T5P4g-Q1-K_jErGcDJZ4i-vqR";
+    const JETTON_WALLET: &str = "EQAlgB03OjJKdXrlwZiGJD5snSzPKF2VL5bErJn_cqJANGH9";
+
+    fn delegation(balance: u64, shares: u64) -> Delegation {
+        let mut delegation = Delegation::mock();
+        delegation.base.validator_id = POOL.to_string();
+        delegation.base.balance = BigUint::from(balance);
+        delegation.base.shares = BigUint::from(shares);
+        delegation
+    }
+
+    fn stake_input(stake_type: StakeType, value: &str, pool_type: TonStakePoolType) -> SignerInput {
+        let address = mock_signer_address();
+        SignerInput::mock_with_input_type(
+            TransactionInputType::Stake(Asset::from_chain(Chain::Ton), stake_type),
+            &address,
+            POOL,
+            value,
+            TransactionLoadMetadata::mock_ton_stake(1, pool_type, Some(JETTON_WALLET)),
+        )
+    }
+
+    fn deposit_input(value: &str, pool_type: TonStakePoolType) -> SignerInput {
+        let validator = DelegationValidator::stake(Chain::Ton, POOL.to_string(), String::new(), true, 0.0, 4.0);
+        stake_input(StakeType::Stake(validator), value, pool_type)
+    }
+
+    fn assert_payload(request: &TransferRequest, builder: CellBuilder) {
+        let Some(TransferPayload::Custom(payload)) = &request.payload else {
+            panic!("expected custom payload");
+        };
+        assert_eq!(payload.hash, builder.build().unwrap().hash);
+    }
+
+    #[test]
+    fn test_nominator_requests() {
+        let deposit = build_stake_request(&deposit_input("10000000000", TonStakePoolType::Nominator)).unwrap();
+        assert_eq!(deposit.destination, Address::parse(POOL).unwrap());
+        assert_eq!(deposit.value, BigUint::from(10_000_000_000u64));
+        assert_eq!(deposit.comment.as_deref(), Some("d"));
+        assert!(deposit.bounceable);
+
+        let withdraw = build_stake_request(&stake_input(StakeType::Unstake(delegation(10, 0)), "10", TonStakePoolType::Nominator)).unwrap();
+        assert_eq!(withdraw.value, BigUint::from(1_000_000_000u64));
+        assert_eq!(withdraw.comment.as_deref(), Some("w"));
+    }
+
+    #[test]
+    fn test_whales_requests() {
+        let gas_limit = BigUint::from(WHALES_GAS_LIMIT);
+
+        let deposit = build_stake_request(&deposit_input("50000000000", TonStakePoolType::Whales)).unwrap();
+        assert_eq!(deposit.value, BigUint::from(50_000_000_000u64));
+        let mut expected = CellBuilder::new();
+        expected
+            .store_u32(32, WHALES_DEPOSIT_OPCODE)
+            .unwrap()
+            .store_u64(64, 0)
+            .unwrap()
+            .store_coins(&gas_limit)
+            .unwrap();
+        assert_payload(&deposit, expected);
+
+        let withdraw = build_stake_request(&stake_input(StakeType::Withdraw(delegation(7_000_000_000, 0)), "0", TonStakePoolType::Whales)).unwrap();
+        assert_eq!(withdraw.destination, Address::parse(POOL).unwrap());
+        assert_eq!(withdraw.value, BigUint::from(200_000_000u64));
+        let mut expected = CellBuilder::new();
+        expected
+            .store_u32(32, WHALES_WITHDRAW_OPCODE)
+            .unwrap()
+            .store_u64(64, 0)
+            .unwrap()
+            .store_coins(&gas_limit)
+            .unwrap()
+            .store_coins(&BigUint::from(7_000_000_000u64))
+            .unwrap();
+        assert_payload(&withdraw, expected);
+    }
+
+    #[test]
+    fn test_liquid_requests() {
+        let deposit = build_stake_request(&deposit_input("1000000000", TonStakePoolType::Liquid)).unwrap();
+        let mut expected = CellBuilder::new();
+        expected.store_u32(32, LIQUID_DEPOSIT_OPCODE).unwrap().store_u64(64, 0).unwrap();
+        assert_payload(&deposit, expected);
+
+        let input = stake_input(StakeType::Unstake(delegation(3_000_000_000, 2_900_000_000)), "1500000000", TonStakePoolType::Liquid);
+        let burn = build_stake_request(&input).unwrap();
+        assert_eq!(burn.destination, Address::parse(JETTON_WALLET).unwrap());
+        assert_eq!(burn.value, BigUint::from(1_000_000_000u64));
+        let mut expected = CellBuilder::new();
+        expected
+            .store_u32(32, JETTON_BURN_OPCODE)
+            .unwrap()
+            .store_u64(64, 0)
+            .unwrap()
+            .store_coins(&BigUint::from(1_450_000_000u64))
+            .unwrap()
+            .store_address(&Address::parse(&input.sender_address).unwrap())
+            .unwrap()
+            .store_bit(false)
+            .unwrap();
+        assert_payload(&burn, expected);
+
+        assert!(build_stake_request(&stake_input(StakeType::Withdraw(delegation(1, 1)), "1", TonStakePoolType::Liquid)).is_err());
+        assert!(build_stake_request(&stake_input(StakeType::Unstake(delegation(3_000_000_000, 0)), "1", TonStakePoolType::Liquid)).is_err());
+    }
+}
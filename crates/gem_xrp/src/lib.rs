@@ -8,3 +8,6 @@ pub use constants::*;
 
 #[cfg(feature = "rpc")]
 pub mod provider;
+
+#[cfg(feature = "signer")]
+pub mod signer;
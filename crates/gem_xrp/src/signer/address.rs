@@ -0,0 +1,53 @@
+use gem_hash::ripemd::hash160;
+use primitives::SignerError;
+
+const ACCOUNT_ID_VERSION: u8 = 0x00;
+const ACCOUNT_ID_LENGTH: usize = 20;
+
+pub type AccountId = [u8; ACCOUNT_ID_LENGTH];
+
+pub fn decode_address(address: &str) -> Result<AccountId, SignerError> {
+    let decoded = bs58::decode(address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check(Some(ACCOUNT_ID_VERSION))
+        .into_vec()
+        .map_err(|_| SignerError::invalid_input(format!("Invalid XRP address: {address}")))?;
+    decoded
+        .get(1..)
+        .and_then(|account_id| account_id.try_into().ok())
+        .ok_or_else(|| SignerError::invalid_input(format!("Invalid XRP address: {address}")))
+}
+
+#[cfg_attr(not(test), allow(unused))]
+pub fn encode_address(account_id: &AccountId) -> String {
+    bs58::encode([&[ACCOUNT_ID_VERSION][..], account_id].concat())
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check()
+        .into_string()
+}
+
+pub fn account_id_from_public_key(public_key: &[u8]) -> AccountId {
+    hash160(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_round_trip() {
+        // Genesis account, secp256k1 key of seed snoPBrXtMeMyMHUVTgbuqAfg1SUTb
+        let public_key = hex::decode("0330e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020").unwrap();
+        let account_id = account_id_from_public_key(&public_key);
+
+        assert_eq!(hex::encode(account_id), "b5f762798a53d543a014caf8b297cff8f2f937e8");
+        assert_eq!(encode_address(&account_id), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(decode_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap(), account_id);
+    }
+
+    #[test]
+    fn test_decode_invalid_address() {
+        assert!(decode_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi").is_err());
+        assert!(decode_address("0x1234").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use primitives::SignerError;
+
+use super::address::AccountId;
+
+const NATIVE_POSITIVE_FLAG: u64 = 0x4000_0000_0000_0000;
+const ISSUED_FLAG: u64 = 0x8000_0000_0000_0000;
+const ISSUED_POSITIVE_FLAG: u64 = 0x4000_0000_0000_0000;
+const MAX_DROPS: u64 = 100_000_000_000_000_000;
+
+const MIN_MANTISSA: u64 = 1_000_000_000_000_000;
+const MAX_MANTISSA: u64 = 9_999_999_999_999_999;
+const MIN_EXPONENT: i32 = -96;
+const MAX_EXPONENT: i32 = 80;
+const EXPONENT_BIAS: i32 = 97;
+
+const CURRENCY_LENGTH: usize = 20;
+const STANDARD_CURRENCY_OFFSET: usize = 12;
+
+pub type Currency = [u8; CURRENCY_LENGTH];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IssuedValue {
+    mantissa: u64,
+    exponent: i32,
+}
+
+impl IssuedValue {
+    pub const MAX: Self = Self {
+        mantissa: MAX_MANTISSA,
+        exponent: MAX_EXPONENT,
+    };
+
+    pub fn from_units(value: &str, decimals: u32) -> Result<Self, SignerError> {
+        let mut mantissa = value
+            .parse::<BigUint>()
+            .map_err(|_| SignerError::invalid_input(format!("Invalid XRP token amount: {value}")))?;
+        if mantissa.is_zero() {
+            return Ok(Self { mantissa: 0, exponent: 0 });
+        }
+        let mut exponent = -(decimals as i32);
+        let ten = BigUint::from(10u32);
+        while mantissa > BigUint::from(MAX_MANTISSA) {
+            if !(&mantissa % &ten).is_zero() {
+                return SignerError::invalid_input_err("XRP token amount exceeds 16 significant digits");
+            }
+            mantissa /= &ten;
+            exponent += 1;
+        }
+        let mut mantissa = mantissa.to_u64().unwrap_or_default();
+        while mantissa < MIN_MANTISSA {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return SignerError::invalid_input_err("XRP token amount out of range");
+        }
+        Ok(Self { mantissa, exponent })
+    }
+
+    fn encode(&self) -> u64 {
+        if self.mantissa == 0 {
+            return ISSUED_FLAG;
+        }
+        ISSUED_FLAG | ISSUED_POSITIVE_FLAG | (((self.exponent + EXPONENT_BIAS) as u64) << 54) | self.mantissa
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Amount {
+    Xrp(u64),
+    Issued { value: IssuedValue, currency: Currency, issuer: AccountId },
+}
+
+impl Amount {
+    pub fn xrp(drops: u64) -> Result<Self, SignerError> {
+        if drops > MAX_DROPS {
+            return SignerError::invalid_input_err("XRP amount out of range");
+        }
+        Ok(Self::Xrp(drops))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Xrp(drops) => (NATIVE_POSITIVE_FLAG | drops).to_be_bytes().to_vec(),
+            Self::Issued { value, currency, issuer } => [&value.encode().to_be_bytes()[..], currency, issuer].concat(),
+        }
+    }
+}
+
+pub fn encode_currency(symbol: &str) -> Result<Currency, SignerError> {
+    let mut currency = [0u8; CURRENCY_LENGTH];
+    match symbol.len() {
+        3 if symbol.is_ascii() && symbol != "XRP" => currency[STANDARD_CURRENCY_OFFSET..STANDARD_CURRENCY_OFFSET + 3].copy_from_slice(symbol.as_bytes()),
+        40 if hex::decode(symbol).is_ok_and(|bytes| bytes[0] != 0) => currency.copy_from_slice(&hex::decode(symbol).unwrap_or_default()),
+        4..=CURRENCY_LENGTH if symbol.is_ascii() => currency[..symbol.len()].copy_from_slice(symbol.as_bytes()),
+        _ => return SignerError::invalid_input_err(format!("Invalid XRP currency: {symbol}")),
+    }
+    Ok(currency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::address::decode_address;
+
+    #[test]
+    fn test_encode_xrp_amount() {
+        assert_eq!(hex::encode(Amount::xrp(10).unwrap().encode()), "400000000000000a");
+        assert_eq!(hex::encode(Amount::xrp(0).unwrap().encode()), "4000000000000000");
+        assert!(Amount::xrp(MAX_DROPS + 1).is_err());
+    }
+
+    #[test]
+    fn test_encode_issued_amount() {
+        let issued = |value: &str, decimals: u32| hex::encode(IssuedValue::from_units(value, decimals).unwrap().encode().to_be_bytes());
+
+        assert_eq!(issued("1", 0), "d4838d7ea4c68000");
+        assert_eq!(issued("1000000000000000", 15), "d4838d7ea4c68000");
+        assert_eq!(issued("1500000000000000", 15), "d485543df729c000");
+        assert_eq!(issued("0", 15), "8000000000000000");
+        assert_eq!(issued("12345678901234560000", 0), "d94462d53c8abac0");
+        assert!(IssuedValue::from_units("12345678901234567", 0).is_err());
+        assert!(IssuedValue::from_units("-1", 0).is_err());
+
+        let amount = Amount::Issued {
+            value: IssuedValue::from_units("1", 0).unwrap(),
+            currency: encode_currency("USD").unwrap(),
+            issuer: decode_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap(),
+        };
+        assert_eq!(
+            hex::encode(amount.encode()),
+            "d4838d7ea4c6800000000000000000000000000055534400000000000a20b3c85f482532a9578dbb3950b85ca06594d1"
+        );
+    }
+
+    #[test]
+    fn test_encode_currency() {
+        assert_eq!(hex::encode(encode_currency("RLUSD").unwrap()), "524c555344000000000000000000000000000000");
+        assert_eq!(
+            hex::encode(encode_currency("524C555344000000000000000000000000000000").unwrap()),
+            "524c555344000000000000000000000000000000"
+        );
+        assert!(encode_currency("XRP").is_err());
+        assert!(encode_currency("").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+use primitives::{ChainSigner, SignerError, SignerInput};
+
+use super::signing::sign_transaction;
+use super::transaction::XrpTransaction;
+
+#[derive(Default)]
+pub struct XrpChainSigner;
+
+impl ChainSigner for XrpChainSigner {
+    fn sign_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        sign_transaction(&XrpTransaction::transfer(input)?, private_key)
+    }
+
+    fn sign_token_transfer(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        sign_transaction(&XrpTransaction::token_transfer(input)?, private_key)
+    }
+
+    fn sign_account_action(&self, input: &SignerInput, private_key: &[u8]) -> Result<String, SignerError> {
+        sign_transaction(&XrpTransaction::account_action(input)?, private_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Payment vector taken from https://github.com/trustwallet/wallet-core/blob/master/tests/chains/XRP/TWAnySignerTests.cpp
+    use super::*;
+    use crate::signer::address::{account_id_from_public_key, encode_address};
+    use primitives::{Asset, AssetType, Chain, TransactionFee, TransactionLoadInput, TransactionLoadMetadata};
+    use signer::Ed25519KeyPair;
+
+    const PRIVATE_KEY: &str = "a5576c0f63da10e584568c8d134569ff44017b0a249eb70657127ae04f38cc77";
+    const SENDER: &str = "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq";
+    const DESTINATION: &str = "rU893viamSnsfP3zjzM2KPxjqZjXSXK6VF";
+    const ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+
+    fn input(asset: Asset, sender: &str, value: &str, memo: Option<&str>) -> SignerInput {
+        SignerInput::new(
+            TransactionLoadInput::mock_transfer(
+                asset,
+                sender,
+                DESTINATION,
+                value,
+                10,
+                memo,
+                TransactionLoadMetadata::Xrp {
+                    sequence: 32268248,
+                    block_number: 32268249,
+                },
+            ),
+            TransactionFee::new_from_fee(10.into()),
+        )
+    }
+
+    fn usd() -> Asset {
+        Asset::mock_with_params(Chain::Xrp, Some(ISSUER.into()), "USD".into(), "USD".into(), 15, AssetType::TOKEN)
+    }
+
+    #[test]
+    fn test_sign_xrp_transfer() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+
+        let signed = XrpChainSigner.sign_transfer(&input(Asset::from_chain(Chain::Xrp), SENDER, "10", None), &key).unwrap();
+        assert_eq!(
+            signed,
+            "12000022000000002401ec5fd8201b01ec5fed61400000000000000a68400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74463044022037d32835c9394f39b2cfd4eaf5b0a80e0db397ace06630fa2b099ff73e425dbc02205288f780330b7a88a1980fa83c647b5908502ad7de9a44500c08f0750b0d9e8481144c55f5a78067206507580be7bb2686c8460adff983148132e4e20aecf29090ac428a9c43f230a829220d"
+        );
+
+        // Numeric memo becomes a DestinationTag (sfDestinationTag = 0x2e)
+        let signed = XrpChainSigner
+            .sign_transfer(&input(Asset::from_chain(Chain::Xrp), SENDER, "10", Some("12345")), &key)
+            .unwrap();
+        assert!(signed.contains("2e00003039"));
+        assert!(!signed.contains("f9ea"));
+
+        // Text memo is attached as MemoData inside Memos
+        let signed = XrpChainSigner
+            .sign_transfer(&input(Asset::from_chain(Chain::Xrp), SENDER, "10", Some("gem")), &key)
+            .unwrap();
+        assert!(signed.contains("f9ea7d0367656de1f1"));
+    }
+
+    #[test]
+    fn test_sign_xrp_token_transfer() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let signed = XrpChainSigner.sign_token_transfer(&input(usd(), SENDER, "1000000000000000", None), &key).unwrap();
+
+        assert!(signed.starts_with("120000"));
+        assert!(signed.contains("61d4838d7ea4c6800000000000000000000000000055534400000000000a20b3c85f482532a9578dbb3950b85ca06594d1"));
+    }
+
+    #[test]
+    fn test_sign_xrp_trust_set() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let signed = XrpChainSigner.sign_account_action(&input(usd(), SENDER, "0", None), &key).unwrap();
+
+        assert!(signed.starts_with("120014"));
+        assert!(signed.contains("2200020000"));
+        assert!(signed.contains("63ec6386f26fc0ffff0000000000000000000000005553440000000000"));
+    }
+
+    #[test]
+    fn test_sign_xrp_ed25519() {
+        let key = hex::decode(PRIVATE_KEY).unwrap();
+        let public_key = [&[0xed][..], &Ed25519KeyPair::from_private_key(&key).unwrap().public_key_bytes].concat();
+        let sender = encode_address(&account_id_from_public_key(&public_key));
+
+        let signed = XrpChainSigner.sign_transfer(&input(Asset::from_chain(Chain::Xrp), &sender, "10", None), &key).unwrap();
+
+        assert!(signed.contains(&format!("7321{}7440", hex::encode(public_key))));
+    }
+
+    #[test]
+    fn test_sign_xrp_wrong_key() {
+        let key = hex::decode("59a313f46ef1c23a9e4f71cea10fc0c56a2a6bb8a4b9ea3d5348823e5a478722").unwrap();
+
+        assert!(XrpChainSigner.sign_transfer(&input(Asset::from_chain(Chain::Xrp), SENDER, "10", None), &key).is_err());
+    }
+}
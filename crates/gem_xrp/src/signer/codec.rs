@@ -0,0 +1,142 @@
+const OBJECT_END_MARKER: u8 = 0xe1;
+const ARRAY_END_MARKER: u8 = 0xf1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FieldType {
+    UInt16 = 1,
+    UInt32 = 2,
+    Amount = 6,
+    Blob = 7,
+    AccountId = 8,
+    Object = 14,
+    Array = 15,
+}
+
+impl FieldType {
+    fn is_variable_length(&self) -> bool {
+        match self {
+            Self::Blob | Self::AccountId => true,
+            Self::UInt16 | Self::UInt32 | Self::Amount | Self::Object | Self::Array => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Field {
+    field_type: FieldType,
+    field_code: u8,
+}
+
+impl Field {
+    const fn new(field_type: FieldType, field_code: u8) -> Self {
+        Self { field_type, field_code }
+    }
+
+    pub const TRANSACTION_TYPE: Self = Self::new(FieldType::UInt16, 2);
+    pub const FLAGS: Self = Self::new(FieldType::UInt32, 2);
+    pub const SEQUENCE: Self = Self::new(FieldType::UInt32, 4);
+    pub const DESTINATION_TAG: Self = Self::new(FieldType::UInt32, 14);
+    pub const LAST_LEDGER_SEQUENCE: Self = Self::new(FieldType::UInt32, 27);
+    pub const AMOUNT: Self = Self::new(FieldType::Amount, 1);
+    pub const LIMIT_AMOUNT: Self = Self::new(FieldType::Amount, 3);
+    pub const FEE: Self = Self::new(FieldType::Amount, 8);
+    pub const SIGNING_PUB_KEY: Self = Self::new(FieldType::Blob, 3);
+    pub const TXN_SIGNATURE: Self = Self::new(FieldType::Blob, 4);
+    pub const MEMO_DATA: Self = Self::new(FieldType::Blob, 13);
+    pub const ACCOUNT: Self = Self::new(FieldType::AccountId, 1);
+    pub const DESTINATION: Self = Self::new(FieldType::AccountId, 3);
+    pub const MEMO: Self = Self::new(FieldType::Object, 10);
+    pub const MEMOS: Self = Self::new(FieldType::Array, 9);
+
+    fn header(&self) -> Vec<u8> {
+        encode_field_id(self.field_type as u8, self.field_code)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Serializer {
+    fields: Vec<(Field, Vec<u8>)>,
+}
+
+impl Serializer {
+    pub fn add(&mut self, field: Field, value: impl Into<Vec<u8>>) {
+        self.fields.push((field, value.into()));
+    }
+
+    pub fn add_u16(&mut self, field: Field, value: u16) {
+        self.add(field, value.to_be_bytes());
+    }
+
+    pub fn add_u32(&mut self, field: Field, value: u32) {
+        self.add(field, value.to_be_bytes());
+    }
+
+    pub fn add_array(&mut self, field: Field, object_field: Field, objects: Vec<Serializer>) {
+        let mut data = vec![];
+        for object in objects {
+            data.extend(object_field.header());
+            data.extend(object.serialize());
+            data.push(OBJECT_END_MARKER);
+        }
+        data.push(ARRAY_END_MARKER);
+        self.add(field, data);
+    }
+
+    pub fn serialize(mut self) -> Vec<u8> {
+        self.fields.sort_by_key(|(field, _)| *field);
+        let mut data = vec![];
+        for (field, value) in self.fields {
+            data.extend(field.header());
+            if field.field_type.is_variable_length() {
+                data.extend(encode_length(value.len()));
+            }
+            data.extend(value);
+        }
+        data
+    }
+}
+
+fn encode_field_id(type_code: u8, field_code: u8) -> Vec<u8> {
+    match (type_code < 16, field_code < 16) {
+        (true, true) => vec![type_code << 4 | field_code],
+        (true, false) => vec![type_code << 4, field_code],
+        (false, true) => vec![field_code, type_code],
+        (false, false) => vec![0, type_code, field_code],
+    }
+}
+
+fn encode_length(length: usize) -> Vec<u8> {
+    match length {
+        0..=192 => vec![length as u8],
+        193..=12480 => {
+            let length = length - 193;
+            vec![193 + (length >> 8) as u8, length as u8]
+        }
+        _ => {
+            let length = length - 12481;
+            vec![241 + (length >> 16) as u8, (length >> 8) as u8, length as u8]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_header() {
+        assert_eq!(Field::TRANSACTION_TYPE.header(), vec![0x12]);
+        assert_eq!(Field::LAST_LEDGER_SEQUENCE.header(), vec![0x20, 0x1b]);
+        assert_eq!(Field::MEMOS.header(), vec![0xf9]);
+        assert_eq!(encode_field_id(16, 1), vec![0x01, 0x10]);
+        assert_eq!(encode_field_id(16, 17), vec![0x00, 0x10, 0x11]);
+    }
+
+    #[test]
+    fn test_encode_length() {
+        assert_eq!(encode_length(192), vec![0xc0]);
+        assert_eq!(encode_length(193), vec![0xc1, 0x00]);
+        assert_eq!(encode_length(12480), vec![0xf0, 0xff]);
+        assert_eq!(encode_length(12481), vec![0xf1, 0x00, 0x00]);
+    }
+}
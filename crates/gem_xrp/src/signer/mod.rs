@@ -0,0 +1,8 @@
+mod address;
+mod amount;
+mod chain_signer;
+mod codec;
+mod signing;
+mod transaction;
+
+pub use chain_signer::XrpChainSigner;
@@ -0,0 +1,61 @@
+use gem_hash::sha2::sha512;
+use primitives::SignerError;
+use signer::{Ed25519KeyPair, RECOVERY_ID_INDEX, SignatureScheme, Signer, encode_der_signature, secp256k1_public_key};
+
+use super::address::{AccountId, account_id_from_public_key};
+use super::transaction::XrpTransaction;
+
+const TRANSACTION_SIGN_PREFIX: &[u8] = b"STX\0";
+const ED25519_PUBLIC_KEY_PREFIX: u8 = 0xed;
+
+enum SigningKey {
+    Secp256k1 { public_key: Vec<u8> },
+    Ed25519 { key_pair: Box<Ed25519KeyPair>, public_key: Vec<u8> },
+}
+
+impl SigningKey {
+    fn for_account(private_key: &[u8], account: &AccountId) -> Result<Self, SignerError> {
+        let public_key = secp256k1_public_key(private_key)?;
+        if account_id_from_public_key(&public_key) == *account {
+            return Ok(Self::Secp256k1 { public_key });
+        }
+        let key_pair = Ed25519KeyPair::from_private_key(private_key)?;
+        let public_key = [&[ED25519_PUBLIC_KEY_PREFIX][..], &key_pair.public_key_bytes].concat();
+        if account_id_from_public_key(&public_key) == *account {
+            return Ok(Self::Ed25519 {
+                key_pair: Box::new(key_pair),
+                public_key,
+            });
+        }
+        SignerError::invalid_input_err("Private key does not match sender address")
+    }
+
+    fn public_key(&self) -> &[u8] {
+        match self {
+            Self::Secp256k1 { public_key } | Self::Ed25519 { public_key, .. } => public_key,
+        }
+    }
+
+    fn sign(&self, message: &[u8], private_key: &[u8]) -> Result<Vec<u8>, SignerError> {
+        match self {
+            Self::Secp256k1 { .. } => {
+                let signature = Signer::sign_digest(SignatureScheme::Secp256k1, sha512_half(message).to_vec(), private_key.to_vec())?;
+                Ok(encode_der_signature(&signature[..RECOVERY_ID_INDEX]))
+            }
+            Self::Ed25519 { key_pair, .. } => Ok(key_pair.sign(message).to_vec()),
+        }
+    }
+}
+
+fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&sha512(data)[..32]);
+    hash
+}
+
+pub(crate) fn sign_transaction(transaction: &XrpTransaction, private_key: &[u8]) -> Result<String, SignerError> {
+    let key = SigningKey::for_account(private_key, &transaction.account)?;
+    let message = [TRANSACTION_SIGN_PREFIX, &transaction.serialize(key.public_key(), None)].concat();
+    let signature = key.sign(&message, private_key)?;
+    Ok(hex::encode(transaction.serialize(key.public_key(), Some(&signature))))
+}
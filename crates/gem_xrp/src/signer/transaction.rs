@@ -0,0 +1,149 @@
+use num_traits::ToPrimitive;
+use primitives::{SignerError, SignerInput};
+use signer::InvalidInput;
+
+use super::address::{AccountId, decode_address};
+use super::amount::{Amount, IssuedValue, encode_currency};
+use super::codec::{Field, Serializer};
+
+const TRANSACTION_TYPE_PAYMENT: u16 = 0;
+const TRANSACTION_TYPE_TRUST_SET: u16 = 20;
+
+const TF_SET_NO_RIPPLE: u32 = 0x0002_0000;
+
+// Ledgers (~3-4s each) a transaction stays valid for, matching xrpl.js autofill
+const LAST_LEDGER_SEQUENCE_OFFSET: u64 = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XrpOperation {
+    Payment {
+        destination: AccountId,
+        amount: Amount,
+        destination_tag: Option<u32>,
+    },
+    TrustSet {
+        limit: Amount,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XrpTransaction {
+    pub account: AccountId,
+    pub fee: u64,
+    pub sequence: u32,
+    pub last_ledger_sequence: Option<u32>,
+    pub flags: u32,
+    pub memo: Option<Vec<u8>>,
+    pub operation: XrpOperation,
+}
+
+impl XrpTransaction {
+    pub fn transfer(input: &SignerInput) -> Result<Self, SignerError> {
+        let drops = input.value.parse::<u64>().invalid_input("invalid XRP amount")?;
+        Self::payment(input, Amount::xrp(drops)?)
+    }
+
+    pub fn token_transfer(input: &SignerInput) -> Result<Self, SignerError> {
+        let asset = input.input_type.get_asset();
+        let amount = Amount::Issued {
+            value: IssuedValue::from_units(&input.value, asset.decimals as u32)?,
+            currency: encode_currency(&asset.symbol)?,
+            issuer: token_issuer(input)?,
+        };
+        Self::payment(input, amount)
+    }
+
+    pub fn account_action(input: &SignerInput) -> Result<Self, SignerError> {
+        let limit = Amount::Issued {
+            value: IssuedValue::MAX,
+            currency: encode_currency(&input.input_type.get_asset().symbol)?,
+            issuer: token_issuer(input)?,
+        };
+        let (_, memo) = parse_memo(input.memo.as_deref());
+        Self::build(input, TF_SET_NO_RIPPLE, memo, XrpOperation::TrustSet { limit })
+    }
+
+    fn payment(input: &SignerInput, amount: Amount) -> Result<Self, SignerError> {
+        let (destination_tag, memo) = parse_memo(input.memo.as_deref());
+        let operation = XrpOperation::Payment {
+            destination: decode_address(&input.destination_address)?,
+            amount,
+            destination_tag,
+        };
+        Self::build(input, 0, memo, operation)
+    }
+
+    fn build(input: &SignerInput, flags: u32, memo: Option<Vec<u8>>, operation: XrpOperation) -> Result<Self, SignerError> {
+        let sequence = input.metadata.get_sequence().map_err(SignerError::from_display)?;
+        let block_number = input.metadata.get_block_number().map_err(SignerError::from_display)?;
+        Ok(Self {
+            account: decode_address(&input.sender_address)?,
+            fee: input.fee.fee.to_u64().invalid_input("invalid XRP fee")?,
+            sequence: sequence.to_u32().invalid_input("invalid XRP sequence")?,
+            last_ledger_sequence: Some(
+                block_number
+                    .checked_add(LAST_LEDGER_SEQUENCE_OFFSET)
+                    .and_then(|value| value.to_u32())
+                    .invalid_input("invalid XRP ledger index")?,
+            ),
+            flags,
+            memo,
+            operation,
+        })
+    }
+
+    pub fn serialize(&self, signing_public_key: &[u8], signature: Option<&[u8]>) -> Vec<u8> {
+        let mut serializer = Serializer::default();
+        serializer.add_u32(Field::FLAGS, self.flags);
+        serializer.add_u32(Field::SEQUENCE, self.sequence);
+        if let Some(last_ledger_sequence) = self.last_ledger_sequence {
+            serializer.add_u32(Field::LAST_LEDGER_SEQUENCE, last_ledger_sequence);
+        }
+        serializer.add(Field::FEE, Amount::Xrp(self.fee).encode());
+        serializer.add(Field::SIGNING_PUB_KEY, signing_public_key);
+        if let Some(signature) = signature {
+            serializer.add(Field::TXN_SIGNATURE, signature);
+        }
+        serializer.add(Field::ACCOUNT, self.account);
+        if let Some(memo) = &self.memo {
+            let mut object = Serializer::default();
+            object.add(Field::MEMO_DATA, memo.clone());
+            serializer.add_array(Field::MEMOS, Field::MEMO, vec![object]);
+        }
+
+        match &self.operation {
+            XrpOperation::Payment {
+                destination,
+                amount,
+                destination_tag,
+            } => {
+                serializer.add_u16(Field::TRANSACTION_TYPE, TRANSACTION_TYPE_PAYMENT);
+                serializer.add(Field::AMOUNT, amount.encode());
+                serializer.add(Field::DESTINATION, *destination);
+                if let Some(destination_tag) = destination_tag {
+                    serializer.add_u32(Field::DESTINATION_TAG, *destination_tag);
+                }
+            }
+            XrpOperation::TrustSet { limit } => {
+                serializer.add_u16(Field::TRANSACTION_TYPE, TRANSACTION_TYPE_TRUST_SET);
+                serializer.add(Field::LIMIT_AMOUNT, limit.encode());
+            }
+        }
+        serializer.serialize()
+    }
+}
+
+fn token_issuer(input: &SignerInput) -> Result<AccountId, SignerError> {
+    let issuer = input.input_type.get_asset().id.token_id.as_deref().invalid_input("missing XRP token issuer")?;
+    decode_address(issuer)
+}
+
+fn parse_memo(memo: Option<&str>) -> (Option<u32>, Option<Vec<u8>>) {
+    match memo.map(str::trim).filter(|memo| !memo.is_empty()) {
+        Some(memo) => match memo.parse::<u32>() {
+            Ok(tag) => (Some(tag), None),
+            Err(_) => (None, Some(memo.as_bytes().to_vec())),
+        },
+        None => (None, None),
+    }
+}
@@ -52,6 +52,8 @@ notification_price_alert_target_title = 🎯 {$symbol} reached {$price}
 notification_price_alert_target_description = Now at {$price} ({$change}).
 notification_stake_rewards_title = 🎁 Staking Rewards
 notification_stake_rewards_description = {$value} rewards available to on {$chain}
+notification_recurring_swap_title = 🔁 Recurring Swap
+notification_recurring_swap_description = Swap {$value} to {$to_symbol} is ready to sign.
 notification_rewards_enabled_title = 💎 Rewards Unlocked
 notification_rewards_enabled_description = You can now earn rewards by inviting friends.
 notification_rewards_redeem_points_title = 🎁 Reward Redeemed
@@ -313,6 +313,13 @@ impl LanguageLocalizer {
         fl!(self.loader.as_ref(), "notification_perpetual_close_negative_description", pnl = pnl)
     }
 
+    pub fn notification_recurring_swap(&self, value: &str, to_symbol: &str) -> LanguageNotification {
+        LanguageNotification {
+            title: fl!(self.loader.as_ref(), "notification_recurring_swap_title"),
+            description: fl!(self.loader.as_ref(), "notification_recurring_swap_description", value = value, to_symbol = to_symbol),
+        }
+    }
+
     pub fn notification_stake_rewards(&self, value: &str, chain: &str) -> LanguageNotification {
         LanguageNotification {
             title: fl!(self.loader.as_ref(), "notification_stake_rewards_title"),
@@ -6,4 +6,5 @@ use typeshare::typeshare;
 #[serde(rename_all = "camelCase")]
 pub enum AddressStatus {
     MultiSignature,
+    Eip7702Delegation,
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+}
+
+impl BlockHeader {
+    pub fn new(number: u64, hash: impl Into<String>, parent_hash: impl Into<String>) -> Self {
+        Self {
+            number,
+            hash: hash.into(),
+            parent_hash: parent_hash.into(),
+        }
+    }
+}
@@ -6,10 +6,15 @@ use typeshare::typeshare;
 #[serde(rename_all = "camelCase")]
 pub struct BroadcastOptions {
     pub skip_preflight: bool,
+    #[serde(default)]
+    pub mev_protection: bool,
 }
 
 impl BroadcastOptions {
     pub fn new(skip_preflight: bool) -> Self {
-        Self { skip_preflight }
+        Self {
+            skip_preflight,
+            mev_protection: false,
+        }
     }
 }
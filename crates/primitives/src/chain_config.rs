@@ -9,12 +9,20 @@ pub enum ChainStack {
     ZkSync,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EvmTransactionType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
 #[derive(Debug, Clone)]
 pub struct EvmChainConfig {
     pub min_priority_fee: u64,
     pub chain_stack: ChainStack,
     pub is_ethereum_layer2: bool,
     pub weth_contract: Option<&'static str>,
+    pub transaction_type: EvmTransactionType,
 }
 
 #[derive(Debug, Clone)]
@@ -126,6 +134,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some(ETHEREUM_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: Some(StakeChainConfig {
                 lock_time: 259200,
@@ -158,6 +167,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: Some(StakeChainConfig {
                 lock_time: 604_800,
@@ -217,6 +227,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -312,6 +323,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: true,
                 weth_contract: Some(ARBITRUM_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -331,7 +343,16 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
             is_nft_supported: true,
             is_utxo: false,
             evm: None,
-            stake: None,
+            stake: Some(StakeChainConfig {
+                lock_time: 131_072,
+                min_stake_amount: 1_000_000_000,
+                change_amount_on_unstake: true,
+                can_redelegate: false,
+                can_withdraw: true,
+                can_claim_rewards: false,
+                can_claim_all_rewards: false,
+                reserved_for_fees: 1_000_000_000,
+            }),
         },
         ChainConfig {
             chain: Chain::Tron,
@@ -416,6 +437,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(OPTIMISM_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -466,6 +488,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(BASE_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -489,6 +512,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -557,6 +581,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: false,
                 weth_contract: Some(OPBNB_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -580,6 +605,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x21be370D5312f44cB42ce377BC9b8a0cEF1A4C83"),
+                transaction_type: EvmTransactionType::Legacy,
             }),
             stake: None,
         },
@@ -603,6 +629,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -707,6 +734,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0xE30feDd158A2e3b13e9badaeABaFc5516e95e8C7"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -730,6 +758,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x0dc808adce2099a9f62aa87d9670745aba741746"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -753,6 +782,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: true,
                 weth_contract: Some(BLAST_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -794,6 +824,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::ZkSync,
                 is_ethereum_layer2: true,
                 weth_contract: Some(ZKSYNC_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -817,6 +848,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: true,
                 weth_contract: Some(LINEA_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -840,6 +872,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: true,
                 weth_contract: Some("0x78c1b0C915c4FAA5FffA6CAbf0219DA63d7f4cb8"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -863,6 +896,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(CELO_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -882,7 +916,16 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
             is_nft_supported: false,
             is_utxo: false,
             evm: None,
-            stake: None,
+            stake: Some(StakeChainConfig {
+                lock_time: 172_800,
+                min_stake_amount: 0,
+                change_amount_on_unstake: true,
+                can_redelegate: false,
+                can_withdraw: true,
+                can_claim_rewards: false,
+                can_claim_all_rewards: false,
+                reserved_for_fees: 0,
+            }),
         },
         ChainConfig {
             chain: Chain::World,
@@ -904,6 +947,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(WORLD_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -945,6 +989,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x039e2fB66102314Ce7b64Ce5Ce3E5183bc94aD38"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1004,6 +1049,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x6100E367285b01F48D07953803A2d8dCA5D19873"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1023,7 +1069,16 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
             is_nft_supported: false,
             is_utxo: true,
             evm: None,
-            stake: None,
+            stake: Some(StakeChainConfig {
+                lock_time: 0,
+                min_stake_amount: 0,
+                change_amount_on_unstake: false,
+                can_redelegate: true,
+                can_withdraw: false,
+                can_claim_rewards: true,
+                can_claim_all_rewards: false,
+                reserved_for_fees: 2_500_000,
+            }),
         },
         ChainConfig {
             chain: Chain::Abstract,
@@ -1045,6 +1100,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::ZkSync,
                 is_ethereum_layer2: true,
                 weth_contract: Some("0x3439153EB7AF838Ad19d56E1571FBD09333C2809"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1068,6 +1124,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x6969696969696969696969696969696969696969"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1091,6 +1148,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(INK_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1114,6 +1172,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Optimism,
                 is_ethereum_layer2: true,
                 weth_contract: Some(UNICHAIN_WETH_TOKEN_ID),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1137,6 +1196,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x5555555555555555555555555555555555555555"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1187,6 +1247,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x3bd359C1119dA7Da1D913D1C4D2B7c461115433A"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: Some(StakeChainConfig {
                 lock_time: 86_400,
@@ -1219,6 +1280,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: true,
                 weth_contract: Some("0xe538905cf8410324e03a5a23c1c177a474d59b2b"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
@@ -1242,6 +1304,7 @@ static CHAIN_CONFIGS: LazyLock<Vec<ChainConfig>> = LazyLock::new(|| {
                 chain_stack: ChainStack::Native,
                 is_ethereum_layer2: false,
                 weth_contract: Some("0x779Ded0c9e1022225f8E0630b35a9b54bE713736"),
+                transaction_type: EvmTransactionType::Eip1559,
             }),
             stake: None,
         },
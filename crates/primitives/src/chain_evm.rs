@@ -7,7 +7,7 @@ use typeshare::typeshare;
 use crate::Chain;
 use crate::chain_config::EvmChainConfig;
 
-pub use crate::chain_config::ChainStack;
+pub use crate::chain_config::{ChainStack, EvmTransactionType};
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, EnumIter, AsRefStr, EnumString, PartialEq, Eq, Hash)]
 #[typeshare(swift = "Equatable, Hashable, CaseIterable, Sendable")]
@@ -81,6 +81,10 @@ impl EVMChain {
         self.chain_stack() == ChainStack::ZkSync
     }
 
+    pub fn transaction_type(&self) -> EvmTransactionType {
+        self.config().transaction_type
+    }
+
     pub fn weth_contract(&self) -> Option<&str> {
         self.config().weth_contract
     }
@@ -25,6 +25,9 @@ pub enum StakeChain {
     Tron,
     Aptos,
     HyperCore,
+    Cardano,
+    Ton,
+    Near,
 }
 
 impl StakeChain {
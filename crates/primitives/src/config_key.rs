@@ -122,6 +122,9 @@ pub enum ConfigKey {
     AlerterStakeRewardsThreshold,
     AlerterStakeRewardsLookback,
 
+    // Swaps
+    SwapsTimerRecurringSwaps,
+
     // Price
     PriceTimerTopMarketCap,
     PriceTimerHighMarketCap,
@@ -212,6 +215,7 @@ pub enum ConfigKey {
     ParserMinCheckInterval,
     ParserMaxCheckInterval,
     ParserErrorInterval,
+    ParserReorgDepth,
 
     // Price Observed (WebSocket)
     PriceObservedFetchInterval,
@@ -371,6 +375,7 @@ impl ConfigKey {
             Self::AlerterStakeRewardsTimer => "6h",
             Self::AlerterStakeRewardsThreshold => "0.01",
             Self::AlerterStakeRewardsLookback => "30d",
+            Self::SwapsTimerRecurringSwaps => "60s",
             Self::DeviceTimerUpdater => "1d",
             Self::DeviceTimerInactiveObserver => "1d",
             Self::VersionTimerUpdateStoreVersions => "1h",
@@ -400,6 +405,7 @@ impl ConfigKey {
             Self::ParserMinCheckInterval => "1s",
             Self::ParserMaxCheckInterval => "8s",
             Self::ParserErrorInterval => "30s",
+            Self::ParserReorgDepth => "64",
             Self::PriceObservedFetchInterval => "30s",
             Self::PriceObservedMaxAssets => "100",
             Self::PriceObservedMinObservers => "2",
@@ -25,7 +25,7 @@ pub use self::chain_type::ChainType;
 pub mod chain_transaction_timeout;
 pub use self::chain_transaction_timeout::chain_transaction_timeout;
 pub mod chain_evm;
-pub use self::chain_evm::EVMChain;
+pub use self::chain_evm::{EVMChain, EvmTransactionType};
 pub mod chain_bitcoin;
 pub use self::chain_bitcoin::BitcoinChain;
 pub mod name;
@@ -36,6 +36,8 @@ pub mod node_status;
 pub use self::node_status::NodeStatus;
 pub mod node_sync_status;
 pub use self::node_sync_status::{NodeStatusState, NodeSyncStatus};
+pub mod block_header;
+pub use self::block_header::BlockHeader;
 pub mod latency_type;
 pub use self::latency_type::{Latency, LatencyType};
 pub mod price;
@@ -167,6 +169,8 @@ pub mod nft;
 pub use self::nft::{MIME_TYPE_PNG, NFTAsset, NFTAssetId, NFTAttribute, NFTCollection, NFTCollectionId, NFTData, NFTImages, NFTResource, NFTType, ReportNft};
 pub mod price_alert;
 pub use self::price_alert::{DevicePriceAlert, PriceAlert, PriceAlertDirection, PriceAlertType, PriceAlerts};
+pub mod recurring_swap;
+pub use self::recurring_swap::{RecurringSwap, RecurringSwapExecution, RecurringSwapExecutionStatus, RecurringSwapExecutionUpdate, RecurringSwapFrequency, RecurringSwapStatus};
 pub mod rewards;
 pub use self::rewards::{ReferralCode, ReferralLeader, ReferralLeaderboard, RewardEvent, RewardEventType, RewardLevel, RewardStatus, Rewards};
 pub mod tag;
@@ -261,7 +265,7 @@ pub use self::transaction_preload_input::TransactionPreloadInput;
 pub mod transaction_fee;
 pub use self::transaction_fee::{FeeOption, TransactionFee};
 pub mod transaction_load_metadata;
-pub use self::transaction_load_metadata::{HyperliquidOrder, TransactionLoadMetadata};
+pub use self::transaction_load_metadata::{CardanoUTXOToken, EvmAccessListItem, HyperliquidOrder, PolkadotCallIndex, TonStakePoolType, TransactionLoadMetadata};
 pub mod transaction_input_type;
 pub use self::transaction_input_type::{SignerInput, TransactionInputType, TransactionLoadData, TransactionLoadInput};
 pub mod transfer_data_extra;
@@ -293,8 +297,8 @@ pub mod notification;
 pub use self::notification::InAppNotification;
 pub mod simulation;
 pub use self::simulation::{
-    SimulationBalanceChange, SimulationHeader, SimulationPayloadField, SimulationPayloadFieldDisplay, SimulationPayloadFieldKind, SimulationPayloadFieldType, SimulationResult,
-    SimulationSeverity, SimulationWarning, SimulationWarningApproval, SimulationWarningType, promote_single_secondary_payload_field,
+    SimulationBalanceChange, SimulationEvent, SimulationEventType, SimulationHeader, SimulationPayloadField, SimulationPayloadFieldDisplay, SimulationPayloadFieldKind,
+    SimulationPayloadFieldType, SimulationResult, SimulationSeverity, SimulationWarning, SimulationWarningApproval, SimulationWarningType, promote_single_secondary_payload_field,
 };
 pub mod ip_usage_type;
 pub use self::ip_usage_type::IpUsageType;
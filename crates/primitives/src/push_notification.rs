@@ -19,6 +19,7 @@ pub enum PushNotificationTypes {
     Rewards,         // PushNotificationReward payload
     Stake,           // PushNotificationWalletAsset payload
     FiatTransaction, // PushNotificationWalletAsset payload
+    RecurringSwap,   // PushNotificationRecurringSwap payload
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -49,6 +50,13 @@ impl PushNotification {
             data: serde_json::to_value(PushNotificationWalletAsset { wallet_id, asset_id }).ok(),
         }
     }
+
+    pub fn new_recurring_swap(wallet_id: String, recurring_swap_id: String) -> Self {
+        Self {
+            notification_type: PushNotificationTypes::RecurringSwap,
+            data: serde_json::to_value(PushNotificationRecurringSwap { wallet_id, recurring_swap_id }).ok(),
+        }
+    }
 }
 
 // Only used to decode notification type
@@ -104,3 +112,11 @@ pub struct PushNotificationWalletAsset {
     pub wallet_id: WalletId,
     pub asset_id: AssetId,
 }
+
+#[typeshare(swift = "Equatable, Sendable")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PushNotificationRecurringSwap {
+    pub wallet_id: String,
+    pub recurring_swap_id: String,
+}
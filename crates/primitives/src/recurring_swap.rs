@@ -0,0 +1,166 @@
+use chrono::{DateTime, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+use typeshare::typeshare;
+
+use crate::{AssetId, SwapProvider};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, AsRefStr, EnumString, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RecurringSwapFrequency {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl RecurringSwapFrequency {
+    pub fn occurrence(&self, start: DateTime<Utc>, index: u32) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Hourly => start.checked_add_signed(Duration::hours(index.into())),
+            Self::Daily => start.checked_add_signed(Duration::days(index.into())),
+            Self::Weekly => start.checked_add_signed(Duration::weeks(index.into())),
+            Self::Monthly => start.checked_add_months(Months::new(index)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, AsRefStr, EnumString, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RecurringSwapStatus {
+    Active,
+    Paused,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringSwap {
+    pub id: String,
+    pub wallet_id: String,
+    pub from_asset_id: AssetId,
+    pub to_asset_id: AssetId,
+    pub amount: String,
+    pub frequency: RecurringSwapFrequency,
+    pub status: RecurringSwapStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<SwapProvider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_order_id: Option<String>,
+    pub start_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_execution_at: Option<DateTime<Utc>>,
+}
+
+impl RecurringSwap {
+    pub fn next_execution_after(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut index = 0;
+        let mut next = self.start_at;
+        while next <= at {
+            index += 1;
+            next = self.frequency.occurrence(self.start_at, index)?;
+        }
+        match self.end_at {
+            Some(end_at) if next > end_at => None,
+            _ => Some(next),
+        }
+    }
+
+    pub fn is_device_executed(&self) -> bool {
+        self.provider.is_none()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, AsRefStr, EnumString, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RecurringSwapExecutionStatus {
+    Pending,
+    Completed,
+    Failed,
+    Expired,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringSwapExecution {
+    pub recurring_swap_id: String,
+    pub status: RecurringSwapExecutionStatus,
+    pub scheduled_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_value: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringSwapExecutionUpdate {
+    pub scheduled_at: DateTime<Utc>,
+    pub status: RecurringSwapExecutionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_value: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chain;
+    use chrono::TimeZone;
+
+    fn recurring_swap(frequency: RecurringSwapFrequency, end_at: Option<DateTime<Utc>>) -> RecurringSwap {
+        RecurringSwap {
+            id: "1".to_string(),
+            wallet_id: "multicoin_0x1".to_string(),
+            from_asset_id: AssetId::from_chain(Chain::Solana),
+            to_asset_id: AssetId::from_chain(Chain::Ethereum),
+            amount: "1000".to_string(),
+            frequency,
+            status: RecurringSwapStatus::Active,
+            provider: None,
+            provider_order_id: None,
+            start_at: Utc.with_ymd_and_hms(2026, 1, 31, 12, 0, 0).unwrap(),
+            end_at,
+            next_execution_at: None,
+        }
+    }
+
+    #[test]
+    fn test_next_execution_after() {
+        let swap = recurring_swap(RecurringSwapFrequency::Daily, Some(Utc.with_ymd_and_hms(2026, 2, 2, 12, 0, 0).unwrap()));
+
+        assert_eq!(swap.next_execution_after(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()), Some(swap.start_at));
+        assert_eq!(swap.next_execution_after(swap.start_at), Some(Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).unwrap()));
+        assert_eq!(
+            swap.next_execution_after(Utc.with_ymd_and_hms(2026, 2, 1, 18, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2026, 2, 2, 12, 0, 0).unwrap())
+        );
+        assert_eq!(swap.next_execution_after(Utc.with_ymd_and_hms(2026, 2, 2, 12, 0, 0).unwrap()), None);
+    }
+
+    #[test]
+    fn test_next_execution_after_monthly() {
+        let swap = recurring_swap(RecurringSwapFrequency::Monthly, None);
+
+        assert_eq!(swap.next_execution_after(swap.start_at), Some(Utc.with_ymd_and_hms(2026, 2, 28, 12, 0, 0).unwrap()));
+        assert_eq!(
+            swap.next_execution_after(Utc.with_ymd_and_hms(2026, 2, 28, 12, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2026, 3, 31, 12, 0, 0).unwrap())
+        );
+    }
+}
@@ -87,6 +87,25 @@ pub struct SimulationBalanceChange {
     pub value: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "lowercase")]
+pub enum SimulationEventType {
+    Transfer,
+    Approval,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[typeshare(swift = "Equatable, Hashable, Sendable")]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationEvent {
+    pub event_type: SimulationEventType,
+    pub asset_id: AssetId,
+    pub from: String,
+    pub to: String,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Hashable, Sendable")]
 #[serde(rename_all = "lowercase")]
@@ -168,9 +187,12 @@ impl SimulationPayloadField {
 pub struct SimulationResult {
     pub warnings: Vec<SimulationWarning>,
     pub balance_changes: Vec<SimulationBalanceChange>,
+    pub events: Vec<SimulationEvent>,
     pub payload: Vec<SimulationPayloadField>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub header: Option<SimulationHeader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
 }
 
 impl Default for SimulationResult {
@@ -184,8 +206,10 @@ impl SimulationResult {
         Self {
             warnings: Self::collapse_warnings(warnings),
             balance_changes: vec![],
+            events: vec![],
             payload: promote_single_secondary_payload_field(payload),
             header: None,
+            revert_reason: None,
         }
     }
 
@@ -19,6 +19,7 @@ pub struct ApprovalData {
 pub enum SwapQuoteDataType {
     Contract,
     Transfer,
+    Eip712,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
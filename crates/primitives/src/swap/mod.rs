@@ -22,6 +22,8 @@ pub struct ProxyQuote {
     pub output_min_value: String,
     pub route_data: serde_json::Value,
     pub eta_in_seconds: u32,
+    #[serde(default)]
+    pub input_value: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,4 +37,6 @@ pub struct ProxyQuoteRequest {
     pub referral_bps: u32,
     pub slippage_bps: u32,
     pub use_max_amount: bool,
+    #[serde(default)]
+    pub swap_mode: SwapMode,
 }
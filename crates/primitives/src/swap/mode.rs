@@ -13,9 +13,10 @@ pub enum SwapProviderMode {
     OmniChain(Vec<Chain>), // supports both on-chain and cross-chain. Specify the chain for on-chain swaps
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[typeshare]
 pub enum SwapMode {
+    #[default]
     ExactIn,
     ExactOut,
 }
@@ -33,6 +33,16 @@ pub enum SwapProvider {
     Hyperliquid,
     Orca,
     Squid,
+    CowSwap,
+    UniswapV2,
+    PancakeswapV2,
+    SushiswapV2,
+    QuickswapV2,
+    CamelotV2,
+    SpookyswapV2,
+    Cctp,
+    OneInch,
+    Composed,
 }
 
 impl SwapProvider {
@@ -46,7 +56,7 @@ impl SwapProvider {
 
     pub fn is_cross_chain(&self) -> bool {
         match self {
-            Self::Thorchain | Self::Across | Self::Mayan | Self::Chainflip | Self::NearIntents | Self::Relay | Self::Hyperliquid | Self::Squid => true,
+            Self::Thorchain | Self::Across | Self::Mayan | Self::Chainflip | Self::NearIntents | Self::Relay | Self::Hyperliquid | Self::Squid | Self::Cctp => true,
             Self::UniswapV3
             | Self::UniswapV4
             | Self::PancakeswapV3
@@ -58,7 +68,16 @@ impl SwapProvider {
             | Self::CetusAggregator
             | Self::StonfiV2
             | Self::Aerodrome
-            | Self::Orca => false,
+            | Self::Orca
+            | Self::CowSwap
+            | Self::UniswapV2
+            | Self::PancakeswapV2
+            | Self::SushiswapV2
+            | Self::QuickswapV2
+            | Self::CamelotV2
+            | Self::SpookyswapV2
+            | Self::OneInch
+            | Self::Composed => false,
         }
     }
 
@@ -87,14 +106,24 @@ impl SwapProvider {
             | Self::StonfiV2
             | Self::Aerodrome
             | Self::Hyperliquid
-            | Self::Orca => None,
+            | Self::Orca
+            | Self::CowSwap
+            | Self::UniswapV2
+            | Self::PancakeswapV2
+            | Self::SushiswapV2
+            | Self::QuickswapV2
+            | Self::CamelotV2
+            | Self::SpookyswapV2
+            | Self::Cctp
+            | Self::OneInch
+            | Self::Composed => None,
         }
     }
 
     pub fn name(&self) -> &str {
         match self {
-            Self::UniswapV3 | Self::UniswapV4 => "Uniswap",
-            Self::PancakeswapV3 => "PancakeSwap",
+            Self::UniswapV2 | Self::UniswapV3 | Self::UniswapV4 => "Uniswap",
+            Self::PancakeswapV2 | Self::PancakeswapV3 => "PancakeSwap",
             Self::Aerodrome => "Aerodrome",
             Self::Panora => "Panora",
             Self::Thorchain => "THORChain",
@@ -112,6 +141,14 @@ impl SwapProvider {
             Self::Hyperliquid => "Hyperliquid",
             Self::Orca => "Orca",
             Self::Squid => "Squid",
+            Self::CowSwap => "CoW Swap",
+            Self::SushiswapV2 => "SushiSwap",
+            Self::QuickswapV2 => "QuickSwap",
+            Self::CamelotV2 => "Camelot",
+            Self::SpookyswapV2 => "SpookySwap",
+            Self::Cctp => "Circle CCTP",
+            Self::OneInch => "1inch",
+            Self::Composed => "Composed route",
         }
     }
 
@@ -124,6 +161,15 @@ impl SwapProvider {
             Self::Across => "Across v3",
             Self::Oku => "Oku",
             Self::StonfiV2 => "STON.fi v2",
+            Self::CowSwap => "CoW Protocol",
+            Self::UniswapV2 => "Uniswap v2",
+            Self::PancakeswapV2 => "PancakeSwap v2",
+            Self::SushiswapV2 => "SushiSwap v2",
+            Self::QuickswapV2 => "QuickSwap v2",
+            Self::CamelotV2 => "Camelot v2",
+            Self::SpookyswapV2 => "SpookySwap v2",
+            Self::Cctp => "CCTP v2",
+            Self::OneInch => "1inch Limit Order v4",
             Self::Thorchain
             | Self::Jupiter
             | Self::Okx
@@ -136,7 +182,8 @@ impl SwapProvider {
             | Self::Relay
             | Self::Hyperliquid
             | Self::Orca
-            | Self::Squid => self.name(),
+            | Self::Squid
+            | Self::Composed => self.name(),
         }
     }
 }
@@ -112,6 +112,7 @@ impl TransactionLoadInput {
             metadata: TransactionLoadMetadata::Near {
                 sequence,
                 block_hash: block_hash.into(),
+                is_destination_storage_registered: true,
             },
         }
     }
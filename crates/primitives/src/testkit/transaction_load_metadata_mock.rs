@@ -1,4 +1,4 @@
-use crate::{TransactionLoadMetadata, stake_type::TronStakeData};
+use crate::{TonStakePoolType, TransactionLoadMetadata, stake_type::TronStakeData};
 
 impl TransactionLoadMetadata {
     pub fn mock_aptos() -> Self {
@@ -10,6 +10,7 @@ impl TransactionLoadMetadata {
             nonce,
             chain_id,
             contract_call: None,
+            access_list: vec![],
         }
     }
 
@@ -30,6 +31,16 @@ impl TransactionLoadMetadata {
             sender_token_address: None,
             recipient_token_address: None,
             sequence,
+            stake_pool_type: None,
+        }
+    }
+
+    pub fn mock_ton_stake(sequence: u64, stake_pool_type: TonStakePoolType, sender_token_address: Option<&str>) -> Self {
+        TransactionLoadMetadata::Ton {
+            sender_token_address: sender_token_address.map(|address| address.to_string()),
+            recipient_token_address: None,
+            sequence,
+            stake_pool_type: Some(stake_pool_type),
         }
     }
 
@@ -38,6 +49,7 @@ impl TransactionLoadMetadata {
             sender_token_address: Some(sender_token_address.to_string()),
             recipient_token_address: None,
             sequence,
+            stake_pool_type: None,
         }
     }
 }
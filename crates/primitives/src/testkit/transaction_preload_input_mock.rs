@@ -6,6 +6,7 @@ impl TransactionPreloadInput {
             input_type: TransactionInputType::Transfer(Asset::from_chain(Chain::Aptos)),
             sender_address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
             destination_address: "0xabcdef1234567890abcdef1234567890abcdef12".to_string(),
+            mev_protection: false,
         }
     }
 
@@ -14,6 +15,7 @@ impl TransactionPreloadInput {
             input_type,
             sender_address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
             destination_address: "0xabcdef1234567890abcdef1234567890abcdef12".to_string(),
+            mev_protection: false,
         }
     }
 }
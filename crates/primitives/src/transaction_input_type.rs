@@ -178,6 +178,7 @@ impl TransactionLoadInput {
             input_type: self.input_type.clone(),
             sender_address: self.sender_address.clone(),
             destination_address: self.destination_address.clone(),
+            mev_protection: false,
         }
     }
 }
@@ -12,6 +12,35 @@ pub struct HyperliquidOrder {
     pub agent_private_key: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolkadotCallIndex {
+    pub pallet: String,
+    pub call: String,
+    pub pallet_index: u8,
+    pub call_index: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvmAccessListItem {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TonStakePoolType {
+    Nominator,
+    Whales,
+    Liquid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardanoUTXOToken {
+    pub transaction_id: String,
+    pub vout: i32,
+    pub token_id: String,
+    pub quantity: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionLoadMetadata {
     None,
@@ -20,11 +49,14 @@ pub enum TransactionLoadMetadata {
         recipient_token_address: Option<String>,
         token_program: Option<SolanaTokenProgramId>,
         block_hash: String,
+        #[serde(default)]
+        jito_tip_lamports: Option<u64>,
     },
     Ton {
         sender_token_address: Option<String>,
         recipient_token_address: Option<String>,
         sequence: u64,
+        stake_pool_type: Option<TonStakePoolType>,
     },
     Cosmos {
         account_number: u64,
@@ -40,15 +72,25 @@ pub enum TransactionLoadMetadata {
     },
     Cardano {
         utxos: Vec<UTXO>,
+        tokens: Vec<CardanoUTXOToken>,
+        min_fee_a: u64,
+        min_fee_b: u64,
+        coins_per_utxo_byte: u64,
+        key_deposit: u64,
+        ttl: u64,
+        is_stake_registered: bool,
+        stake_rewards: u64,
     },
     Evm {
         nonce: u64,
         chain_id: u64,
         contract_call: Option<ContractCallData>,
+        access_list: Vec<EvmAccessListItem>,
     },
     Near {
         sequence: u64,
         block_hash: String,
+        is_destination_storage_registered: bool,
     },
     Stellar {
         sequence: u64,
@@ -75,6 +117,7 @@ pub enum TransactionLoadMetadata {
         spec_version: u64,
         transaction_version: u64,
         period: u64,
+        call_indices: Vec<PolkadotCallIndex>,
     },
     Tron {
         block_number: u64,
@@ -148,7 +191,7 @@ impl TransactionLoadMetadata {
         match self {
             TransactionLoadMetadata::Bitcoin { utxos } => Ok(utxos.clone()),
             TransactionLoadMetadata::Zcash { utxos, .. } => Ok(utxos.clone()),
-            TransactionLoadMetadata::Cardano { utxos } => Ok(utxos.clone()),
+            TransactionLoadMetadata::Cardano { utxos, .. } => Ok(utxos.clone()),
             _ => Err("UTXOs not available for this metadata type".into()),
         }
     }
@@ -160,6 +203,16 @@ impl TransactionLoadMetadata {
         }
     }
 
+    pub fn get_is_destination_storage_registered(&self) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            TransactionLoadMetadata::Near {
+                is_destination_storage_registered,
+                ..
+            } => Ok(*is_destination_storage_registered),
+            _ => Err("Destination storage registration not available for this metadata type".into()),
+        }
+    }
+
     pub fn get_recipient_token_address(&self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
         match self {
             TransactionLoadMetadata::Solana { recipient_token_address, .. } => Ok(recipient_token_address.clone()),
@@ -176,6 +229,16 @@ impl TransactionLoadMetadata {
         }
     }
 
+    pub fn get_ton_stake_pool_type(&self) -> Result<TonStakePoolType, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            TransactionLoadMetadata::Ton {
+                stake_pool_type: Some(stake_pool_type),
+                ..
+            } => Ok(*stake_pool_type),
+            _ => Err("Stake pool type not available for this metadata type".into()),
+        }
+    }
+
     pub fn get_message_bytes(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         match self {
             TransactionLoadMetadata::Sui { message_bytes, .. } => Ok(message_bytes.clone()),
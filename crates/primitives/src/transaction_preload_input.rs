@@ -7,6 +7,8 @@ pub struct TransactionPreloadInput {
     pub input_type: TransactionInputType,
     pub sender_address: String,
     pub destination_address: String,
+    #[serde(default)]
+    pub mev_protection: bool,
 }
 
 impl TransactionPreloadInput {
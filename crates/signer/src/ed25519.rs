@@ -1,4 +1,6 @@
-use ed25519_dalek::{Signer as DalekSigner, SigningKey};
+use curve25519_dalek::scalar::Scalar;
+use ed25519_dalek::hazmat::{ExpandedSecretKey, raw_sign};
+use ed25519_dalek::{Sha512, Signer as DalekSigner, SigningKey, VerifyingKey};
 
 use primitives::SignerError;
 
@@ -25,3 +27,33 @@ impl Ed25519KeyPair {
         self.signing_key.sign(digest).to_bytes()
     }
 }
+
+pub struct Ed25519ExtendedKeyPair {
+    secret: ExpandedSecretKey,
+    verifying_key: VerifyingKey,
+    pub public_key_bytes: [u8; ed25519_dalek::PUBLIC_KEY_LENGTH],
+}
+
+impl Ed25519ExtendedKeyPair {
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self, SignerError> {
+        if private_key.len() < 64 {
+            return Err(SignerError::invalid_input("Invalid Ed25519 extended private key length"));
+        }
+        let scalar_bytes: [u8; 32] = private_key[..32].try_into().unwrap();
+        // kL is not re-clamped: derived child keys are valid scalars that clamping would change
+        let secret = ExpandedSecretKey {
+            scalar: Scalar::from_bytes_mod_order(scalar_bytes),
+            hash_prefix: private_key[32..64].try_into().unwrap(),
+        };
+        let verifying_key = VerifyingKey::from(&secret);
+        Ok(Self {
+            public_key_bytes: verifying_key.to_bytes(),
+            secret,
+            verifying_key,
+        })
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; ed25519_dalek::SIGNATURE_LENGTH] {
+        raw_sign::<Sha512>(&self.secret, message, &self.verifying_key).to_bytes()
+    }
+}
@@ -3,7 +3,9 @@ mod decode;
 mod ed25519;
 mod eip712;
 mod error;
+mod schnorr;
 mod secp256k1;
+mod sr25519;
 
 #[cfg(test)]
 pub(crate) mod testkit {
@@ -13,9 +15,11 @@ pub(crate) mod testkit {
 use zeroize::Zeroizing;
 
 pub use crate::address::Base32Address;
-pub use crate::ed25519::{ED25519_KEY_TYPE, Ed25519KeyPair};
+pub use crate::ed25519::{ED25519_KEY_TYPE, Ed25519ExtendedKeyPair, Ed25519KeyPair};
 pub use crate::error::InvalidInput;
-pub use crate::secp256k1::{RECOVERY_ID_INDEX, SIGNATURE_LENGTH, apply_eth_recovery_id, public_key_from_private as secp256k1_public_key};
+pub use crate::schnorr::{SCHNORR_SIGNATURE_LENGTH, sign_taproot_digest, tagged_hash, taproot_output_key};
+pub use crate::secp256k1::{RECOVERY_ID_INDEX, SIGNATURE_LENGTH, apply_eth_recovery_id, encode_der_signature, public_key_from_private as secp256k1_public_key};
+pub use crate::sr25519::{SR25519_SIGNING_CONTEXT, Sr25519KeyPair, verify_sr25519};
 
 pub use decode::{decode_private_key, encode_private_key, supports_private_key_import};
 pub use eip712::hash_typed_data as hash_eip712;
@@ -78,4 +82,32 @@ mod tests {
         assert_eq!(other.sign(digest), signature);
         assert_eq!(other.public_key_bytes, key_pair.public_key_bytes);
     }
+
+    #[test]
+    fn ed25519_extended_key_pair_matches_expanded_seed() {
+        let seed = hex::decode(TEST_PRIVATE_KEY).unwrap();
+        let mut expanded = gem_hash::sha2::sha512(&seed);
+        expanded[0] &= 248;
+        expanded[31] &= 127;
+        expanded[31] |= 64;
+
+        let extended = Ed25519ExtendedKeyPair::from_private_key(&expanded).unwrap();
+        let key_pair = Ed25519KeyPair::from_private_key(&seed).unwrap();
+
+        assert_eq!(extended.public_key_bytes, key_pair.public_key_bytes);
+        assert_eq!(extended.sign(b"test message"), key_pair.sign(b"test message"));
+        assert!(Ed25519ExtendedKeyPair::from_private_key(&seed).is_err());
+    }
+
+    #[test]
+    fn ed25519_extended_key_pair_derives_cardano_public_key() {
+        // wallet-core Cardano test key (kL || kR || chain code || staking key)
+        let private_key = hex::decode(
+            "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e",
+        )
+        .unwrap();
+        let key_pair = Ed25519ExtendedKeyPair::from_private_key(&private_key).unwrap();
+
+        assert_eq!(hex::encode(key_pair.public_key_bytes), "6d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df290");
+    }
 }
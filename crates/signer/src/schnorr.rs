@@ -0,0 +1,82 @@
+use gem_hash::sha2::sha256;
+use k256::elliptic_curve::PrimeField;
+use k256::schnorr::SigningKey;
+use k256::{FieldBytes, NonZeroScalar, Scalar};
+use primitives::SignerError;
+
+pub const SCHNORR_SIGNATURE_LENGTH: usize = 64;
+
+const TAP_TWEAK_TAG: &[u8] = b"TapTweak";
+
+pub fn tagged_hash(tag: &[u8], data: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag);
+    sha256(&[tag_hash.as_slice(), tag_hash.as_slice(), data].concat())
+}
+
+fn taproot_signing_key(private_key: &[u8]) -> Result<SigningKey, SignerError> {
+    let internal_key = SigningKey::from_bytes(private_key).map_err(|_| SignerError::invalid_input("Invalid Secp256k1 private key"))?;
+    let tweak = tagged_hash(TAP_TWEAK_TAG, &internal_key.verifying_key().to_bytes());
+    let tweak = Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(tweak))).ok_or_else(|| SignerError::signing_error("Invalid taproot tweak"))?;
+    let tweaked = Option::<NonZeroScalar>::from(NonZeroScalar::new(*internal_key.as_nonzero_scalar().as_ref() + tweak))
+        .ok_or_else(|| SignerError::signing_error("Invalid taproot tweaked key"))?;
+    Ok(SigningKey::from(tweaked))
+}
+
+pub fn taproot_output_key(private_key: &[u8]) -> Result<[u8; 32], SignerError> {
+    Ok(taproot_signing_key(private_key)?.verifying_key().to_bytes().into())
+}
+
+pub fn sign_taproot_digest(digest: &[u8], private_key: &[u8]) -> Result<Vec<u8>, SignerError> {
+    let signing_key = taproot_signing_key(private_key)?;
+    let signature = signing_key
+        .sign_raw(digest, &[0u8; 32])
+        .map_err(|_| SignerError::signing_error("Failed to sign Schnorr digest"))?;
+    Ok(signature.to_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::TEST_PRIVATE_KEY;
+    use k256::schnorr::{Signature, VerifyingKey};
+
+    #[test]
+    fn test_tagged_hash() {
+        assert_eq!(
+            hex::encode(tagged_hash(b"TapTweak", &[])),
+            "8aa4229474ab0100b2d6f0687f031d1fc9d8eef92a042ad97d279bff456b15e4"
+        );
+    }
+
+    #[test]
+    fn test_sign_taproot_digest_verifies_with_output_key() {
+        let private_key = hex::decode(TEST_PRIVATE_KEY).unwrap();
+        let digest = [7u8; 32];
+
+        let signature = sign_taproot_digest(&digest, &private_key).unwrap();
+        let output_key = taproot_output_key(&private_key).unwrap();
+
+        assert_eq!(signature.len(), SCHNORR_SIGNATURE_LENGTH);
+        assert_eq!(signature, sign_taproot_digest(&digest, &private_key).unwrap());
+
+        let verifying_key = VerifyingKey::from_bytes(&output_key).unwrap();
+        let signature = Signature::try_from(signature.as_slice()).unwrap();
+        assert!(verifying_key.verify_raw(&digest, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_sign_taproot_digest_bip341_vector() {
+        // BIP-341 wallet test vectors, keyPathSpending input 0 (no script tree)
+        let private_key = hex::decode("6b973d88838f27366ed61c9ad6367663045cb456e28335c109e30717ae0c6baa").unwrap();
+        let digest = hex::decode("2514a6272f85cfa0f45eb907fcb0d121b808ed37c6ea160a5a9046ed5526d555").unwrap();
+
+        assert_eq!(
+            hex::encode(taproot_output_key(&private_key).unwrap()),
+            "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"
+        );
+        assert_eq!(
+            hex::encode(sign_taproot_digest(&digest, &private_key).unwrap()),
+            "ed7c1647cb97379e76892be0cacff57ec4a7102aa24296ca39af7541246d8ff14d38958d4cc1e2e478e4d4a764bbfd835b16d4e314b72937b29833060b87276c"
+        );
+    }
+}
@@ -31,6 +31,21 @@ pub fn public_key_from_private(private_key: &[u8]) -> Result<Vec<u8>, SignerErro
     Ok(signing_key.verifying_key().to_sec1_bytes().to_vec())
 }
 
+/// DER-encodes a compact [r(32), s(32)] signature.
+pub fn encode_der_signature(signature: &[u8]) -> Vec<u8> {
+    let encode_integer = |bytes: &[u8]| {
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len() - 1);
+        let mut value = bytes[start..].to_vec();
+        if value[0] & 0x80 != 0 {
+            value.insert(0, 0x00);
+        }
+        [vec![0x02, value.len() as u8], value].concat()
+    };
+    let r = encode_integer(&signature[..32]);
+    let s = encode_integer(&signature[32..]);
+    [vec![0x30, (r.len() + s.len()) as u8], r, s].concat()
+}
+
 /// Apply Ethereum recovery id offset (+27) to a 65-byte signature. Idempotent.
 pub fn apply_eth_recovery_id(signature: &mut [u8]) {
     if signature.len() != 65 {
@@ -44,11 +59,17 @@ pub fn apply_eth_recovery_id(signature: &mut [u8]) {
 
 #[cfg(test)]
 mod tests {
-    use super::{ETHEREUM_RECOVERY_ID_OFFSET, SecpSigningKey, apply_eth_recovery_id, sign_digest, sign_eth_digest};
+    use super::{ETHEREUM_RECOVERY_ID_OFFSET, SecpSigningKey, apply_eth_recovery_id, encode_der_signature, sign_digest, sign_eth_digest};
     use crate::testkit::TEST_PRIVATE_KEY;
     use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
     const DIGEST: [u8; 32] = [7u8; 32];
 
+    #[test]
+    fn encode_der_signature_strips_and_pads_integers() {
+        let signature = [[0x00; 31].as_slice(), &[0x01], &[0x80], &[0x02; 31]].concat();
+        assert_eq!(hex::encode(encode_der_signature(&signature)), format!("302602010102210080{}", "02".repeat(31)));
+    }
+
     #[test]
     fn sign_digest_returns_raw_recovery_id() {
         let private_key = hex::decode(TEST_PRIVATE_KEY).unwrap();
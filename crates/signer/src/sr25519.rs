@@ -0,0 +1,63 @@
+use schnorrkel::{ExpansionMode, Keypair, MiniSecretKey, PublicKey, Signature, signing_context};
+
+use primitives::SignerError;
+
+pub const SR25519_SIGNING_CONTEXT: &[u8] = b"substrate";
+
+pub struct Sr25519KeyPair {
+    key_pair: Keypair,
+    pub public_key_bytes: [u8; 32],
+}
+
+impl Sr25519KeyPair {
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self, SignerError> {
+        let mini_secret = MiniSecretKey::from_bytes(private_key).map_err(|_| SignerError::invalid_input("Invalid Sr25519 private key"))?;
+        let key_pair = mini_secret.expand_to_keypair(ExpansionMode::Ed25519);
+        Ok(Self {
+            public_key_bytes: key_pair.public.to_bytes(),
+            key_pair,
+        })
+    }
+
+    pub fn sign(&self, context: &[u8], message: &[u8]) -> [u8; 64] {
+        self.key_pair.sign(signing_context(context).bytes(message)).to_bytes()
+    }
+}
+
+pub fn verify_sr25519(context: &[u8], message: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+    let (Ok(signature), Ok(public_key)) = (Signature::from_bytes(signature), PublicKey::from_bytes(public_key)) else {
+        return false;
+    };
+    public_key.verify_simple(context, message, &signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sr25519_derives_substrate_public_key() {
+        // Substrate `//Alice` dev account
+        let private_key = hex::decode("e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a").unwrap();
+        let key_pair = Sr25519KeyPair::from_private_key(&private_key).unwrap();
+
+        assert_eq!(hex::encode(key_pair.public_key_bytes), "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+
+        // sp_core `sr_test_vector_should_work`
+        let private_key = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60").unwrap();
+        let key_pair = Sr25519KeyPair::from_private_key(&private_key).unwrap();
+
+        assert_eq!(hex::encode(key_pair.public_key_bytes), "44a996beb1eef7bdcab976ab6d2ca26104834164ecf28fb375600576fcc6eb0f");
+    }
+
+    #[test]
+    fn test_sr25519_signs_and_verifies() {
+        let private_key = hex::decode("e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a").unwrap();
+        let key_pair = Sr25519KeyPair::from_private_key(&private_key).unwrap();
+        let signature = key_pair.sign(SR25519_SIGNING_CONTEXT, b"message");
+
+        assert!(verify_sr25519(SR25519_SIGNING_CONTEXT, b"message", &signature, &key_pair.public_key_bytes));
+        assert!(!verify_sr25519(SR25519_SIGNING_CONTEXT, b"other", &signature, &key_pair.public_key_bytes));
+        assert!(!verify_sr25519(b"other", b"message", &signature, &key_pair.public_key_bytes));
+    }
+}
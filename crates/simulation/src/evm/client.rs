@@ -8,6 +8,7 @@ use primitives::{Chain, SimulationResult, SimulationSeverity, SimulationWarning,
 use super::{
     approval_request::ApprovalRequest,
     decode::{decode_eip712_approval, decode_evm_approval},
+    execution::{ExecutionResult, map_call_frame, map_simulated_blocks},
 };
 
 pub struct SimulationClient<'a, C: Client + Clone> {
@@ -33,6 +34,43 @@ impl<'a, C: Client + Clone> SimulationClient<'a, C> {
         }
     }
 
+    pub async fn simulate_evm_transaction(
+        &self,
+        chain: Chain,
+        from: &str,
+        to: &str,
+        value: Option<&str>,
+        calldata: &[u8],
+    ) -> Result<SimulationResult, Box<dyn Error + Send + Sync>> {
+        let mut result = self.simulate_evm_calldata(chain, calldata, to).await?;
+        match self.simulate_execution(chain, from, to, value, calldata).await {
+            Ok(execution) => Ok(execution.apply(result)),
+            Err(err) => {
+                result.warnings.push(SimulationWarning::new(
+                    SimulationSeverity::Warning,
+                    SimulationWarningType::ValidationError,
+                    Some(format!("Simulation unavailable: {err}")),
+                ));
+                Ok(result)
+            }
+        }
+    }
+
+    async fn simulate_execution(&self, chain: Chain, from: &str, to: &str, value: Option<&str>, calldata: &[u8]) -> Result<ExecutionResult, Box<dyn Error + Send + Sync>> {
+        let data = hex::encode_with_0x(calldata);
+        let simulate_error = match self.ethereum_client.simulate_v1(from, to, value, Some(&data)).await {
+            Ok(blocks) => match map_simulated_blocks(chain, from, blocks) {
+                Some(execution) => return Ok(execution),
+                None => "empty result".to_string(),
+            },
+            Err(err) => err.to_string(),
+        };
+        match self.ethereum_client.debug_trace_call(from, to, value, Some(&data)).await {
+            Ok(frame) => Ok(map_call_frame(chain, from, &frame)),
+            Err(err) => Err(format!("eth_simulateV1: {simulate_error}, debug_traceCall: {err}").into()),
+        }
+    }
+
     async fn simulate_approval(&self, approval: ApprovalRequest) -> Result<SimulationResult, Box<dyn Error + Send + Sync>> {
         let warnings = self.approval_warnings(&approval).await?.into_iter().chain(approval.expiration_warning()).collect();
         Ok(approval.build_simulation_result(warnings))
@@ -171,6 +209,75 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn evm_transaction_includes_balance_changes_from_simulate_v1() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let owner = "0x52A07c930157d07D9EffD147ecF41C5cBbC6000c";
+        let client = EthereumClient::new(
+            mock_jsonrpc_client(|method, _| match method {
+                "eth_simulateV1" => Ok(serde_json::json!([{
+                    "calls": [{
+                        "status": "0x1",
+                        "returnData": "0x",
+                        "logs": [{
+                            "address": "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+                            "topics": [
+                                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                                "0x00000000000000000000000052a07c930157d07d9effd147ecf41c5cbbc6000c",
+                                "0x00000000000000000000000066a9893cc07d91d95644aedd05d03f95e1dba8af"
+                            ],
+                            "data": "0x00000000000000000000000000000000000000000000000000000000000003e8"
+                        }]
+                    }]
+                }])),
+                _ => Ok(Value::Null),
+            }),
+            EVMChain::Ethereum,
+        );
+
+        let result = SimulationClient::new(&client)
+            .simulate_evm_transaction(
+                Chain::Ethereum,
+                owner,
+                "0x66a9893cC07D91D95644AEDD05D03f95e1dBA8Af",
+                Some("0x3e8"),
+                &[0x12, 0x34, 0x56, 0x78],
+            )
+            .await?;
+
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.balance_changes.len(), 1);
+        assert_eq!(result.balance_changes[0].value, "-1000");
+        assert_eq!(result.revert_reason, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn evm_transaction_without_simulation_support_keeps_decoded_result() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let client = ethereum_client("0x1234");
+
+        let result = SimulationClient::new(&client)
+            .simulate_evm_transaction(
+                Chain::Ethereum,
+                "0x52A07c930157d07D9EffD147ecF41C5cBbC6000c",
+                ETHEREUM_USDC_TOKEN_ID,
+                None,
+                &[0x12, 0x34, 0x56, 0x78],
+            )
+            .await?;
+
+        assert!(result.events.is_empty());
+        assert!(result.balance_changes.is_empty());
+        assert_eq!(result.payload.len(), 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].severity, SimulationSeverity::Warning);
+        assert_eq!(result.warnings[0].warning, SimulationWarningType::ValidationError);
+        assert!(result.warnings[0].message.as_deref().is_some_and(|x| x.starts_with("Simulation unavailable")));
+
+        Ok(())
+    }
+
     fn ethereum_client(code: &str) -> EthereumClient<gem_client::testkit::MockClient> {
         let code = code.to_string();
         let client = mock_jsonrpc_client(move |method, _| match method {
@@ -0,0 +1,268 @@
+use alloy_sol_types::{Revert, SolError, decode_revert_reason};
+use num_bigint::{BigInt, BigUint};
+use primitives::{AssetId, Chain, SimulationBalanceChange, SimulationEvent, SimulationEventType, SimulationResult, hex};
+
+use gem_evm::{
+    ethereum_address_checksum,
+    rpc::{
+        mapper::{APPROVAL_TOPIC, TRANSFER_TOPIC},
+        model::{CallFrame, Log, SimulatedBlock},
+        staking_mapper::ethereum_value_from_log_data,
+    },
+};
+
+const NATIVE_TRANSFER_ADDRESS: &str = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ExecutionResult {
+    pub events: Vec<SimulationEvent>,
+    pub balance_changes: Vec<SimulationBalanceChange>,
+    pub revert_reason: Option<String>,
+}
+
+impl ExecutionResult {
+    fn new(owner: &str, events: Vec<SimulationEvent>) -> Self {
+        Self {
+            balance_changes: map_balance_changes(owner, &events),
+            events,
+            revert_reason: None,
+        }
+    }
+
+    fn reverted(reason: String) -> Self {
+        Self {
+            revert_reason: Some(reason),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn apply(self, result: SimulationResult) -> SimulationResult {
+        SimulationResult {
+            events: self.events,
+            balance_changes: self.balance_changes,
+            revert_reason: self.revert_reason,
+            ..result
+        }
+    }
+}
+
+pub(crate) fn map_simulated_blocks(chain: Chain, owner: &str, blocks: Vec<SimulatedBlock>) -> Option<ExecutionResult> {
+    let call = blocks.into_iter().next()?.calls.into_iter().next()?;
+    if !call.is_success() {
+        let error = call.error.as_ref();
+        let reason = map_revert_reason(error.and_then(|x| x.data.as_deref()).or(Some(call.return_data.as_str())))
+            .or_else(|| error.map(|x| x.message.clone()))
+            .unwrap_or_else(|| "execution reverted".to_string());
+        return Some(ExecutionResult::reverted(reason));
+    }
+    let events = call.logs.iter().filter_map(|log| map_log_event(chain, log)).collect();
+    Some(ExecutionResult::new(owner, events))
+}
+
+pub(crate) fn map_call_frame(chain: Chain, owner: &str, frame: &CallFrame) -> ExecutionResult {
+    if let Some(error) = &frame.error {
+        let reason = frame
+            .revert_reason
+            .clone()
+            .or_else(|| map_revert_reason(frame.output.as_deref()))
+            .unwrap_or_else(|| error.clone());
+        return ExecutionResult::reverted(reason);
+    }
+    let mut events = Vec::new();
+    collect_frame_events(chain, frame, &mut events);
+    ExecutionResult::new(owner, events)
+}
+
+fn collect_frame_events(chain: Chain, frame: &CallFrame, events: &mut Vec<SimulationEvent>) {
+    if let (Some(value), Some(to)) = (&frame.value, &frame.to)
+        && *value > BigUint::ZERO
+    {
+        events.push(SimulationEvent {
+            event_type: SimulationEventType::Transfer,
+            asset_id: AssetId::from_chain(chain),
+            from: checksum(&frame.from),
+            to: checksum(to),
+            value: value.to_string(),
+        });
+    }
+    events.extend(frame.logs.iter().filter_map(|log| map_log_event(chain, log)));
+    for call in frame.calls.iter().filter(|call| call.error.is_none()) {
+        collect_frame_events(chain, call, events);
+    }
+}
+
+fn map_log_event(chain: Chain, log: &Log) -> Option<SimulationEvent> {
+    if log.topics.len() != 3 {
+        return None;
+    }
+    let event_type = match log.topics[0].as_str() {
+        TRANSFER_TOPIC => SimulationEventType::Transfer,
+        APPROVAL_TOPIC => SimulationEventType::Approval,
+        _ => return None,
+    };
+    let asset_id = if log.address.eq_ignore_ascii_case(NATIVE_TRANSFER_ADDRESS) {
+        AssetId::from_chain(chain)
+    } else {
+        AssetId::from_token(chain, &checksum(&log.address))
+    };
+    Some(SimulationEvent {
+        event_type,
+        asset_id,
+        from: topic_address(&log.topics[1])?,
+        to: topic_address(&log.topics[2])?,
+        value: ethereum_value_from_log_data(&log.data, 0, 64)?.to_string(),
+    })
+}
+
+fn map_balance_changes(owner: &str, events: &[SimulationEvent]) -> Vec<SimulationBalanceChange> {
+    let mut changes: Vec<(AssetId, BigInt)> = Vec::new();
+    for event in events.iter().filter(|event| event.event_type == SimulationEventType::Transfer) {
+        let Ok(value) = event.value.parse::<BigInt>() else {
+            continue;
+        };
+        let delta = match (event.from.eq_ignore_ascii_case(owner), event.to.eq_ignore_ascii_case(owner)) {
+            (true, false) => -value,
+            (false, true) => value,
+            _ => continue,
+        };
+        match changes.iter_mut().find(|(asset_id, _)| *asset_id == event.asset_id) {
+            Some((_, total)) => *total += delta,
+            None => changes.push((event.asset_id.clone(), delta)),
+        }
+    }
+    changes
+        .into_iter()
+        .filter(|(_, value)| *value != BigInt::ZERO)
+        .map(|(asset_id, value)| SimulationBalanceChange {
+            asset_id,
+            value: value.to_string(),
+        })
+        .collect()
+}
+
+fn map_revert_reason(data: Option<&str>) -> Option<String> {
+    let bytes = hex::decode_hex(data?).ok()?;
+    let reason = match Revert::abi_decode(&bytes) {
+        Ok(revert) => revert.reason,
+        Err(_) => decode_revert_reason(&bytes)?,
+    };
+    Some(reason).filter(|reason| !reason.is_empty())
+}
+
+fn topic_address(topic: &str) -> Option<String> {
+    let topic = topic.trim_start_matches("0x");
+    if topic.len() != 64 {
+        return None;
+    }
+    Some(checksum(&format!("0x{}", &topic[24..])))
+}
+
+fn checksum(address: &str) -> String {
+    ethereum_address_checksum(address).unwrap_or_else(|_| address.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gem_evm::rpc::model::SimulatedBlock;
+    use primitives::asset_constants::ETHEREUM_USDC_TOKEN_ID;
+    use serde_json::json;
+
+    const OWNER: &str = "0x52A07c930157d07D9EffD147ecF41C5cBbC6000c";
+    const OWNER_TOPIC: &str = "0x00000000000000000000000052a07c930157d07d9effd147ecf41c5cbbc6000c";
+    const ROUTER_TOPIC: &str = "0x00000000000000000000000066a9893cc07d91d95644aedd05d03f95e1dba8af";
+    const ROUTER: &str = "0x66a9893cC07D91D95644AEDD05D03f95e1dBA8Af";
+
+    fn value(amount: u64) -> String {
+        format!("0x{:064x}", amount)
+    }
+
+    fn simulated_blocks(call: serde_json::Value) -> Vec<SimulatedBlock> {
+        serde_json::from_value(json!([{ "calls": [call] }])).unwrap()
+    }
+
+    #[test]
+    fn test_map_simulated_blocks_swap() {
+        let blocks = simulated_blocks(json!({
+            "status": "0x1",
+            "returnData": "0x",
+            "logs": [
+                { "address": NATIVE_TRANSFER_ADDRESS, "topics": [TRANSFER_TOPIC, OWNER_TOPIC, ROUTER_TOPIC], "data": value(1_000) },
+                { "address": ETHEREUM_USDC_TOKEN_ID, "topics": [TRANSFER_TOPIC, ROUTER_TOPIC, OWNER_TOPIC], "data": value(2_500) },
+                { "address": ETHEREUM_USDC_TOKEN_ID, "topics": [APPROVAL_TOPIC, OWNER_TOPIC, ROUTER_TOPIC], "data": value(7) }
+            ]
+        }));
+
+        let result = map_simulated_blocks(Chain::Ethereum, OWNER, blocks).unwrap();
+
+        assert_eq!(result.revert_reason, None);
+        assert_eq!(result.events.len(), 3);
+        assert_eq!(result.events[2].event_type, SimulationEventType::Approval);
+        assert_eq!(result.events[2].from, OWNER);
+        assert_eq!(result.events[2].to, ROUTER);
+        assert_eq!(
+            result.balance_changes,
+            vec![
+                SimulationBalanceChange {
+                    asset_id: AssetId::from_chain(Chain::Ethereum),
+                    value: "-1000".to_string(),
+                },
+                SimulationBalanceChange {
+                    asset_id: AssetId::from_token(Chain::Ethereum, ETHEREUM_USDC_TOKEN_ID),
+                    value: "2500".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_simulated_blocks_reverted() {
+        let reason = hex::encode_with_0x(&Revert::from("Too little received").abi_encode());
+        let blocks = simulated_blocks(json!({
+            "status": "0x0",
+            "returnData": reason,
+            "logs": [],
+            "error": { "message": "execution reverted", "code": 3 }
+        }));
+
+        let result = map_simulated_blocks(Chain::Ethereum, OWNER, blocks).unwrap();
+
+        assert_eq!(result.revert_reason.as_deref(), Some("Too little received"));
+        assert!(result.balance_changes.is_empty());
+
+        let blocks = simulated_blocks(json!({ "status": "0x0", "returnData": "0x", "error": { "message": "out of gas" } }));
+
+        assert_eq!(map_simulated_blocks(Chain::Ethereum, OWNER, blocks).unwrap().revert_reason.as_deref(), Some("out of gas"));
+    }
+
+    #[test]
+    fn test_map_call_frame() {
+        let frame: CallFrame = serde_json::from_value(json!({
+            "from": OWNER,
+            "to": ROUTER,
+            "value": "0x3e8",
+            "logs": [{ "address": ETHEREUM_USDC_TOKEN_ID, "topics": [TRANSFER_TOPIC, ROUTER_TOPIC, OWNER_TOPIC], "data": value(2_500) }],
+            "calls": [
+                { "from": ROUTER, "to": OWNER, "value": "0x64" },
+                { "from": ROUTER, "to": OWNER, "value": "0x1", "error": "execution reverted" }
+            ]
+        }))
+        .unwrap();
+
+        let result = map_call_frame(Chain::Ethereum, OWNER, &frame);
+
+        assert_eq!(result.events.len(), 3);
+        assert_eq!(result.balance_changes[0].value, "-900");
+        assert_eq!(result.balance_changes[1].value, "2500");
+
+        let frame: CallFrame = serde_json::from_value(json!({
+            "from": OWNER,
+            "to": ROUTER,
+            "error": "execution reverted",
+            "revertReason": "Expired"
+        }))
+        .unwrap();
+
+        assert_eq!(map_call_frame(Chain::Ethereum, OWNER, &frame).revert_reason.as_deref(), Some("Expired"));
+    }
+}
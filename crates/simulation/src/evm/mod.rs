@@ -5,6 +5,8 @@ mod decode;
 
 #[cfg(feature = "rpc")]
 mod client;
+#[cfg(feature = "rpc")]
+mod execution;
 
 pub use decode::{simulate_eip712_message, simulate_evm_calldata};
 
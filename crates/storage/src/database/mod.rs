@@ -11,11 +11,13 @@ pub mod fiat;
 pub mod migrations;
 pub mod nft;
 pub mod notifications;
+pub mod parser_blocks;
 pub mod parser_state;
 pub mod perpetuals;
 pub mod price_alerts;
 pub mod prices;
 pub mod prices_providers;
+pub mod recurring_swaps;
 pub mod referrals;
 pub mod releases;
 pub mod rewards;
@@ -39,11 +41,11 @@ use crate::repositories::{
     assets_addresses_repository::AssetsAddressesRepository, assets_links_repository::AssetsLinksRepository, assets_repository::AssetsRepository,
     assets_usage_ranks_repository::AssetsUsageRanksRepository, chains_repository::ChainsRepository, charts_repository::ChartsRepository, config_repository::ConfigRepository,
     devices_repository::DevicesRepository, fiat_repository::FiatRepository, migrations_repository::MigrationsRepository, nft_repository::NftRepository,
-    notifications_repository::NotificationsRepository, parser_state_repository::ParserStateRepository, perpetuals_repository::PerpetualsRepository,
-    price_alerts_repository::PriceAlertsRepository, prices_providers_repository::PricesProvidersRepository, prices_repository::PricesRepository,
-    releases_repository::ReleasesRepository, rewards_redemptions_repository::RewardsRedemptionsRepository, rewards_repository::RewardsRepository,
-    scan_addresses_repository::ScanAddressesRepository, tag_repository::TagRepository, transactions_repository::TransactionsRepository, wallets_repository::WalletsRepository,
-    webhooks_repository::WebhooksRepository,
+    notifications_repository::NotificationsRepository, parser_blocks_repository::ParserBlocksRepository, parser_state_repository::ParserStateRepository,
+    perpetuals_repository::PerpetualsRepository, price_alerts_repository::PriceAlertsRepository, prices_providers_repository::PricesProvidersRepository,
+    prices_repository::PricesRepository, recurring_swaps_repository::RecurringSwapsRepository, releases_repository::ReleasesRepository,
+    rewards_redemptions_repository::RewardsRedemptionsRepository, rewards_repository::RewardsRepository, scan_addresses_repository::ScanAddressesRepository,
+    tag_repository::TagRepository, transactions_repository::TransactionsRepository, wallets_repository::WalletsRepository, webhooks_repository::WebhooksRepository,
 };
 
 pub fn create_pool(database_url: &str, pool_size: u32) -> PgPool {
@@ -116,6 +118,10 @@ impl DatabaseClient {
         self
     }
 
+    pub fn parser_blocks(&mut self) -> &mut dyn ParserBlocksRepository {
+        self
+    }
+
     pub fn parser_state(&mut self) -> &mut dyn ParserStateRepository {
         self
     }
@@ -132,6 +138,10 @@ impl DatabaseClient {
         self
     }
 
+    pub fn recurring_swaps(&mut self) -> &mut dyn RecurringSwapsRepository {
+        self
+    }
+
     pub fn rewards(&mut self) -> &mut dyn RewardsRepository {
         self
     }
@@ -0,0 +1,59 @@
+use crate::{DatabaseClient, models::*, sql_types::ChainRow};
+
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use primitives::Chain;
+
+pub(crate) trait ParserBlocksStore {
+    fn add_parser_blocks(&mut self, values: Vec<ParserBlockRow>) -> Result<usize, diesel::result::Error>;
+    fn get_parser_blocks(&mut self, chain: Chain, limit: i64) -> Result<Vec<ParserBlockRow>, diesel::result::Error>;
+    fn get_parser_block(&mut self, chain: Chain, block_number: i64) -> Result<Option<ParserBlockRow>, diesel::result::Error>;
+    fn delete_parser_blocks_after(&mut self, chain: Chain, block_number: i64) -> Result<usize, diesel::result::Error>;
+    fn delete_parser_blocks_before(&mut self, chain: Chain, block_number: i64) -> Result<usize, diesel::result::Error>;
+}
+
+impl ParserBlocksStore for DatabaseClient {
+    fn add_parser_blocks(&mut self, values: Vec<ParserBlockRow>) -> Result<usize, diesel::result::Error> {
+        use crate::schema::parser_blocks::dsl::*;
+        diesel::insert_into(parser_blocks)
+            .values(values)
+            .on_conflict((chain, block_number))
+            .do_update()
+            .set((
+                hash.eq(excluded(hash)),
+                parent_hash.eq(excluded(parent_hash)),
+                transaction_hashes.eq(excluded(transaction_hashes)),
+            ))
+            .execute(&mut self.connection)
+    }
+
+    fn get_parser_blocks(&mut self, chain_value: Chain, limit: i64) -> Result<Vec<ParserBlockRow>, diesel::result::Error> {
+        use crate::schema::parser_blocks::dsl::*;
+        parser_blocks
+            .filter(chain.eq(ChainRow::from(chain_value)))
+            .order(block_number.desc())
+            .limit(limit)
+            .select(ParserBlockRow::as_select())
+            .load(&mut self.connection)
+    }
+
+    fn get_parser_block(&mut self, chain_value: Chain, block_number_value: i64) -> Result<Option<ParserBlockRow>, diesel::result::Error> {
+        use crate::schema::parser_blocks::dsl::*;
+        parser_blocks
+            .filter(chain.eq(ChainRow::from(chain_value)))
+            .filter(block_number.eq(block_number_value))
+            .select(ParserBlockRow::as_select())
+            .first(&mut self.connection)
+            .optional()
+    }
+
+    fn delete_parser_blocks_after(&mut self, chain_value: Chain, block_number_value: i64) -> Result<usize, diesel::result::Error> {
+        use crate::schema::parser_blocks::dsl::*;
+        diesel::delete(parser_blocks.filter(chain.eq(ChainRow::from(chain_value))).filter(block_number.gt(block_number_value))).execute(&mut self.connection)
+    }
+
+    fn delete_parser_blocks_before(&mut self, chain_value: Chain, block_number_value: i64) -> Result<usize, diesel::result::Error> {
+        use crate::schema::parser_blocks::dsl::*;
+        diesel::delete(parser_blocks.filter(chain.eq(ChainRow::from(chain_value))).filter(block_number.lt(block_number_value))).execute(&mut self.connection)
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use primitives::{RecurringSwapExecutionStatus, RecurringSwapStatus};
+
+use crate::DatabaseClient;
+use crate::models::{DeviceRow, NewRecurringSwapExecutionRow, NewRecurringSwapRow, RecurringSwapExecutionRow, RecurringSwapRow};
+use crate::schema::{devices, recurring_swaps, recurring_swaps_executions, wallets};
+use crate::sql_types::{RecurringSwapExecutionStatusRow, RecurringSwapStatusRow};
+
+pub(crate) trait RecurringSwapsStore {
+    fn get_recurring_swaps(&mut self, wallet_id: i32) -> Result<Vec<(RecurringSwapRow, String)>, diesel::result::Error>;
+    fn get_recurring_swap(&mut self, wallet_id: i32, identifier: &str) -> Result<(RecurringSwapRow, String), diesel::result::Error>;
+    fn add_recurring_swap(&mut self, value: NewRecurringSwapRow) -> Result<usize, diesel::result::Error>;
+    fn update_recurring_swap_status(&mut self, id: i32, status: RecurringSwapStatus, next_execution_at: Option<NaiveDateTime>) -> Result<usize, diesel::result::Error>;
+    fn get_due_recurring_swaps(&mut self, before: NaiveDateTime) -> Result<Vec<(RecurringSwapRow, String, DeviceRow)>, diesel::result::Error>;
+    fn get_recurring_swap_executions(&mut self, recurring_swap_id: i32) -> Result<Vec<RecurringSwapExecutionRow>, diesel::result::Error>;
+    fn update_recurring_swap_execution(
+        &mut self,
+        recurring_swap_id: i32,
+        scheduled_at: NaiveDateTime,
+        status: RecurringSwapExecutionStatus,
+        transaction_hash: Option<String>,
+        to_value: Option<String>,
+    ) -> Result<usize, diesel::result::Error>;
+    fn schedule_recurring_swap_execution(
+        &mut self,
+        value: NewRecurringSwapExecutionRow,
+        status: RecurringSwapStatus,
+        next_execution_at: Option<NaiveDateTime>,
+    ) -> Result<usize, diesel::result::Error>;
+}
+
+impl RecurringSwapsStore for DatabaseClient {
+    fn get_recurring_swaps(&mut self, wallet_id: i32) -> Result<Vec<(RecurringSwapRow, String)>, diesel::result::Error> {
+        recurring_swaps::table
+            .inner_join(wallets::table)
+            .filter(recurring_swaps::wallet_id.eq(wallet_id))
+            .order(recurring_swaps::created_at.desc())
+            .select((RecurringSwapRow::as_select(), wallets::identifier))
+            .load(&mut self.connection)
+    }
+
+    fn get_recurring_swap(&mut self, wallet_id: i32, identifier: &str) -> Result<(RecurringSwapRow, String), diesel::result::Error> {
+        recurring_swaps::table
+            .inner_join(wallets::table)
+            .filter(recurring_swaps::wallet_id.eq(wallet_id))
+            .filter(recurring_swaps::identifier.eq(identifier))
+            .select((RecurringSwapRow::as_select(), wallets::identifier))
+            .first(&mut self.connection)
+    }
+
+    fn add_recurring_swap(&mut self, value: NewRecurringSwapRow) -> Result<usize, diesel::result::Error> {
+        diesel::insert_into(recurring_swaps::table)
+            .values(&value)
+            .on_conflict((recurring_swaps::wallet_id, recurring_swaps::identifier))
+            .do_update()
+            .set((
+                recurring_swaps::provider_order_id.eq(excluded(recurring_swaps::provider_order_id)),
+                recurring_swaps::end_at.eq(excluded(recurring_swaps::end_at)),
+                recurring_swaps::next_execution_at.eq(excluded(recurring_swaps::next_execution_at)),
+            ))
+            .execute(&mut self.connection)
+    }
+
+    fn update_recurring_swap_status(&mut self, id: i32, status: RecurringSwapStatus, next_execution_at: Option<NaiveDateTime>) -> Result<usize, diesel::result::Error> {
+        diesel::update(recurring_swaps::table.find(id))
+            .set((
+                recurring_swaps::status.eq(RecurringSwapStatusRow::from(status)),
+                recurring_swaps::next_execution_at.eq(next_execution_at),
+            ))
+            .execute(&mut self.connection)
+    }
+
+    fn get_due_recurring_swaps(&mut self, before: NaiveDateTime) -> Result<Vec<(RecurringSwapRow, String, DeviceRow)>, diesel::result::Error> {
+        recurring_swaps::table
+            .inner_join(wallets::table)
+            .inner_join(devices::table)
+            .filter(recurring_swaps::status.eq(RecurringSwapStatusRow::from(RecurringSwapStatus::Active)))
+            .filter(recurring_swaps::provider.is_null())
+            .filter(recurring_swaps::next_execution_at.le(before))
+            .select((RecurringSwapRow::as_select(), wallets::identifier, DeviceRow::as_select()))
+            .load(&mut self.connection)
+    }
+
+    fn get_recurring_swap_executions(&mut self, recurring_swap_id: i32) -> Result<Vec<RecurringSwapExecutionRow>, diesel::result::Error> {
+        recurring_swaps_executions::table
+            .filter(recurring_swaps_executions::recurring_swap_id.eq(recurring_swap_id))
+            .order(recurring_swaps_executions::scheduled_at.desc())
+            .select(RecurringSwapExecutionRow::as_select())
+            .load(&mut self.connection)
+    }
+
+    fn update_recurring_swap_execution(
+        &mut self,
+        recurring_swap_id: i32,
+        scheduled_at: NaiveDateTime,
+        status: RecurringSwapExecutionStatus,
+        transaction_hash: Option<String>,
+        to_value: Option<String>,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::update(recurring_swaps_executions::table)
+            .filter(recurring_swaps_executions::recurring_swap_id.eq(recurring_swap_id))
+            .filter(recurring_swaps_executions::scheduled_at.eq(scheduled_at))
+            .set((
+                recurring_swaps_executions::status.eq(RecurringSwapExecutionStatusRow::from(status)),
+                recurring_swaps_executions::transaction_hash.eq(transaction_hash),
+                recurring_swaps_executions::to_value.eq(to_value),
+            ))
+            .execute(&mut self.connection)
+    }
+
+    fn schedule_recurring_swap_execution(
+        &mut self,
+        value: NewRecurringSwapExecutionRow,
+        status: RecurringSwapStatus,
+        next_execution_at: Option<NaiveDateTime>,
+    ) -> Result<usize, diesel::result::Error> {
+        use diesel::Connection;
+
+        let recurring_swap_id = value.recurring_swap_id;
+        let scheduled_at = value.scheduled_at;
+        self.connection.transaction(|conn| {
+            diesel::update(recurring_swaps_executions::table)
+                .filter(recurring_swaps_executions::recurring_swap_id.eq(recurring_swap_id))
+                .filter(recurring_swaps_executions::status.eq(RecurringSwapExecutionStatusRow::from(RecurringSwapExecutionStatus::Pending)))
+                .filter(recurring_swaps_executions::scheduled_at.lt(scheduled_at))
+                .set(recurring_swaps_executions::status.eq(RecurringSwapExecutionStatusRow::from(RecurringSwapExecutionStatus::Expired)))
+                .execute(conn)?;
+
+            let count = diesel::insert_into(recurring_swaps_executions::table)
+                .values(&value)
+                .on_conflict((recurring_swaps_executions::recurring_swap_id, recurring_swaps_executions::scheduled_at))
+                .do_nothing()
+                .execute(conn)?;
+
+            diesel::update(recurring_swaps::table.find(recurring_swap_id))
+                .set((
+                    recurring_swaps::status.eq(RecurringSwapStatusRow::from(status)),
+                    recurring_swaps::next_execution_at.eq(next_execution_at),
+                ))
+                .execute(conn)?;
+
+            Ok(count)
+        })
+    }
+}
@@ -41,6 +41,7 @@ pub(crate) trait TransactionsStore {
     fn get_transactions_by_wallet_since(&mut self, wallet_id: i32, since: NaiveDateTime, filters: Vec<TransactionFilter>) -> Result<Vec<TransactionRow>, diesel::result::Error>;
     fn get_transactions_by_filter(&mut self, filters: Vec<TransactionFilter>, limit: i64) -> Result<Vec<TransactionRow>, diesel::result::Error>;
     fn update_transaction(&mut self, chain: &str, hash: &str, updates: Vec<TransactionUpdate>) -> Result<usize, diesel::result::Error>;
+    fn update_transactions_state(&mut self, chain: &str, hashes: Vec<String>, state: TransactionState) -> Result<Vec<TransactionRow>, diesel::result::Error>;
     fn get_addresses_by_chain_and_kind(&mut self, chain: &str, kinds: Vec<TransactionType>, since: NaiveDateTime) -> Result<Vec<String>, diesel::result::Error>;
 }
 
@@ -263,6 +264,19 @@ impl TransactionsStore for DatabaseClient {
         Ok(total)
     }
 
+    fn update_transactions_state(&mut self, chain: &str, hashes: Vec<String>, state: TransactionState) -> Result<Vec<TransactionRow>, diesel::result::Error> {
+        use crate::schema::transactions::dsl;
+
+        if hashes.is_empty() {
+            return Ok(vec![]);
+        }
+
+        diesel::update(dsl::transactions.filter(dsl::chain.eq(chain)).filter(dsl::hash.eq_any(hashes)))
+            .set(dsl::state.eq(state))
+            .returning(TransactionRow::as_select())
+            .get_results(&mut self.connection)
+    }
+
     fn get_addresses_by_chain_and_kind(&mut self, chain: &str, kinds: Vec<TransactionType>, since: NaiveDateTime) -> Result<Vec<String>, diesel::result::Error> {
         use crate::schema::transactions::dsl as tx_dsl;
         use crate::schema::transactions_addresses::dsl::*;
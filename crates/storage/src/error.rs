@@ -91,6 +91,10 @@ impl ResourceName for crate::models::PriceRow {
     const RESOURCE_NAME: &'static str = "Price";
 }
 
+impl ResourceName for crate::models::RecurringSwapRow {
+    const RESOURCE_NAME: &'static str = "RecurringSwap";
+}
+
 impl ResourceName for crate::models::RedemptionOptionFull {
     const RESOURCE_NAME: &'static str = "RewardRedemptionOption";
 }
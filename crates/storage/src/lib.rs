@@ -42,11 +42,13 @@ pub use self::repositories::{
     migrations_repository::MigrationsRepository,
     nft_repository::NftRepository,
     notifications_repository::NotificationsRepository,
+    parser_blocks_repository::ParserBlocksRepository,
     parser_state_repository::ParserStateRepository,
     perpetuals_repository::PerpetualsRepository,
     price_alerts_repository::PriceAlertsRepository,
     prices_providers_repository::PricesProvidersRepository,
     prices_repository::PricesRepository,
+    recurring_swaps_repository::RecurringSwapsRepository,
     releases_repository::ReleasesRepository,
     rewards_redemptions_repository::RewardsRedemptionsRepository,
     rewards_repository::{ReferrerInfo, RewardsEligibilityConfig, RewardsRepository},
@@ -122,6 +124,10 @@ impl Database {
         self.client()
     }
 
+    pub fn parser_blocks(&self) -> Result<DatabaseClient, Box<dyn Error + Send + Sync>> {
+        self.client()
+    }
+
     pub fn parser_state(&self) -> Result<DatabaseClient, Box<dyn Error + Send + Sync>> {
         self.client()
     }
@@ -138,6 +144,10 @@ impl Database {
         self.client()
     }
 
+    pub fn recurring_swaps(&self) -> Result<DatabaseClient, Box<dyn Error + Send + Sync>> {
+        self.client()
+    }
+
     pub fn rewards(&self) -> Result<DatabaseClient, Box<dyn Error + Send + Sync>> {
         self.client()
     }
@@ -0,0 +1,9 @@
+CREATE TABLE parser_blocks (
+    chain VARCHAR(16) NOT NULL REFERENCES chains (id) ON DELETE CASCADE,
+    block_number BIGINT NOT NULL,
+    hash VARCHAR(128) NOT NULL,
+    parent_hash VARCHAR(128) NOT NULL,
+    transaction_hashes VARCHAR(128)[] NOT NULL DEFAULT '{}',
+    created_at timestamp NOT NULL default current_timestamp,
+    PRIMARY KEY (chain, block_number)
+);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS recurring_swaps_executions;
+DROP INDEX IF EXISTS recurring_swaps_next_execution_at_idx;
+DROP TABLE IF EXISTS recurring_swaps;
@@ -0,0 +1,38 @@
+CREATE TABLE recurring_swaps (
+    id SERIAL PRIMARY KEY,
+    identifier VARCHAR(128) NOT NULL,
+    device_id INTEGER NOT NULL REFERENCES devices (id) ON DELETE CASCADE,
+    wallet_id INTEGER NOT NULL REFERENCES wallets (id) ON DELETE CASCADE,
+    from_asset_id VARCHAR(128) NOT NULL REFERENCES assets (id) ON DELETE CASCADE,
+    to_asset_id VARCHAR(128) NOT NULL REFERENCES assets (id) ON DELETE CASCADE,
+    amount VARCHAR(256) NOT NULL,
+    frequency VARCHAR(16) NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    provider VARCHAR(32),
+    provider_order_id VARCHAR(256),
+    start_at TIMESTAMP NOT NULL,
+    end_at TIMESTAMP,
+    next_execution_at TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT current_timestamp,
+    created_at TIMESTAMP NOT NULL DEFAULT current_timestamp,
+    UNIQUE (wallet_id, identifier)
+);
+
+SELECT diesel_manage_updated_at('recurring_swaps');
+
+CREATE INDEX recurring_swaps_next_execution_at_idx ON recurring_swaps (next_execution_at);
+
+CREATE TABLE recurring_swaps_executions (
+    id SERIAL PRIMARY KEY,
+    recurring_swap_id INTEGER NOT NULL REFERENCES recurring_swaps (id) ON DELETE CASCADE,
+    status VARCHAR(16) NOT NULL,
+    scheduled_at TIMESTAMP NOT NULL,
+    quote_value VARCHAR(256),
+    transaction_hash VARCHAR(256),
+    to_value VARCHAR(256),
+    updated_at TIMESTAMP NOT NULL DEFAULT current_timestamp,
+    created_at TIMESTAMP NOT NULL DEFAULT current_timestamp,
+    UNIQUE (recurring_swap_id, scheduled_at)
+);
+
+SELECT diesel_manage_updated_at('recurring_swaps_executions');
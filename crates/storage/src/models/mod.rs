@@ -13,11 +13,13 @@ pub mod nft_collection;
 pub mod nft_link;
 pub mod nft_report;
 pub mod notification;
+pub mod parser_block;
 pub mod parser_state;
 pub mod perpetual;
 pub mod price;
 pub mod price_alert;
 pub mod price_provider;
+pub mod recurring_swap;
 pub mod release;
 pub mod reward;
 pub mod scan_addresses;
@@ -43,11 +45,13 @@ pub use self::nft_collection::{NewNftCollectionRow, NftCollectionRow};
 pub use self::nft_link::NftLinkRow;
 pub use self::nft_report::NewNftReportRow;
 pub use self::notification::{NewNotificationRow, NotificationRow};
+pub use self::parser_block::ParserBlockRow;
 pub use self::parser_state::ParserStateRow;
 pub use self::perpetual::{NewPerpetualAssetRow, NewPerpetualRow, PerpetualRow};
 pub use self::price::{NewPriceRow, PriceAssetDataRow, PriceAssetRow, PriceRow};
 pub use self::price_alert::{NewPriceAlertRow, PriceAlertRow};
 pub use self::price_provider::PriceProviderConfigRow;
+pub use self::recurring_swap::{NewRecurringSwapExecutionRow, NewRecurringSwapRow, RecurringSwapExecutionRow, RecurringSwapRow};
 pub use self::release::ReleaseRow;
 pub use self::reward::{
     NewRewardEventRow, NewRewardRedemptionRow, NewRewardReferralRow, NewRewardsRow, NewRiskSignalRow, RedemptionOptionFull, ReferralAttemptRow, RewardEventRow,
@@ -0,0 +1,36 @@
+use diesel::prelude::*;
+use primitives::{BlockHeader, Chain};
+use serde::{Deserialize, Serialize};
+
+use crate::sql_types::ChainRow;
+
+#[derive(Debug, Queryable, Selectable, Insertable, Serialize, Deserialize, Clone)]
+#[diesel(table_name = crate::schema::parser_blocks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ParserBlockRow {
+    pub chain: ChainRow,
+    pub block_number: i64,
+    pub hash: String,
+    pub parent_hash: String,
+    pub transaction_hashes: Vec<Option<String>>,
+}
+
+impl ParserBlockRow {
+    pub fn from_primitive(chain: Chain, header: BlockHeader, transaction_hashes: Vec<String>) -> Self {
+        Self {
+            chain: ChainRow::from(chain),
+            block_number: header.number as i64,
+            hash: header.hash,
+            parent_hash: header.parent_hash,
+            transaction_hashes: transaction_hashes.into_iter().map(Some).collect(),
+        }
+    }
+
+    pub fn as_primitive(&self) -> BlockHeader {
+        BlockHeader::new(self.block_number as u64, self.hash.clone(), self.parent_hash.clone())
+    }
+
+    pub fn transaction_hashes(&self) -> Vec<String> {
+        self.transaction_hashes.iter().flatten().cloned().collect()
+    }
+}
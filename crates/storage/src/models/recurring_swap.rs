@@ -0,0 +1,174 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use primitives::{RecurringSwap, RecurringSwapExecution, RecurringSwapExecutionStatus, RecurringSwapStatus};
+use serde::{Deserialize, Serialize};
+
+use crate::sql_types::{AssetId, RecurringSwapExecutionStatusRow, RecurringSwapFrequencyRow, RecurringSwapStatusRow, SwapProviderRow};
+
+#[derive(Debug, Queryable, Selectable, Serialize, Deserialize, Clone)]
+#[diesel(table_name = crate::schema::recurring_swaps)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RecurringSwapRow {
+    pub id: i32,
+    pub identifier: String,
+    pub device_id: i32,
+    pub wallet_id: i32,
+    pub from_asset_id: AssetId,
+    pub to_asset_id: AssetId,
+    pub amount: String,
+    pub frequency: RecurringSwapFrequencyRow,
+    pub status: RecurringSwapStatusRow,
+    pub provider: Option<SwapProviderRow>,
+    pub provider_order_id: Option<String>,
+    pub start_at: NaiveDateTime,
+    pub end_at: Option<NaiveDateTime>,
+    pub next_execution_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Insertable, AsChangeset, Clone)]
+#[diesel(table_name = crate::schema::recurring_swaps)]
+pub struct NewRecurringSwapRow {
+    pub identifier: String,
+    pub device_id: i32,
+    pub wallet_id: i32,
+    pub from_asset_id: AssetId,
+    pub to_asset_id: AssetId,
+    pub amount: String,
+    pub frequency: RecurringSwapFrequencyRow,
+    pub status: RecurringSwapStatusRow,
+    pub provider: Option<SwapProviderRow>,
+    pub provider_order_id: Option<String>,
+    pub start_at: NaiveDateTime,
+    pub end_at: Option<NaiveDateTime>,
+    pub next_execution_at: Option<NaiveDateTime>,
+}
+
+impl RecurringSwapRow {
+    pub fn as_primitive(&self, wallet_identifier: String) -> RecurringSwap {
+        RecurringSwap {
+            id: self.identifier.clone(),
+            wallet_id: wallet_identifier,
+            from_asset_id: self.from_asset_id.0.clone(),
+            to_asset_id: self.to_asset_id.0.clone(),
+            amount: self.amount.clone(),
+            frequency: self.frequency.0,
+            status: self.status.0,
+            provider: self.provider.as_ref().map(|x| x.0),
+            provider_order_id: self.provider_order_id.clone(),
+            start_at: self.start_at.and_utc(),
+            end_at: self.end_at.map(|x| x.and_utc()),
+            next_execution_at: self.next_execution_at.map(|x| x.and_utc()),
+        }
+    }
+
+    pub fn new_recurring_swap(primitive: RecurringSwap, device_id: i32, wallet_id: i32, now: NaiveDateTime) -> NewRecurringSwapRow {
+        let next_execution_at = if primitive.is_device_executed() {
+            primitive.next_execution_after(now.and_utc()).map(|x| x.naive_utc())
+        } else {
+            None
+        };
+        let status = if primitive.is_device_executed() && next_execution_at.is_none() {
+            RecurringSwapStatus::Completed
+        } else {
+            RecurringSwapStatus::Active
+        };
+        NewRecurringSwapRow {
+            identifier: primitive.id,
+            device_id,
+            wallet_id,
+            from_asset_id: primitive.from_asset_id.into(),
+            to_asset_id: primitive.to_asset_id.into(),
+            amount: primitive.amount,
+            frequency: primitive.frequency.into(),
+            status: status.into(),
+            provider: primitive.provider.map(Into::into),
+            provider_order_id: primitive.provider_order_id,
+            start_at: primitive.start_at.naive_utc(),
+            end_at: primitive.end_at.map(|x| x.naive_utc()),
+            next_execution_at,
+        }
+    }
+}
+
+#[derive(Debug, Queryable, Selectable, Serialize, Deserialize, Clone)]
+#[diesel(table_name = crate::schema::recurring_swaps_executions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RecurringSwapExecutionRow {
+    pub id: i32,
+    pub recurring_swap_id: i32,
+    pub status: RecurringSwapExecutionStatusRow,
+    pub scheduled_at: NaiveDateTime,
+    pub quote_value: Option<String>,
+    pub transaction_hash: Option<String>,
+    pub to_value: Option<String>,
+}
+
+#[derive(Debug, Insertable, Clone)]
+#[diesel(table_name = crate::schema::recurring_swaps_executions)]
+pub struct NewRecurringSwapExecutionRow {
+    pub recurring_swap_id: i32,
+    pub status: RecurringSwapExecutionStatusRow,
+    pub scheduled_at: NaiveDateTime,
+    pub quote_value: Option<String>,
+}
+
+impl RecurringSwapExecutionRow {
+    pub fn as_primitive(&self, recurring_swap_identifier: String) -> RecurringSwapExecution {
+        RecurringSwapExecution {
+            recurring_swap_id: recurring_swap_identifier,
+            status: self.status.0,
+            scheduled_at: self.scheduled_at.and_utc(),
+            quote_value: self.quote_value.clone(),
+            transaction_hash: self.transaction_hash.clone(),
+            to_value: self.to_value.clone(),
+        }
+    }
+}
+
+impl NewRecurringSwapExecutionRow {
+    pub fn new(recurring_swap_id: i32, status: RecurringSwapExecutionStatus, scheduled_at: NaiveDateTime, quote_value: Option<String>) -> Self {
+        Self {
+            recurring_swap_id,
+            status: status.into(),
+            scheduled_at,
+            quote_value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use primitives::{AssetId, Chain, RecurringSwapFrequency, RecurringSwapStatus};
+
+    fn recurring_swap(status: RecurringSwapStatus, end_at: Option<NaiveDateTime>) -> RecurringSwap {
+        RecurringSwap {
+            id: "1".to_string(),
+            wallet_id: "wallet".to_string(),
+            from_asset_id: AssetId::from_chain(Chain::Ethereum),
+            to_asset_id: AssetId::from_chain(Chain::Bitcoin),
+            amount: "100".to_string(),
+            frequency: RecurringSwapFrequency::Daily,
+            status,
+            provider: None,
+            provider_order_id: None,
+            start_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            end_at: end_at.map(|x| x.and_utc()),
+            next_execution_at: None,
+        }
+    }
+
+    #[test]
+    fn test_new_recurring_swap_status() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap().naive_utc();
+
+        let row = RecurringSwapRow::new_recurring_swap(recurring_swap(RecurringSwapStatus::Cancelled, None), 1, 1, now);
+        assert_eq!(row.status.0, RecurringSwapStatus::Active);
+        assert_eq!(row.next_execution_at, Some(Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap().naive_utc()));
+
+        let row = RecurringSwapRow::new_recurring_swap(recurring_swap(RecurringSwapStatus::Active, Some(now)), 1, 1, now);
+        assert_eq!(row.status.0, RecurringSwapStatus::Completed);
+        assert_eq!(row.next_execution_at, None);
+    }
+}
@@ -10,11 +10,13 @@ pub mod fiat_repository;
 pub mod migrations_repository;
 pub mod nft_repository;
 pub mod notifications_repository;
+pub mod parser_blocks_repository;
 pub mod parser_state_repository;
 pub mod perpetuals_repository;
 pub mod price_alerts_repository;
 pub mod prices_providers_repository;
 pub mod prices_repository;
+pub mod recurring_swaps_repository;
 pub mod releases_repository;
 pub mod rewards_redemptions_repository;
 pub mod rewards_repository;
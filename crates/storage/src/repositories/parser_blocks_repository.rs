@@ -0,0 +1,34 @@
+use crate::database::parser_blocks::ParserBlocksStore;
+use crate::models::ParserBlockRow;
+use crate::{DatabaseClient, DatabaseError};
+use primitives::Chain;
+
+pub trait ParserBlocksRepository {
+    fn add_parser_blocks(&mut self, values: Vec<ParserBlockRow>) -> Result<usize, DatabaseError>;
+    fn get_parser_blocks(&mut self, chain: Chain, limit: i64) -> Result<Vec<ParserBlockRow>, DatabaseError>;
+    fn get_parser_block(&mut self, chain: Chain, block_number: i64) -> Result<Option<ParserBlockRow>, DatabaseError>;
+    fn delete_parser_blocks_after(&mut self, chain: Chain, block_number: i64) -> Result<usize, DatabaseError>;
+    fn delete_parser_blocks_before(&mut self, chain: Chain, block_number: i64) -> Result<usize, DatabaseError>;
+}
+
+impl ParserBlocksRepository for DatabaseClient {
+    fn add_parser_blocks(&mut self, values: Vec<ParserBlockRow>) -> Result<usize, DatabaseError> {
+        Ok(ParserBlocksStore::add_parser_blocks(self, values)?)
+    }
+
+    fn get_parser_blocks(&mut self, chain: Chain, limit: i64) -> Result<Vec<ParserBlockRow>, DatabaseError> {
+        Ok(ParserBlocksStore::get_parser_blocks(self, chain, limit)?)
+    }
+
+    fn get_parser_block(&mut self, chain: Chain, block_number: i64) -> Result<Option<ParserBlockRow>, DatabaseError> {
+        Ok(ParserBlocksStore::get_parser_block(self, chain, block_number)?)
+    }
+
+    fn delete_parser_blocks_after(&mut self, chain: Chain, block_number: i64) -> Result<usize, DatabaseError> {
+        Ok(ParserBlocksStore::delete_parser_blocks_after(self, chain, block_number)?)
+    }
+
+    fn delete_parser_blocks_before(&mut self, chain: Chain, block_number: i64) -> Result<usize, DatabaseError> {
+        Ok(ParserBlocksStore::delete_parser_blocks_before(self, chain, block_number)?)
+    }
+}
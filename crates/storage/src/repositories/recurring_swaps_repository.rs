@@ -0,0 +1,95 @@
+use chrono::{NaiveDateTime, Utc};
+use primitives::{Device, RecurringSwap, RecurringSwapExecution, RecurringSwapExecutionStatus, RecurringSwapExecutionUpdate, RecurringSwapStatus};
+
+use crate::database::devices::DevicesStore;
+use crate::database::recurring_swaps::RecurringSwapsStore;
+use crate::models::{NewRecurringSwapExecutionRow, RecurringSwapRow};
+use crate::{DatabaseClient, DatabaseError, DieselResultExt};
+
+pub trait RecurringSwapsRepository {
+    fn get_recurring_swaps(&mut self, wallet_id: i32) -> Result<Vec<RecurringSwap>, DatabaseError>;
+    fn add_recurring_swap(&mut self, device_id: &str, wallet_id: i32, recurring_swap: RecurringSwap) -> Result<usize, DatabaseError>;
+    fn update_recurring_swap_status(&mut self, wallet_id: i32, id: &str, status: RecurringSwapStatus) -> Result<usize, DatabaseError>;
+    fn get_recurring_swap_executions(&mut self, wallet_id: i32, id: &str) -> Result<Vec<RecurringSwapExecution>, DatabaseError>;
+    fn update_recurring_swap_execution(&mut self, wallet_id: i32, id: &str, update: RecurringSwapExecutionUpdate) -> Result<usize, DatabaseError>;
+    fn get_due_recurring_swaps(&mut self, before: NaiveDateTime) -> Result<Vec<(RecurringSwapRow, RecurringSwap, Device)>, DatabaseError>;
+    fn schedule_recurring_swap_execution(
+        &mut self,
+        recurring_swap_id: i32,
+        scheduled_at: NaiveDateTime,
+        status: RecurringSwapExecutionStatus,
+        quote_value: Option<String>,
+        next_execution_at: Option<NaiveDateTime>,
+    ) -> Result<usize, DatabaseError>;
+}
+
+impl RecurringSwapsRepository for DatabaseClient {
+    fn get_recurring_swaps(&mut self, wallet_id: i32) -> Result<Vec<RecurringSwap>, DatabaseError> {
+        Ok(RecurringSwapsStore::get_recurring_swaps(self, wallet_id)?
+            .into_iter()
+            .map(|(row, wallet_identifier)| row.as_primitive(wallet_identifier))
+            .collect())
+    }
+
+    fn add_recurring_swap(&mut self, device_id: &str, wallet_id: i32, recurring_swap: RecurringSwap) -> Result<usize, DatabaseError> {
+        let device = DevicesStore::get_device(self, device_id).or_not_found(device_id.to_string())?;
+        let value = RecurringSwapRow::new_recurring_swap(recurring_swap, device.id, wallet_id, Utc::now().naive_utc());
+        Ok(RecurringSwapsStore::add_recurring_swap(self, value)?)
+    }
+
+    fn update_recurring_swap_status(&mut self, wallet_id: i32, id: &str, status: RecurringSwapStatus) -> Result<usize, DatabaseError> {
+        let (row, wallet_identifier) = RecurringSwapsStore::get_recurring_swap(self, wallet_id, id).or_not_found_for::<RecurringSwapRow>(id.to_string())?;
+        let next_execution_at = match status {
+            RecurringSwapStatus::Active if row.provider.is_none() => row.as_primitive(wallet_identifier).next_execution_after(Utc::now()).map(|x| x.naive_utc()),
+            _ => None,
+        };
+        Ok(RecurringSwapsStore::update_recurring_swap_status(self, row.id, status, next_execution_at)?)
+    }
+
+    fn get_recurring_swap_executions(&mut self, wallet_id: i32, id: &str) -> Result<Vec<RecurringSwapExecution>, DatabaseError> {
+        let (row, _) = RecurringSwapsStore::get_recurring_swap(self, wallet_id, id).or_not_found_for::<RecurringSwapRow>(id.to_string())?;
+        Ok(RecurringSwapsStore::get_recurring_swap_executions(self, row.id)?
+            .into_iter()
+            .map(|x| x.as_primitive(row.identifier.clone()))
+            .collect())
+    }
+
+    fn update_recurring_swap_execution(&mut self, wallet_id: i32, id: &str, update: RecurringSwapExecutionUpdate) -> Result<usize, DatabaseError> {
+        let (row, _) = RecurringSwapsStore::get_recurring_swap(self, wallet_id, id).or_not_found_for::<RecurringSwapRow>(id.to_string())?;
+        Ok(RecurringSwapsStore::update_recurring_swap_execution(
+            self,
+            row.id,
+            update.scheduled_at.naive_utc(),
+            update.status,
+            update.transaction_hash,
+            update.to_value,
+        )?)
+    }
+
+    fn get_due_recurring_swaps(&mut self, before: NaiveDateTime) -> Result<Vec<(RecurringSwapRow, RecurringSwap, Device)>, DatabaseError> {
+        Ok(RecurringSwapsStore::get_due_recurring_swaps(self, before)?
+            .into_iter()
+            .map(|(row, wallet_identifier, device)| {
+                let recurring_swap = row.as_primitive(wallet_identifier);
+                (row, recurring_swap, device.as_primitive())
+            })
+            .collect())
+    }
+
+    fn schedule_recurring_swap_execution(
+        &mut self,
+        recurring_swap_id: i32,
+        scheduled_at: NaiveDateTime,
+        status: RecurringSwapExecutionStatus,
+        quote_value: Option<String>,
+        next_execution_at: Option<NaiveDateTime>,
+    ) -> Result<usize, DatabaseError> {
+        let execution = NewRecurringSwapExecutionRow::new(recurring_swap_id, status, scheduled_at, quote_value);
+        let status = if next_execution_at.is_some() {
+            RecurringSwapStatus::Active
+        } else {
+            RecurringSwapStatus::Completed
+        };
+        Ok(RecurringSwapsStore::schedule_recurring_swap_execution(self, execution, status, next_execution_at)?)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::database::transactions::{TransactionFilter, TransactionUpdate, TransactionsStore};
 use crate::models::{AddressChainIdResultRow, TransactionRow};
-use crate::sql_types::TransactionType;
+use crate::sql_types::{TransactionState, TransactionType};
 use crate::{DatabaseClient, DatabaseError, DieselResultExt};
 use chrono::NaiveDateTime;
 use primitives::{AssetId, Transaction, TransactionId};
@@ -23,6 +23,7 @@ pub trait TransactionsRepository {
     fn get_asset_usage_counts(&mut self, since: NaiveDateTime) -> Result<Vec<(AssetId, i64)>, DatabaseError>;
     fn get_transactions_by_filter(&mut self, filters: Vec<TransactionFilter>, limit: i64) -> Result<Vec<TransactionRow>, DatabaseError>;
     fn update_transaction(&mut self, chain: &str, hash: &str, updates: Vec<TransactionUpdate>) -> Result<usize, DatabaseError>;
+    fn update_transactions_state(&mut self, chain: &str, hashes: Vec<String>, state: TransactionState) -> Result<Vec<TransactionRow>, DatabaseError>;
     fn get_addresses_by_chain_and_kind(&mut self, chain: &str, kinds: Vec<TransactionType>, since: NaiveDateTime) -> Result<Vec<String>, DatabaseError>;
 }
 
@@ -84,6 +85,10 @@ impl TransactionsRepository for DatabaseClient {
         Ok(TransactionsStore::update_transaction(self, chain, hash, updates)?)
     }
 
+    fn update_transactions_state(&mut self, chain: &str, hashes: Vec<String>, state: TransactionState) -> Result<Vec<TransactionRow>, DatabaseError> {
+        Ok(TransactionsStore::update_transactions_state(self, chain, hashes, state)?)
+    }
+
     fn get_addresses_by_chain_and_kind(&mut self, chain: &str, kinds: Vec<TransactionType>, since: NaiveDateTime) -> Result<Vec<String>, DatabaseError> {
         Ok(TransactionsStore::get_addresses_by_chain_and_kind(self, chain, kinds, since)?)
     }
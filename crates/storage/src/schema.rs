@@ -471,6 +471,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    parser_blocks (chain, block_number) {
+        #[max_length = 16]
+        chain -> Varchar,
+        block_number -> Int8,
+        #[max_length = 128]
+        hash -> Varchar,
+        #[max_length = 128]
+        parent_hash -> Varchar,
+        transaction_hashes -> Array<Nullable<Varchar>>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     parser_state (chain) {
         chain -> Varchar,
@@ -589,6 +603,53 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    recurring_swaps (id) {
+        id -> Int4,
+        #[max_length = 128]
+        identifier -> Varchar,
+        device_id -> Int4,
+        wallet_id -> Int4,
+        #[max_length = 128]
+        from_asset_id -> Varchar,
+        #[max_length = 128]
+        to_asset_id -> Varchar,
+        #[max_length = 256]
+        amount -> Varchar,
+        #[max_length = 16]
+        frequency -> Varchar,
+        #[max_length = 16]
+        status -> Varchar,
+        #[max_length = 32]
+        provider -> Nullable<Varchar>,
+        #[max_length = 256]
+        provider_order_id -> Nullable<Varchar>,
+        start_at -> Timestamp,
+        end_at -> Nullable<Timestamp>,
+        next_execution_at -> Nullable<Timestamp>,
+        updated_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    recurring_swaps_executions (id) {
+        id -> Int4,
+        recurring_swap_id -> Int4,
+        #[max_length = 16]
+        status -> Varchar,
+        scheduled_at -> Timestamp,
+        #[max_length = 256]
+        quote_value -> Nullable<Varchar>,
+        #[max_length = 256]
+        transaction_hash -> Nullable<Varchar>,
+        #[max_length = 256]
+        to_value -> Nullable<Varchar>,
+        updated_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::PlatformStore;
@@ -933,6 +994,7 @@ diesel::joinable!(nft_reports -> nft_assets (asset_id));
 diesel::joinable!(nft_reports -> nft_collections (collection_id));
 diesel::joinable!(notifications -> assets (asset_id));
 diesel::joinable!(notifications -> wallets (wallet_id));
+diesel::joinable!(parser_blocks -> chains (chain));
 diesel::joinable!(parser_state -> chains (chain));
 diesel::joinable!(perpetuals -> assets (asset_id));
 diesel::joinable!(perpetuals_assets -> assets (asset_id));
@@ -944,6 +1006,9 @@ diesel::joinable!(prices -> prices_providers (provider));
 diesel::joinable!(prices_assets -> assets (asset_id));
 diesel::joinable!(prices_assets -> prices (price_id));
 diesel::joinable!(prices_assets -> prices_providers (provider));
+diesel::joinable!(recurring_swaps -> devices (device_id));
+diesel::joinable!(recurring_swaps -> wallets (wallet_id));
+diesel::joinable!(recurring_swaps_executions -> recurring_swaps (recurring_swap_id));
 diesel::joinable!(rewards -> devices (device_id));
 diesel::joinable!(rewards -> usernames (username));
 diesel::joinable!(rewards_events -> usernames (username));
@@ -994,6 +1059,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     nft_collections_links,
     nft_reports,
     notifications,
+    parser_blocks,
     parser_state,
     perpetuals,
     perpetuals_assets,
@@ -1001,6 +1067,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     prices,
     prices_assets,
     prices_providers,
+    recurring_swaps,
+    recurring_swaps_executions,
     releases,
     rewards,
     rewards_events,
@@ -13,8 +13,10 @@ use primitives::{
     AssetType as PrimitiveAssetType, Chain, FiatProviderName as PrimitiveFiatProviderName, FiatQuoteType as PrimitiveFiatQuoteType,
     FiatTransactionStatus as PrimitiveFiatTransactionStatus, IpUsageType as PrimitiveIpUsageType, LinkType as PrimitiveLinkType, NotificationType as PrimitiveNotificationType,
     PerpetualProvider as PrimitivePerpetualProvider, Platform as PrimitivePlatform, PlatformStore as PrimitivePlatformStore, PriceAlertDirection as PrimitivePriceAlertDirection,
-    PriceProvider as PrimitivePriceProvider, TransactionState as PrimitiveTransactionState, TransactionType as PrimitiveTransactionType, UsernameStatus as PrimitiveUsernameStatus,
-    WalletSource as PrimitiveWalletSource, WalletType as PrimitiveWalletType, WebhookKind as PrimitiveWebhookKind,
+    PriceProvider as PrimitivePriceProvider, RecurringSwapExecutionStatus as PrimitiveRecurringSwapExecutionStatus, RecurringSwapFrequency as PrimitiveRecurringSwapFrequency,
+    RecurringSwapStatus as PrimitiveRecurringSwapStatus, SwapProvider as PrimitiveSwapProvider, TransactionState as PrimitiveTransactionState,
+    TransactionType as PrimitiveTransactionType, UsernameStatus as PrimitiveUsernameStatus, WalletSource as PrimitiveWalletSource, WalletType as PrimitiveWalletType,
+    WebhookKind as PrimitiveWebhookKind,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -234,6 +236,10 @@ diesel_varchar!(PriceAlertDirectionRow, PrimitivePriceAlertDirection);
 diesel_varchar!(PerpetualProviderRow, PrimitivePerpetualProvider);
 diesel_varchar!(PriceProviderRow, PrimitivePriceProvider);
 diesel_varchar!(FiatProviderNameRow, PrimitiveFiatProviderName);
+diesel_varchar!(RecurringSwapFrequencyRow, PrimitiveRecurringSwapFrequency);
+diesel_varchar!(RecurringSwapStatusRow, PrimitiveRecurringSwapStatus);
+diesel_varchar!(RecurringSwapExecutionStatusRow, PrimitiveRecurringSwapExecutionStatus);
+diesel_varchar!(SwapProviderRow, PrimitiveSwapProvider);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, AsExpression, FromSqlRow)]
 #[serde(transparent)]
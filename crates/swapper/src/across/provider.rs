@@ -5,7 +5,7 @@ use super::{
     hubpool::HubPoolClient,
 };
 use crate::{
-    SwapResult, Swapper, SwapperError, SwapperProvider, SwapperQuoteData,
+    SwapResult, Swapper, SwapperError, SwapperMode, SwapperProvider, SwapperQuoteData,
     across::{DEFAULT_DEPOSIT_GAS_LIMIT, DEFAULT_FILL_GAS_LIMIT},
     alien::RpcProvider,
     approval::check_approval_erc20,
@@ -66,6 +66,21 @@ impl Across {
         Ok(U256::from_be_slice(bytes.as_slice()))
     }
 
+    fn u256_to_bigint(value: &U256) -> BigInt {
+        BigInt::from_bytes_le(Sign::Plus, &value.to_le_bytes::<32>())
+    }
+
+    /// Return the amount that leaves `amount` after deducting `fee_percent` (18 decimals), rounded up
+    pub fn amount_before_fee(amount: U256, fee_percent: &BigInt) -> Result<U256, SwapperError> {
+        let one = BigInt::from(10u64.pow(18));
+        let remaining_percent = &one - fee_percent;
+        if remaining_percent.sign() != Sign::Plus {
+            return Err(SwapperError::NoQuoteAvailable);
+        }
+        let value = (Self::u256_to_bigint(&amount) * &one + &remaining_percent - 1) / &remaining_percent;
+        Self::bigint_to_u256(&value)
+    }
+
     pub fn new(rpc_provider: Arc<dyn RpcProvider>) -> Self {
         Self {
             provider: ProviderType::new(SwapperProvider::Across),
@@ -297,6 +312,14 @@ impl Across {
         }
     }
 
+    async fn relayer_gas_fee(&self, gas_fee: U256, input_is_native: bool, chain: Chain, existing_results: &[IMulticall3::Result]) -> Result<U256, SwapperError> {
+        if input_is_native {
+            return Ok(gas_fee);
+        }
+        let price = self.usd_price_for_chain(chain, existing_results).await?;
+        Ok(Self::calculate_fee_in_token(&gas_fee, &price, 6))
+    }
+
     pub fn update_v3_relay_data(
         &self,
         v3_relay_data: &mut V3RelayData,
@@ -361,6 +384,14 @@ impl Swapper for Across {
         ]
     }
 
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
+    fn get_approval_spender(&self, quote: &Quote) -> Option<String> {
+        AcrossDeployment::deployment_by_chain(&quote.request.from_asset.chain()).map(|x| x.spoke_pool.to_string())
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
         if request.from_asset.chain() == request.to_asset.chain() {
             return Err(SwapperError::NoQuoteAvailable);
@@ -368,7 +399,7 @@ impl Swapper for Across {
 
         let input_is_native = request.from_asset.is_native();
         let from_chain = EVMChain::from_chain(request.from_asset.chain()).ok_or(SwapperError::NotSupportedChain)?;
-        let from_amount: U256 = request.value.parse().map_err(SwapperError::from)?;
+        let amount: U256 = request.value.parse().map_err(SwapperError::from)?;
         let wallet_address = eth_address::parse_str(&request.wallet_address)?;
 
         let _ = AcrossDeployment::deployment_by_chain(&request.from_asset.chain()).ok_or(SwapperError::NotSupportedChain)?;
@@ -404,16 +435,13 @@ impl Swapper for Across {
             return Err(SwapperError::ComputeQuoteError("Across protocol is paused".into()));
         }
 
-        // Check bridge amount is too large (Across API has some limit in USD amount but we don't have that info)
-        if from_amount > hubpool_client.decoded_pooled_token_call3(&results[2])?.liquidReserves {
-            return Err(SwapperError::ComputeQuoteError("Bridge amount is too large".into()));
-        }
+        let liquid_reserves = hubpool_client.decoded_pooled_token_call3(&results[2])?.liquidReserves;
 
         // Prepare data for lp fee calculation (token config, utilization, current time)
         let token_config_req = config_client.fetch_config(&mainnet_token); // cache is used inside config_client
         let mut calls = vec![
             hubpool_client.utilization_call3(&mainnet_token, U256::from(0)),
-            hubpool_client.utilization_call3(&mainnet_token, from_amount),
+            hubpool_client.utilization_call3(&mainnet_token, amount),
             hubpool_client.get_current_time(),
         ];
 
@@ -431,49 +459,95 @@ impl Swapper for Across {
 
         let rate_model = Self::get_rate_model(&input_asset, &output_asset, &token_config);
         let cost_config = &asset_mapping.capital_cost;
-
-        // Calculate lp fee
         let lpfee_calc = LpFeeCalculator::new(rate_model);
-        let lpfee_percent = lpfee_calc.realized_lp_fee_pct(&util_before, &util_after, false);
-        let lpfee = fees::multiply(from_amount, lpfee_percent, cost_config.decimals);
-
-        // Calculate relayer fee
         let relayer_calc = RelayerFeeCalculator::default();
-        let relayer_fee_percent = relayer_calc.capital_fee_percent(&BigInt::from_str(&request.value).unwrap(), cost_config);
-        let relayer_fee = fees::multiply(from_amount, relayer_fee_percent, cost_config.decimals);
 
         let referral_config = request.options.fee.clone().unwrap_or_default().evm;
-
-        // Calculate gas limit / price for relayer
-        let remain_amount = from_amount - lpfee - relayer_fee;
-        let (message, referral_fee) = self.message_for_multicall_handler(&remain_amount, &original_output_asset, &wallet_address, &output_token, &referral_config);
-
         let gas_price = self.gas_price(request.to_asset.chain()).await?;
-        let (gas_limit, mut v3_relay_data) = self
-            .estimate_gas_limit(
-                &from_amount,
-                input_is_native,
-                &input_asset,
-                &output_token,
-                &wallet_address,
-                &message,
-                &destination_deployment,
-                request.to_asset.chain(),
-            )
-            .await?;
-        let mut gas_fee = gas_limit * gas_price;
-        if !input_is_native {
-            let price = self.usd_price_for_chain(request.to_asset.chain(), &multicall_results).await?;
-            gas_fee = Self::calculate_fee_in_token(&gas_fee, &price, 6);
-        }
 
-        // Check if bridge amount is too small
-        if remain_amount < gas_fee {
-            return Err(SwapperError::InputAmountError { min_amount: None });
-        }
+        let (from_amount, output_amount, to_value, mut v3_relay_data) = match request.mode {
+            SwapperMode::ExactIn => {
+                let from_amount = amount;
+
+                // Calculate lp fee
+                let lpfee_percent = lpfee_calc.realized_lp_fee_pct(&util_before, &util_after, false);
+                let lpfee = fees::multiply(from_amount, lpfee_percent, cost_config.decimals);
+
+                // Calculate relayer fee
+                let relayer_fee_percent = relayer_calc.capital_fee_percent(&BigInt::from_str(&request.value).unwrap(), cost_config);
+                let relayer_fee = fees::multiply(from_amount, relayer_fee_percent, cost_config.decimals);
+
+                // Calculate gas limit / price for relayer
+                let remain_amount = from_amount - lpfee - relayer_fee;
+                let (message, referral_fee) = self.message_for_multicall_handler(&remain_amount, &original_output_asset, &wallet_address, &output_token, &referral_config);
+
+                let (gas_limit, v3_relay_data) = self
+                    .estimate_gas_limit(
+                        &from_amount,
+                        input_is_native,
+                        &input_asset,
+                        &output_token,
+                        &wallet_address,
+                        &message,
+                        &destination_deployment,
+                        request.to_asset.chain(),
+                    )
+                    .await?;
+                let gas_fee = self
+                    .relayer_gas_fee(gas_limit * gas_price, input_is_native, request.to_asset.chain(), &multicall_results)
+                    .await?;
+
+                // Check if bridge amount is too small
+                if remain_amount < gas_fee {
+                    return Err(SwapperError::InputAmountError { min_amount: None });
+                }
+
+                let output_amount = remain_amount - gas_fee;
+                (from_amount, output_amount, output_amount - referral_fee, v3_relay_data)
+            }
+            SwapperMode::ExactOut => {
+                // Output amount of the deposit, before the referral fee is taken by the multicall handler
+                let output_amount = Self::amount_before_fee(amount, &(BigInt::from(referral_config.bps) * BigInt::from(10u64.pow(14))))?;
+                let (message, referral_fee) = self.message_for_multicall_handler(&output_amount, &original_output_asset, &output_token, &wallet_address, &referral_config);
+
+                let (gas_limit, mut v3_relay_data) = self
+                    .estimate_gas_limit(
+                        &output_amount,
+                        input_is_native,
+                        &input_asset,
+                        &output_token,
+                        &wallet_address,
+                        &message,
+                        &destination_deployment,
+                        request.to_asset.chain(),
+                    )
+                    .await?;
+                let gas_fee = self
+                    .relayer_gas_fee(gas_limit * gas_price, input_is_native, request.to_asset.chain(), &multicall_results)
+                    .await?;
+                let remain_amount = output_amount + gas_fee;
+
+                // Estimate input amount from fees at the output amount, then reprice lp and relayer fees at the estimated input amount
+                let fee_percent =
+                    lpfee_calc.realized_lp_fee_pct(&util_before, &util_after, false) + relayer_calc.capital_fee_percent(&Self::u256_to_bigint(&remain_amount), cost_config);
+                let estimated_amount = Self::amount_before_fee(remain_amount, &fee_percent)?;
+                let results = self
+                    .multicall3(hubpool_client.chain, vec![hubpool_client.utilization_call3(&mainnet_token, estimated_amount)])
+                    .await?;
+                let util_after = hubpool_client.decoded_utilization_call3(&results[0])?;
+                let fee_percent =
+                    lpfee_calc.realized_lp_fee_pct(&util_before, &util_after, false) + relayer_calc.capital_fee_percent(&Self::u256_to_bigint(&estimated_amount), cost_config);
+                let from_amount = Self::amount_before_fee(remain_amount, &fee_percent)?;
+
+                v3_relay_data.inputAmount = from_amount;
+                (from_amount, output_amount, output_amount - referral_fee, v3_relay_data)
+            }
+        };
 
-        let output_amount = remain_amount - gas_fee;
-        let to_value = output_amount - referral_fee;
+        // Check bridge amount is too large (Across API has some limit in USD amount but we don't have that info)
+        if from_amount > liquid_reserves {
+            return Err(SwapperError::ComputeQuoteError("Bridge amount is too large".into()));
+        }
 
         // Update v3 relay data (was used to estimate gas limit) with final output amount, quote timestamp and referral fee.
         self.update_v3_relay_data(
@@ -488,7 +562,7 @@ impl Swapper for Across {
         let route_data = HexEncode(v3_relay_data.abi_encode());
 
         Ok(Quote {
-            from_value: request.value.clone(),
+            from_value: from_amount.to_string(),
             to_value: to_value.to_string(),
             data: ProviderData {
                 provider: self.provider().clone(),
@@ -501,6 +575,9 @@ impl Swapper for Across {
             },
             request: request.clone(),
             eta_in_seconds: self.get_eta_in_seconds(&request.from_asset.chain(), &request.to_asset.chain()),
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
@@ -665,6 +742,16 @@ mod tests {
         assert_eq!(fee_in_token.to_string(), "6243790");
     }
 
+    #[test]
+    fn test_amount_before_fee() {
+        let fee_percent = BigInt::from(10u64.pow(15));
+
+        assert_eq!(Across::amount_before_fee(U256::from(999_000), &fee_percent).unwrap(), U256::from(1_000_000));
+        assert_eq!(Across::amount_before_fee(U256::from(999_001), &fee_percent).unwrap(), U256::from(1_000_002));
+        assert_eq!(Across::amount_before_fee(U256::from(1_000), &BigInt::from(0)).unwrap(), U256::from(1_000));
+        assert!(Across::amount_before_fee(U256::from(1_000), &BigInt::from(10u64.pow(18))).is_err());
+    }
+
     #[test]
     fn test_resolve_token_asset_native_eth_via_weth() {
         let result = resolve_token_asset(Chain::Ethereum, ETHEREUM_WETH_TOKEN_ID);
@@ -816,6 +903,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             };
 
             let request = QuoteRequest {
@@ -851,6 +939,7 @@ mod tests {
                 fee: None,
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             };
 
             let wallet = "0x9b1fe00135e0ff09389bfaeff0c8f299ec818d4a";
@@ -0,0 +1,30 @@
+use super::model::{CctpMessage, CctpMessages};
+use crate::SwapperError;
+use gem_client::{Client, ClientError, ClientExt};
+use std::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct IrisClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    client: C,
+}
+
+impl<C> IrisClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    pub async fn get_message(&self, source_domain: u32, transaction_hash: &str) -> Result<Option<CctpMessage>, SwapperError> {
+        let result: Result<CctpMessages, ClientError> = self.client.get(&format!("/v2/messages/{source_domain}?transactionHash={transaction_hash}")).await;
+        match result {
+            Ok(response) => Ok(response.messages.into_iter().next()),
+            Err(ClientError::Http { status: 404, .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
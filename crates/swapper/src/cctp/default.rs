@@ -0,0 +1,10 @@
+use super::{IRIS_API_URL, client::IrisClient, provider::Cctp};
+use crate::alien::{RpcClient, RpcProvider};
+use std::sync::Arc;
+
+impl Cctp<RpcClient> {
+    pub fn new(provider: Arc<dyn RpcProvider>) -> Self {
+        let client = IrisClient::new(RpcClient::new(IRIS_API_URL.to_string(), provider.clone()));
+        Self::with_client(client, provider)
+    }
+}
@@ -0,0 +1,12 @@
+mod client;
+mod default;
+mod model;
+mod provider;
+mod solana;
+
+pub use provider::Cctp;
+
+pub const IRIS_API_URL: &str = "https://iris-api.circle.com";
+
+pub(crate) const DEFAULT_DEPOSIT_GAS_LIMIT: u64 = 200_000;
+pub(crate) const DEFAULT_MINT_GAS_LIMIT: u64 = 200_000;
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CctpMessageStatus {
+    PendingConfirmations,
+    Complete,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CctpMessageBody {
+    pub burn_token: String,
+    pub mint_recipient: String,
+    pub amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CctpDecodedMessage {
+    pub source_domain: String,
+    pub destination_domain: String,
+    pub nonce: String,
+    pub decoded_message_body: Option<CctpMessageBody>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CctpMessage {
+    pub message: String,
+    pub attestation: String,
+    pub status: CctpMessageStatus,
+    pub decoded_message: Option<CctpDecodedMessage>,
+}
+
+impl CctpMessage {
+    pub fn is_attested(&self) -> bool {
+        self.status == CctpMessageStatus::Complete && self.attestation.starts_with("0x")
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CctpMessages {
+    #[serde(default)]
+    pub messages: Vec<CctpMessage>,
+}
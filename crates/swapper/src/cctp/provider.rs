@@ -0,0 +1,472 @@
+use super::{
+    DEFAULT_DEPOSIT_GAS_LIMIT,
+    client::IrisClient,
+    model::{CctpDecodedMessage, CctpMessage},
+    solana::{
+        CctpBurnMessage, CctpEventAccount, MESSAGE_TRANSMITTER_V2_PROGRAM, TOKEN_MESSENGER_MINTER_V2_PROGRAM, build_transaction, decode_fee_recipient,
+        deposit_for_burn_instruction, get_account_data, get_recent_blockhash, parse_pubkey, receive_message_instruction, token_messenger_address, usdc_token_account,
+        used_nonce_address,
+    },
+};
+use crate::{
+    CctpAttestationStatus, FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperProvider,
+    SwapperQuoteData, alien::RpcProvider, approval::check_approval_erc20, client_factory::create_eth_client, eth_address,
+};
+use alloy_primitives::{B256, Bytes, U256, hex::encode_prefixed as HexEncode};
+use alloy_sol_types::SolCall;
+use async_trait::async_trait;
+use gem_client::Client;
+use gem_evm::cctp::{
+    contracts::{IMessageTransmitterV2, ITokenMessengerV2},
+    deployment::{CctpDeployment, FINALITY_THRESHOLD_STANDARD, MESSAGE_TRANSMITTER_V2, TOKEN_MESSENGER_V2, deployment_by_chain, deployment_by_domain, deployments},
+};
+use primitives::{AssetId, Chain, TransactionSwapMetadata, swap::SwapStatus};
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+#[derive(Debug)]
+pub struct Cctp<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    provider: ProviderType,
+    client: IrisClient<C>,
+    rpc_provider: Arc<dyn RpcProvider>,
+}
+
+impl<C> Cctp<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn with_client(client: IrisClient<C>, rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        Self {
+            provider: ProviderType::new(SwapperProvider::Cctp),
+            client,
+            rpc_provider,
+        }
+    }
+
+    fn usdc_deployment(asset_id: &AssetId) -> Result<CctpDeployment, SwapperError> {
+        let deployment = deployment_by_chain(&asset_id.chain).ok_or(SwapperError::NotSupportedChain)?;
+        match &asset_id.token_id {
+            Some(token_id) if token_id.eq_ignore_ascii_case(deployment.usdc) => Ok(deployment),
+            _ => Err(SwapperError::NotSupportedAsset),
+        }
+    }
+
+    fn eta_in_seconds(chain: Chain) -> u32 {
+        match chain {
+            Chain::Ethereum | Chain::Arbitrum | Chain::Base | Chain::Optimism | Chain::Unichain => 19 * 60,
+            _ => 30,
+        }
+    }
+
+    async fn get_message(&self, chain: Chain, transaction_hash: &str) -> Result<Option<CctpMessage>, SwapperError> {
+        let deployment = deployment_by_chain(&chain).ok_or(SwapperError::NotSupportedChain)?;
+        self.client.get_message(deployment.domain, transaction_hash).await
+    }
+
+    async fn attestation_status(&self, message: &CctpMessage) -> Result<CctpAttestationStatus, SwapperError> {
+        if !message.is_attested() {
+            return Ok(CctpAttestationStatus::PendingAttestation);
+        }
+        let (decoded, destination) = get_destination(message)?;
+        match self.is_minted(&destination, &decoded.nonce).await? {
+            true => Ok(CctpAttestationStatus::Minted),
+            false => Ok(CctpAttestationStatus::Attested),
+        }
+    }
+
+    fn mint_recipient(chain: Chain, destination_address: &str) -> Result<B256, SwapperError> {
+        match chain {
+            Chain::Solana => Ok(B256::from(*usdc_token_account(&parse_pubkey(destination_address)?)?.as_bytes())),
+            _ => Ok(eth_address::parse_str(destination_address)?.into_word()),
+        }
+    }
+
+    async fn is_minted(&self, destination: &CctpDeployment, nonce: &str) -> Result<bool, SwapperError> {
+        let nonce = B256::from_str(nonce).map_err(|_| SwapperError::InvalidRoute)?;
+        if destination.chain == Chain::Solana {
+            let used_nonce = get_account_data(self.rpc_provider.clone(), &used_nonce_address(&nonce.0)?).await?;
+            return Ok(used_nonce.is_some());
+        }
+        let used = create_eth_client(self.rpc_provider.clone(), destination.chain)?
+            .call_contract(eth_address::parse_str(MESSAGE_TRANSMITTER_V2)?, IMessageTransmitterV2::usedNoncesCall { nonce })
+            .await
+            .map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))?;
+        Ok(!used.is_zero())
+    }
+
+    async fn get_solana_mint_data(&self, message: &[u8], attestation: &[u8], wallet_address: &str) -> Result<SwapperQuoteData, SwapperError> {
+        let payer = parse_pubkey(wallet_address)?;
+        let mint_recipient = CctpBurnMessage::decode(message)?.mint_recipient;
+        let token_messenger_address = token_messenger_address()?;
+        let (token_messenger, recipient_account, recent_blockhash) = futures::try_join!(
+            get_account_data(self.rpc_provider.clone(), &token_messenger_address),
+            get_account_data(self.rpc_provider.clone(), &mint_recipient),
+            get_recent_blockhash(self.rpc_provider.clone())
+        )?;
+        if recipient_account.is_none() {
+            return Err(SwapperError::TransactionError("recipient USDC token account does not exist".into()));
+        }
+        let fee_recipient = decode_fee_recipient(&token_messenger.ok_or(SwapperError::InvalidRoute)?)?;
+        let instruction = receive_message_instruction(&payer, message, attestation, &fee_recipient)?;
+        let data = build_transaction(&payer, vec![instruction], recent_blockhash, None)?;
+        Ok(SwapperQuoteData::new_contract(
+            MESSAGE_TRANSMITTER_V2_PROGRAM.to_string(),
+            "0".to_string(),
+            data,
+            None,
+            None,
+        ))
+    }
+
+    async fn get_solana_burn_data(&self, request: &QuoteRequest, destination: &CctpDeployment, mint_recipient: B256) -> Result<SwapperQuoteData, SwapperError> {
+        let owner = parse_pubkey(&request.wallet_address)?;
+        let amount = request.value.parse::<u64>()?;
+        let event_account = CctpEventAccount::generate()?;
+        let instruction = deposit_for_burn_instruction(&owner, &event_account.pubkey, amount, destination.domain, mint_recipient.0, FINALITY_THRESHOLD_STANDARD)?;
+        let recent_blockhash = get_recent_blockhash(self.rpc_provider.clone()).await?;
+        let data = build_transaction(&owner, vec![instruction], recent_blockhash, Some(&event_account))?;
+        Ok(SwapperQuoteData::new_contract(
+            TOKEN_MESSENGER_MINTER_V2_PROGRAM.to_string(),
+            "0".to_string(),
+            data,
+            None,
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<C> Swapper for Cctp<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    fn provider(&self) -> &ProviderType {
+        &self.provider
+    }
+
+    fn supported_assets(&self) -> Vec<SwapperChainAsset> {
+        deployments()
+            .into_iter()
+            .map(|x| SwapperChainAsset::Assets(x.chain, vec![AssetId::from_token(x.chain, x.usdc)]))
+            .collect()
+    }
+
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
+    fn get_approval_spender(&self, quote: &Quote) -> Option<String> {
+        deployment_by_chain(&quote.request.from_asset.chain())
+            .filter(|x| x.chain != Chain::Solana)
+            .map(|_| TOKEN_MESSENGER_V2.to_string())
+    }
+
+    async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        let from_asset = request.from_asset.asset_id();
+        let to_asset = request.to_asset.asset_id();
+        if from_asset.chain == to_asset.chain {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        Self::usdc_deployment(&from_asset)?;
+        Self::usdc_deployment(&to_asset)?;
+        Self::mint_recipient(to_asset.chain, &request.destination_address)?;
+
+        Ok(Quote {
+            from_value: request.value.clone(),
+            to_value: request.value.clone(),
+            data: ProviderData {
+                provider: self.provider.clone(),
+                routes: vec![Route {
+                    input: from_asset.clone(),
+                    output: to_asset,
+                    route_data: String::new(),
+                }],
+                slippage_bps: request.options.slippage.bps,
+            },
+            request: request.clone(),
+            eta_in_seconds: Some(Self::eta_in_seconds(from_asset.chain)),
+            fees: None,
+            price_impact: None,
+            composed: None,
+        })
+    }
+
+    async fn get_quote_data(&self, quote: &Quote, _data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError> {
+        let request = &quote.request;
+        let source = Self::usdc_deployment(&request.from_asset.asset_id())?;
+        let destination = Self::usdc_deployment(&request.to_asset.asset_id())?;
+        let mint_recipient = Self::mint_recipient(destination.chain, &request.destination_address)?;
+        if source.chain == Chain::Solana {
+            return self.get_solana_burn_data(request, &destination, mint_recipient).await;
+        }
+        let amount = U256::from_str(&request.value)?;
+
+        let call = ITokenMessengerV2::depositForBurnCall {
+            amount,
+            destinationDomain: destination.domain,
+            mintRecipient: mint_recipient,
+            burnToken: eth_address::parse_str(source.usdc)?,
+            destinationCaller: B256::ZERO,
+            maxFee: U256::ZERO,
+            minFinalityThreshold: FINALITY_THRESHOLD_STANDARD,
+        };
+        let approval = check_approval_erc20(
+            request.wallet_address.clone(),
+            source.usdc.to_string(),
+            TOKEN_MESSENGER_V2.to_string(),
+            amount,
+            self.rpc_provider.clone(),
+            &source.chain,
+        )
+        .await?
+        .approval_data();
+        let gas_limit = approval.as_ref().map(|_| DEFAULT_DEPOSIT_GAS_LIMIT.to_string());
+
+        Ok(SwapperQuoteData::new_contract(
+            TOKEN_MESSENGER_V2.to_string(),
+            "0".to_string(),
+            HexEncode(call.abi_encode()),
+            approval,
+            gas_limit,
+        ))
+    }
+
+    async fn get_swap_result(&self, chain: Chain, transaction_hash: &str) -> Result<SwapResult, SwapperError> {
+        let Some(message) = self.get_message(chain, transaction_hash).await? else {
+            return Ok(SwapResult {
+                status: SwapStatus::Pending,
+                metadata: None,
+            });
+        };
+        let status = self.attestation_status(&message).await?;
+        map_swap_result(chain, &message, status)
+    }
+
+    async fn get_attestation_status(&self, chain: Chain, transaction_hash: &str) -> Result<CctpAttestationStatus, SwapperError> {
+        match self.get_message(chain, transaction_hash).await? {
+            Some(message) => self.attestation_status(&message).await,
+            None => Ok(CctpAttestationStatus::PendingAttestation),
+        }
+    }
+
+    async fn get_mint_data(&self, chain: Chain, transaction_hash: &str, wallet_address: &str) -> Result<SwapperQuoteData, SwapperError> {
+        let message = self
+            .get_message(chain, transaction_hash)
+            .await?
+            .filter(CctpMessage::is_attested)
+            .ok_or(SwapperError::TransactionError("attestation is not ready".into()))?;
+        let (_, destination) = get_destination(&message)?;
+        let message_bytes = Bytes::from_str(&message.message).map_err(|_| SwapperError::InvalidRoute)?;
+        let attestation = Bytes::from_str(&message.attestation).map_err(|_| SwapperError::InvalidRoute)?;
+
+        if destination.chain == Chain::Solana {
+            return self.get_solana_mint_data(&message_bytes, &attestation, wallet_address).await;
+        }
+        let call = IMessageTransmitterV2::receiveMessageCall {
+            message: message_bytes,
+            attestation,
+        };
+        Ok(SwapperQuoteData::new_contract(
+            MESSAGE_TRANSMITTER_V2.to_string(),
+            "0".to_string(),
+            HexEncode(call.abi_encode()),
+            None,
+            None,
+        ))
+    }
+}
+
+fn get_destination(message: &CctpMessage) -> Result<(&CctpDecodedMessage, CctpDeployment), SwapperError> {
+    let decoded = message.decoded_message.as_ref().ok_or(SwapperError::InvalidRoute)?;
+    let domain = decoded.destination_domain.parse::<u32>()?;
+    let deployment = deployment_by_domain(domain).ok_or(SwapperError::NotSupportedChain)?;
+    Ok((decoded, deployment))
+}
+
+fn map_swap_result(chain: Chain, message: &CctpMessage, status: CctpAttestationStatus) -> Result<SwapResult, SwapperError> {
+    if status != CctpAttestationStatus::Minted {
+        return Ok(SwapResult {
+            status: SwapStatus::Pending,
+            metadata: None,
+        });
+    }
+    let source = deployment_by_chain(&chain).ok_or(SwapperError::NotSupportedChain)?;
+    let (decoded, destination) = get_destination(message)?;
+    let amount = decoded.decoded_message_body.as_ref().map(|x| x.amount.clone()).ok_or(SwapperError::InvalidRoute)?;
+    Ok(SwapResult {
+        status: SwapStatus::Completed,
+        metadata: Some(TransactionSwapMetadata {
+            from_asset: AssetId::from_token(source.chain, source.usdc),
+            from_value: amount.clone(),
+            to_asset: AssetId::from_token(destination.chain, destination.usdc),
+            to_value: amount,
+            provider: Some(SwapperProvider::Cctp.id().to_string()),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SwapperQuoteAsset, alien::mock::ProviderMock, cctp::model::CctpMessages, testkit::mock_quote};
+    use gem_client::{ClientError, testkit::MockClient};
+    use primitives::asset_constants::{ARBITRUM_USDC_TOKEN_ID, BASE_USDC_TOKEN_ID, ETHEREUM_USDT_TOKEN_ID, SOLANA_USDC_TOKEN_ID};
+
+    const TX_HASH: &str = "0x912f22a13e9ccb979b621500f6952b2afd6e75be7eadaed93fc2625fe11c52a2";
+
+    fn mock_message(status: &str, attestation: &str) -> serde_json::Value {
+        serde_json::json!({
+            "messages": [{
+                "message": "0x0000000100000003",
+                "attestation": attestation,
+                "status": status,
+                "decodedMessage": {
+                    "sourceDomain": "3",
+                    "destinationDomain": "6",
+                    "nonce": "0x5b2b1b2e7f5e1bd0d0ef1ff8fc1b0a21f1e5e7b2c2dba3f1f39b25e2b0bdbf66",
+                    "decodedMessageBody": {
+                        "burnToken": ARBITRUM_USDC_TOKEN_ID,
+                        "mintRecipient": "0x000000000000000000000000514bcb1f9aabb904e6106bd1052b66d2706dbbb7",
+                        "amount": "1000000"
+                    }
+                }
+            }]
+        })
+    }
+
+    fn mock_provider(response: Result<serde_json::Value, u16>) -> Cctp<MockClient> {
+        mock_provider_with_rpc(response, "{}")
+    }
+
+    fn mock_provider_with_rpc(response: Result<serde_json::Value, u16>, rpc_response: &str) -> Cctp<MockClient> {
+        let client = MockClient::new().with_get(move |_| match &response {
+            Ok(value) => Ok(serde_json::to_vec(value).unwrap()),
+            Err(status) => Err(ClientError::Http { status: *status, body: vec![] }),
+        });
+        Cctp::with_client(IrisClient::new(client), Arc::new(ProviderMock::new(rpc_response.to_string())))
+    }
+
+    fn mock_used_nonce(value: u8) -> String {
+        serde_json::json!({"jsonrpc": "2.0", "id": 1, "result": format!("0x{value:064x}")}).to_string()
+    }
+
+    fn usdc(chain: Chain, token_id: &str) -> SwapperQuoteAsset {
+        SwapperQuoteAsset {
+            id: AssetId::from_token(chain, token_id).to_string(),
+            symbol: "USDC".to_string(),
+            decimals: 6,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_quote() {
+        let provider = mock_provider(Err(404));
+        let request = mock_quote(usdc(Chain::Arbitrum, ARBITRUM_USDC_TOKEN_ID), usdc(Chain::Base, BASE_USDC_TOKEN_ID));
+
+        let quote = provider.get_quote(&request).await.unwrap();
+
+        assert_eq!(quote.to_value, request.value);
+        assert_eq!(quote.eta_in_seconds, Some(19 * 60));
+
+        let request = mock_quote(usdc(Chain::Arbitrum, ARBITRUM_USDC_TOKEN_ID), usdc(Chain::Ethereum, ETHEREUM_USDT_TOKEN_ID));
+        assert_eq!(provider.get_quote(&request).await.unwrap_err(), SwapperError::NotSupportedAsset);
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_solana() {
+        let provider = mock_provider(Err(404));
+        let mut request = mock_quote(usdc(Chain::Arbitrum, ARBITRUM_USDC_TOKEN_ID), usdc(Chain::Solana, SOLANA_USDC_TOKEN_ID));
+        assert_eq!(provider.get_quote(&request).await.unwrap_err(), SwapperError::InvalidRoute);
+
+        request.destination_address = "A21o4asMbFHYadqXdLusT9Bvx9xaC5YV9gcaidjqtdXC".to_string();
+        let quote = provider.get_quote(&request).await.unwrap();
+        assert_eq!(quote.to_value, request.value);
+        assert_eq!(provider.get_approval_spender(&quote), Some(TOKEN_MESSENGER_V2.to_string()));
+
+        let request = mock_quote(usdc(Chain::Solana, SOLANA_USDC_TOKEN_ID), usdc(Chain::Base, BASE_USDC_TOKEN_ID));
+        let quote = provider.get_quote(&request).await.unwrap();
+        assert_eq!(provider.get_approval_spender(&quote), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_swap_result_pending() {
+        let result = mock_provider(Err(404)).get_swap_result(Chain::Arbitrum, TX_HASH).await.unwrap();
+        assert_eq!(result.status, SwapStatus::Pending);
+
+        let result = mock_provider(Ok(mock_message("pending_confirmations", "PENDING")))
+            .get_swap_result(Chain::Arbitrum, TX_HASH)
+            .await
+            .unwrap();
+        assert_eq!(result.status, SwapStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_get_attestation_status() {
+        let provider = mock_provider(Err(404));
+        assert_eq!(
+            provider.get_attestation_status(Chain::Arbitrum, TX_HASH).await.unwrap(),
+            CctpAttestationStatus::PendingAttestation
+        );
+
+        let provider = mock_provider(Ok(mock_message("pending_confirmations", "PENDING")));
+        assert_eq!(
+            provider.get_attestation_status(Chain::Arbitrum, TX_HASH).await.unwrap(),
+            CctpAttestationStatus::PendingAttestation
+        );
+
+        let provider = mock_provider_with_rpc(Ok(mock_message("complete", "0xaabb")), &mock_used_nonce(1));
+        assert_eq!(provider.get_attestation_status(Chain::Arbitrum, TX_HASH).await.unwrap(), CctpAttestationStatus::Minted);
+    }
+
+    #[tokio::test]
+    async fn test_get_swap_result_attested_not_minted() {
+        let provider = mock_provider_with_rpc(Ok(mock_message("complete", "0xaabb")), &mock_used_nonce(0));
+
+        assert_eq!(provider.get_attestation_status(Chain::Arbitrum, TX_HASH).await.unwrap(), CctpAttestationStatus::Attested);
+
+        let result = provider.get_swap_result(Chain::Arbitrum, TX_HASH).await.unwrap();
+        assert_eq!(result.status, SwapStatus::Pending);
+        assert_eq!(result.metadata, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_mint_data() {
+        let provider = mock_provider(Ok(mock_message("pending_confirmations", "PENDING")));
+        assert!(
+            provider
+                .get_mint_data(Chain::Arbitrum, TX_HASH, "0x514BCb1F9AAbb904e6106Bd1052B66d2706dBbb7")
+                .await
+                .is_err()
+        );
+
+        let provider = mock_provider(Ok(mock_message("complete", "0xaabb")));
+        let data = provider
+            .get_mint_data(Chain::Arbitrum, TX_HASH, "0x514BCb1F9AAbb904e6106Bd1052B66d2706dBbb7")
+            .await
+            .unwrap();
+
+        assert_eq!(data.to, MESSAGE_TRANSMITTER_V2);
+        assert!(data.data.starts_with(&HexEncode(IMessageTransmitterV2::receiveMessageCall::SELECTOR)));
+    }
+
+    #[test]
+    fn test_map_swap_result() {
+        let messages: CctpMessages = serde_json::from_value(mock_message("complete", "0xaabb")).unwrap();
+        let message = &messages.messages[0];
+
+        assert_eq!(
+            map_swap_result(Chain::Arbitrum, message, CctpAttestationStatus::Attested).unwrap().status,
+            SwapStatus::Pending
+        );
+
+        let result = map_swap_result(Chain::Arbitrum, message, CctpAttestationStatus::Minted).unwrap();
+        let metadata = result.metadata.unwrap();
+
+        assert_eq!(result.status, SwapStatus::Completed);
+        assert_eq!(metadata.from_asset, AssetId::from_token(Chain::Arbitrum, ARBITRUM_USDC_TOKEN_ID));
+        assert_eq!(metadata.to_asset, AssetId::from_token(Chain::Base, BASE_USDC_TOKEN_ID));
+        assert_eq!(metadata.to_value, "1000000");
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{SwapperError, alien::RpcProvider, client_factory::create_client_with_chain};
+use gem_encoding::{decode_base64, encode_base64};
+use gem_solana::{
+    USDC_TOKEN_MINT,
+    jsonrpc::SolanaRpc,
+    models::{AccountData, LatestBlockhash, ValueResult},
+};
+use primitives::Chain;
+use solana_primitives::{
+    AccountMeta, Instruction, InstructionBuilder, Pubkey, TransactionBuilder, find_program_address, get_public_key,
+    instructions::{
+        associated_token::get_associated_token_address,
+        program_ids::{system_program, token_program},
+    },
+};
+use std::{str::FromStr, sync::Arc};
+
+// https://github.com/circlefin/solana-cctp-contracts/tree/master/programs/v2
+pub const MESSAGE_TRANSMITTER_V2_PROGRAM: &str = "CCTPV2Sm4AdWt5296sk4P66VBZ7bEhcARwFaaS9YPbeC";
+pub const TOKEN_MESSENGER_MINTER_V2_PROGRAM: &str = "CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe";
+
+const DEPOSIT_FOR_BURN_DISCRIMINATOR: [u8; 8] = [215, 60, 61, 46, 114, 55, 128, 176];
+const RECEIVE_MESSAGE_DISCRIMINATOR: [u8; 8] = [38, 144, 127, 225, 31, 225, 238, 25];
+
+// TokenMessenger account: discriminator, denylister, owner, pending_owner, message_body_version (u32), authority_bump (u8), fee_recipient
+const TOKEN_MESSENGER_FEE_RECIPIENT_OFFSET: usize = 8 + 32 * 3 + 4 + 1;
+
+// Message header: version, source_domain, destination_domain, nonce, sender, recipient, destination_caller, finality thresholds
+const MESSAGE_SOURCE_DOMAIN_OFFSET: usize = 4;
+const MESSAGE_NONCE_OFFSET: usize = 12;
+const MESSAGE_BODY_OFFSET: usize = 148;
+// Burn message body: version, burn_token, mint_recipient, amount, ...
+const BURN_TOKEN_OFFSET: usize = MESSAGE_BODY_OFFSET + 4;
+const MINT_RECIPIENT_OFFSET: usize = BURN_TOKEN_OFFSET + 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CctpBurnMessage {
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    pub burn_token: [u8; 32],
+    pub mint_recipient: Pubkey,
+}
+
+impl CctpBurnMessage {
+    pub fn decode(message: &[u8]) -> Result<Self, SwapperError> {
+        if message.len() < MINT_RECIPIENT_OFFSET + 32 {
+            return Err(SwapperError::InvalidRoute);
+        }
+        let bytes32 = |offset: usize| -> [u8; 32] { message[offset..offset + 32].try_into().unwrap() };
+        let source_domain = u32::from_be_bytes(message[MESSAGE_SOURCE_DOMAIN_OFFSET..MESSAGE_SOURCE_DOMAIN_OFFSET + 4].try_into().unwrap());
+        Ok(Self {
+            source_domain,
+            nonce: bytes32(MESSAGE_NONCE_OFFSET),
+            burn_token: bytes32(BURN_TOKEN_OFFSET),
+            mint_recipient: Pubkey::new(bytes32(MINT_RECIPIENT_OFFSET)),
+        })
+    }
+}
+
+pub struct CctpEventAccount {
+    pub private_key: [u8; 32],
+    pub pubkey: Pubkey,
+}
+
+impl CctpEventAccount {
+    pub fn generate() -> Result<Self, SwapperError> {
+        let private_key: [u8; 32] = rand::random();
+        let pubkey = get_public_key(&private_key).map_err(|e| SwapperError::TransactionError(e.to_string()))?;
+        Ok(Self {
+            private_key,
+            pubkey: Pubkey::new(pubkey),
+        })
+    }
+}
+
+pub fn parse_pubkey(address: &str) -> Result<Pubkey, SwapperError> {
+    Pubkey::from_str(address).map_err(|_| SwapperError::InvalidRoute)
+}
+
+pub fn usdc_token_account(wallet: &Pubkey) -> Result<Pubkey, SwapperError> {
+    Ok(get_associated_token_address(wallet, &parse_pubkey(USDC_TOKEN_MINT)?))
+}
+
+pub fn used_nonce_address(nonce: &[u8; 32]) -> Result<Pubkey, SwapperError> {
+    pda(&parse_pubkey(MESSAGE_TRANSMITTER_V2_PROGRAM)?, &[b"used_nonce", nonce])
+}
+
+pub fn token_messenger_address() -> Result<Pubkey, SwapperError> {
+    pda(&parse_pubkey(TOKEN_MESSENGER_MINTER_V2_PROGRAM)?, &[b"token_messenger"])
+}
+
+pub fn decode_fee_recipient(token_messenger_data: &[u8]) -> Result<Pubkey, SwapperError> {
+    token_messenger_data
+        .get(TOKEN_MESSENGER_FEE_RECIPIENT_OFFSET..TOKEN_MESSENGER_FEE_RECIPIENT_OFFSET + 32)
+        .map(|bytes| Pubkey::new(bytes.try_into().unwrap()))
+        .ok_or(SwapperError::InvalidRoute)
+}
+
+fn pda(program_id: &Pubkey, seeds: &[&[u8]]) -> Result<Pubkey, SwapperError> {
+    find_program_address(program_id, seeds)
+        .map(|(address, _)| address)
+        .map_err(|e| SwapperError::TransactionError(e.to_string()))
+}
+
+pub fn deposit_for_burn_instruction(
+    owner: &Pubkey,
+    event_account: &Pubkey,
+    amount: u64,
+    destination_domain: u32,
+    mint_recipient: [u8; 32],
+    min_finality_threshold: u32,
+) -> Result<Instruction, SwapperError> {
+    let token_messenger_minter = parse_pubkey(TOKEN_MESSENGER_MINTER_V2_PROGRAM)?;
+    let message_transmitter = parse_pubkey(MESSAGE_TRANSMITTER_V2_PROGRAM)?;
+    let usdc_mint = parse_pubkey(USDC_TOKEN_MINT)?;
+    let destination_domain_seed = destination_domain.to_string();
+
+    let data = [
+        DEPOSIT_FOR_BURN_DISCRIMINATOR.as_slice(),
+        &amount.to_le_bytes(),
+        &destination_domain.to_le_bytes(),
+        &mint_recipient,
+        &[0u8; 32],
+        &0u64.to_le_bytes(),
+        &min_finality_threshold.to_le_bytes(),
+    ]
+    .concat();
+
+    Ok(InstructionBuilder::new(token_messenger_minter)
+        .account(*owner, true, false)
+        .account(*owner, true, true)
+        .account(pda(&token_messenger_minter, &[b"sender_authority"])?, false, false)
+        .account(usdc_token_account(owner)?, false, true)
+        .account(pda(&token_messenger_minter, &[b"denylist_account", owner.as_bytes()])?, false, false)
+        .account(pda(&message_transmitter, &[b"message_transmitter"])?, false, true)
+        .account(token_messenger_address()?, false, false)
+        .account(
+            pda(&token_messenger_minter, &[b"remote_token_messenger", destination_domain_seed.as_bytes()])?,
+            false,
+            false,
+        )
+        .account(pda(&token_messenger_minter, &[b"token_minter"])?, false, false)
+        .account(pda(&token_messenger_minter, &[b"local_token", usdc_mint.as_bytes()])?, false, true)
+        .account(usdc_mint, false, true)
+        .account(*event_account, true, true)
+        .account(message_transmitter, false, false)
+        .account(token_messenger_minter, false, false)
+        .account(token_program(), false, false)
+        .account(system_program(), false, false)
+        .account(pda(&token_messenger_minter, &[b"__event_authority"])?, false, false)
+        .account(token_messenger_minter, false, false)
+        .data(data)
+        .build())
+}
+
+// The mint recipient token account must already exist: creating it in the same transaction exceeds the legacy transaction size limit.
+pub fn receive_message_instruction(payer: &Pubkey, message: &[u8], attestation: &[u8], fee_recipient: &Pubkey) -> Result<Instruction, SwapperError> {
+    let burn_message = CctpBurnMessage::decode(message)?;
+    let token_messenger_minter = parse_pubkey(TOKEN_MESSENGER_MINTER_V2_PROGRAM)?;
+    let message_transmitter = parse_pubkey(MESSAGE_TRANSMITTER_V2_PROGRAM)?;
+    let usdc_mint = parse_pubkey(USDC_TOKEN_MINT)?;
+    let source_domain_seed = burn_message.source_domain.to_string();
+
+    let data = [
+        RECEIVE_MESSAGE_DISCRIMINATOR.as_slice(),
+        &(message.len() as u32).to_le_bytes(),
+        message,
+        &(attestation.len() as u32).to_le_bytes(),
+        attestation,
+    ]
+    .concat();
+
+    Ok(InstructionBuilder::new(message_transmitter)
+        .account(*payer, true, true)
+        .account(*payer, true, false)
+        .account(
+            pda(&message_transmitter, &[b"message_transmitter_authority", token_messenger_minter.as_bytes()])?,
+            false,
+            false,
+        )
+        .account(pda(&message_transmitter, &[b"message_transmitter"])?, false, false)
+        .account(used_nonce_address(&burn_message.nonce)?, false, true)
+        .account(token_messenger_minter, false, false)
+        .account(system_program(), false, false)
+        .account(pda(&message_transmitter, &[b"__event_authority"])?, false, false)
+        .account(message_transmitter, false, false)
+        .accounts(vec![
+            AccountMeta::new_readonly(token_messenger_address()?),
+            AccountMeta::new_readonly(pda(&token_messenger_minter, &[b"remote_token_messenger", source_domain_seed.as_bytes()])?),
+            AccountMeta::new_writable(pda(&token_messenger_minter, &[b"token_minter"])?),
+            AccountMeta::new_writable(pda(&token_messenger_minter, &[b"local_token", usdc_mint.as_bytes()])?),
+            AccountMeta::new_readonly(pda(&token_messenger_minter, &[b"token_pair", source_domain_seed.as_bytes(), &burn_message.burn_token])?),
+            AccountMeta::new_writable(get_associated_token_address(fee_recipient, &usdc_mint)),
+            AccountMeta::new_writable(burn_message.mint_recipient),
+            AccountMeta::new_writable(pda(&token_messenger_minter, &[b"custody", usdc_mint.as_bytes()])?),
+            AccountMeta::new_readonly(token_program()),
+            AccountMeta::new_readonly(pda(&token_messenger_minter, &[b"__event_authority"])?),
+            AccountMeta::new_readonly(token_messenger_minter),
+        ])
+        .data(data)
+        .build())
+}
+
+pub fn build_transaction(fee_payer: &Pubkey, instructions: Vec<Instruction>, recent_blockhash: [u8; 32], event_account: Option<&CctpEventAccount>) -> Result<String, SwapperError> {
+    let mut builder = TransactionBuilder::new(*fee_payer, recent_blockhash);
+    builder.add_instructions(instructions);
+    let mut transaction = builder.build().map_err(|e| SwapperError::TransactionError(e.to_string()))?;
+    if let Some(event_account) = event_account {
+        transaction
+            .partial_sign(&[&event_account.private_key], &[event_account.pubkey])
+            .map_err(|e| SwapperError::TransactionError(e.to_string()))?;
+    }
+    transaction.validate_size().map_err(|e| SwapperError::TransactionError(e.to_string()))?;
+    let bytes = transaction.serialize_legacy().map_err(|e| SwapperError::TransactionError(e.to_string()))?;
+    Ok(encode_base64(&bytes))
+}
+
+pub async fn get_recent_blockhash(provider: Arc<dyn RpcProvider>) -> Result<[u8; 32], SwapperError> {
+    let response: LatestBlockhash = create_client_with_chain(provider, Chain::Solana).request(SolanaRpc::GetLatestBlockhash).await?;
+    bs58::decode(response.value.blockhash)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(SwapperError::InvalidRoute)
+}
+
+pub async fn get_account_data(provider: Arc<dyn RpcProvider>, address: &Pubkey) -> Result<Option<Vec<u8>>, SwapperError> {
+    let response: ValueResult<Option<AccountData>> = create_client_with_chain(provider, Chain::Solana)
+        .request(SolanaRpc::GetAccountInfo(address.to_base58()))
+        .await?;
+    response
+        .value
+        .map(|account| account.data.first().map(|data| decode_base64(data)).transpose())
+        .transpose()
+        .map(Option::flatten)
+        .map_err(|_| SwapperError::InvalidRoute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    const WALLET: &str = "A21o4asMbFHYadqXdLusT9Bvx9xaC5YV9gcaidjqtdXC";
+
+    fn mock_message(source_domain: u32, mint_recipient: &Pubkey) -> Vec<u8> {
+        let mut message = vec![0u8; MESSAGE_BODY_OFFSET + 228];
+        message[4..8].copy_from_slice(&source_domain.to_be_bytes());
+        message[8..12].copy_from_slice(&5u32.to_be_bytes());
+        message[MESSAGE_NONCE_OFFSET..MESSAGE_NONCE_OFFSET + 32].copy_from_slice(&[7u8; 32]);
+        message[BURN_TOKEN_OFFSET + 12..BURN_TOKEN_OFFSET + 32].copy_from_slice(&hex::decode("af88d065e77c8cC2239327C5EDb3A432268e5831").unwrap());
+        message[MINT_RECIPIENT_OFFSET..MINT_RECIPIENT_OFFSET + 32].copy_from_slice(mint_recipient.as_bytes());
+        message
+    }
+
+    #[test]
+    fn test_decode_burn_message() {
+        let recipient = usdc_token_account(&parse_pubkey(WALLET).unwrap()).unwrap();
+        let message = CctpBurnMessage::decode(&mock_message(3, &recipient)).unwrap();
+
+        assert_eq!(message.source_domain, 3);
+        assert_eq!(message.nonce, [7u8; 32]);
+        assert_eq!(message.mint_recipient, recipient);
+        assert_eq!(hex::encode(&message.burn_token[12..]), "af88d065e77c8cc2239327c5edb3a432268e5831");
+        assert_eq!(CctpBurnMessage::decode(&[0u8; 100]).unwrap_err(), SwapperError::InvalidRoute);
+    }
+
+    #[test]
+    fn test_build_deposit_for_burn_transaction() {
+        let owner = parse_pubkey(WALLET).unwrap();
+        let event_account = CctpEventAccount::generate().unwrap();
+        let instruction = deposit_for_burn_instruction(&owner, &event_account.pubkey, 1_000_000, 3, [1u8; 32], 2000).unwrap();
+
+        assert_eq!(instruction.accounts.len(), 18);
+        assert_eq!(&instruction.data[..8], &DEPOSIT_FOR_BURN_DISCRIMINATOR);
+        assert_eq!(instruction.data.len(), 8 + 8 + 4 + 32 + 32 + 8 + 4);
+
+        let encoded = build_transaction(&owner, vec![instruction], [9u8; 32], Some(&event_account)).unwrap();
+        let transaction = solana_primitives::VersionedTransaction::deserialize_with_version(&decode_base64(&encoded).unwrap()).unwrap();
+
+        assert_eq!(transaction.num_required_signatures(), 2);
+        assert_eq!(transaction.account_keys()[0], owner);
+        assert_eq!(transaction.signatures()[0].as_bytes(), &[0u8; 64]);
+        assert_ne!(transaction.signatures()[1].as_bytes(), &[0u8; 64]);
+    }
+
+    #[test]
+    fn test_build_receive_message_transaction() {
+        let payer = parse_pubkey(WALLET).unwrap();
+        let message = mock_message(3, &usdc_token_account(&payer).unwrap());
+        let instruction = receive_message_instruction(&payer, &message, &[1u8; 130], &payer).unwrap();
+
+        assert_eq!(&instruction.data[..8], &RECEIVE_MESSAGE_DISCRIMINATOR);
+        assert_eq!(instruction.accounts.len(), 20);
+        assert_eq!(instruction.accounts[4].pubkey, used_nonce_address(&[7u8; 32]).unwrap());
+        assert_eq!(instruction.accounts[15].pubkey, usdc_token_account(&payer).unwrap());
+
+        assert!(build_transaction(&payer, vec![instruction], [9u8; 32], None).is_ok());
+    }
+
+    #[test]
+    fn test_decode_fee_recipient() {
+        let fee_recipient = parse_pubkey(WALLET).unwrap();
+        let mut data = vec![0u8; TOKEN_MESSENGER_FEE_RECIPIENT_OFFSET];
+        data.extend_from_slice(fee_recipient.as_bytes());
+
+        assert_eq!(decode_fee_recipient(&data).unwrap(), fee_recipient);
+        assert_eq!(decode_fee_recipient(&[0u8; 40]).unwrap_err(), SwapperError::InvalidRoute);
+    }
+}
@@ -24,7 +24,7 @@ use crate::{
 };
 use primitives::{ChainType, chain::Chain, swap::QuoteAsset};
 
-const DEFAULT_SWAP_ERC20_GAS_LIMIT: u64 = 100_000;
+pub(crate) const DEFAULT_SWAP_ERC20_GAS_LIMIT: u64 = 100_000;
 
 const VAULT_ETH: &str = "0xF5e10380213880111522dd0efD3dbb45b9f62Bcc";
 const VAULT_ARB: &str = "0x79001a5e762f3bEFC8e5871b42F6734e00498920";
@@ -213,6 +213,9 @@ where
                 }],
             },
             eta_in_seconds: Some(eta_in_seconds),
+            fees: None,
+            price_impact: None,
+            composed: None,
             request: request.clone(),
         })
     }
@@ -22,6 +22,7 @@ pub struct Config {
     pub permit2_sig_deadline: u64,
     pub referral_fee: ReferralFees,
     pub high_price_impact_percent: u32,
+    pub max_price_impact_percent: u32,
 }
 
 pub fn get_swap_config() -> Config {
@@ -34,6 +35,7 @@ pub fn get_swap_config() -> Config {
         permit2_sig_deadline: 1800,    // 30 minutes
         referral_fee: default_referral_fees(),
         high_price_impact_percent: 10,
+        max_price_impact_percent: 50,
     }
 }
 
@@ -0,0 +1,33 @@
+use super::model::{CowOrder, CowOrderCreation, CowQuoteRequest, CowQuoteResponse};
+use crate::SwapperError;
+use gem_client::{Client, ClientExt};
+use std::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct CowSwapClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    client: C,
+}
+
+impl<C> CowSwapClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    pub async fn get_quote(&self, network: &str, request: &CowQuoteRequest) -> Result<CowQuoteResponse, SwapperError> {
+        Ok(self.client.post(&format!("/{network}/api/v1/quote"), request).await?)
+    }
+
+    pub async fn post_order(&self, network: &str, order: &CowOrderCreation) -> Result<String, SwapperError> {
+        Ok(self.client.post(&format!("/{network}/api/v1/orders"), order).await?)
+    }
+
+    pub async fn get_order(&self, network: &str, uid: &str) -> Result<CowOrder, SwapperError> {
+        Ok(self.client.get(&format!("/{network}/api/v1/orders/{uid}")).await?)
+    }
+}
@@ -0,0 +1,13 @@
+use super::{client::CowSwapClient, provider::CowSwap};
+use crate::{
+    alien::{RpcClient, RpcProvider},
+    config,
+};
+use std::sync::Arc;
+
+impl CowSwap<RpcClient> {
+    pub fn new(provider: Arc<dyn RpcProvider>) -> Self {
+        let client = CowSwapClient::new(RpcClient::new(config::get_swap_api_url("cowswap"), provider.clone()));
+        Self::with_client(client, provider)
+    }
+}
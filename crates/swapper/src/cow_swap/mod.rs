@@ -0,0 +1,35 @@
+mod client;
+mod default;
+mod model;
+mod order;
+mod provider;
+
+pub use provider::CowSwap;
+
+use primitives::Chain;
+
+pub const SETTLEMENT_CONTRACT: &str = "0x9008D19f58AAbD9eD0D60971565AA8510560ab41";
+pub const VAULT_RELAYER_CONTRACT: &str = "0xC92E8bdf79f0507f65a392b0ab4667716BFE0110";
+
+pub const SUPPORTED_CHAINS: [Chain; 7] = [
+    Chain::Ethereum,
+    Chain::Gnosis,
+    Chain::Arbitrum,
+    Chain::Base,
+    Chain::Polygon,
+    Chain::AvalancheC,
+    Chain::SmartChain,
+];
+
+pub fn get_network(chain: Chain) -> Option<&'static str> {
+    match chain {
+        Chain::Ethereum => Some("mainnet"),
+        Chain::Gnosis => Some("xdai"),
+        Chain::Arbitrum => Some("arbitrum_one"),
+        Chain::Base => Some("base"),
+        Chain::Polygon => Some("polygon"),
+        Chain::AvalancheC => Some("avalanche"),
+        Chain::SmartChain => Some("bnb"),
+        _ => None,
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+pub const ORDER_KIND_SELL: &str = "sell";
+pub const TOKEN_BALANCE_ERC20: &str = "erc20";
+pub const SIGNING_SCHEME_EIP712: &str = "eip712";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowQuoteRequest {
+    pub sell_token: String,
+    pub buy_token: String,
+    pub from: String,
+    pub receiver: String,
+    pub app_data: String,
+    pub app_data_hash: String,
+    pub kind: String,
+    pub sell_amount_before_fee: String,
+    pub partially_fillable: bool,
+    pub sell_token_balance: String,
+    pub buy_token_balance: String,
+    pub signing_scheme: String,
+    pub valid_for: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowQuote {
+    pub sell_token: String,
+    pub buy_token: String,
+    pub sell_amount: String,
+    pub buy_amount: String,
+    pub valid_to: u32,
+    pub fee_amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowQuoteResponse {
+    pub quote: CowQuote,
+    pub id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CowRouteData {
+    pub quote_id: Option<i64>,
+    pub valid_to: u32,
+    pub app_data: String,
+    pub app_data_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowOrderCreation {
+    pub sell_token: String,
+    pub buy_token: String,
+    pub receiver: String,
+    pub sell_amount: String,
+    pub buy_amount: String,
+    pub valid_to: u32,
+    pub fee_amount: String,
+    pub kind: String,
+    pub partially_fillable: bool,
+    pub sell_token_balance: String,
+    pub buy_token_balance: String,
+    pub signing_scheme: String,
+    pub signature: String,
+    pub from: String,
+    pub app_data: String,
+    pub app_data_hash: String,
+    pub quote_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CowOrderStatus {
+    PresignaturePending,
+    Open,
+    Fulfilled,
+    Cancelled,
+    Expired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowOrder {
+    pub uid: String,
+    pub status: CowOrderStatus,
+    pub sell_token: String,
+    pub buy_token: String,
+    pub executed_sell_amount: String,
+    pub executed_buy_amount: String,
+}
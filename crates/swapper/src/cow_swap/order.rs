@@ -0,0 +1,154 @@
+use super::SETTLEMENT_CONTRACT;
+use crate::{SwapperError, fees::ReferralFee};
+use gem_evm::eip712::{EIP712Domain, EIP712Type, eip712_domain_types};
+use gem_hash::keccak::keccak256;
+use primitives::hex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+const DOMAIN_NAME: &str = "Gnosis Protocol";
+const DOMAIN_VERSION: &str = "v2";
+const APP_CODE: &str = "Gem Wallet";
+const APP_DATA_VERSION: &str = "1.3.0";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CowOrderMessage {
+    pub sell_token: String,
+    pub buy_token: String,
+    pub receiver: String,
+    pub sell_amount: String,
+    pub buy_amount: String,
+    pub valid_to: u32,
+    pub app_data: String,
+    pub fee_amount: String,
+    pub kind: String,
+    pub partially_fillable: bool,
+    pub sell_token_balance: String,
+    pub buy_token_balance: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CowOrderTypes {
+    #[serde(rename = "EIP712Domain")]
+    pub eip712_domain: Vec<EIP712Type>,
+    #[serde(rename = "Order")]
+    pub order: Vec<EIP712Type>,
+}
+
+impl Default for CowOrderTypes {
+    fn default() -> Self {
+        let field = |name: &str, r#type: &str| EIP712Type {
+            name: name.into(),
+            r#type: r#type.into(),
+        };
+        Self {
+            eip712_domain: eip712_domain_types(),
+            order: vec![
+                field("sellToken", "address"),
+                field("buyToken", "address"),
+                field("receiver", "address"),
+                field("sellAmount", "uint256"),
+                field("buyAmount", "uint256"),
+                field("validTo", "uint32"),
+                field("appData", "bytes32"),
+                field("feeAmount", "uint256"),
+                field("kind", "string"),
+                field("partiallyFillable", "bool"),
+                field("sellTokenBalance", "string"),
+                field("buyTokenBalance", "string"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CowOrderTypedData {
+    pub domain: EIP712Domain,
+    pub types: CowOrderTypes,
+    #[serde(rename = "primaryType")]
+    pub primary_type: String,
+    pub message: CowOrderMessage,
+}
+
+impl CowOrderTypedData {
+    pub fn new(chain_id: u64, message: CowOrderMessage) -> Self {
+        Self {
+            domain: EIP712Domain {
+                name: Some(DOMAIN_NAME.to_string()),
+                version: Some(DOMAIN_VERSION.to_string()),
+                chain_id: Some(chain_id),
+                verifying_contract: Some(SETTLEMENT_CONTRACT.to_string()),
+                salts: None,
+            },
+            types: CowOrderTypes::default(),
+            primary_type: "Order".into(),
+            message,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, SwapperError> {
+        serde_json::to_string(self).map_err(|_| SwapperError::TransactionError("failed to serialize EIP712 message to JSON".into()))
+    }
+}
+
+pub fn app_data(fee: Option<&ReferralFee>) -> String {
+    let metadata = match fee {
+        Some(fee) => json!({ "partnerFee": { "bps": fee.bps, "recipient": fee.address } }),
+        None => json!({}),
+    };
+    json!({ "appCode": APP_CODE, "metadata": metadata, "version": APP_DATA_VERSION }).to_string()
+}
+
+pub fn app_data_hash(app_data: &str) -> String {
+    hex::encode_with_0x(&keccak256(app_data.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gem_evm::eip712::eip712_hash_message;
+    use primitives::asset_constants::{ETHEREUM_USDC_TOKEN_ID, ETHEREUM_USDT_TOKEN_ID};
+
+    #[test]
+    fn test_app_data() {
+        let fee = ReferralFee {
+            address: "0x0D9DAB1A248f63B0a48965bA8435e4de7497a3dC".to_string(),
+            bps: 50,
+        };
+
+        assert_eq!(app_data(None), r#"{"appCode":"Gem Wallet","metadata":{},"version":"1.3.0"}"#);
+        assert_eq!(
+            app_data(Some(&fee)),
+            r#"{"appCode":"Gem Wallet","metadata":{"partnerFee":{"bps":50,"recipient":"0x0D9DAB1A248f63B0a48965bA8435e4de7497a3dC"}},"version":"1.3.0"}"#
+        );
+        assert_eq!(app_data_hash("{}"), "0xb48d38f93eaa084033fc5970bf96e559c33c4cdc07d889ab00b4d63f9590739d");
+    }
+
+    #[test]
+    fn test_order_typed_data() {
+        let message = CowOrderMessage {
+            sell_token: ETHEREUM_USDC_TOKEN_ID.to_string(),
+            buy_token: ETHEREUM_USDT_TOKEN_ID.to_string(),
+            receiver: "0x52A07c930157d07D9EffD147ecF41C5cBbC6000c".to_string(),
+            sell_amount: "1000000".to_string(),
+            buy_amount: "990000".to_string(),
+            valid_to: 1_900_000_000,
+            app_data: app_data_hash("{}"),
+            fee_amount: "0".to_string(),
+            kind: "sell".to_string(),
+            partially_fillable: false,
+            sell_token_balance: "erc20".to_string(),
+            buy_token_balance: "erc20".to_string(),
+        };
+        let json = CowOrderTypedData::new(1, message.clone()).to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["domain"]["name"], DOMAIN_NAME);
+        assert_eq!(value["message"]["validTo"], 1_900_000_000);
+        assert_eq!(eip712_hash_message(value).unwrap().len(), 32);
+
+        let typed_data: CowOrderTypedData = serde_json::from_str(&json).unwrap();
+        assert_eq!(typed_data.message, message);
+    }
+}
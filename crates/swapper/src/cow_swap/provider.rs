@@ -0,0 +1,272 @@
+use super::{
+    SETTLEMENT_CONTRACT, SUPPORTED_CHAINS, VAULT_RELAYER_CONTRACT,
+    client::CowSwapClient,
+    get_network,
+    model::{CowOrder, CowOrderCreation, CowOrderStatus, CowQuoteRequest, CowRouteData, ORDER_KIND_SELL, SIGNING_SCHEME_EIP712, TOKEN_BALANCE_ERC20},
+    order::{CowOrderMessage, CowOrderTypedData, app_data, app_data_hash},
+};
+use crate::{
+    ApprovalType, FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperProvider, SwapperQuoteData,
+    alien::RpcProvider, approval::check_approval_erc20, eth_address, fees::apply_slippage_in_bp,
+};
+use alloy_primitives::U256;
+use async_trait::async_trait;
+use gem_client::Client;
+use primitives::{
+    AssetId, Chain, TransactionSwapMetadata,
+    swap::{SwapQuoteDataType, SwapStatus},
+};
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+const ORDER_VALIDITY_SECONDS: u32 = 1800;
+const BATCH_ETA_SECONDS: u32 = 30;
+
+#[derive(Debug)]
+pub struct CowSwap<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    provider: ProviderType,
+    client: CowSwapClient<C>,
+    rpc_provider: Arc<dyn RpcProvider>,
+}
+
+impl<C> CowSwap<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn with_client(client: CowSwapClient<C>, rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        Self {
+            provider: ProviderType::new(SwapperProvider::CowSwap),
+            client,
+            rpc_provider,
+        }
+    }
+
+    fn network(chain: Chain) -> Result<&'static str, SwapperError> {
+        get_network(chain).ok_or(SwapperError::NotSupportedChain)
+    }
+
+    fn token_address(asset_id: &AssetId) -> Result<String, SwapperError> {
+        if asset_id.is_native() {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+        Ok(eth_address::parse_asset_id(asset_id)?.to_checksum(None))
+    }
+
+    fn route_data(quote: &Quote) -> Result<CowRouteData, SwapperError> {
+        let route = quote.data.routes.first().ok_or(SwapperError::InvalidRoute)?;
+        serde_json::from_str(&route.route_data).map_err(|_| SwapperError::InvalidRoute)
+    }
+}
+
+#[async_trait]
+impl<C> Swapper for CowSwap<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    fn provider(&self) -> &ProviderType {
+        &self.provider
+    }
+
+    fn supported_assets(&self) -> Vec<SwapperChainAsset> {
+        SUPPORTED_CHAINS.into_iter().map(SwapperChainAsset::All).collect()
+    }
+
+    fn get_approval_spender(&self, _quote: &Quote) -> Option<String> {
+        Some(VAULT_RELAYER_CONTRACT.to_string())
+    }
+
+    async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        let from_asset = request.from_asset.asset_id();
+        let to_asset = request.to_asset.asset_id();
+        if from_asset.chain != to_asset.chain {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        let network = Self::network(from_asset.chain)?;
+        let fee = request.options.fee.as_ref().map(|x| &x.evm).filter(|x| x.bps > 0);
+        let app_data = app_data(fee);
+        let app_data_hash = app_data_hash(&app_data);
+
+        let quote_request = CowQuoteRequest {
+            sell_token: Self::token_address(&from_asset)?,
+            buy_token: Self::token_address(&to_asset)?,
+            from: request.wallet_address.clone(),
+            receiver: request.destination_address.clone(),
+            app_data: app_data.clone(),
+            app_data_hash: app_data_hash.clone(),
+            kind: ORDER_KIND_SELL.to_string(),
+            sell_amount_before_fee: request.value.clone(),
+            partially_fillable: false,
+            sell_token_balance: TOKEN_BALANCE_ERC20.to_string(),
+            buy_token_balance: TOKEN_BALANCE_ERC20.to_string(),
+            signing_scheme: SIGNING_SCHEME_EIP712.to_string(),
+            valid_for: ORDER_VALIDITY_SECONDS,
+        };
+        let response = self.client.get_quote(network, &quote_request).await?;
+
+        // Network costs are already deducted from the buy amount, the partner fee is taken from the output.
+        let buy_amount = U256::from_str(&response.quote.buy_amount)?;
+        let to_value = apply_slippage_in_bp(&buy_amount, fee.map(|x| x.bps).unwrap_or_default());
+        let route_data = CowRouteData {
+            quote_id: response.id,
+            valid_to: response.quote.valid_to,
+            app_data,
+            app_data_hash,
+        };
+
+        Ok(Quote {
+            from_value: request.value.clone(),
+            to_value: to_value.to_string(),
+            data: ProviderData {
+                provider: self.provider().clone(),
+                routes: vec![Route {
+                    input: from_asset,
+                    output: to_asset,
+                    route_data: serde_json::to_string(&route_data)?,
+                }],
+                slippage_bps: request.options.slippage.bps,
+            },
+            request: request.clone(),
+            eta_in_seconds: Some(BATCH_ETA_SECONDS),
+            fees: None,
+            price_impact: None,
+            composed: None,
+        })
+    }
+
+    async fn get_quote_data(&self, quote: &Quote, _data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError> {
+        let route_data = Self::route_data(quote)?;
+        let from_asset = quote.request.from_asset.asset_id();
+        let chain = from_asset.chain;
+        let chain_id = chain.network_id().parse::<u64>()?;
+        let sell_token = Self::token_address(&from_asset)?;
+        let sell_amount = U256::from_str(&quote.from_value)?;
+        let buy_amount = apply_slippage_in_bp(&U256::from_str(&quote.to_value)?, quote.data.slippage_bps);
+
+        let message = CowOrderMessage {
+            sell_token: sell_token.clone(),
+            buy_token: Self::token_address(&quote.request.to_asset.asset_id())?,
+            receiver: quote.request.destination_address.clone(),
+            sell_amount: sell_amount.to_string(),
+            buy_amount: buy_amount.to_string(),
+            valid_to: route_data.valid_to,
+            app_data: route_data.app_data_hash,
+            fee_amount: "0".to_string(),
+            kind: ORDER_KIND_SELL.to_string(),
+            partially_fillable: false,
+            sell_token_balance: TOKEN_BALANCE_ERC20.to_string(),
+            buy_token_balance: TOKEN_BALANCE_ERC20.to_string(),
+        };
+        let approval = check_approval_erc20(
+            quote.request.wallet_address.clone(),
+            sell_token,
+            VAULT_RELAYER_CONTRACT.to_string(),
+            sell_amount,
+            self.rpc_provider.clone(),
+            &chain,
+        )
+        .await?;
+
+        Ok(SwapperQuoteData {
+            to: SETTLEMENT_CONTRACT.to_string(),
+            data_type: SwapQuoteDataType::Eip712,
+            value: "0".to_string(),
+            data: CowOrderTypedData::new(chain_id, message).to_json()?,
+            memo: None,
+            approval: match approval {
+                ApprovalType::Approve(data) => Some(data),
+                ApprovalType::Permit2(_) | ApprovalType::None => None,
+            },
+            gas_limit: None,
+        })
+    }
+
+    async fn submit_order(&self, quote: &Quote, typed_data: &str, signature: &str) -> Result<String, SwapperError> {
+        let route_data = Self::route_data(quote)?;
+        let network = Self::network(quote.request.from_asset.chain())?;
+        let message = serde_json::from_str::<CowOrderTypedData>(typed_data)?.message;
+        let order = CowOrderCreation {
+            sell_token: message.sell_token,
+            buy_token: message.buy_token,
+            receiver: message.receiver,
+            sell_amount: message.sell_amount,
+            buy_amount: message.buy_amount,
+            valid_to: message.valid_to,
+            fee_amount: message.fee_amount,
+            kind: message.kind,
+            partially_fillable: message.partially_fillable,
+            sell_token_balance: message.sell_token_balance,
+            buy_token_balance: message.buy_token_balance,
+            signing_scheme: SIGNING_SCHEME_EIP712.to_string(),
+            signature: signature.to_string(),
+            from: quote.request.wallet_address.clone(),
+            app_data: route_data.app_data,
+            app_data_hash: route_data.app_data_hash,
+            quote_id: route_data.quote_id,
+        };
+        self.client.post_order(network, &order).await
+    }
+
+    async fn get_swap_result(&self, chain: Chain, transaction_hash: &str) -> Result<SwapResult, SwapperError> {
+        let order = self.client.get_order(Self::network(chain)?, transaction_hash).await?;
+        Ok(map_swap_result(chain, &order))
+    }
+}
+
+fn map_swap_result(chain: Chain, order: &CowOrder) -> SwapResult {
+    let status = match order.status {
+        CowOrderStatus::PresignaturePending | CowOrderStatus::Open => SwapStatus::Pending,
+        CowOrderStatus::Fulfilled => SwapStatus::Completed,
+        CowOrderStatus::Cancelled | CowOrderStatus::Expired => SwapStatus::Failed,
+    };
+    let metadata = (status == SwapStatus::Completed).then(|| TransactionSwapMetadata {
+        from_asset: AssetId::from_token(chain, &order.sell_token),
+        from_value: order.executed_sell_amount.clone(),
+        to_asset: AssetId::from_token(chain, &order.buy_token),
+        to_value: order.executed_buy_amount.clone(),
+        provider: Some(SwapperProvider::CowSwap.id().to_string()),
+    });
+    SwapResult { status, metadata }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::{ETHEREUM_USDC_TOKEN_ID, ETHEREUM_USDT_TOKEN_ID};
+
+    #[test]
+    fn test_map_swap_result() {
+        let order: CowOrder = serde_json::from_value(serde_json::json!({
+            "uid": "0xaa",
+            "status": "fulfilled",
+            "sellToken": ETHEREUM_USDC_TOKEN_ID,
+            "buyToken": ETHEREUM_USDT_TOKEN_ID,
+            "executedSellAmount": "1000000",
+            "executedBuyAmount": "999100"
+        }))
+        .unwrap();
+
+        let result = map_swap_result(Chain::Ethereum, &order);
+        let metadata = result.metadata.unwrap();
+
+        assert_eq!(result.status, SwapStatus::Completed);
+        assert_eq!(metadata.to_asset, AssetId::from_token(Chain::Ethereum, ETHEREUM_USDT_TOKEN_ID));
+        assert_eq!(metadata.to_value, "999100");
+        assert_eq!(metadata.provider.as_deref(), Some("cow_swap"));
+
+        let order = CowOrder {
+            status: CowOrderStatus::Expired,
+            ..order
+        };
+        assert_eq!(map_swap_result(Chain::Ethereum, &order).status, SwapStatus::Failed);
+    }
+
+    #[test]
+    fn test_token_address() {
+        assert_eq!(
+            CowSwap::<crate::RpcClient>::token_address(&AssetId::from_chain(Chain::Ethereum)),
+            Err(SwapperError::NotSupportedAsset)
+        );
+    }
+}
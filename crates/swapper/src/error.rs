@@ -22,6 +22,8 @@ pub enum SwapperError {
     ComputeQuoteError(String),
     TransactionError(String),
     NoQuoteAvailable,
+    Timeout,
+    PriceImpactTooHigh { price_impact: f64 },
 }
 
 impl std::fmt::Display for SwapperError {
@@ -41,6 +43,8 @@ impl std::fmt::Display for SwapperError {
             Self::ComputeQuoteError(msg) => write!(f, "Compute quote error: {}", msg),
             Self::TransactionError(msg) => write!(f, "Transaction error: {}", msg),
             Self::NoQuoteAvailable => write!(f, "No quote available"),
+            Self::Timeout => write!(f, "Request timed out"),
+            Self::PriceImpactTooHigh { price_impact } => write!(f, "Price impact {price_impact:.2}% is too high"),
         }
     }
 }
@@ -67,7 +71,7 @@ impl From<ClientError> for SwapperError {
     fn from(err: ClientError) -> Self {
         match err {
             ClientError::Network(msg) => Self::ComputeQuoteError(msg),
-            ClientError::Timeout => Self::ComputeQuoteError("Request timed out".into()),
+            ClientError::Timeout => Self::Timeout,
             ClientError::Http { status, ref body } => {
                 if let Ok(proxy_error) = serde_json::from_slice::<ProxyError>(body) {
                     return proxy_error.err;
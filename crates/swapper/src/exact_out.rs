@@ -0,0 +1,348 @@
+use std::str::FromStr;
+
+use alloy_primitives::U256;
+
+use crate::{Quote, QuoteRequest, Swapper, SwapperError, SwapperMode, error::INVALID_AMOUNT, quote_fees::asset_price};
+
+const MAX_ITERATIONS: usize = 5;
+const OVERSHOOT_BPS: u32 = 10;
+const TOLERANCE_BPS: u32 = 50;
+const BPS: u32 = 10_000;
+const AMOUNT_STEP: u64 = 10;
+
+pub(crate) async fn get_quote(swapper: &dyn Swapper, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+    if request.mode == SwapperMode::ExactIn || swapper.supports_exact_out(request) {
+        return swapper.get_quote(request).await;
+    }
+    let requested = U256::from_str(&request.value)?;
+    if requested.is_zero() {
+        return Err(SwapperError::InputAmountError { min_amount: None });
+    }
+    let target = slippage_target(requested, request.options.slippage.bps).ok_or(SwapperError::NoQuoteAvailable)?;
+    let max_amount_out = target * U256::from(BPS + TOLERANCE_BPS) / U256::from(BPS);
+    let mut amount_in = initial_amount_in(swapper, request, target).await?;
+    let mut best: Option<(U256, Quote)> = None;
+    let mut quoted = false;
+    let mut last_error = SwapperError::NoQuoteAvailable;
+
+    for _ in 0..MAX_ITERATIONS {
+        let quote = match swapper.get_quote(&exact_in_request(request, amount_in)).await {
+            Ok(quote) => quote,
+            Err(err) if !quoted => {
+                amount_in = match &err {
+                    SwapperError::InputAmountError { min_amount: Some(min_amount) } => U256::from_str(min_amount)?.max(amount_in + U256::from(1)),
+                    SwapperError::InputAmountError { min_amount: None } => amount_in.saturating_mul(U256::from(AMOUNT_STEP)),
+                    SwapperError::NoQuoteAvailable if amount_in > U256::from(1) => amount_in / U256::from(AMOUNT_STEP),
+                    _ => return Err(err),
+                };
+                last_error = err;
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        quoted = true;
+        let amount_out = U256::from_str(&quote.to_value)?;
+        if min_amount_out(amount_out, quote.data.slippage_bps) >= requested {
+            if amount_out <= max_amount_out {
+                return Ok(quote);
+            }
+            if best.as_ref().is_none_or(|(best_in, _)| amount_in < *best_in) {
+                best = Some((amount_in, quote));
+            }
+        }
+        amount_in = next_amount_in(amount_in, amount_out, target).ok_or(SwapperError::NoQuoteAvailable)?;
+    }
+    match best {
+        Some((_, quote)) => Ok(quote),
+        None if quoted => Err(SwapperError::NoQuoteAvailable),
+        None => Err(last_error),
+    }
+}
+
+pub(crate) fn exact_in_request(request: &QuoteRequest, amount_in: U256) -> QuoteRequest {
+    let mut request = request.clone();
+    request.mode = SwapperMode::ExactIn;
+    request.value = amount_in.to_string();
+    request.options.use_max_amount = false;
+    request
+}
+
+fn reverse_request(request: &QuoteRequest, amount: U256) -> QuoteRequest {
+    QuoteRequest {
+        from_asset: request.to_asset.clone(),
+        to_asset: request.from_asset.clone(),
+        wallet_address: request.destination_address.clone(),
+        destination_address: request.wallet_address.clone(),
+        ..exact_in_request(request, amount)
+    }
+}
+
+async fn initial_amount_in(swapper: &dyn Swapper, request: &QuoteRequest, target: U256) -> Result<U256, SwapperError> {
+    if let Some(amount) = price_amount_in(request, target)? {
+        return Ok(amount);
+    }
+    if let Ok(quote) = swapper.get_quote(&reverse_request(request, target)).await
+        && let Ok(amount) = U256::from_str(&quote.to_value)
+        && !amount.is_zero()
+    {
+        return Ok(amount);
+    }
+    Ok(decimals_amount_in(request, target))
+}
+
+fn price_amount_in(request: &QuoteRequest, target: U256) -> Result<Option<U256>, SwapperError> {
+    let prices = &request.options.prices;
+    let from = &request.from_asset;
+    let to = &request.to_asset;
+    let (Some(from_price), Some(to_price)) = (asset_price(prices, &from.asset_id()), asset_price(prices, &to.asset_id())) else {
+        return Ok(None);
+    };
+    let value = f64::from(target) / 10f64.powi(to.decimals as i32) * to_price / from_price * 10f64.powi(from.decimals as i32);
+    let amount = U256::try_from(value.round()).map_err(|_| SwapperError::ComputeQuoteError(format!("{INVALID_AMOUNT}: {value}")))?;
+    Ok(Some(amount).filter(|amount| !amount.is_zero()))
+}
+
+fn decimals_amount_in(request: &QuoteRequest, target: U256) -> U256 {
+    let from = &request.from_asset;
+    let to = &request.to_asset;
+    let amount = if from.decimals >= to.decimals {
+        target * U256::from(10).pow(U256::from(from.decimals - to.decimals))
+    } else {
+        target / U256::from(10).pow(U256::from(to.decimals - from.decimals))
+    };
+    amount.max(U256::from(1))
+}
+
+fn slippage_target(amount: U256, slippage_bps: u32) -> Option<U256> {
+    let remaining = BPS.checked_sub(slippage_bps).filter(|remaining| *remaining > 0)?;
+    Some((amount * U256::from(BPS)).div_ceil(U256::from(remaining)))
+}
+
+fn min_amount_out(amount: U256, slippage_bps: u32) -> U256 {
+    amount * U256::from(BPS.saturating_sub(slippage_bps)) / U256::from(BPS)
+}
+
+fn next_amount_in(amount_in: U256, amount_out: U256, target: U256) -> Option<U256> {
+    if amount_out.is_zero() {
+        return None;
+    }
+    let next = amount_in.checked_mul(target)?.checked_mul(U256::from(BPS + OVERSHOOT_BPS))? / (amount_out * U256::from(BPS));
+    if amount_out < target && next <= amount_in {
+        return Some(amount_in + U256::from(1));
+    }
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FetchQuoteData, ProviderType, QuoteAssetPrice, SwapperChainAsset, SwapperProvider, SwapperQuoteAsset, SwapperQuoteData, testkit::mock_quote};
+    use async_trait::async_trait;
+    use primitives::{
+        AssetId, Chain,
+        asset_constants::{ETHEREUM_USDC_ASSET_ID, ETHEREUM_USDT_ASSET_ID},
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct PoolSwapper {
+        provider: ProviderType,
+        requests: AtomicUsize,
+        reverse: bool,
+        max_amount_in: Option<U256>,
+    }
+
+    impl PoolSwapper {
+        fn new() -> Self {
+            Self {
+                provider: ProviderType::new(SwapperProvider::Thorchain),
+                requests: AtomicUsize::new(0),
+                reverse: true,
+                max_amount_in: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Swapper for PoolSwapper {
+        fn provider(&self) -> &ProviderType {
+            &self.provider
+        }
+
+        fn supported_assets(&self) -> Vec<SwapperChainAsset> {
+            vec![SwapperChainAsset::All(Chain::Ethereum)]
+        }
+
+        async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+            assert_eq!(request.mode, SwapperMode::ExactIn);
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let eth_reserve = U256::from(1_000u64) * U256::from(10).pow(U256::from(18));
+            let usdc_reserve = U256::from(4_000_000_000_000u64);
+            let (reserve_in, reserve_out) = match (request.from_asset.asset_id().is_native(), self.reverse) {
+                (true, _) => (eth_reserve, usdc_reserve),
+                (false, true) => (usdc_reserve, eth_reserve),
+                (false, false) => return Err(SwapperError::NotSupportedAsset),
+            };
+            let value = U256::from_str(&request.value)?;
+            if self.max_amount_in.is_some_and(|max_amount_in| value > max_amount_in) {
+                return Err(SwapperError::NoQuoteAvailable);
+            }
+            let amount_in = value * U256::from(997);
+            let amount_out = amount_in * reserve_out / (reserve_in * U256::from(1000) + amount_in);
+
+            let mut quote = Quote::mock_with_provider(SwapperProvider::Thorchain, &amount_out.to_string());
+            quote.from_value = request.value.clone();
+            quote.data.slippage_bps = request.options.slippage.bps;
+            quote.request = request.clone();
+            Ok(quote)
+        }
+
+        async fn get_quote_data(&self, _quote: &Quote, _data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError> {
+            Err(SwapperError::NotSupportedAsset)
+        }
+    }
+
+    fn exact_out_request(value: &str, prices: Vec<QuoteAssetPrice>) -> QuoteRequest {
+        let mut request = mock_quote(
+            SwapperQuoteAsset {
+                decimals: 18,
+                ..SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum))
+            },
+            SwapperQuoteAsset {
+                decimals: 6,
+                ..SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone())
+            },
+        );
+        request.mode = SwapperMode::ExactOut;
+        request.value = value.to_string();
+        request.options.prices = prices;
+        request
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_searches_input() {
+        let swapper = PoolSwapper::new();
+        let request = exact_out_request("100000000", vec![]);
+
+        let quote = get_quote(&swapper, &request).await.unwrap();
+        let amount_out = U256::from_str(&quote.to_value).unwrap();
+
+        assert!(min_amount_out(amount_out, quote.data.slippage_bps) >= U256::from(100_000_000u64));
+        assert!(amount_out <= U256::from(101_005_025u64));
+        assert_eq!(quote.request.mode, SwapperMode::ExactIn);
+        assert_eq!(quote.from_value, quote.request.value);
+        assert_eq!(swapper.requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_covers_slippage() {
+        let swapper = PoolSwapper::new();
+        let mut request = exact_out_request("100000000", vec![]);
+        request.options.slippage.bps = 300;
+
+        let quote = get_quote(&swapper, &request).await.unwrap();
+        let amount_out = U256::from_str(&quote.to_value).unwrap();
+
+        assert_eq!(quote.data.slippage_bps, 300);
+        assert!(amount_out >= U256::from(103_092_784u64));
+        assert!(min_amount_out(amount_out, quote.data.slippage_bps) >= U256::from(100_000_000u64));
+
+        request.options.slippage.bps = 10_000;
+
+        assert_eq!(get_quote(&swapper, &request).await, Err(SwapperError::NoQuoteAvailable));
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_with_prices() {
+        let swapper = PoolSwapper::new();
+        let prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 4000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: ETHEREUM_USDC_ASSET_ID.clone(),
+                price: 1.0,
+            },
+        ];
+        let request = exact_out_request("100000000", prices);
+
+        let quote = get_quote(&swapper, &request).await.unwrap();
+
+        assert!(U256::from_str(&quote.to_value).unwrap() >= U256::from(100_000_000u64));
+        assert_eq!(swapper.requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_shrinks_rejected_amount() {
+        let swapper = PoolSwapper {
+            reverse: false,
+            max_amount_in: Some(U256::from(50u64) * U256::from(10).pow(U256::from(18))),
+            ..PoolSwapper::new()
+        };
+        let request = exact_out_request("100000000", vec![]);
+
+        let quote = get_quote(&swapper, &request).await.unwrap();
+
+        assert!(U256::from_str(&quote.to_value).unwrap() >= U256::from(100_000_000u64));
+
+        let swapper = PoolSwapper {
+            reverse: false,
+            max_amount_in: Some(U256::ZERO),
+            ..PoolSwapper::new()
+        };
+
+        assert_eq!(get_quote(&swapper, &request).await, Err(SwapperError::NoQuoteAvailable));
+    }
+
+    #[test]
+    fn test_price_amount_in() {
+        let prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 4000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: ETHEREUM_USDC_ASSET_ID.clone(),
+                price: 1.0,
+            },
+        ];
+        let target = U256::from(100_000_000u64);
+
+        assert_eq!(
+            price_amount_in(&exact_out_request("100000000", prices), target).unwrap(),
+            Some(U256::from(25_000_000_000_000_000u64))
+        );
+        assert_eq!(price_amount_in(&exact_out_request("100000000", vec![]), target).unwrap(), None);
+    }
+
+    #[test]
+    fn test_decimals_amount_in() {
+        let mut request = exact_out_request("100000000", vec![]);
+        assert_eq!(decimals_amount_in(&request, U256::from(100_000_000u64)), U256::from(100_000_000_000_000_000_000u128));
+
+        request.from_asset = SwapperQuoteAsset {
+            decimals: 6,
+            ..SwapperQuoteAsset::from(ETHEREUM_USDT_ASSET_ID.clone())
+        };
+        request.to_asset.decimals = 18;
+        assert_eq!(decimals_amount_in(&request, U256::from(1u64)), U256::from(1u64));
+    }
+
+    #[test]
+    fn test_slippage_target() {
+        assert_eq!(slippage_target(U256::from(100_000_000u64), 50), Some(U256::from(100_502_513u64)));
+        assert_eq!(slippage_target(U256::from(100_000_000u64), 0), Some(U256::from(100_000_000u64)));
+        assert_eq!(slippage_target(U256::from(100_000_000u64), 10_000), None);
+        assert_eq!(min_amount_out(U256::from(100_502_513u64), 50), U256::from(100_000_000u64));
+    }
+
+    #[test]
+    fn test_next_amount_in() {
+        let next = next_amount_in(U256::from(1000u64), U256::from(500u64), U256::from(1000u64)).unwrap();
+        assert_eq!(next, U256::from(2002u64));
+
+        assert_eq!(next_amount_in(U256::from(1u64), U256::from(1u64), U256::from(2u64)), Some(U256::from(2u64)));
+        assert_eq!(next_amount_in(U256::from(1u64), U256::ZERO, U256::from(2u64)), None);
+    }
+}
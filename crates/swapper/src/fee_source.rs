@@ -0,0 +1,127 @@
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use async_trait::async_trait;
+use gem_evm::{
+    contracts::erc20::IERC20,
+    multicall3::{IMulticall3, decode_call3_return},
+};
+use gem_solana::{constants::STATIC_BASE_FEE, rpc::client::SolanaClient};
+use gem_sui::rpc::client::SuiClient;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use primitives::{BitcoinChain, Chain};
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{
+    SwapperError,
+    alien::RpcProvider,
+    chainlink::ChainlinkPriceFeed,
+    client_factory::{create_client_with_chain, create_eth_client},
+    eth_address,
+};
+
+const SOLANA_SWAP_COMPUTE_UNITS: u64 = 420_000;
+const SUI_SWAP_GAS_UNITS: u64 = 10_000;
+const BITCOIN_SWAP_VBYTES: u64 = 250;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AllowanceRequest {
+    pub token: String,
+    pub spender: String,
+}
+
+#[async_trait]
+pub trait FeeSource: Send + Sync + Debug {
+    async fn get_gas_price(&self, chain: Chain) -> Result<BigUint, SwapperError>;
+    async fn get_network_fee(&self, chain: Chain) -> Result<BigUint, SwapperError>;
+    async fn get_eth_usd_price(&self) -> Result<f64, SwapperError>;
+    async fn get_allowances(&self, chain: Chain, owner: &str, requests: &[AllowanceRequest]) -> Result<Vec<U256>, SwapperError>;
+}
+
+#[derive(Debug)]
+pub struct RpcFeeSource {
+    rpc_provider: Arc<dyn RpcProvider>,
+}
+
+impl RpcFeeSource {
+    pub fn new(rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        Self { rpc_provider }
+    }
+
+    async fn multicall3(&self, chain: Chain, calls: Vec<IMulticall3::Call3>) -> Result<Vec<IMulticall3::Result>, SwapperError> {
+        create_eth_client(self.rpc_provider.clone(), chain)?
+            .multicall3(calls)
+            .await
+            .map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl FeeSource for RpcFeeSource {
+    async fn get_gas_price(&self, chain: Chain) -> Result<BigUint, SwapperError> {
+        let gas_price = create_eth_client(self.rpc_provider.clone(), chain)?.gas_price().await?;
+        gas_price
+            .to_biguint()
+            .ok_or_else(|| SwapperError::ComputeQuoteError(format!("invalid gas price: {gas_price}")))
+    }
+
+    async fn get_network_fee(&self, chain: Chain) -> Result<BigUint, SwapperError> {
+        match chain {
+            Chain::Solana => {
+                let client = SolanaClient::new(create_client_with_chain(self.rpc_provider.clone(), chain));
+                let mut fees: Vec<i64> = client.get_recent_prioritization_fees().await?.iter().map(|x| x.prioritization_fee).collect();
+                fees.sort_by(|a, b| b.cmp(a));
+                fees.truncate(5);
+                let priority_fee = if fees.is_empty() { 0 } else { fees.iter().sum::<i64>() / fees.len() as i64 };
+                let priority_fee = u64::try_from(priority_fee).map_err(|_| SwapperError::ComputeQuoteError(format!("invalid priority fee: {priority_fee}")))?;
+                Ok(BigUint::from(STATIC_BASE_FEE) + BigUint::from(priority_fee) * SOLANA_SWAP_COMPUTE_UNITS / 1_000_000u64)
+            }
+            Chain::Sui => {
+                let client = SuiClient::new(create_client_with_chain(self.rpc_provider.clone(), chain));
+                let gas_price = client.get_gas_price().await.map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))?;
+                let gas_price = gas_price
+                    .to_biguint()
+                    .ok_or_else(|| SwapperError::ComputeQuoteError(format!("invalid gas price: {gas_price}")))?;
+                Ok(gas_price * SUI_SWAP_GAS_UNITS)
+            }
+            _ => {
+                let chain = BitcoinChain::from_chain(chain).ok_or(SwapperError::NotSupportedChain)?;
+                Ok(BigUint::from(chain.minimum_byte_fee() as u64 * BITCOIN_SWAP_VBYTES))
+            }
+        }
+    }
+
+    async fn get_eth_usd_price(&self) -> Result<f64, SwapperError> {
+        let results = self.multicall3(Chain::Ethereum, vec![ChainlinkPriceFeed::new_eth_usd_feed().latest_round_call3()]).await?;
+        let result = results.first().ok_or_else(|| SwapperError::ComputeQuoteError("missing ETH/USD answer".into()))?;
+        let answer = ChainlinkPriceFeed::decoded_answer(result)?;
+        answer
+            .to_f64()
+            .map(|x| x / 1e8)
+            .ok_or_else(|| SwapperError::ComputeQuoteError(format!("invalid ETH/USD answer: {answer}")))
+    }
+
+    async fn get_allowances(&self, chain: Chain, owner: &str, requests: &[AllowanceRequest]) -> Result<Vec<U256>, SwapperError> {
+        let owner = eth_address::parse_str(owner)?;
+        let calls = requests
+            .iter()
+            .map(|request| {
+                Ok(IMulticall3::Call3 {
+                    target: eth_address::parse_str(&request.token)?,
+                    allowFailure: true,
+                    callData: IERC20::allowanceCall {
+                        owner,
+                        spender: eth_address::parse_str(&request.spender)?,
+                    }
+                    .abi_encode()
+                    .into(),
+                })
+            })
+            .collect::<Result<Vec<_>, SwapperError>>()?;
+        self.multicall3(chain, calls)
+            .await?
+            .iter()
+            .map(|result| decode_call3_return::<IERC20::allowanceCall>(result).map_err(|e| SwapperError::ComputeQuoteError(e.to_string())))
+            .collect()
+    }
+}
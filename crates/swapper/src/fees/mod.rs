@@ -4,7 +4,7 @@ mod slippage;
 
 pub use referral::{ReferralFee, ReferralFees, default_referral_fees};
 pub use reserve::{RESERVED_NATIVE_FEES, reserved_tx_fees, resolve_max_quote_value};
-pub use slippage::{BasisPointConvert, apply_slippage_in_bp};
+pub use slippage::{BasisPointConvert, apply_slippage_in_bp, reverse_slippage_in_bp};
 
 pub const DEFAULT_SWAP_FEE_BPS: u32 = 50;
 pub const DEFAULT_AGGREGATOR_FEE_BPS: u32 = 70;
@@ -34,6 +34,11 @@ where
     (*amount * slippage) / basis_points
 }
 
+pub fn reverse_slippage_in_bp(amount: U256, bps: u32) -> U256 {
+    let remaining = HUNDRED_PERCENT_IN_BPS - bps.min(HUNDRED_PERCENT_IN_BPS - 1);
+    (amount * U256::from(HUNDRED_PERCENT_IN_BPS)).div_ceil(U256::from(remaining))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,4 +51,12 @@ mod tests {
         assert_eq!(apply_slippage_in_bp(&U256::from(1000), 0), U256::from(1000));
         assert_eq!(apply_slippage_in_bp(&U256::from(1000), HUNDRED_PERCENT_IN_BPS), U256::ZERO);
     }
+
+    #[test]
+    fn test_reverse_slippage_in_bp() {
+        assert_eq!(reverse_slippage_in_bp(U256::from(97), 300), U256::from(100));
+        assert_eq!(reverse_slippage_in_bp(U256::from(100), 300), U256::from(104));
+        assert_eq!(apply_slippage_in_bp(&U256::from(104), 300), U256::from(100));
+        assert_eq!(reverse_slippage_in_bp(U256::from(1000), 0), U256::from(1000));
+    }
 }
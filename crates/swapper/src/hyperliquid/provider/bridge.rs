@@ -65,6 +65,9 @@ impl Swapper for HyperCoreBridge {
             },
             request: request.clone(),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         };
 
         Ok(quote)
@@ -235,6 +235,9 @@ impl Swapper for HyperCoreSpot {
             },
             request: request.clone(),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         };
 
         Ok(quote)
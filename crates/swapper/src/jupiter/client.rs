@@ -28,4 +28,39 @@ where
         let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
         self.client.post_with_headers("/swap/v1/swap", request, headers).await
     }
+
+    pub async fn create_trigger_order(&self, request: &CreateTriggerOrderRequest) -> Result<CreateTriggerOrderResponse, ClientError> {
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
+        self.client.post_with_headers("/trigger/v1/createOrder", request, headers).await
+    }
+
+    pub async fn cancel_trigger_order(&self, request: &CancelTriggerOrderRequest) -> Result<CancelTriggerOrderResponse, ClientError> {
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
+        self.client.post_with_headers("/trigger/v1/cancelOrder", request, headers).await
+    }
+
+    pub async fn get_trigger_orders(&self, user: &str, order_status: &str) -> Result<TriggerOrdersResponse, ClientError> {
+        let query = vec![("user".to_string(), user.to_string()), ("orderStatus".to_string(), order_status.to_string())];
+        self.client.get_with_query("/trigger/v1/getTriggerOrders", &query).await
+    }
+
+    pub async fn create_recurring_order(&self, request: &CreateRecurringOrderRequest) -> Result<RecurringOrderTransactionResponse, ClientError> {
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
+        self.client.post_with_headers("/recurring/v1/createOrder", request, headers).await
+    }
+
+    pub async fn cancel_recurring_order(&self, request: &CancelRecurringOrderRequest) -> Result<RecurringOrderTransactionResponse, ClientError> {
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
+        self.client.post_with_headers("/recurring/v1/cancelOrder", request, headers).await
+    }
+
+    pub async fn get_recurring_orders(&self, user: &str, order_status: &str) -> Result<RecurringOrdersResponse, ClientError> {
+        let query = vec![
+            ("user".to_string(), user.to_string()),
+            ("orderStatus".to_string(), order_status.to_string()),
+            ("recurringType".to_string(), "time".to_string()),
+            ("includeFailedTx".to_string(), "false".to_string()),
+        ];
+        self.client.get_with_query("/recurring/v1/getRecurringOrders", &query).await
+    }
 }
@@ -0,0 +1,209 @@
+use super::{
+    TRIGGER_PROGRAM_ADDRESS,
+    model::{CancelTriggerOrderRequest, CreateTriggerOrderRequest, TriggerOrder, TriggerOrderParams, TriggerOrderStatus},
+    provider::Jupiter,
+};
+use crate::{
+    Swapper, SwapperError, SwapperQuoteData,
+    error::INVALID_AMOUNT,
+    limit_order::{LimitOrder, LimitOrderData, LimitOrderProvider, LimitOrderRequest, LimitOrderStatus, LimitOrderSwapper},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use gem_client::Client;
+use gem_solana::{WSOL_TOKEN_ADDRESS, models::BlockTransaction};
+use num_bigint::BigUint;
+use num_traits::CheckedSub;
+use primitives::{
+    AssetId, Chain,
+    swap::{SwapResult, SwapStatus},
+};
+
+const COMPUTE_UNIT_PRICE: &str = "auto";
+const ORDER_STATUSES: [&str; 2] = ["active", "history"];
+
+#[async_trait]
+impl<C, R> LimitOrderSwapper for Jupiter<C, R>
+where
+    C: Client + Clone + Send + Sync + 'static,
+    R: Client + Clone + Send + Sync + 'static,
+{
+    fn provider(&self) -> LimitOrderProvider {
+        LimitOrderProvider::Jupiter
+    }
+
+    fn supported_chains(&self) -> Vec<Chain> {
+        vec![Chain::Solana]
+    }
+
+    async fn create_order(&self, request: &LimitOrderRequest) -> Result<LimitOrderData, SwapperError> {
+        let order_request = CreateTriggerOrderRequest {
+            input_mint: self.get_asset_address(&request.from_asset.id)?,
+            output_mint: self.get_asset_address(&request.to_asset.id)?,
+            maker: request.wallet_address.clone(),
+            payer: request.wallet_address.clone(),
+            params: TriggerOrderParams {
+                making_amount: request.from_value.clone(),
+                taking_amount: request.to_value.clone(),
+                expired_at: request.expires_at.map(|x| x.to_string()),
+            },
+            compute_unit_price: COMPUTE_UNIT_PRICE.to_string(),
+            wrap_and_unwrap_sol: true,
+        };
+        let response = self.http_client.create_trigger_order(&order_request).await?;
+
+        Ok(LimitOrderData::Transaction {
+            order_id: response.order,
+            data: SwapperQuoteData::new_contract(TRIGGER_PROGRAM_ADDRESS.to_string(), "".to_string(), response.transaction, None, None),
+        })
+    }
+
+    async fn get_orders(&self, _chain: Chain, wallet_address: &str) -> Result<Vec<LimitOrder>, SwapperError> {
+        let responses = futures::future::try_join_all(ORDER_STATUSES.map(|status| self.http_client.get_trigger_orders(wallet_address, status))).await?;
+        let now = Utc::now().timestamp() as u64;
+        responses.into_iter().flat_map(|x| x.orders).map(|x| map_order(&x, now)).collect()
+    }
+
+    async fn get_swap_result(&self, chain: Chain, transaction_hash: &str) -> Result<SwapResult, SwapperError> {
+        let params = serde_json::json!([transaction_hash, { "maxSupportedTransactionVersion": 0 }]);
+        let transaction: Option<BlockTransaction> = self.rpc_client.call("getTransaction", params).await?;
+        let Some(transaction) = transaction else {
+            return Ok(SwapResult {
+                status: SwapStatus::Pending,
+                metadata: None,
+            });
+        };
+        let account_keys = &transaction.transaction.message.account_keys;
+        if !account_keys.iter().any(|x| x == TRIGGER_PROGRAM_ADDRESS) {
+            return Swapper::get_swap_result(self, chain, transaction_hash).await;
+        }
+        let maker = account_keys.first().ok_or(SwapperError::InvalidRoute)?;
+        self.get_orders(chain, maker)
+            .await?
+            .into_iter()
+            .find(|x| account_keys.contains(&x.id))
+            .map(|x| x.swap_result())
+            .ok_or_else(|| SwapperError::ComputeQuoteError(format!("order not found: {transaction_hash}")))
+    }
+
+    async fn cancel_order(&self, order: &LimitOrder) -> Result<SwapperQuoteData, SwapperError> {
+        let request = CancelTriggerOrderRequest {
+            maker: order.wallet_address.clone(),
+            order: order.id.clone(),
+            compute_unit_price: COMPUTE_UNIT_PRICE.to_string(),
+        };
+        let response = self.http_client.cancel_trigger_order(&request).await?;
+        Ok(SwapperQuoteData::new_contract(
+            TRIGGER_PROGRAM_ADDRESS.to_string(),
+            "".to_string(),
+            response.transaction,
+            None,
+            None,
+        ))
+    }
+}
+
+fn map_order(order: &TriggerOrder, now: u64) -> Result<LimitOrder, SwapperError> {
+    let making_amount = parse_amount(&order.raw_making_amount)?;
+    let remaining_amount = parse_amount(&order.raw_remaining_making_amount)?;
+    let filled_from_value = making_amount
+        .checked_sub(&remaining_amount)
+        .ok_or_else(|| invalid_amount(&order.raw_remaining_making_amount))?;
+    let filled_to_value = order.trades.iter().map(|x| parse_amount(&x.raw_output_amount)).sum::<Result<BigUint, _>>()?;
+    let expires_at = order.expired_at.as_deref().map(require_timestamp).transpose()?;
+    let status = match order.status {
+        TriggerOrderStatus::Open if expires_at.is_some_and(|x| x <= now) => LimitOrderStatus::Expired,
+        TriggerOrderStatus::Open => LimitOrderStatus::Open,
+        TriggerOrderStatus::Completed => LimitOrderStatus::Filled,
+        TriggerOrderStatus::Cancelled => LimitOrderStatus::Cancelled,
+        TriggerOrderStatus::Expired => LimitOrderStatus::Expired,
+    };
+    Ok(LimitOrder {
+        provider: LimitOrderProvider::Jupiter,
+        id: order.order_key.clone(),
+        wallet_address: order.user_pubkey.clone(),
+        from_asset: asset_id(&order.input_mint),
+        to_asset: asset_id(&order.output_mint),
+        from_value: order.raw_making_amount.clone(),
+        to_value: order.raw_taking_amount.clone(),
+        filled_from_value: filled_from_value.to_string(),
+        filled_to_value: filled_to_value.to_string(),
+        status,
+        created_at: Some(require_timestamp(&order.created_at)?),
+        expires_at,
+    })
+}
+
+fn parse_amount(value: &str) -> Result<BigUint, SwapperError> {
+    value.parse::<BigUint>().map_err(|_| invalid_amount(value))
+}
+
+fn require_timestamp(value: &str) -> Result<u64, SwapperError> {
+    parse_timestamp(value).ok_or_else(|| SwapperError::ComputeQuoteError(format!("invalid timestamp: {value}")))
+}
+
+fn invalid_amount(value: &str) -> SwapperError {
+    SwapperError::ComputeQuoteError(format!("{INVALID_AMOUNT}: {value}"))
+}
+
+pub(super) fn asset_id(mint: &str) -> AssetId {
+    if mint == WSOL_TOKEN_ADDRESS {
+        return AssetId::from_chain(Chain::Solana);
+    }
+    AssetId::from_token(Chain::Solana, mint)
+}
+
+pub(super) fn parse_timestamp(value: &str) -> Option<u64> {
+    value.parse::<u64>().ok().or_else(|| DateTime::parse_from_rfc3339(value).ok().map(|x| x.timestamp() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jupiter::model::TriggerOrdersResponse;
+    use gem_solana::USDC_TOKEN_MINT;
+
+    #[test]
+    fn test_map_order() {
+        let response: TriggerOrdersResponse = serde_json::from_value(serde_json::json!({
+            "orders": [{
+                "userPubkey": "7g2rVN8fAAQdPh1mkajpvELqYa3gWvFXJsBLnKfEQfqy",
+                "orderKey": "9z8AxTEF5QN1uWXdTEdZdZYmHzNzSeRVsBfFdxjsyDW5",
+                "inputMint": WSOL_TOKEN_ADDRESS,
+                "outputMint": USDC_TOKEN_MINT,
+                "makingAmount": "1",
+                "takingAmount": "200",
+                "rawMakingAmount": "1000000000",
+                "rawTakingAmount": "200000000",
+                "rawRemainingMakingAmount": "400000000",
+                "rawRemainingTakingAmount": "80000000",
+                "expiredAt": "1742464800",
+                "createdAt": "2025-03-20T09:43:59Z",
+                "status": "Open",
+                "trades": [{ "rawInputAmount": "600000000", "rawOutputAmount": "121000000" }]
+            }]
+        }))
+        .unwrap();
+        let order = &response.orders[0];
+
+        let result = map_order(order, 1742464000).unwrap();
+
+        assert_eq!(result.from_asset, AssetId::from_chain(Chain::Solana));
+        assert_eq!(result.to_asset, AssetId::from_token(Chain::Solana, USDC_TOKEN_MINT));
+        assert_eq!(result.filled_from_value, "600000000");
+        assert_eq!(result.filled_to_value, "121000000");
+        assert_eq!(result.status, LimitOrderStatus::Open);
+        assert_eq!(result.created_at, Some(1742463839));
+        assert_eq!(map_order(order, 1742464800).unwrap().status, LimitOrderStatus::Expired);
+        assert_eq!(
+            map_order(
+                &TriggerOrder {
+                    raw_remaining_making_amount: "".to_string(),
+                    ..order.clone()
+                },
+                1742464000
+            ),
+            Err(SwapperError::ComputeQuoteError("Invalid amount: ".to_string()))
+        );
+    }
+}
@@ -1,7 +1,11 @@
 mod client;
 mod default;
+mod limit_order;
 mod model;
 mod provider;
+mod recurring;
 pub use provider::Jupiter;
 
 pub const PROGRAM_ADDRESS: &str = gem_solana::JUPITER_PROGRAM_ID;
+pub const RECURRING_PROGRAM_ADDRESS: &str = "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M";
+pub const TRIGGER_PROGRAM_ADDRESS: &str = "j1o2qRpjcyUwEvwtcfhEQefh773ZgjxcVRry7LDqg5X";
@@ -1,11 +1,15 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+
+use crate::SwapperMode;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuoteRequest {
     pub input_mint: String,
     pub output_mint: String,
     pub amount: String,
+    pub swap_mode: SwapperMode,
     pub slippage_bps: u32,
     pub platform_fee_bps: u32,
     pub instruction_version: String,
@@ -51,3 +55,150 @@ pub struct QuoteDataRequest {
     pub quote_response: QuoteResponse,
     pub prioritization_fee_lamports: i64,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerOrderParams {
+    pub making_amount: String,
+    pub taking_amount: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTriggerOrderRequest {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub maker: String,
+    pub payer: String,
+    pub params: TriggerOrderParams,
+    pub compute_unit_price: String,
+    pub wrap_and_unwrap_sol: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTriggerOrderResponse {
+    pub order: String,
+    pub transaction: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelTriggerOrderRequest {
+    pub maker: String,
+    pub order: String,
+    pub compute_unit_price: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelTriggerOrderResponse {
+    pub transaction: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TriggerOrderStatus {
+    Open,
+    Completed,
+    Cancelled,
+    Expired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerOrderTrade {
+    pub raw_input_amount: String,
+    pub raw_output_amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerOrder {
+    pub user_pubkey: String,
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub raw_making_amount: String,
+    pub raw_taking_amount: String,
+    pub raw_remaining_making_amount: String,
+    pub expired_at: Option<String>,
+    pub created_at: String,
+    pub status: TriggerOrderStatus,
+    #[serde(default)]
+    pub trades: Vec<TriggerOrderTrade>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerOrdersResponse {
+    pub orders: Vec<TriggerOrder>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringTimeParams {
+    pub in_amount: String,
+    pub number_of_orders: u32,
+    pub interval: u64,
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    pub start_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringOrderParams {
+    pub time: RecurringTimeParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRecurringOrderRequest {
+    pub user: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub params: RecurringOrderParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelRecurringOrderRequest {
+    pub order: String,
+    pub user: String,
+    pub recurring_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringOrderTransactionResponse {
+    pub request_id: String,
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringOrder {
+    pub user_pubkey: String,
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub cycle_frequency: String,
+    pub raw_in_deposited: String,
+    pub raw_in_amount_per_cycle: String,
+    pub raw_in_used: String,
+    pub raw_out_received: String,
+    #[serde(default)]
+    pub close_tx: String,
+    #[serde(default)]
+    pub user_closed: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringOrdersResponse {
+    #[serde(default)]
+    pub time: Vec<RecurringOrder>,
+}
@@ -29,8 +29,8 @@ where
 {
     pub provider: ProviderType,
     pub fee_mints: HashSet<&'static str>,
-    http_client: JupiterClient<C>,
-    rpc_client: JsonRpcClient<R>,
+    pub(crate) http_client: JupiterClient<C>,
+    pub(crate) rpc_client: JsonRpcClient<R>,
 }
 
 impl<C, R> Jupiter<C, R>
@@ -122,6 +122,10 @@ where
         vec![SwapperChainAsset::All(Chain::Solana)]
     }
 
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
         let input_mint = self.get_asset_address(&request.from_asset.id)?;
         let output_mint = self.get_asset_address(&request.to_asset.id)?;
@@ -133,6 +137,7 @@ where
             input_mint: input_mint.clone(),
             output_mint: output_mint.clone(),
             amount: request.value.clone(),
+            swap_mode: request.mode,
             platform_fee_bps,
             slippage_bps,
             instruction_version: INSTRUCTION_VERSION.to_string(),
@@ -142,9 +147,10 @@ where
         // Updated docs: https://dev.jup.ag/docs/api/swap-api/quote
         // The value includes platform fees and DEX fees, excluding slippage.
         let out_amount: U256 = swap_quote.out_amount.parse().map_err(SwapperError::from)?;
+        let in_amount: U256 = swap_quote.in_amount.parse().map_err(SwapperError::from)?;
 
         let quote = Quote {
-            from_value: request.value.clone(),
+            from_value: in_amount.to_string(),
             to_value: out_amount.to_string(),
             data: ProviderData {
                 provider: self.provider().clone(),
@@ -157,6 +163,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         };
         Ok(quote)
     }
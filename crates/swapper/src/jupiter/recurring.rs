@@ -0,0 +1,156 @@
+use super::{
+    RECURRING_PROGRAM_ADDRESS,
+    limit_order::{asset_id, parse_timestamp},
+    model::{CancelRecurringOrderRequest, CreateRecurringOrderRequest, RecurringOrder as JupiterRecurringOrder, RecurringOrderParams, RecurringTimeParams},
+    provider::Jupiter,
+};
+use crate::{
+    SwapperError, SwapperProvider, SwapperQuoteData,
+    recurring_order::{RecurringOrder, RecurringOrderRequest, RecurringOrderSwapper},
+};
+use async_trait::async_trait;
+use gem_client::Client;
+use primitives::{Chain, RecurringSwapFrequency, RecurringSwapStatus};
+
+const RECURRING_TYPE: &str = "time";
+const ORDER_STATUSES: [&str; 2] = ["active", "history"];
+
+#[async_trait]
+impl<C, R> RecurringOrderSwapper for Jupiter<C, R>
+where
+    C: Client + Clone + Send + Sync + 'static,
+    R: Client + Clone + Send + Sync + 'static,
+{
+    fn provider(&self) -> SwapperProvider {
+        SwapperProvider::Jupiter
+    }
+
+    fn supported_chains(&self) -> Vec<Chain> {
+        vec![Chain::Solana]
+    }
+
+    async fn create_order(&self, request: &RecurringOrderRequest) -> Result<SwapperQuoteData, SwapperError> {
+        let order_request = CreateRecurringOrderRequest {
+            user: request.wallet_address.clone(),
+            input_mint: self.get_asset_address(&request.from_asset.id)?,
+            output_mint: self.get_asset_address(&request.to_asset.id)?,
+            params: RecurringOrderParams {
+                time: RecurringTimeParams {
+                    in_amount: request.total_value()?,
+                    number_of_orders: request.orders_count()?,
+                    interval: interval_in_seconds(request.frequency),
+                    min_price: None,
+                    max_price: None,
+                    start_at: Some(request.start_at),
+                },
+            },
+        };
+        let response = self.http_client.create_recurring_order(&order_request).await?;
+        Ok(SwapperQuoteData::new_contract(
+            RECURRING_PROGRAM_ADDRESS.to_string(),
+            "".to_string(),
+            response.transaction,
+            None,
+            None,
+        ))
+    }
+
+    async fn get_orders(&self, _chain: Chain, wallet_address: &str) -> Result<Vec<RecurringOrder>, SwapperError> {
+        let responses = futures::future::try_join_all(ORDER_STATUSES.map(|status| self.http_client.get_recurring_orders(wallet_address, status))).await?;
+        Ok(responses.into_iter().flat_map(|x| x.time).map(|x| map_order(&x)).collect())
+    }
+
+    async fn cancel_order(&self, order: &RecurringOrder) -> Result<SwapperQuoteData, SwapperError> {
+        let request = CancelRecurringOrderRequest {
+            order: order.id.clone(),
+            user: order.wallet_address.clone(),
+            recurring_type: RECURRING_TYPE.to_string(),
+        };
+        let response = self.http_client.cancel_recurring_order(&request).await?;
+        Ok(SwapperQuoteData::new_contract(
+            RECURRING_PROGRAM_ADDRESS.to_string(),
+            "".to_string(),
+            response.transaction,
+            None,
+            None,
+        ))
+    }
+}
+
+fn interval_in_seconds(frequency: RecurringSwapFrequency) -> u64 {
+    match frequency {
+        RecurringSwapFrequency::Hourly => 3_600,
+        RecurringSwapFrequency::Daily => 86_400,
+        RecurringSwapFrequency::Weekly => 604_800,
+        RecurringSwapFrequency::Monthly => 2_592_000,
+    }
+}
+
+fn map_order(order: &JupiterRecurringOrder) -> RecurringOrder {
+    let status = match (order.close_tx.is_empty(), order.user_closed) {
+        (true, _) => RecurringSwapStatus::Active,
+        (false, true) => RecurringSwapStatus::Cancelled,
+        (false, false) => RecurringSwapStatus::Completed,
+    };
+    RecurringOrder {
+        provider: SwapperProvider::Jupiter,
+        id: order.order_key.clone(),
+        wallet_address: order.user_pubkey.clone(),
+        from_asset: asset_id(&order.input_mint),
+        to_asset: asset_id(&order.output_mint),
+        amount: order.raw_in_amount_per_cycle.clone(),
+        deposited_value: order.raw_in_deposited.clone(),
+        used_value: order.raw_in_used.clone(),
+        received_value: order.raw_out_received.clone(),
+        interval_in_seconds: order.cycle_frequency.parse().unwrap_or_default(),
+        status,
+        created_at: parse_timestamp(&order.created_at),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jupiter::model::RecurringOrdersResponse;
+    use gem_solana::{USDC_TOKEN_MINT, WSOL_TOKEN_ADDRESS};
+    use primitives::AssetId;
+
+    #[test]
+    fn test_map_order() {
+        let response: RecurringOrdersResponse = serde_json::from_value(serde_json::json!({
+            "time": [{
+                "userPubkey": "7g2rVN8fAAQdPh1mkajpvELqYa3gWvFXJsBLnKfEQfqy",
+                "orderKey": "4xzzMYgUPaCDWkWrXxYhZ5s4jUv1CWjyvhXpb8AM3mxV",
+                "inputMint": USDC_TOKEN_MINT,
+                "outputMint": WSOL_TOKEN_ADDRESS,
+                "cycleFrequency": "86400",
+                "rawInDeposited": "700000000",
+                "rawInAmountPerCycle": "100000000",
+                "rawInUsed": "300000000",
+                "rawOutReceived": "2100000000",
+                "closeTx": "",
+                "userClosed": false,
+                "createdAt": "2025-03-20T09:43:59Z"
+            }]
+        }))
+        .unwrap();
+        let order = &response.time[0];
+
+        let result = map_order(order);
+
+        assert_eq!(result.from_asset, AssetId::from_token(Chain::Solana, USDC_TOKEN_MINT));
+        assert_eq!(result.to_asset, AssetId::from_chain(Chain::Solana));
+        assert_eq!(result.amount, "100000000");
+        assert_eq!(result.interval_in_seconds, 86400);
+        assert_eq!(result.status, RecurringSwapStatus::Active);
+        assert_eq!(result.created_at, Some(1742463839));
+
+        let closed = JupiterRecurringOrder {
+            close_tx: "5Kq".to_string(),
+            user_closed: true,
+            ..order.clone()
+        };
+
+        assert_eq!(map_order(&closed).status, RecurringSwapStatus::Cancelled);
+    }
+}
@@ -3,6 +3,7 @@ mod approval;
 mod chainlink;
 pub mod cross_chain;
 mod eth_address;
+mod fee_source;
 pub mod fees;
 mod swapper_trait;
 
@@ -10,20 +11,31 @@ mod swapper_trait;
 pub mod testkit;
 
 pub mod across;
+pub mod cctp;
 pub mod chainflip;
 pub mod client_factory;
 pub mod config;
+pub mod cow_swap;
 pub mod error;
+mod exact_out;
 pub mod hyperliquid;
 pub mod jupiter;
+pub mod limit_order;
 pub mod models;
 pub mod near_intents;
+pub mod one_inch;
 pub mod permit2_data;
+mod price_impact;
+mod provider_health;
 pub mod proxy;
+mod quote_fees;
+pub mod recurring_order;
 pub mod relay;
+mod route_composer;
 pub mod squid;
 pub mod swapper;
 pub mod thorchain;
+mod timeout;
 pub mod uniswap;
 
 use number_formatter::BigNumberFormatter;
@@ -0,0 +1,35 @@
+mod model;
+mod orders;
+
+pub use model::*;
+pub use orders::GemLimitOrders;
+
+use crate::{SwapperError, SwapperQuoteData};
+use async_trait::async_trait;
+use primitives::{Chain, swap::SwapResult};
+use std::fmt::Debug;
+
+#[async_trait]
+pub trait LimitOrderSwapper: Send + Sync + Debug {
+    fn provider(&self) -> LimitOrderProvider;
+    fn supported_chains(&self) -> Vec<Chain>;
+    async fn create_order(&self, request: &LimitOrderRequest) -> Result<LimitOrderData, SwapperError>;
+    async fn submit_order(&self, _chain: Chain, _typed_data: &str, _signature: &str) -> Result<String, SwapperError> {
+        Err(SwapperError::NotSupportedChain)
+    }
+    async fn get_orders(&self, chain: Chain, wallet_address: &str) -> Result<Vec<LimitOrder>, SwapperError>;
+    async fn get_order_by_id(&self, _chain: Chain, _order_id: &str) -> Result<LimitOrder, SwapperError> {
+        Err(SwapperError::NotSupportedChain)
+    }
+    async fn get_swap_result(&self, chain: Chain, id: &str) -> Result<SwapResult, SwapperError> {
+        Ok(self.get_order_by_id(chain, id).await?.swap_result())
+    }
+    async fn get_order(&self, chain: Chain, wallet_address: &str, order_id: &str) -> Result<LimitOrder, SwapperError> {
+        self.get_orders(chain, wallet_address)
+            .await?
+            .into_iter()
+            .find(|x| x.id == order_id)
+            .ok_or_else(|| SwapperError::ComputeQuoteError(format!("order not found: {order_id}")))
+    }
+    async fn cancel_order(&self, order: &LimitOrder) -> Result<SwapperQuoteData, SwapperError>;
+}
@@ -0,0 +1,220 @@
+use crate::{SwapperError, SwapperProvider, SwapperQuoteAsset, SwapperQuoteData};
+use num_bigint::BigUint;
+use primitives::{
+    AssetId, Chain, TransactionSwapMetadata,
+    swap::{ApprovalData, SwapResult, SwapStatus},
+};
+use strum::{AsRefStr, EnumString};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, AsRefStr, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum LimitOrderProvider {
+    Jupiter,
+    OneInch,
+}
+
+impl LimitOrderProvider {
+    pub fn id(&self) -> &str {
+        self.as_ref()
+    }
+
+    pub fn from_swap_provider(provider: SwapperProvider) -> Option<Self> {
+        match provider {
+            SwapperProvider::Jupiter => Some(Self::Jupiter),
+            SwapperProvider::OneInch => Some(Self::OneInch),
+            SwapperProvider::UniswapV3
+            | SwapperProvider::UniswapV4
+            | SwapperProvider::PancakeswapV3
+            | SwapperProvider::Aerodrome
+            | SwapperProvider::Panora
+            | SwapperProvider::Thorchain
+            | SwapperProvider::Okx
+            | SwapperProvider::Across
+            | SwapperProvider::Oku
+            | SwapperProvider::Wagmi
+            | SwapperProvider::StonfiV2
+            | SwapperProvider::Mayan
+            | SwapperProvider::Chainflip
+            | SwapperProvider::NearIntents
+            | SwapperProvider::CetusAggregator
+            | SwapperProvider::Relay
+            | SwapperProvider::Hyperliquid
+            | SwapperProvider::Orca
+            | SwapperProvider::Squid
+            | SwapperProvider::CowSwap
+            | SwapperProvider::UniswapV2
+            | SwapperProvider::PancakeswapV2
+            | SwapperProvider::SushiswapV2
+            | SwapperProvider::QuickswapV2
+            | SwapperProvider::CamelotV2
+            | SwapperProvider::SpookyswapV2
+            | SwapperProvider::Cctp
+            | SwapperProvider::Composed => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitOrderRequest {
+    pub from_asset: SwapperQuoteAsset,
+    pub to_asset: SwapperQuoteAsset,
+    pub wallet_address: String,
+    pub from_value: String,
+    pub to_value: String,
+    pub expires_at: Option<u64>,
+}
+
+impl LimitOrderRequest {
+    pub fn chain(&self) -> Result<Chain, SwapperError> {
+        let chain = self.from_asset.chain();
+        if chain != self.to_asset.chain() {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        if self.from_asset.id == self.to_asset.id {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+        for value in [&self.from_value, &self.to_value] {
+            if value.parse::<BigUint>().unwrap_or_default() == BigUint::ZERO {
+                return Err(SwapperError::InputAmountError { min_amount: None });
+            }
+        }
+        Ok(chain)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LimitOrderData {
+    Transaction {
+        order_id: String,
+        data: SwapperQuoteData,
+    },
+    SignMessage {
+        order_id: String,
+        typed_data: String,
+        approval: Option<ApprovalData>,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LimitOrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+    Expired,
+    Invalid,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitOrder {
+    pub provider: LimitOrderProvider,
+    pub id: String,
+    pub wallet_address: String,
+    pub from_asset: AssetId,
+    pub to_asset: AssetId,
+    pub from_value: String,
+    pub to_value: String,
+    pub filled_from_value: String,
+    pub filled_to_value: String,
+    pub status: LimitOrderStatus,
+    pub created_at: Option<u64>,
+    pub expires_at: Option<u64>,
+}
+
+impl LimitOrder {
+    pub fn chain(&self) -> Chain {
+        self.from_asset.chain
+    }
+
+    pub fn swap_result(&self) -> SwapResult {
+        let status = match self.status {
+            LimitOrderStatus::Open => SwapStatus::Pending,
+            LimitOrderStatus::Filled => SwapStatus::Completed,
+            LimitOrderStatus::Cancelled | LimitOrderStatus::Expired | LimitOrderStatus::Invalid => SwapStatus::Failed,
+        };
+        let is_filled = self.filled_from_value.parse::<BigUint>().unwrap_or_default() > BigUint::ZERO;
+        let (from_value, to_value) = if is_filled {
+            (self.filled_from_value.clone(), self.filled_to_value.clone())
+        } else {
+            (self.from_value.clone(), self.to_value.clone())
+        };
+        SwapResult {
+            status,
+            metadata: Some(TransactionSwapMetadata {
+                from_asset: self.from_asset.clone(),
+                from_value,
+                to_asset: self.to_asset.clone(),
+                to_value,
+                provider: Some(self.provider.id().to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::ETHEREUM_USDC_ASSET_ID;
+
+    fn order(status: LimitOrderStatus, filled_from_value: &str, filled_to_value: &str) -> LimitOrder {
+        LimitOrder {
+            provider: LimitOrderProvider::OneInch,
+            id: "0x1".to_string(),
+            wallet_address: "0x2".to_string(),
+            from_asset: ETHEREUM_USDC_ASSET_ID.clone(),
+            to_asset: AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+            from_value: "1000".to_string(),
+            to_value: "990".to_string(),
+            filled_from_value: filled_from_value.to_string(),
+            filled_to_value: filled_to_value.to_string(),
+            status,
+            created_at: None,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_swap_result() {
+        let result = order(LimitOrderStatus::Open, "0", "0").swap_result();
+        let metadata = result.metadata.unwrap();
+
+        assert_eq!(result.status, SwapStatus::Pending);
+        assert_eq!(metadata.from_value, "1000");
+        assert_eq!(metadata.provider.as_deref(), Some("one_inch"));
+
+        let result = order(LimitOrderStatus::Filled, "1000", "995").swap_result();
+
+        assert_eq!(result.status, SwapStatus::Completed);
+        assert_eq!(result.metadata.unwrap().to_value, "995");
+        assert_eq!(order(LimitOrderStatus::Expired, "0", "0").swap_result().status, SwapStatus::Failed);
+    }
+
+    #[test]
+    fn test_request_chain() {
+        let request = LimitOrderRequest {
+            from_asset: SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+            to_asset: SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            wallet_address: "0x2".to_string(),
+            from_value: "1000".to_string(),
+            to_value: "1".to_string(),
+            expires_at: None,
+        };
+
+        assert_eq!(request.chain(), Ok(Chain::Ethereum));
+        assert_eq!(
+            LimitOrderRequest {
+                to_asset: SwapperQuoteAsset::from(AssetId::from_chain(Chain::Arbitrum)),
+                ..request.clone()
+            }
+            .chain(),
+            Err(SwapperError::NotSupportedChain)
+        );
+        assert_eq!(
+            LimitOrderRequest {
+                to_value: "0".to_string(),
+                ..request
+            }
+            .chain(),
+            Err(SwapperError::InputAmountError { min_amount: None })
+        );
+    }
+}
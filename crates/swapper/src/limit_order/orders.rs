@@ -0,0 +1,71 @@
+use super::{LimitOrder, LimitOrderData, LimitOrderProvider, LimitOrderRequest, LimitOrderSwapper};
+use crate::{SwapperError, SwapperQuoteData, alien::RpcProvider, jupiter, one_inch};
+use primitives::{Chain, swap::SwapResult};
+use std::{cmp::Reverse, sync::Arc};
+
+#[derive(Debug)]
+pub struct GemLimitOrders {
+    pub providers: Vec<Box<dyn LimitOrderSwapper>>,
+}
+
+impl GemLimitOrders {
+    pub fn new(rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        let providers: Vec<Box<dyn LimitOrderSwapper>> = vec![
+            Box::new(jupiter::Jupiter::new(rpc_provider.clone())),
+            Box::new(one_inch::OneInch::new(rpc_provider.clone())),
+        ];
+        Self { providers }
+    }
+
+    fn get_provider(&self, provider: LimitOrderProvider) -> Result<&dyn LimitOrderSwapper, SwapperError> {
+        self.providers
+            .iter()
+            .find(|x| x.provider() == provider)
+            .map(|x| x.as_ref())
+            .ok_or(SwapperError::NoAvailableProvider)
+    }
+
+    fn get_providers_for_chain(&self, chain: Chain) -> impl Iterator<Item = &dyn LimitOrderSwapper> {
+        self.providers.iter().filter(move |x| x.supported_chains().contains(&chain)).map(|x| x.as_ref())
+    }
+
+    pub fn supported_chains(&self) -> Vec<Chain> {
+        self.providers.iter().flat_map(|x| x.supported_chains()).collect()
+    }
+
+    pub async fn create_order(&self, request: &LimitOrderRequest) -> Result<LimitOrderData, SwapperError> {
+        let chain = request.chain()?;
+        let provider = self.get_providers_for_chain(chain).next().ok_or(SwapperError::NotSupportedChain)?;
+        provider.create_order(request).await
+    }
+
+    pub async fn submit_order(&self, provider: LimitOrderProvider, chain: Chain, typed_data: &str, signature: &str) -> Result<String, SwapperError> {
+        self.get_provider(provider)?.submit_order(chain, typed_data, signature).await
+    }
+
+    pub async fn get_orders(&self, chain: Chain, wallet_address: &str) -> Result<Vec<LimitOrder>, SwapperError> {
+        let results = futures::future::join_all(self.get_providers_for_chain(chain).map(|x| x.get_orders(chain, wallet_address))).await;
+        let mut orders = Vec::new();
+        for result in results {
+            orders.extend(result?);
+        }
+        orders.sort_by_key(|x| Reverse(x.created_at));
+        Ok(orders)
+    }
+
+    pub async fn get_order(&self, provider: LimitOrderProvider, chain: Chain, wallet_address: &str, order_id: &str) -> Result<LimitOrder, SwapperError> {
+        self.get_provider(provider)?.get_order(chain, wallet_address, order_id).await
+    }
+
+    pub async fn cancel_order(&self, order: &LimitOrder) -> Result<SwapperQuoteData, SwapperError> {
+        self.get_provider(order.provider)?.cancel_order(order).await
+    }
+
+    pub async fn get_swap_result_by_id(&self, provider: LimitOrderProvider, chain: Chain, id: &str) -> Result<SwapResult, SwapperError> {
+        self.get_provider(provider)?.get_swap_result(chain, id).await
+    }
+
+    pub async fn get_swap_result(&self, provider: LimitOrderProvider, chain: Chain, wallet_address: &str, order_id: &str) -> Result<SwapResult, SwapperError> {
+        Ok(self.get_order(provider, chain, wallet_address, order_id).await?.swap_result())
+    }
+}
@@ -1,9 +1,9 @@
 use super::permit2_data::Permit2Data;
-use crate::{SwapperMode, SwapperProvider, SwapperQuoteAsset, SwapperSlippage, config::DEFAULT_SLIPPAGE_BPS, fees::ReferralFees};
+use crate::{SwapperError, SwapperMode, SwapperProvider, SwapperQuoteAsset, SwapperSlippage, config::DEFAULT_SLIPPAGE_BPS, fees::ReferralFees, quote_fees};
 pub use primitives::swap::SwapResult;
 use primitives::{
     AssetId, Chain,
-    swap::{ApprovalData, SwapProviderMode},
+    swap::{ApprovalData, SwapProviderMode, SwapStatus},
 };
 use std::fmt::Debug;
 
@@ -40,11 +40,19 @@ impl ProviderType {
             | SwapperProvider::StonfiV2
             | SwapperProvider::Aerodrome
             | SwapperProvider::Orca
-            | SwapperProvider::Okx => SwapProviderMode::OnChain,
-            SwapperProvider::Mayan | SwapperProvider::Chainflip | SwapperProvider::NearIntents | SwapperProvider::Squid => SwapProviderMode::CrossChain,
+            | SwapperProvider::Okx
+            | SwapperProvider::CowSwap
+            | SwapperProvider::UniswapV2
+            | SwapperProvider::PancakeswapV2
+            | SwapperProvider::SushiswapV2
+            | SwapperProvider::QuickswapV2
+            | SwapperProvider::CamelotV2
+            | SwapperProvider::SpookyswapV2
+            | SwapperProvider::OneInch => SwapProviderMode::OnChain,
+            SwapperProvider::Mayan | SwapperProvider::Chainflip | SwapperProvider::NearIntents | SwapperProvider::Squid | SwapperProvider::Composed => SwapProviderMode::CrossChain,
             SwapperProvider::Thorchain => SwapProviderMode::OmniChain(vec![Chain::Thorchain, Chain::Tron]),
             SwapperProvider::Relay => SwapProviderMode::OmniChain(vec![Chain::Hyperliquid, Chain::Berachain]),
-            SwapperProvider::Across => SwapProviderMode::Bridge,
+            SwapperProvider::Across | SwapperProvider::Cctp => SwapProviderMode::Bridge,
             SwapperProvider::Hyperliquid => SwapProviderMode::OmniChain(vec![Chain::HyperCore, Chain::Hyperliquid]),
         }
     }
@@ -67,6 +75,7 @@ pub struct Options {
     pub fee: Option<ReferralFees>,
     pub preferred_providers: Vec<SwapperProvider>,
     pub use_max_amount: bool,
+    pub prices: Vec<QuoteAssetPrice>,
 }
 
 impl Options {
@@ -82,10 +91,17 @@ impl Default for Options {
             fee: None,
             preferred_providers: vec![],
             use_max_amount: false,
+            prices: vec![],
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteAssetPrice {
+    pub asset_id: AssetId,
+    pub price: f64,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Quote {
     pub from_value: String,
@@ -93,6 +109,102 @@ pub struct Quote {
     pub data: ProviderData,
     pub request: QuoteRequest,
     pub eta_in_seconds: Option<u32>,
+    pub fees: Option<QuoteFees>,
+    pub price_impact: Option<QuotePriceImpact>,
+    pub composed: Option<ComposedQuote>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteFees {
+    pub network_fee: String,
+    pub approval_fee: String,
+    pub destination_fee: String,
+    pub fee_value: Option<String>,
+    pub net_value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotePriceImpact {
+    pub percentage: f64,
+    pub is_high: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderQuoteError {
+    pub provider: SwapperProvider,
+    pub error: SwapperError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProviderHealthState {
+    Healthy,
+    Degraded,
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CctpAttestationStatus {
+    PendingAttestation,
+    Attested,
+    Minted,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderErrorCount {
+    pub error: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderHealth {
+    pub provider: SwapperProvider,
+    pub state: ProviderHealthState,
+    pub requests: u32,
+    pub success_rate: f64,
+    pub average_latency_ms: u64,
+    pub errors: Vec<ProviderErrorCount>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteStreamSummary {
+    pub quotes: Vec<Quote>,
+    pub errors: Vec<ProviderQuoteError>,
+}
+
+impl QuoteStreamSummary {
+    pub fn new(mode: SwapperMode, quotes: Vec<Quote>, errors: Vec<ProviderQuoteError>) -> Self {
+        Self {
+            quotes: quote_fees::sort_quotes(mode, quotes),
+            errors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposedQuote {
+    pub from_value: String,
+    pub to_value: String,
+    pub legs: Vec<Quote>,
+    pub eta_in_seconds: Option<u32>,
+    pub fee_value: Option<String>,
+    pub net_value: Option<String>,
+}
+
+impl ComposedQuote {
+    pub fn leg_request(&self, index: usize, value: &str) -> Option<QuoteRequest> {
+        let leg = self.legs.get(index)?;
+        Some(QuoteRequest {
+            value: value.to_string(),
+            ..leg.request.clone()
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposedSwapResult {
+    pub status: SwapStatus,
+    pub legs: Vec<SwapResult>,
+    pub next_leg: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
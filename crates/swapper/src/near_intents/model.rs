@@ -37,6 +37,7 @@ pub struct QuoteRequest {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SwapType {
     ExactInput,
+    ExactOutput,
     FlexInput,
 }
 
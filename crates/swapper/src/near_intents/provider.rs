@@ -286,22 +286,30 @@ where
         self.supported_assets.clone()
     }
 
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
-        let mode = match request.mode {
-            SwapperMode::ExactIn => SwapType::FlexInput,
-            SwapperMode::ExactOut => return Err(SwapperError::NotSupportedAsset),
+        let (mode, amount) = match request.mode {
+            SwapperMode::ExactIn => (SwapType::FlexInput, resolve_max_quote_value(request)?),
+            SwapperMode::ExactOut => (SwapType::ExactOutput, request.value.clone()),
         };
 
-        let amount = resolve_max_quote_value(request)?;
         let quote_request = self.build_quote_request(request, mode, amount.clone(), true)?;
         let response = Self::extract_quote(self.client.fetch_quote(&quote_request).await?, request.from_asset.decimals)?;
+        // Exact output quotes return the deposit needed for the requested output.
+        let from_value = match request.mode {
+            SwapperMode::ExactIn => amount,
+            SwapperMode::ExactOut => Self::parse_amount(&response.quote.amount_in, "amountIn")?,
+        };
         let amount_out = Self::parse_amount(&response.quote.amount_out, "amountOut")?;
 
         let eta = response.quote.time_estimate;
         let route_data = serde_json::to_string(&quote_request)?;
 
         Ok(Quote {
-            from_value: amount,
+            from_value,
             to_value: amount_out,
             data: ProviderData {
                 provider: self.provider.clone(),
@@ -314,6 +322,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds: Some(eta),
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
@@ -408,6 +419,7 @@ mod tests {
 
         let options = Options {
             use_max_amount: use_max,
+            prices: vec![],
             ..Default::default()
         };
 
@@ -578,6 +590,7 @@ mod swap_integration_tests {
             fee: Some(swap_config.referral_fee),
             preferred_providers: vec![],
             use_max_amount: false,
+            prices: vec![],
         };
 
         let request = QuoteRequest {
@@ -0,0 +1,38 @@
+use super::model::{OrderbookOrder, PostOrderRequest};
+use crate::SwapperError;
+use gem_client::{CONTENT_TYPE, Client, ClientExt};
+use serde_json::Value;
+use std::{collections::HashMap, fmt::Debug};
+
+/// Orderbook calls go through the swap proxy, which adds the 1inch API key.
+#[derive(Clone, Debug)]
+pub struct OneInchClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    client: C,
+}
+
+impl<C> OneInchClient<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    pub async fn post_order(&self, chain_id: &str, request: &PostOrderRequest) -> Result<(), SwapperError> {
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), "application/json".into())]);
+        let _: Value = self.client.post_with_headers(&format!("/orderbook/v4.0/{chain_id}"), request, headers).await?;
+        Ok(())
+    }
+
+    pub async fn get_orders(&self, chain_id: &str, address: &str) -> Result<Vec<OrderbookOrder>, SwapperError> {
+        let path = format!("/orderbook/v4.0/{chain_id}/address/{address}?limit=100&statuses=1,2,3");
+        Ok(self.client.get(&path).await?)
+    }
+
+    pub async fn get_order(&self, chain_id: &str, order_hash: &str) -> Result<OrderbookOrder, SwapperError> {
+        Ok(self.client.get(&format!("/orderbook/v4.0/{chain_id}/order/{order_hash}")).await?)
+    }
+}
@@ -0,0 +1,13 @@
+use super::{client::OneInchClient, provider::OneInch};
+use crate::{
+    alien::{RpcClient, RpcProvider},
+    config,
+};
+use std::sync::Arc;
+
+impl OneInch<RpcClient> {
+    pub fn new(provider: Arc<dyn RpcProvider>) -> Self {
+        let client = OneInchClient::new(RpcClient::new(config::get_swap_api_url("1inch"), provider.clone()));
+        Self::with_client(client, provider)
+    }
+}
@@ -0,0 +1,22 @@
+mod client;
+mod default;
+mod model;
+mod order;
+mod provider;
+
+pub use provider::OneInch;
+
+use primitives::Chain;
+
+pub const ROUTER_ADDRESS: &str = "0x111111125421cA6dc452d289314280a0f8842A65";
+
+pub const SUPPORTED_CHAINS: [Chain; 8] = [
+    Chain::Ethereum,
+    Chain::SmartChain,
+    Chain::Polygon,
+    Chain::Optimism,
+    Chain::Arbitrum,
+    Chain::Gnosis,
+    Chain::AvalancheC,
+    Chain::Base,
+];
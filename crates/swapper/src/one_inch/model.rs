@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderData {
+    pub salt: String,
+    pub maker: String,
+    pub receiver: String,
+    pub maker_asset: String,
+    pub taker_asset: String,
+    pub making_amount: String,
+    pub taking_amount: String,
+    pub maker_traits: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostOrderRequest {
+    pub order_hash: String,
+    pub signature: String,
+    pub data: OrderData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderbookOrder {
+    pub order_hash: String,
+    pub create_date_time: String,
+    pub remaining_maker_amount: String,
+    pub data: OrderData,
+    #[serde(default)]
+    pub order_status: Option<i32>,
+}
@@ -0,0 +1,187 @@
+use super::{ROUTER_ADDRESS, model::OrderData};
+use crate::SwapperError;
+use alloy_primitives::{Address, B256, U256, address};
+use alloy_sol_types::{Eip712Domain, SolCall, SolStruct, sol};
+use serde_json::{Value, json};
+use std::str::FromStr;
+
+const DOMAIN_NAME: &str = "1inch Aggregation Router";
+const DOMAIN_VERSION: &str = "6";
+const VERIFYING_CONTRACT: Address = address!("0x111111125421cA6dc452d289314280a0f8842A65");
+const ALLOW_MULTIPLE_FILLS_FLAG: usize = 254;
+const UNWRAP_WETH_FLAG: usize = 247;
+const EXPIRATION_OFFSET: usize = 80;
+const EXPIRATION_MASK: u64 = (1 << 40) - 1;
+
+// https://github.com/1inch/limit-order-protocol/blob/master/contracts/OrderLib.sol
+sol! {
+    #[derive(Debug, PartialEq)]
+    struct Order {
+        uint256 salt;
+        address maker;
+        address receiver;
+        address makerAsset;
+        address takerAsset;
+        uint256 makingAmount;
+        uint256 takingAmount;
+        uint256 makerTraits;
+    }
+
+    interface IOrderMixin {
+        function cancelOrder(uint256 makerTraits, bytes32 orderHash) external;
+    }
+}
+
+pub fn build_maker_traits(expires_at: Option<u64>, unwrap_weth: bool) -> U256 {
+    let expiration = U256::from(expires_at.unwrap_or_default() & EXPIRATION_MASK);
+    let mut maker_traits = (U256::from(1) << ALLOW_MULTIPLE_FILLS_FLAG) | (expiration << EXPIRATION_OFFSET);
+    if unwrap_weth {
+        maker_traits |= U256::from(1) << UNWRAP_WETH_FLAG;
+    }
+    maker_traits
+}
+
+pub fn maker_traits_expiration(maker_traits: U256) -> Option<u64> {
+    let expiration = ((maker_traits >> EXPIRATION_OFFSET) & U256::from(EXPIRATION_MASK)).to::<u64>();
+    (expiration > 0).then_some(expiration)
+}
+
+fn domain(chain_id: u64) -> Eip712Domain {
+    Eip712Domain::new(
+        Some(DOMAIN_NAME.into()),
+        Some(DOMAIN_VERSION.into()),
+        Some(U256::from(chain_id)),
+        Some(VERIFYING_CONTRACT),
+        None,
+    )
+}
+
+pub fn order_hash(order: &Order, chain_id: u64) -> B256 {
+    order.eip712_signing_hash(&domain(chain_id))
+}
+
+pub fn order_typed_data(order: &Order, chain_id: u64) -> Value {
+    json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Order": [
+                { "name": "salt", "type": "uint256" },
+                { "name": "maker", "type": "address" },
+                { "name": "receiver", "type": "address" },
+                { "name": "makerAsset", "type": "address" },
+                { "name": "takerAsset", "type": "address" },
+                { "name": "makingAmount", "type": "uint256" },
+                { "name": "takingAmount", "type": "uint256" },
+                { "name": "makerTraits", "type": "uint256" }
+            ]
+        },
+        "primaryType": "Order",
+        "domain": {
+            "name": DOMAIN_NAME,
+            "version": DOMAIN_VERSION,
+            "chainId": chain_id,
+            "verifyingContract": ROUTER_ADDRESS
+        },
+        "message": order_data(order)
+    })
+}
+
+pub fn order_data(order: &Order) -> OrderData {
+    OrderData {
+        salt: order.salt.to_string(),
+        maker: order.maker.to_checksum(None),
+        receiver: order.receiver.to_checksum(None),
+        maker_asset: order.makerAsset.to_checksum(None),
+        taker_asset: order.takerAsset.to_checksum(None),
+        making_amount: order.makingAmount.to_string(),
+        taking_amount: order.takingAmount.to_string(),
+        maker_traits: order.makerTraits.to_string(),
+        extension: None,
+    }
+}
+
+impl TryFrom<&OrderData> for Order {
+    type Error = SwapperError;
+
+    fn try_from(data: &OrderData) -> Result<Self, Self::Error> {
+        let address = |value: &str| Address::from_str(value).map_err(|_| SwapperError::TransactionError(format!("invalid address: {value}")));
+        let number = |value: &str| U256::from_str(value).map_err(SwapperError::from);
+        Ok(Order {
+            salt: number(&data.salt)?,
+            maker: address(&data.maker)?,
+            receiver: address(&data.receiver)?,
+            makerAsset: address(&data.maker_asset)?,
+            takerAsset: address(&data.taker_asset)?,
+            makingAmount: number(&data.making_amount)?,
+            takingAmount: number(&data.taking_amount)?,
+            makerTraits: number(&data.maker_traits)?,
+        })
+    }
+}
+
+pub fn encode_cancel_order(maker_traits: U256, order_hash: B256) -> Vec<u8> {
+    IOrderMixin::cancelOrderCall {
+        makerTraits: maker_traits,
+        orderHash: order_hash,
+    }
+    .abi_encode()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gem_evm::eip712::eip712_hash_message;
+    use primitives::asset_constants::{ETHEREUM_USDC_TOKEN_ID, ETHEREUM_USDT_TOKEN_ID};
+
+    fn order() -> Order {
+        Order {
+            salt: U256::from(42),
+            maker: Address::from_str("0x52A07c930157d07D9EffD147ecF41C5cBbC6000c").unwrap(),
+            receiver: Address::ZERO,
+            makerAsset: Address::from_str(ETHEREUM_USDC_TOKEN_ID).unwrap(),
+            takerAsset: Address::from_str(ETHEREUM_USDT_TOKEN_ID).unwrap(),
+            makingAmount: U256::from(1_000_000),
+            takingAmount: U256::from(1_010_000),
+            makerTraits: build_maker_traits(Some(1_900_000_000), false),
+        }
+    }
+
+    #[test]
+    fn test_maker_traits() {
+        let maker_traits = build_maker_traits(Some(1_900_000_000), false);
+
+        assert!(maker_traits.bit(ALLOW_MULTIPLE_FILLS_FLAG));
+        assert!(!maker_traits.bit(UNWRAP_WETH_FLAG));
+        assert_eq!(maker_traits_expiration(maker_traits), Some(1_900_000_000));
+
+        let maker_traits = build_maker_traits(None, true);
+
+        assert!(maker_traits.bit(UNWRAP_WETH_FLAG));
+        assert_eq!(maker_traits_expiration(maker_traits), None);
+    }
+
+    #[test]
+    fn test_order_typed_data_hash() {
+        let order = order();
+        let typed_data = order_typed_data(&order, 1);
+
+        assert_eq!(eip712_hash_message(typed_data.clone()).unwrap(), order_hash(&order, 1).to_vec());
+        assert_eq!(typed_data["message"]["makerTraits"], order.makerTraits.to_string());
+
+        let data: OrderData = serde_json::from_value(typed_data["message"].clone()).unwrap();
+        assert_eq!(Order::try_from(&data).unwrap(), order);
+    }
+
+    #[test]
+    fn test_encode_cancel_order() {
+        let data = encode_cancel_order(U256::from(1), B256::ZERO);
+
+        assert_eq!(&data[..4], IOrderMixin::cancelOrderCall::SELECTOR.as_slice());
+        assert_eq!(data.len(), 68);
+    }
+}
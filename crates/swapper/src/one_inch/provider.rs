@@ -0,0 +1,231 @@
+use super::{
+    ROUTER_ADDRESS, SUPPORTED_CHAINS,
+    client::OneInchClient,
+    model::{OrderData, OrderbookOrder, PostOrderRequest},
+    order::{Order, build_maker_traits, encode_cancel_order, maker_traits_expiration, order_data, order_hash, order_typed_data},
+};
+use crate::{
+    ApprovalType, SwapperError, SwapperQuoteData,
+    alien::RpcProvider,
+    approval::check_approval_erc20,
+    eth_address,
+    limit_order::{LimitOrder, LimitOrderData, LimitOrderProvider, LimitOrderRequest, LimitOrderStatus, LimitOrderSwapper},
+};
+use alloy_primitives::{Address, B256, U256};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use gem_client::Client;
+use num_bigint::BigUint;
+use num_traits::CheckedSub;
+use primitives::{AssetId, Chain, EVMChain, hex};
+use rand::RngExt;
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+#[derive(Debug)]
+pub struct OneInch<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    client: OneInchClient<C>,
+    rpc_provider: Arc<dyn RpcProvider>,
+}
+
+impl<C> OneInch<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    pub fn with_client(client: OneInchClient<C>, rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        Self { client, rpc_provider }
+    }
+
+    fn chain_id(chain: Chain) -> Result<u64, SwapperError> {
+        if !SUPPORTED_CHAINS.contains(&chain) {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        Ok(chain.network_id().parse::<u64>()?)
+    }
+
+    fn maker_asset(asset_id: &AssetId) -> Result<Address, SwapperError> {
+        if asset_id.is_native() {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+        eth_address::parse_asset_id(asset_id)
+    }
+}
+
+#[async_trait]
+impl<C> LimitOrderSwapper for OneInch<C>
+where
+    C: Client + Clone + Send + Sync + Debug + 'static,
+{
+    fn provider(&self) -> LimitOrderProvider {
+        LimitOrderProvider::OneInch
+    }
+
+    fn supported_chains(&self) -> Vec<Chain> {
+        SUPPORTED_CHAINS.to_vec()
+    }
+
+    async fn create_order(&self, request: &LimitOrderRequest) -> Result<LimitOrderData, SwapperError> {
+        let chain = request.chain()?;
+        let chain_id = Self::chain_id(chain)?;
+        let maker_asset = Self::maker_asset(&request.from_asset.asset_id())?;
+        let to_asset = request.to_asset.asset_id();
+        let evm_chain = EVMChain::from_chain(chain).ok_or(SwapperError::NotSupportedChain)?;
+        let order = Order {
+            salt: U256::from(rand::rng().random::<u64>()),
+            maker: eth_address::parse_str(&request.wallet_address)?,
+            receiver: Address::ZERO,
+            makerAsset: maker_asset,
+            takerAsset: eth_address::parse_or_weth_address(&to_asset, evm_chain)?,
+            makingAmount: U256::from_str(&request.from_value)?,
+            takingAmount: U256::from_str(&request.to_value)?,
+            makerTraits: build_maker_traits(request.expires_at, to_asset.is_native()),
+        };
+        let approval = check_approval_erc20(
+            request.wallet_address.clone(),
+            maker_asset.to_checksum(None),
+            ROUTER_ADDRESS.to_string(),
+            order.makingAmount,
+            self.rpc_provider.clone(),
+            &chain,
+        )
+        .await?;
+
+        Ok(LimitOrderData::SignMessage {
+            order_id: order_hash(&order, chain_id).to_string(),
+            typed_data: order_typed_data(&order, chain_id).to_string(),
+            approval: match approval {
+                ApprovalType::Approve(data) => Some(data),
+                ApprovalType::Permit2(_) | ApprovalType::None => None,
+            },
+        })
+    }
+
+    async fn submit_order(&self, chain: Chain, typed_data: &str, signature: &str) -> Result<String, SwapperError> {
+        let chain_id = Self::chain_id(chain)?;
+        let typed_data: serde_json::Value = serde_json::from_str(typed_data)?;
+        let data: OrderData = serde_json::from_value(typed_data["message"].clone())?;
+        let order = Order::try_from(&data)?;
+        let order_hash = order_hash(&order, chain_id).to_string();
+        let request = PostOrderRequest {
+            order_hash: order_hash.clone(),
+            signature: signature.to_string(),
+            data: order_data(&order),
+        };
+        self.client.post_order(&chain_id.to_string(), &request).await?;
+        Ok(order_hash)
+    }
+
+    async fn get_orders(&self, chain: Chain, wallet_address: &str) -> Result<Vec<LimitOrder>, SwapperError> {
+        let orders = self.client.get_orders(&Self::chain_id(chain)?.to_string(), wallet_address).await?;
+        let now = Utc::now().timestamp() as u64;
+        Ok(orders.iter().filter_map(|x| map_order(chain, x, now)).collect())
+    }
+
+    async fn get_order(&self, chain: Chain, _wallet_address: &str, order_id: &str) -> Result<LimitOrder, SwapperError> {
+        self.get_order_by_id(chain, order_id).await
+    }
+
+    async fn get_order_by_id(&self, chain: Chain, order_id: &str) -> Result<LimitOrder, SwapperError> {
+        let order = self.client.get_order(&Self::chain_id(chain)?.to_string(), order_id).await?;
+        map_order(chain, &order, Utc::now().timestamp() as u64).ok_or(SwapperError::InvalidRoute)
+    }
+
+    async fn cancel_order(&self, order: &LimitOrder) -> Result<SwapperQuoteData, SwapperError> {
+        let chain = order.chain();
+        let orderbook_order = self.client.get_order(&Self::chain_id(chain)?.to_string(), &order.id).await?;
+        let maker_traits = U256::from_str(&orderbook_order.data.maker_traits)?;
+        let order_hash = B256::from_str(&order.id).map_err(|_| SwapperError::InvalidRoute)?;
+        let data = encode_cancel_order(maker_traits, order_hash);
+        Ok(SwapperQuoteData::new_contract(
+            ROUTER_ADDRESS.to_string(),
+            "0".to_string(),
+            hex::encode_with_0x(&data),
+            None,
+            None,
+        ))
+    }
+}
+
+fn map_order(chain: Chain, order: &OrderbookOrder, now: u64) -> Option<LimitOrder> {
+    let data = &order.data;
+    let making_amount = data.making_amount.parse::<BigUint>().ok()?;
+    let taking_amount = data.taking_amount.parse::<BigUint>().ok()?;
+    let remaining_amount = order.remaining_maker_amount.parse::<BigUint>().ok()?;
+    let filled_from_value = making_amount.checked_sub(&remaining_amount).unwrap_or_default();
+    let filled_to_value = if making_amount > BigUint::ZERO {
+        &taking_amount * &filled_from_value / &making_amount
+    } else {
+        BigUint::ZERO
+    };
+    let expires_at = maker_traits_expiration(U256::from_str(&data.maker_traits).ok()?);
+    let status = if remaining_amount == BigUint::ZERO {
+        LimitOrderStatus::Filled
+    } else if expires_at.is_some_and(|x| x <= now) {
+        LimitOrderStatus::Expired
+    } else if order.order_status.is_some_and(|x| x < 0) {
+        LimitOrderStatus::Invalid
+    } else {
+        LimitOrderStatus::Open
+    };
+    Some(LimitOrder {
+        provider: LimitOrderProvider::OneInch,
+        id: order.order_hash.clone(),
+        wallet_address: data.maker.clone(),
+        from_asset: AssetId::from_token(chain, &data.maker_asset),
+        to_asset: AssetId::from_token(chain, &data.taker_asset),
+        from_value: data.making_amount.clone(),
+        to_value: data.taking_amount.clone(),
+        filled_from_value: filled_from_value.to_string(),
+        filled_to_value: filled_to_value.to_string(),
+        status,
+        created_at: DateTime::parse_from_rfc3339(&order.create_date_time).ok().map(|x| x.timestamp() as u64),
+        expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::{ETHEREUM_USDC_TOKEN_ID, ETHEREUM_USDT_TOKEN_ID};
+
+    fn orderbook_order(remaining_maker_amount: &str, order_status: Option<i32>) -> OrderbookOrder {
+        OrderbookOrder {
+            order_hash: "0x5b2b".to_string(),
+            create_date_time: "2025-03-20T09:43:59.000Z".to_string(),
+            remaining_maker_amount: remaining_maker_amount.to_string(),
+            data: OrderData {
+                salt: "42".to_string(),
+                maker: "0x52A07c930157d07D9EffD147ecF41C5cBbC6000c".to_string(),
+                receiver: Address::ZERO.to_checksum(None),
+                maker_asset: ETHEREUM_USDC_TOKEN_ID.to_string(),
+                taker_asset: ETHEREUM_USDT_TOKEN_ID.to_string(),
+                making_amount: "1000000".to_string(),
+                taking_amount: "1010000".to_string(),
+                maker_traits: build_maker_traits(Some(1_900_000_000), false).to_string(),
+                extension: None,
+            },
+            order_status,
+        }
+    }
+
+    #[test]
+    fn test_map_order() {
+        let order = map_order(Chain::Ethereum, &orderbook_order("400000", Some(1)), 1_800_000_000).unwrap();
+
+        assert_eq!(order.status, LimitOrderStatus::Open);
+        assert_eq!(order.from_asset, AssetId::from_token(Chain::Ethereum, ETHEREUM_USDC_TOKEN_ID));
+        assert_eq!(order.filled_from_value, "600000");
+        assert_eq!(order.filled_to_value, "606000");
+        assert_eq!(order.expires_at, Some(1_900_000_000));
+        assert_eq!(order.created_at, Some(1742463839));
+
+        let status = |remaining: &str, order_status: Option<i32>, now: u64| map_order(Chain::Ethereum, &orderbook_order(remaining, order_status), now).unwrap().status;
+
+        assert_eq!(status("0", Some(-1), 1_800_000_000), LimitOrderStatus::Filled);
+        assert_eq!(status("400000", Some(1), 1_900_000_000), LimitOrderStatus::Expired);
+        assert_eq!(status("400000", Some(-3), 1_800_000_000), LimitOrderStatus::Invalid);
+        assert_eq!(status("400000", Some(1), 1_800_000_000), LimitOrderStatus::Open);
+    }
+}
@@ -0,0 +1,107 @@
+use num_traits::ToPrimitive;
+use primitives::{Asset, AssetId, EVMChain};
+
+use crate::{Quote, QuoteAssetPrice, QuotePriceImpact, SwapperError, config::Config, quote_fees::asset_price};
+
+pub(crate) fn calculate(quote: &Quote, prices: &[QuoteAssetPrice], config: &Config) -> Option<QuotePriceImpact> {
+    let from_value = usd_value(&quote.from_value, &quote.request.from_asset.id, quote.request.from_asset.decimals, prices)?;
+    let to_value = usd_value(&quote.to_value, &quote.request.to_asset.id, quote.request.to_asset.decimals, prices)?;
+    if from_value <= 0.0 || !to_value.is_finite() {
+        return None;
+    }
+    let percentage = (to_value / from_value - 1.0) * 100.0;
+    Some(QuotePriceImpact {
+        percentage,
+        is_high: -percentage >= config.high_price_impact_percent as f64,
+    })
+}
+
+pub(crate) fn apply(mut quote: Quote, prices: &[QuoteAssetPrice], config: &Config) -> Result<Quote, SwapperError> {
+    quote.price_impact = calculate(&quote, prices, config);
+    if let Some(impact) = &quote.price_impact
+        && -impact.percentage >= config.max_price_impact_percent as f64
+    {
+        tracing::warn!(
+            provider = quote.data.provider.id.id(),
+            price_impact = impact.percentage,
+            "rejected quote with price impact above limit"
+        );
+        return Err(SwapperError::PriceImpactTooHigh { price_impact: impact.percentage });
+    }
+    Ok(quote)
+}
+
+pub(crate) fn is_eth(asset_id: &AssetId) -> bool {
+    asset_id.is_native() && EVMChain::from_chain(asset_id.chain).is_some() && Asset::from_chain(asset_id.chain).symbol == "ETH"
+}
+
+fn usd_value(value: &str, asset_id: &str, decimals: u32, prices: &[QuoteAssetPrice]) -> Option<f64> {
+    let price = asset_price(prices, &AssetId::new(asset_id)?)?;
+    let value = value.parse::<num_bigint::BigUint>().ok()?.to_f64()?;
+    Some(value / 10f64.powi(decimals as i32) * price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SwapperProvider, SwapperQuoteAsset, config::get_swap_config};
+    use primitives::{Chain, asset_constants::ETHEREUM_USDC_ASSET_ID};
+
+    fn quote(to_value: &str) -> Quote {
+        let mut quote = Quote::mock_with_provider(SwapperProvider::UniswapV3, to_value);
+        quote.from_value = "1000000000000000000".to_string();
+        quote.request.from_asset = SwapperQuoteAsset {
+            decimals: 18,
+            ..SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum))
+        };
+        quote.request.to_asset = SwapperQuoteAsset {
+            decimals: 6,
+            ..SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone())
+        };
+        quote
+    }
+
+    fn prices() -> Vec<QuoteAssetPrice> {
+        vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: ETHEREUM_USDC_ASSET_ID.clone(),
+                price: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_calculate() {
+        let config = get_swap_config();
+
+        let impact = calculate(&quote("2970000000"), &prices(), &config).unwrap();
+
+        assert!((impact.percentage + 1.0).abs() < 1e-9);
+        assert!(!impact.is_high);
+        assert!(calculate(&quote("2400000000"), &prices(), &config).unwrap().is_high);
+        assert_eq!(calculate(&quote("2970000000"), &prices()[..1], &config), None);
+    }
+
+    #[test]
+    fn test_apply() {
+        let config = get_swap_config();
+
+        assert!(apply(quote("2400000000"), &prices(), &config).unwrap().price_impact.is_some());
+        assert_eq!(
+            apply(quote("1500000000"), &prices(), &config),
+            Err(SwapperError::PriceImpactTooHigh { price_impact: -50.0 })
+        );
+        assert_eq!(apply(quote("1000000000"), &[], &config).unwrap().price_impact, None);
+    }
+
+    #[test]
+    fn test_is_eth() {
+        assert!(is_eth(&AssetId::from_chain(Chain::Arbitrum)));
+        assert!(!is_eth(&AssetId::from_chain(Chain::SmartChain)));
+        assert!(!is_eth(&ETHEREUM_USDC_ASSET_ID));
+    }
+}
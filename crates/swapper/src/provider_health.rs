@@ -0,0 +1,242 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{ProviderErrorCount, ProviderHealth, ProviderHealthState, SwapperError, SwapperProvider};
+
+const WINDOW: Duration = Duration::from_secs(5 * 60);
+const MIN_REQUESTS: usize = 5;
+const DEGRADED_FAILURE_RATE: f64 = 0.2;
+const UNAVAILABLE_FAILURE_RATE: f64 = 0.5;
+const COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct Sample {
+    at: Instant,
+    latency: Duration,
+    error: Option<&'static str>,
+    is_failure: bool,
+}
+
+#[derive(Debug, Default)]
+struct ProviderStats {
+    samples: VecDeque<Sample>,
+    open_until: Option<Instant>,
+    probe_started_at: Option<Instant>,
+}
+
+impl ProviderStats {
+    fn prune(&mut self, now: Instant) {
+        while self.samples.front().is_some_and(|x| now.duration_since(x.at) > WINDOW) {
+            self.samples.pop_front();
+        }
+    }
+
+    fn failure_rate(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().filter(|x| x.is_failure).count() as f64 / self.samples.len() as f64
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ProviderHealthTracker {
+    providers: Mutex<BTreeMap<SwapperProvider, ProviderStats>>,
+}
+
+fn is_failure(error: &SwapperError) -> bool {
+    match error {
+        SwapperError::ComputeQuoteError(_) | SwapperError::TransactionError(_) | SwapperError::InvalidRoute | SwapperError::Timeout => true,
+        SwapperError::NotSupportedChain
+        | SwapperError::NotSupportedAsset
+        | SwapperError::NoAvailableProvider
+        | SwapperError::InputAmountError { .. }
+        | SwapperError::NoQuoteAvailable
+        | SwapperError::PriceImpactTooHigh { .. } => false,
+    }
+}
+
+fn error_class(error: &SwapperError) -> &'static str {
+    match error {
+        SwapperError::NotSupportedChain => "not_supported_chain",
+        SwapperError::NotSupportedAsset => "not_supported_asset",
+        SwapperError::NoAvailableProvider => "no_available_provider",
+        SwapperError::InputAmountError { .. } => "input_amount_error",
+        SwapperError::InvalidRoute => "invalid_route",
+        SwapperError::ComputeQuoteError(_) => "compute_quote_error",
+        SwapperError::TransactionError(_) => "transaction_error",
+        SwapperError::NoQuoteAvailable => "no_quote_available",
+        SwapperError::Timeout => "timeout",
+        SwapperError::PriceImpactTooHigh { .. } => "price_impact_too_high",
+    }
+}
+
+impl ProviderHealthTracker {
+    pub fn record<T>(&self, provider: SwapperProvider, latency: Duration, result: &Result<T, SwapperError>) {
+        self.record_at(provider, Instant::now(), latency, result.as_ref().err());
+    }
+
+    pub fn allow_request(&self, provider: &SwapperProvider) -> bool {
+        self.allow_request_at(provider, Instant::now())
+    }
+
+    pub fn health(&self) -> Vec<ProviderHealth> {
+        self.health_at(Instant::now())
+    }
+
+    fn record_at(&self, provider: SwapperProvider, at: Instant, latency: Duration, error: Option<&SwapperError>) {
+        let is_failure = error.is_some_and(is_failure);
+        let mut providers = self.providers();
+        let stats = providers.entry(provider).or_default();
+        stats.prune(at);
+        stats.samples.push_back(Sample {
+            at,
+            latency,
+            error: error.map(error_class),
+            is_failure,
+        });
+
+        if stats.open_until.is_some() {
+            stats.probe_started_at = None;
+            if is_failure {
+                stats.open_until = Some(at + COOLDOWN);
+            } else {
+                stats.open_until = None;
+                stats.samples.clear();
+            }
+        } else if stats.samples.len() >= MIN_REQUESTS && stats.failure_rate() >= UNAVAILABLE_FAILURE_RATE {
+            stats.open_until = Some(at + COOLDOWN);
+        }
+    }
+
+    fn allow_request_at(&self, provider: &SwapperProvider, now: Instant) -> bool {
+        let mut providers = self.providers();
+        let Some(stats) = providers.get_mut(provider) else {
+            return true;
+        };
+        match (stats.open_until, stats.probe_started_at) {
+            (None, _) => true,
+            (Some(open_until), _) if now < open_until => false,
+            (Some(_), Some(probe_started_at)) if now.duration_since(probe_started_at) < COOLDOWN => false,
+            (Some(_), _) => {
+                stats.probe_started_at = Some(now);
+                true
+            }
+        }
+    }
+
+    fn health_at(&self, now: Instant) -> Vec<ProviderHealth> {
+        let mut providers = self.providers();
+        providers
+            .iter_mut()
+            .map(|(provider, stats)| {
+                stats.prune(now);
+                let requests = stats.samples.len() as u32;
+                let failure_rate = stats.failure_rate();
+                let state = match stats.open_until {
+                    Some(open_until) if now < open_until => ProviderHealthState::Unavailable,
+                    _ if stats.samples.len() >= MIN_REQUESTS && failure_rate >= DEGRADED_FAILURE_RATE => ProviderHealthState::Degraded,
+                    _ => ProviderHealthState::Healthy,
+                };
+                let average_latency_ms = match requests {
+                    0 => 0,
+                    _ => (stats.samples.iter().map(|x| x.latency.as_millis()).sum::<u128>() / requests as u128) as u64,
+                };
+                let mut errors = BTreeMap::<&str, u32>::new();
+                for error in stats.samples.iter().filter_map(|x| x.error) {
+                    *errors.entry(error).or_default() += 1;
+                }
+                ProviderHealth {
+                    provider: *provider,
+                    state,
+                    requests,
+                    success_rate: if requests == 0 { 1.0 } else { 1.0 - failure_rate },
+                    average_latency_ms,
+                    errors: errors.into_iter().map(|(error, count)| ProviderErrorCount { error: error.to_string(), count }).collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn providers(&self) -> MutexGuard<'_, BTreeMap<SwapperProvider, ProviderStats>> {
+        self.providers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROVIDER: SwapperProvider = SwapperProvider::Jupiter;
+
+    fn record(tracker: &ProviderHealthTracker, at: Instant, error: Option<SwapperError>) {
+        tracker.record_at(PROVIDER, at, Duration::from_millis(100), error.as_ref());
+    }
+
+    #[test]
+    fn test_circuit_opens_and_recovers() {
+        let tracker = ProviderHealthTracker::default();
+        let now = Instant::now();
+        let timeout = || Some(SwapperError::Timeout);
+
+        for _ in 0..MIN_REQUESTS - 1 {
+            record(&tracker, now, timeout());
+        }
+        assert!(tracker.allow_request_at(&PROVIDER, now));
+
+        record(&tracker, now, timeout());
+        assert!(!tracker.allow_request_at(&PROVIDER, now));
+        assert_eq!(tracker.health_at(now)[0].state, ProviderHealthState::Unavailable);
+
+        let probe = now + COOLDOWN;
+        assert!(tracker.allow_request_at(&PROVIDER, probe));
+        assert!(!tracker.allow_request_at(&PROVIDER, probe));
+        record(&tracker, probe, timeout());
+        assert!(!tracker.allow_request_at(&PROVIDER, probe));
+
+        let probe = probe + COOLDOWN;
+        assert!(tracker.allow_request_at(&PROVIDER, probe));
+        record(&tracker, probe, None);
+        assert!(tracker.allow_request_at(&PROVIDER, probe));
+        assert_eq!(tracker.health_at(probe)[0].state, ProviderHealthState::Healthy);
+    }
+
+    #[test]
+    fn test_health() {
+        let tracker = ProviderHealthTracker::default();
+        let now = Instant::now();
+
+        record(&tracker, now, None);
+        record(&tracker, now, None);
+        record(&tracker, now, Some(SwapperError::NoQuoteAvailable));
+        record(&tracker, now, Some(SwapperError::NoQuoteAvailable));
+        record(&tracker, now, Some(SwapperError::ComputeQuoteError("HTTP error: status 502".into())));
+
+        let health = tracker.health_at(now);
+
+        assert_eq!(
+            health,
+            vec![ProviderHealth {
+                provider: PROVIDER,
+                state: ProviderHealthState::Degraded,
+                requests: 5,
+                success_rate: 0.8,
+                average_latency_ms: 100,
+                errors: vec![
+                    ProviderErrorCount {
+                        error: "compute_quote_error".to_string(),
+                        count: 1
+                    },
+                    ProviderErrorCount {
+                        error: "no_quote_available".to_string(),
+                        count: 2
+                    },
+                ],
+            }]
+        );
+        assert!(tracker.health_at(now + WINDOW + Duration::from_secs(1))[0].requests == 0);
+    }
+}
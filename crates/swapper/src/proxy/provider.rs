@@ -7,7 +7,7 @@ use super::{
     mayan::{MAYAN_DEPOSIT_CONTRACTS, MAYAN_SEND_CONTRACTS, MayanChain, MayanExplorer, MayanPrice, map_swap_result},
 };
 use crate::{
-    FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, SwapResult, Swapper, SwapperError, SwapperProvider, SwapperProviderMode, SwapperQuoteData,
+    FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, SwapResult, Swapper, SwapperError, SwapperMode, SwapperProvider, SwapperProviderMode, SwapperQuoteData,
     alien::{RpcClient, RpcProvider},
     approval::evm::check_approval_erc20,
     config::get_swap_api_url,
@@ -23,6 +23,7 @@ use primitives::{
 };
 
 const DEFAULT_GAS_LIMIT: u64 = 750_000;
+const OKX_EXACT_OUT_CHAINS: [Chain; 4] = [Chain::Ethereum, Chain::Base, Chain::SmartChain, Chain::Arbitrum];
 
 #[derive(Debug)]
 pub struct ProxyProvider<C>
@@ -213,6 +214,10 @@ where
         self.assets.clone()
     }
 
+    fn supports_exact_out(&self, request: &QuoteRequest) -> bool {
+        self.provider.id == SwapperProvider::Okx && OKX_EXACT_OUT_CHAINS.contains(&request.from_asset.chain())
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
         let quote_request = ProxyQuoteRequest {
             from_address: request.wallet_address.clone(),
@@ -223,12 +228,20 @@ where
             referral_bps: self.referral_bps(),
             slippage_bps: request.options.slippage.bps,
             use_max_amount: request.options.use_max_amount,
+            swap_mode: request.mode,
         };
 
         let quote = self.client.get_quote(quote_request.clone()).await?;
+        let from_value = match request.mode {
+            SwapperMode::ExactIn => request.value.clone(),
+            SwapperMode::ExactOut => quote
+                .input_value
+                .clone()
+                .ok_or_else(|| SwapperError::ComputeQuoteError("missing input value for exact output quote".into()))?,
+        };
 
         Ok(Quote {
-            from_value: request.value.clone(),
+            from_value,
             to_value: quote.output_value.clone(),
             data: ProviderData {
                 provider: self.provider().clone(),
@@ -241,6 +254,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds: Some(quote.eta_in_seconds),
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
@@ -337,6 +353,40 @@ mod tests {
         assert!(gas_limit.is_none());
     }
 
+    #[tokio::test]
+    async fn test_okx_exact_out_quote() {
+        let client = MockClient::new().with_post(|_, body| {
+            let request: ProxyQuoteRequest = serde_json::from_slice(body).unwrap();
+            assert_eq!(request.swap_mode, SwapperMode::ExactOut);
+            assert_eq!(request.from_value, "1000000");
+            let response = serde_json::json!({
+                "ok": {
+                    "quote": request,
+                    "output_value": "1000000",
+                    "output_min_value": "995000",
+                    "route_data": {},
+                    "eta_in_seconds": 0,
+                    "input_value": "500000000000000",
+                }
+            });
+            Ok(serde_json::to_vec(&response).unwrap())
+        });
+        let rpc_provider = Arc::new(ProviderMock::new("{}".to_string()));
+        let provider = ProxyProvider::new_with_client(SwapperProvider::Okx, ProxyClient::new(client), vec![], rpc_provider);
+        let request = QuoteRequest {
+            mode: SwapperMode::ExactOut,
+            ..QuoteRequest::mock(Chain::Ethereum, None)
+        };
+
+        let quote = provider.get_quote(&request).await.unwrap();
+
+        assert_eq!(quote.from_value, "500000000000000");
+        assert_eq!(quote.to_value, "1000000");
+        assert!(provider.supports_exact_out(&request));
+        assert!(!provider.supports_exact_out(&QuoteRequest::mock(Chain::Polygon, None)));
+        assert!(!mock_provider(SwapperProvider::Orca).supports_exact_out(&request));
+    }
+
     #[tokio::test]
     async fn test_evm_native_ignores_provider_gas_limit() {
         let provider = mock_provider(SwapperProvider::Mayan);
@@ -0,0 +1,448 @@
+use std::cmp::Reverse;
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use primitives::{Asset, AssetId, Chain, EVMChain};
+
+use crate::{Quote, QuoteAssetPrice, QuoteFees, QuoteRequest, SwapperError, SwapperMode, SwapperProvider, SwapperQuoteAsset, across, cctp, chainflip, relay, thorchain, uniswap};
+
+const DEFAULT_SWAP_GAS_LIMIT: u64 = 200_000;
+const APPROVAL_GAS_LIMIT: u64 = 60_000;
+
+#[derive(Debug, Clone)]
+pub enum NetworkFeeRate {
+    GasPrice(BigUint),
+    Transaction(BigUint),
+}
+
+fn swap_gas_limit(provider: SwapperProvider) -> u64 {
+    match provider {
+        SwapperProvider::UniswapV3 | SwapperProvider::PancakeswapV3 | SwapperProvider::Oku | SwapperProvider::Wagmi | SwapperProvider::Aerodrome => {
+            uniswap::v3::DEFAULT_SWAP_GAS_LIMIT
+        }
+        SwapperProvider::UniswapV4 => uniswap::v4::DEFAULT_SWAP_GAS_LIMIT,
+        SwapperProvider::UniswapV2
+        | SwapperProvider::PancakeswapV2
+        | SwapperProvider::SushiswapV2
+        | SwapperProvider::QuickswapV2
+        | SwapperProvider::CamelotV2
+        | SwapperProvider::SpookyswapV2 => uniswap::v2::DEFAULT_SWAP_GAS_LIMIT,
+        SwapperProvider::Across => across::DEFAULT_DEPOSIT_GAS_LIMIT,
+        SwapperProvider::Cctp => cctp::DEFAULT_DEPOSIT_GAS_LIMIT,
+        SwapperProvider::Thorchain => thorchain::DEFAULT_DEPOSIT_GAS_LIMIT,
+        SwapperProvider::Relay => relay::DEFAULT_SWAP_GAS_LIMIT,
+        SwapperProvider::Chainflip => chainflip::provider::DEFAULT_SWAP_ERC20_GAS_LIMIT,
+        SwapperProvider::CowSwap | SwapperProvider::OneInch => 0,
+        SwapperProvider::Okx
+        | SwapperProvider::Squid
+        | SwapperProvider::Mayan
+        | SwapperProvider::NearIntents
+        | SwapperProvider::Jupiter
+        | SwapperProvider::Panora
+        | SwapperProvider::StonfiV2
+        | SwapperProvider::CetusAggregator
+        | SwapperProvider::Orca
+        | SwapperProvider::Hyperliquid
+        | SwapperProvider::Composed => DEFAULT_SWAP_GAS_LIMIT,
+    }
+}
+
+fn destination_gas_limit(provider: SwapperProvider) -> u64 {
+    match provider {
+        SwapperProvider::Cctp => cctp::DEFAULT_MINT_GAS_LIMIT,
+        _ => 0,
+    }
+}
+
+pub(crate) fn requires_approval(quote: &Quote) -> bool {
+    let from_native = AssetId::new(&quote.request.from_asset.id).is_none_or(|asset_id| asset_id.is_native());
+    !from_native && quote.data.provider.id != SwapperProvider::NearIntents && EVMChain::from_chain(quote.request.from_asset.chain()).is_some()
+}
+
+pub fn estimate_quote_fees(quote: &Quote, fee_rate: &NetworkFeeRate, destination_gas_price: Option<&BigUint>, requires_approval: bool) -> Result<QuoteFees, SwapperError> {
+    let (network_fee, approval_fee) = match fee_rate {
+        NetworkFeeRate::GasPrice(gas_price) => (
+            gas_price * swap_gas_limit(quote.data.provider.id),
+            if requires_approval { gas_price * APPROVAL_GAS_LIMIT } else { BigUint::ZERO },
+        ),
+        NetworkFeeRate::Transaction(fee) => (fee.clone(), BigUint::ZERO),
+    };
+    let destination_fee = match destination_gas_price {
+        Some(destination_gas_price) => destination_gas_price * destination_gas_limit(quote.data.provider.id),
+        None => BigUint::ZERO,
+    };
+    let fee_value = convert_fee_to_output(&(&network_fee + &approval_fee), &destination_fee, &quote.request);
+    let net_value = match &fee_value {
+        Some(fee) => {
+            let to_value = parse_value::<BigUint>(&quote.to_value)?;
+            Some(if to_value > *fee { to_value - fee } else { BigUint::ZERO })
+        }
+        None => None,
+    };
+    Ok(QuoteFees {
+        network_fee: network_fee.to_string(),
+        approval_fee: approval_fee.to_string(),
+        destination_fee: destination_fee.to_string(),
+        fee_value: fee_value.map(|x| x.to_string()),
+        net_value: net_value.map(|x| x.to_string()),
+    })
+}
+
+fn convert_fee_to_output(fee: &BigUint, destination_fee: &BigUint, request: &QuoteRequest) -> Option<BigUint> {
+    let from_chain = AssetId::new(&request.from_asset.id)?.chain;
+    let source = convert_native_fee(fee, from_chain, &request.to_asset, &request.options.prices)?;
+    if destination_fee.is_zero() {
+        return Some(source);
+    }
+    let to_chain = AssetId::new(&request.to_asset.id)?.chain;
+    Some(source + convert_native_fee(destination_fee, to_chain, &request.to_asset, &request.options.prices)?)
+}
+
+fn convert_native_fee(fee: &BigUint, chain: Chain, output: &SwapperQuoteAsset, prices: &[QuoteAssetPrice]) -> Option<BigUint> {
+    let native_asset_id = chain.as_asset_id();
+    let output_asset_id = AssetId::new(&output.id)?;
+    if output_asset_id == native_asset_id {
+        return Some(fee.clone());
+    }
+    let native_price = asset_price(prices, &native_asset_id)?;
+    let output_price = asset_price(prices, &output_asset_id)?;
+    let exponent = output.decimals as i32 - native_decimals(chain);
+    let value = fee.to_f64()? * native_price / output_price * 10f64.powi(exponent);
+    BigUint::from_f64(value.round())
+}
+
+pub fn composed_fee_value(legs: &[Quote], output: &SwapperQuoteAsset, prices: &[QuoteAssetPrice]) -> Option<BigUint> {
+    legs.iter().try_fold(BigUint::ZERO, |total, leg| {
+        let fees = leg.fees.as_ref()?;
+        let fee = fees.network_fee.parse::<BigUint>().ok()? + fees.approval_fee.parse::<BigUint>().ok()?;
+        let destination_fee = fees.destination_fee.parse::<BigUint>().ok()?;
+        let destination_value = if destination_fee.is_zero() {
+            BigUint::ZERO
+        } else {
+            convert_native_fee(&destination_fee, leg.request.to_asset.chain(), output, prices)?
+        };
+        Some(total + convert_native_fee(&fee, leg.request.from_asset.chain(), output, prices)? + destination_value)
+    })
+}
+
+pub(crate) fn asset_price(prices: &[QuoteAssetPrice], asset_id: &AssetId) -> Option<f64> {
+    prices.iter().find(|x| x.asset_id == *asset_id).map(|x| x.price).filter(|price| *price > 0.0)
+}
+
+fn native_decimals(chain: Chain) -> i32 {
+    Asset::from_chain(chain).decimals
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str) -> Result<T, SwapperError> {
+    value.parse::<T>().map_err(|_| SwapperError::ComputeQuoteError(format!("invalid quote value: {value}")))
+}
+
+fn sort_quotes_by_key<K: Ord>(quotes: Vec<Quote>, key: impl Fn(&Quote) -> Result<K, SwapperError>) -> Vec<Quote> {
+    let mut keyed: Vec<_> = quotes
+        .into_iter()
+        .filter_map(|quote| match key(&quote) {
+            Ok(value) => Some((value, quote.eta_in_seconds.unwrap_or(u32::MAX), quote)),
+            Err(error) => {
+                tracing::warn!(provider = quote.data.provider.id.as_ref(), %error, "dropping quote");
+                None
+            }
+        })
+        .collect();
+    keyed.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    keyed.into_iter().map(|(_, _, quote)| quote).collect()
+}
+
+pub fn sort_quotes_by_net_value(quotes: Vec<Quote>) -> Vec<Quote> {
+    sort_quotes_by_key(quotes, |quote| match quote.fees.as_ref().and_then(|fees| fees.net_value.as_ref()) {
+        Some(net_value) => Ok((false, Reverse(parse_value::<BigInt>(net_value)?))),
+        None => Ok((true, Reverse(parse_value::<BigInt>(&quote.to_value)?))),
+    })
+}
+
+pub fn sort_quotes_by_input_value(quotes: Vec<Quote>) -> Vec<Quote> {
+    sort_quotes_by_key(quotes, |quote| parse_value::<BigInt>(&quote.from_value))
+}
+
+pub fn sort_quotes(mode: SwapperMode, quotes: Vec<Quote>) -> Vec<Quote> {
+    match mode {
+        SwapperMode::ExactIn => sort_quotes_by_net_value(quotes),
+        SwapperMode::ExactOut => sort_quotes_by_input_value(quotes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::ETHEREUM_USDC_ASSET_ID;
+
+    fn quote(provider: SwapperProvider, from: AssetId, to: AssetId, to_value: &str) -> Quote {
+        let mut quote = Quote::mock_with_provider(provider, to_value);
+        quote.request.from_asset = SwapperQuoteAsset::from(from);
+        quote.request.to_asset = SwapperQuoteAsset {
+            decimals: 6,
+            ..SwapperQuoteAsset::from(to)
+        };
+        quote
+    }
+
+    #[test]
+    fn test_estimate_quote_fees_with_prices() {
+        let mut quote = quote(SwapperProvider::Across, ETHEREUM_USDC_ASSET_ID.clone(), AssetId::from_chain(Chain::Arbitrum), "0");
+        quote.request.to_asset.decimals = 18;
+        quote.to_value = "1000000000000000000".to_string();
+
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(1_000_000_000u64)), None, requires_approval(&quote)).unwrap();
+
+        assert_eq!(fees.network_fee, "180000000000000");
+        assert_eq!(fees.approval_fee, "60000000000000");
+        assert_eq!(fees.fee_value, None);
+
+        let mut quote = quote.clone();
+        quote.request.options.prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Arbitrum),
+                price: 3000.0,
+            },
+        ];
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(1_000_000_000u64)), None, requires_approval(&quote)).unwrap();
+
+        assert_eq!(fees.fee_value.as_deref(), Some("240000000000000"));
+        assert_eq!(fees.net_value.as_deref(), Some("999760000000000000"));
+    }
+
+    #[test]
+    fn test_estimate_quote_fees_native_output() {
+        let quote = quote(
+            SwapperProvider::UniswapV4,
+            ETHEREUM_USDC_ASSET_ID.clone(),
+            AssetId::from_chain(Chain::Ethereum),
+            "1000000000000000",
+        );
+
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(2u32)), None, requires_approval(&quote)).unwrap();
+
+        assert_eq!(fees.fee_value.as_deref(), Some("720000"));
+        assert_eq!(fees.net_value.as_deref(), Some("999999999280000"));
+
+        let quote = Quote {
+            to_value: "invalid".to_string(),
+            ..quote
+        };
+
+        assert!(estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(2u32)), None, requires_approval(&quote)).is_err());
+    }
+
+    #[test]
+    fn test_estimate_quote_fees_stable_output() {
+        let mut quote = quote(
+            SwapperProvider::UniswapV3,
+            AssetId::from_chain(Chain::Ethereum),
+            ETHEREUM_USDC_ASSET_ID.clone(),
+            "3000000000",
+        );
+        quote.request.options.prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: ETHEREUM_USDC_ASSET_ID.clone(),
+                price: 1.0,
+            },
+        ];
+
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(2_000_000_000u64)), None, requires_approval(&quote)).unwrap();
+
+        assert_eq!(fees.approval_fee, "0");
+        assert_eq!(fees.fee_value.as_deref(), Some("3000000"));
+        assert_eq!(fees.net_value.as_deref(), Some("2997000000"));
+    }
+
+    #[test]
+    fn test_estimate_quote_fees_destination() {
+        let base_usdc = AssetId::from_token(Chain::Base, "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913");
+        let mut quote = quote(SwapperProvider::Cctp, ETHEREUM_USDC_ASSET_ID.clone(), base_usdc.clone(), "1000000000");
+        quote.request.options.prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Base),
+                price: 3000.0,
+            },
+            QuoteAssetPrice { asset_id: base_usdc, price: 1.0 },
+        ];
+
+        let fees = estimate_quote_fees(
+            &quote,
+            &NetworkFeeRate::GasPrice(BigUint::from(1_000_000_000u64)),
+            Some(&BigUint::from(10_000_000u64)),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(fees.approval_fee, "0");
+        assert_eq!(fees.destination_fee, "2000000000000");
+        assert_eq!(fees.fee_value.as_deref(), Some("606000"));
+        assert_eq!(fees.net_value.as_deref(), Some("999394000"));
+
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::GasPrice(BigUint::from(1_000_000_000u64)), None, true).unwrap();
+
+        assert_eq!(fees.destination_fee, "0");
+        assert_eq!(fees.approval_fee, "60000000000000");
+    }
+
+    #[test]
+    fn test_estimate_quote_fees_transaction_fee() {
+        let mut quote = quote(
+            SwapperProvider::Jupiter,
+            AssetId::from_token(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+            AssetId::from_chain(Chain::Solana),
+            "1000000000",
+        );
+        quote.request.to_asset.decimals = 9;
+
+        let fees = estimate_quote_fees(&quote, &NetworkFeeRate::Transaction(BigUint::from(47_000u64)), None, requires_approval(&quote)).unwrap();
+
+        assert!(!requires_approval(&quote));
+        assert_eq!(fees.network_fee, "47000");
+        assert_eq!(fees.approval_fee, "0");
+        assert_eq!(fees.net_value.as_deref(), Some("999953000"));
+    }
+
+    #[test]
+    fn test_composed_fee_value() {
+        let fees = |network_fee: &str| QuoteFees {
+            network_fee: network_fee.to_string(),
+            approval_fee: "0".to_string(),
+            destination_fee: "0".to_string(),
+            fee_value: None,
+            net_value: None,
+        };
+        let output = SwapperQuoteAsset {
+            decimals: 6,
+            ..SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone())
+        };
+        let prices = vec![
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Ethereum),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: AssetId::from_chain(Chain::Base),
+                price: 3000.0,
+            },
+            QuoteAssetPrice {
+                asset_id: ETHEREUM_USDC_ASSET_ID.clone(),
+                price: 1.0,
+            },
+        ];
+        let mut source = quote(SwapperProvider::Across, AssetId::from_chain(Chain::Base), AssetId::from_chain(Chain::Ethereum), "0");
+        source.fees = Some(fees("1000000000000000"));
+        let mut destination = quote(SwapperProvider::UniswapV3, AssetId::from_chain(Chain::Ethereum), ETHEREUM_USDC_ASSET_ID.clone(), "0");
+        destination.fees = Some(fees("2000000000000000"));
+
+        assert_eq!(
+            composed_fee_value(&[source.clone(), destination.clone()], &output, &prices),
+            Some(BigUint::from(9_000_000u32))
+        );
+
+        destination.fees = None;
+
+        assert_eq!(composed_fee_value(&[source, destination], &output, &prices), None);
+    }
+
+    #[test]
+    fn test_sort_quotes_by_net_value() {
+        let fees = |net_value: &str| QuoteFees {
+            network_fee: "0".to_string(),
+            approval_fee: "0".to_string(),
+            destination_fee: "0".to_string(),
+            fee_value: None,
+            net_value: Some(net_value.to_string()),
+        };
+        let quotes = vec![
+            Quote {
+                fees: Some(fees("90")),
+                ..Quote::mock_with_provider(SwapperProvider::UniswapV3, "102")
+            },
+            Quote {
+                fees: Some(fees("95")),
+                eta_in_seconds: Some(60),
+                ..Quote::mock_with_provider(SwapperProvider::Across, "100")
+            },
+            Quote {
+                fees: Some(fees("95")),
+                eta_in_seconds: Some(10),
+                ..Quote::mock_with_provider(SwapperProvider::Relay, "96")
+            },
+        ];
+
+        let quotes = sort_quotes_by_net_value(quotes);
+
+        assert_eq!(
+            quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::Relay, SwapperProvider::Across, SwapperProvider::UniswapV3]
+        );
+    }
+
+    #[test]
+    fn test_sort_quotes_by_net_value_ranks_quotes_without_fees_last() {
+        let fees = |net_value: &str| QuoteFees {
+            network_fee: "0".to_string(),
+            approval_fee: "0".to_string(),
+            destination_fee: "0".to_string(),
+            fee_value: None,
+            net_value: Some(net_value.to_string()),
+        };
+        let quotes = vec![
+            Quote::mock_with_provider(SwapperProvider::Thorchain, "101"),
+            Quote {
+                fees: Some(fees("95")),
+                ..Quote::mock_with_provider(SwapperProvider::Across, "100")
+            },
+            Quote::mock_with_provider(SwapperProvider::Chainflip, "103"),
+            Quote {
+                fees: Some(fees("97")),
+                ..Quote::mock_with_provider(SwapperProvider::Relay, "98")
+            },
+        ];
+
+        let quotes = sort_quotes_by_net_value(quotes);
+
+        assert_eq!(
+            quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::Relay, SwapperProvider::Across, SwapperProvider::Chainflip, SwapperProvider::Thorchain]
+        );
+    }
+
+    #[test]
+    fn test_sort_quotes_by_input_value() {
+        let quote = |provider: SwapperProvider, from_value: &str, eta_in_seconds: Option<u32>| Quote {
+            from_value: from_value.to_string(),
+            eta_in_seconds,
+            ..Quote::mock_with_provider(provider, "100")
+        };
+        let quotes = vec![
+            quote(SwapperProvider::Thorchain, "105", Some(600)),
+            quote(SwapperProvider::Across, "101", Some(60)),
+            quote(SwapperProvider::Relay, "101", Some(10)),
+        ];
+
+        let quotes = sort_quotes_by_input_value(quotes);
+
+        assert_eq!(
+            quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::Relay, SwapperProvider::Across, SwapperProvider::Thorchain]
+        );
+
+        let quotes = sort_quotes_by_input_value(vec![quote(SwapperProvider::Across, "", None), quote(SwapperProvider::Relay, "101", None)]);
+
+        assert_eq!(quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(), vec![SwapperProvider::Relay]);
+    }
+}
@@ -0,0 +1,19 @@
+mod model;
+mod orders;
+
+pub use model::*;
+pub use orders::GemRecurringOrders;
+
+use crate::{SwapperError, SwapperProvider, SwapperQuoteData};
+use async_trait::async_trait;
+use primitives::Chain;
+use std::fmt::Debug;
+
+#[async_trait]
+pub trait RecurringOrderSwapper: Send + Sync + Debug {
+    fn provider(&self) -> SwapperProvider;
+    fn supported_chains(&self) -> Vec<Chain>;
+    async fn create_order(&self, request: &RecurringOrderRequest) -> Result<SwapperQuoteData, SwapperError>;
+    async fn get_orders(&self, chain: Chain, wallet_address: &str) -> Result<Vec<RecurringOrder>, SwapperError>;
+    async fn cancel_order(&self, order: &RecurringOrder) -> Result<SwapperQuoteData, SwapperError>;
+}
@@ -0,0 +1,97 @@
+use crate::{SwapperError, SwapperProvider, SwapperQuoteAsset};
+use num_bigint::BigUint;
+use primitives::{AssetId, Chain, RecurringSwapFrequency, RecurringSwapStatus};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurringOrderRequest {
+    pub from_asset: SwapperQuoteAsset,
+    pub to_asset: SwapperQuoteAsset,
+    pub wallet_address: String,
+    pub amount: String,
+    pub frequency: RecurringSwapFrequency,
+    pub start_at: u64,
+    pub end_at: u64,
+}
+
+impl RecurringOrderRequest {
+    pub fn chain(&self) -> Result<Chain, SwapperError> {
+        let chain = self.from_asset.chain();
+        if chain != self.to_asset.chain() {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        if self.from_asset.id == self.to_asset.id {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+        if self.amount.parse::<BigUint>().unwrap_or_default() == BigUint::ZERO {
+            return Err(SwapperError::InputAmountError { min_amount: None });
+        }
+        Ok(chain)
+    }
+
+    pub fn orders_count(&self) -> Result<u32, SwapperError> {
+        let start_at = chrono::DateTime::from_timestamp(self.start_at as i64, 0).ok_or(SwapperError::InvalidRoute)?;
+        let mut count = 0;
+        while let Some(next) = self.frequency.occurrence(start_at, count) {
+            if next.timestamp() as u64 > self.end_at {
+                break;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Err(SwapperError::InvalidRoute);
+        }
+        Ok(count)
+    }
+
+    pub fn total_value(&self) -> Result<String, SwapperError> {
+        let amount = self.amount.parse::<BigUint>().map_err(|_| SwapperError::InputAmountError { min_amount: None })?;
+        Ok((amount * self.orders_count()?).to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurringOrder {
+    pub provider: SwapperProvider,
+    pub id: String,
+    pub wallet_address: String,
+    pub from_asset: AssetId,
+    pub to_asset: AssetId,
+    pub amount: String,
+    pub deposited_value: String,
+    pub used_value: String,
+    pub received_value: String,
+    pub interval_in_seconds: u64,
+    pub status: RecurringSwapStatus,
+    pub created_at: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::asset_constants::ETHEREUM_USDC_ASSET_ID;
+
+    #[test]
+    fn test_orders_count() {
+        let request = RecurringOrderRequest {
+            from_asset: SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+            to_asset: SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            wallet_address: "0x2".to_string(),
+            amount: "1000".to_string(),
+            frequency: RecurringSwapFrequency::Daily,
+            start_at: 1767225600,
+            end_at: 1767225600 + 6 * 86400,
+        };
+
+        assert_eq!(request.chain(), Ok(Chain::Ethereum));
+        assert_eq!(request.orders_count(), Ok(7));
+        assert_eq!(request.total_value(), Ok("7000".to_string()));
+        assert_eq!(
+            RecurringOrderRequest {
+                end_at: request.start_at - 1,
+                ..request.clone()
+            }
+            .orders_count(),
+            Err(SwapperError::InvalidRoute)
+        );
+    }
+}
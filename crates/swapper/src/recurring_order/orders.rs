@@ -0,0 +1,52 @@
+use super::{RecurringOrder, RecurringOrderRequest, RecurringOrderSwapper};
+use crate::{SwapperError, SwapperProvider, SwapperQuoteData, alien::RpcProvider, jupiter};
+use primitives::Chain;
+use std::{cmp::Reverse, sync::Arc};
+
+#[derive(Debug)]
+pub struct GemRecurringOrders {
+    pub providers: Vec<Box<dyn RecurringOrderSwapper>>,
+}
+
+impl GemRecurringOrders {
+    pub fn new(rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        let providers: Vec<Box<dyn RecurringOrderSwapper>> = vec![Box::new(jupiter::Jupiter::new(rpc_provider.clone()))];
+        Self { providers }
+    }
+
+    fn get_provider(&self, provider: SwapperProvider) -> Result<&dyn RecurringOrderSwapper, SwapperError> {
+        self.providers
+            .iter()
+            .find(|x| x.provider() == provider)
+            .map(|x| x.as_ref())
+            .ok_or(SwapperError::NoAvailableProvider)
+    }
+
+    fn get_providers_for_chain(&self, chain: Chain) -> impl Iterator<Item = &dyn RecurringOrderSwapper> {
+        self.providers.iter().filter(move |x| x.supported_chains().contains(&chain)).map(|x| x.as_ref())
+    }
+
+    pub fn supported_chains(&self) -> Vec<Chain> {
+        self.providers.iter().flat_map(|x| x.supported_chains()).collect()
+    }
+
+    pub async fn create_order(&self, request: &RecurringOrderRequest) -> Result<SwapperQuoteData, SwapperError> {
+        let chain = request.chain()?;
+        let provider = self.get_providers_for_chain(chain).next().ok_or(SwapperError::NotSupportedChain)?;
+        provider.create_order(request).await
+    }
+
+    pub async fn get_orders(&self, chain: Chain, wallet_address: &str) -> Result<Vec<RecurringOrder>, SwapperError> {
+        let results = futures::future::join_all(self.get_providers_for_chain(chain).map(|x| x.get_orders(chain, wallet_address))).await;
+        let mut orders = Vec::new();
+        for result in results {
+            orders.extend(result?);
+        }
+        orders.sort_by_key(|x| Reverse(x.created_at));
+        Ok(orders)
+    }
+
+    pub async fn cancel_order(&self, order: &RecurringOrder) -> Result<SwapperQuoteData, SwapperError> {
+        self.get_provider(order.provider)?.cancel_order(order).await
+    }
+}
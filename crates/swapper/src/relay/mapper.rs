@@ -52,6 +52,7 @@ mod tests {
         let quote_response = RelayQuoteResponse {
             steps: vec![Step::mock_transaction("swap", "0xrouter", "1000000000000000000", "0xabcdef")],
             details: QuoteDetails {
+                currency_in: None,
                 currency_out: CurrencyAmount { amount: "0".to_string() },
                 time_estimate: None,
                 swap_impact: None,
@@ -73,6 +74,7 @@ mod tests {
         let quote_response = RelayQuoteResponse {
             steps: vec![Step::mock_transaction("swap", "0xrouter", "0", "0xabcdef")],
             details: QuoteDetails {
+                currency_in: None,
                 currency_out: CurrencyAmount { amount: "0".to_string() },
                 time_estimate: None,
                 swap_impact: None,
@@ -130,6 +132,7 @@ mod tests {
         let quote_response = RelayQuoteResponse {
             steps: vec![Step::mock_empty("approve", "transaction")],
             details: QuoteDetails {
+                currency_in: None,
                 currency_out: CurrencyAmount { amount: "0".to_string() },
                 time_estimate: None,
                 swap_impact: None,
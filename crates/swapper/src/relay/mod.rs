@@ -7,6 +7,6 @@ mod provider;
 #[cfg(test)]
 mod testkit;
 
-const DEFAULT_SWAP_GAS_LIMIT: u64 = 150_000;
+pub(crate) const DEFAULT_SWAP_GAS_LIMIT: u64 = 150_000;
 
 pub use provider::Relay;
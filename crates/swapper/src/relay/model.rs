@@ -125,6 +125,7 @@ pub struct EvmStepData {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuoteDetails {
+    pub currency_in: Option<CurrencyAmount>,
     pub currency_out: CurrencyAmount,
     pub time_estimate: Option<f64>,
     pub swap_impact: Option<SwapImpact>,
@@ -13,8 +13,9 @@ use super::{
     model::{RelayAppFee, RelayQuoteRequest, RelayQuoteResponse, relay_trade_type},
 };
 use crate::{
-    FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, RpcClient, RpcProvider, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperProvider,
-    SwapperQuoteData, approval::check_approval_erc20, config::get_swap_api_url, cross_chain::VaultAddresses, fees::DEFAULT_REFERRER, fees::resolve_max_quote_value,
+    FetchQuoteData, ProviderData, ProviderType, Quote, QuoteRequest, Route, RpcClient, RpcProvider, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperMode,
+    SwapperProvider, SwapperQuoteData, approval::check_approval_erc20, config::get_swap_api_url, cross_chain::VaultAddresses, fees::DEFAULT_REFERRER,
+    fees::resolve_max_quote_value,
 };
 
 #[derive(Debug)]
@@ -62,6 +63,10 @@ where
         SUPPORTED_CHAINS.clone()
     }
 
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
         let from_chain = RelayChain::from_chain(&request.from_asset.chain()).ok_or(SwapperError::NotSupportedChain)?;
         let to_chain = RelayChain::from_chain(&request.to_asset.chain()).ok_or(SwapperError::NotSupportedChain)?;
@@ -91,6 +96,12 @@ where
 
         let response = self.client.get_quote(relay_request).await?;
 
+        // Exact output quotes return the input to deposit in `currencyIn`.
+        let from_value = match (request.mode, &response.details.currency_in) {
+            (SwapperMode::ExactOut, Some(currency_in)) => currency_in.amount.clone(),
+            (SwapperMode::ExactOut, None) => return Err(SwapperError::InvalidRoute),
+            (SwapperMode::ExactIn, _) => from_value,
+        };
         let to_value = response.details.currency_out.amount.clone();
         let eta_in_seconds = response.details.time_estimate_u32();
 
@@ -108,6 +119,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds,
+            fees: None,
+            price_impact: None,
+            composed: None,
         };
 
         Ok(quote)
@@ -0,0 +1,232 @@
+use std::cmp::Reverse;
+
+use alloy_primitives::U256;
+use num_bigint::BigUint;
+use primitives::{Asset, Chain, swap::SwapStatus};
+
+use crate::{
+    ComposedQuote, ProviderData, ProviderType, Quote, QuoteFees, QuoteRequest, SwapResult, SwapperError, SwapperMode, SwapperProvider, SwapperQuoteAsset,
+    fees::apply_slippage_in_bp, quote_fees,
+};
+
+pub(crate) const BRIDGE_PROVIDERS: [SwapperProvider; 3] = [SwapperProvider::Across, SwapperProvider::Relay, SwapperProvider::Mayan];
+
+pub(crate) fn bridge_asset(chain: Chain) -> SwapperQuoteAsset {
+    let asset = Asset::from_chain(chain);
+    SwapperQuoteAsset {
+        id: asset.id.to_string(),
+        symbol: asset.symbol,
+        decimals: asset.decimals as u32,
+    }
+}
+
+pub(crate) fn leg_request(request: &QuoteRequest, from_asset: &SwapperQuoteAsset, to_asset: &SwapperQuoteAsset, value: String, is_first: bool) -> QuoteRequest {
+    let to_chain = request.to_asset.chain();
+    let wallet_address = if from_asset.chain() == to_chain {
+        request.destination_address.clone()
+    } else {
+        request.wallet_address.clone()
+    };
+    let destination_address = if to_asset.chain() == to_chain {
+        request.destination_address.clone()
+    } else {
+        request.wallet_address.clone()
+    };
+    let mut options = request.options.clone();
+    options.preferred_providers = if from_asset.chain() == to_asset.chain() { vec![] } else { BRIDGE_PROVIDERS.to_vec() };
+    if !is_first {
+        options.fee = None;
+    }
+    QuoteRequest {
+        from_asset: from_asset.clone(),
+        to_asset: to_asset.clone(),
+        wallet_address,
+        destination_address,
+        value,
+        mode: SwapperMode::ExactIn,
+        options,
+    }
+}
+
+pub(crate) fn min_output(quote: &Quote) -> Result<String, SwapperError> {
+    let to_value = quote.to_value.parse::<U256>().map_err(|_| SwapperError::InvalidRoute)?;
+    Ok(apply_slippage_in_bp(&to_value, quote.data.slippage_bps).to_string())
+}
+
+pub(crate) fn compose(request: &QuoteRequest, legs: Vec<Quote>) -> Result<ComposedQuote, SwapperError> {
+    let last = legs.last().ok_or(SwapperError::NoQuoteAvailable)?;
+    let to_value = last.to_value.clone();
+    let to_amount = to_value.parse::<BigUint>().map_err(|_| SwapperError::InvalidRoute)?;
+    let eta_in_seconds = legs.iter().filter_map(|x| x.eta_in_seconds).reduce(|total, eta| total + eta);
+    let fee_value = quote_fees::composed_fee_value(&legs, &request.to_asset, &request.options.prices);
+    let net_value = fee_value.as_ref().map(|fee| if to_amount > *fee { &to_amount - fee } else { BigUint::ZERO });
+    Ok(ComposedQuote {
+        from_value: request.value.clone(),
+        to_value,
+        legs,
+        eta_in_seconds,
+        fee_value: fee_value.map(|x| x.to_string()),
+        net_value: net_value.map(|x| x.to_string()),
+    })
+}
+
+pub(crate) fn into_quote(request: &QuoteRequest, composed: ComposedQuote) -> Result<Quote, SwapperError> {
+    let first = composed.legs.first().ok_or(SwapperError::NoQuoteAvailable)?;
+    let data = ProviderData {
+        provider: ProviderType::new(SwapperProvider::Composed),
+        slippage_bps: first.data.slippage_bps,
+        routes: composed.legs.iter().flat_map(|leg| leg.data.routes.clone()).collect(),
+    };
+    let fees = first.fees.clone().map(|fees| QuoteFees {
+        fee_value: composed.fee_value.clone(),
+        net_value: composed.net_value.clone(),
+        ..fees
+    });
+    Ok(Quote {
+        from_value: composed.from_value.clone(),
+        to_value: composed.to_value.clone(),
+        data,
+        request: request.clone(),
+        eta_in_seconds: composed.eta_in_seconds,
+        fees,
+        price_impact: None,
+        composed: Some(composed),
+    })
+}
+
+pub(crate) fn best_quote(quotes: Vec<ComposedQuote>) -> Result<Option<ComposedQuote>, SwapperError> {
+    let keyed = quotes
+        .into_iter()
+        .map(|quote| {
+            let value = quote
+                .net_value
+                .as_ref()
+                .unwrap_or(&quote.to_value)
+                .parse::<U256>()
+                .map_err(|_| SwapperError::InvalidRoute)?;
+            Ok(((value, Reverse(quote.eta_in_seconds.unwrap_or(u32::MAX))), quote))
+        })
+        .collect::<Result<Vec<_>, SwapperError>>()?;
+    Ok(keyed.into_iter().max_by(|a, b| a.0.cmp(&b.0)).map(|(_, quote)| quote))
+}
+
+pub(crate) fn composed_status(legs_count: usize, results: &[SwapResult]) -> (SwapStatus, Option<u32>) {
+    if results.iter().any(|x| x.status == SwapStatus::Failed) {
+        return (SwapStatus::Failed, None);
+    }
+    if !results.iter().all(|x| x.status == SwapStatus::Completed) {
+        return (SwapStatus::Pending, None);
+    }
+    if results.len() < legs_count {
+        return (SwapStatus::Pending, Some(results.len() as u32));
+    }
+    (SwapStatus::Completed, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::{AssetId, asset_constants::ETHEREUM_USDC_ASSET_ID};
+
+    fn leg(provider: SwapperProvider, from: &SwapperQuoteAsset, to: &SwapperQuoteAsset, to_value: &str, eta: Option<u32>) -> Quote {
+        let mut quote = Quote::mock_with_provider(provider, to_value);
+        quote.request.from_asset = from.clone();
+        quote.request.to_asset = to.clone();
+        quote.eta_in_seconds = eta;
+        quote
+    }
+
+    #[test]
+    fn test_leg_request() {
+        let mut request = QuoteRequest::mock(Chain::Base, None);
+        request.to_asset = SwapperQuoteAsset::from(AssetId::from_token(Chain::Solana, "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN"));
+        request.destination_address = "solana".to_string();
+        let base = bridge_asset(Chain::Base);
+        let solana = bridge_asset(Chain::Solana);
+
+        let bridge = leg_request(&request, &base, &solana, "100".to_string(), false);
+
+        assert_eq!(bridge.wallet_address, "address");
+        assert_eq!(bridge.destination_address, "solana");
+        assert_eq!(bridge.options.preferred_providers, BRIDGE_PROVIDERS.to_vec());
+        assert_eq!(bridge.options.fee, None);
+
+        let destination = leg_request(&request, &solana, &request.to_asset, "100".to_string(), false);
+
+        assert_eq!(destination.wallet_address, "solana");
+        assert_eq!(destination.destination_address, "solana");
+        assert!(destination.options.preferred_providers.is_empty());
+        assert_eq!(solana.decimals, 9);
+    }
+
+    #[test]
+    fn test_compose() {
+        let usdc = SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone());
+        let eth = bridge_asset(Chain::Ethereum);
+        let sol = bridge_asset(Chain::Solana);
+        let mut request = QuoteRequest::mock(Chain::Ethereum, None);
+        request.from_asset = usdc.clone();
+        request.to_asset = sol.clone();
+        let mut source = leg(SwapperProvider::UniswapV3, &usdc, &eth, "1000000000000000000", None);
+        source.fees = Some(QuoteFees {
+            network_fee: "200".to_string(),
+            approval_fee: "100".to_string(),
+            destination_fee: "0".to_string(),
+            fee_value: None,
+            net_value: None,
+        });
+        let bridge = leg(SwapperProvider::Mayan, &eth, &sol, "25000000000", Some(60));
+
+        let quote = compose(&request, vec![source.clone(), bridge.clone()]).unwrap();
+
+        assert_eq!(quote.from_value, "1000000");
+        assert_eq!(quote.to_value, "25000000000");
+        assert_eq!(quote.eta_in_seconds, Some(60));
+        assert_eq!(quote.fee_value, None);
+        assert_eq!(min_output(&source).unwrap(), "995000000000000000");
+
+        let faster = ComposedQuote {
+            eta_in_seconds: Some(30),
+            ..quote.clone()
+        };
+        let better = ComposedQuote {
+            to_value: "26000000000".to_string(),
+            ..quote.clone()
+        };
+
+        assert_eq!(best_quote(vec![quote.clone(), faster.clone()]).unwrap(), Some(faster));
+        assert_eq!(best_quote(vec![quote.clone(), better.clone()]).unwrap(), Some(better));
+
+        let invalid = ComposedQuote {
+            to_value: "invalid".to_string(),
+            ..quote.clone()
+        };
+
+        assert_eq!(best_quote(vec![quote.clone(), invalid]), Err(SwapperError::InvalidRoute));
+        assert_eq!(
+            compose(&request, vec![leg(SwapperProvider::Mayan, &eth, &sol, "invalid", None)]),
+            Err(SwapperError::InvalidRoute)
+        );
+
+        let composed = into_quote(&request, quote.clone()).unwrap();
+
+        assert_eq!(composed.data.provider.id, SwapperProvider::Composed);
+        assert_eq!(composed.to_value, "25000000000");
+        assert_eq!(composed.fees.unwrap().network_fee, "200");
+        assert_eq!(composed.composed, Some(quote));
+    }
+
+    #[test]
+    fn test_composed_status() {
+        let result = |status| SwapResult { status, metadata: None };
+
+        assert_eq!(composed_status(2, &[]), (SwapStatus::Pending, Some(0)));
+        assert_eq!(composed_status(2, &[result(SwapStatus::Pending)]), (SwapStatus::Pending, None));
+        assert_eq!(composed_status(2, &[result(SwapStatus::Completed)]), (SwapStatus::Pending, Some(1)));
+        assert_eq!(
+            composed_status(2, &[result(SwapStatus::Completed), result(SwapStatus::Completed)]),
+            (SwapStatus::Completed, None)
+        );
+        assert_eq!(composed_status(2, &[result(SwapStatus::Completed), result(SwapStatus::Failed)]), (SwapStatus::Failed, None));
+    }
+}
@@ -129,6 +129,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds: Some(response.route.estimate.estimated_route_duration),
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
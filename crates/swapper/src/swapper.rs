@@ -1,22 +1,43 @@
+use crate::fee_source::{AllowanceRequest, FeeSource, RpcFeeSource};
+use crate::limit_order::{GemLimitOrders, LimitOrderProvider};
 use crate::{
-    AssetList, FetchQuoteData, Permit2ApprovalData, ProviderType, Quote, QuoteRequest, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperProvider, SwapperProviderMode,
-    SwapperQuoteData, across, alien::RpcProvider, chainflip, cross_chain::VaultAddresses, fees::DEFAULT_STABLE_SWAP_REFERRAL_BPS, fees::is_stablecoin_symbol, hyperliquid, jupiter,
-    near_intents, proxy::provider_factory, relay, squid, thorchain, uniswap,
+    AssetList, CctpAttestationStatus, ComposedQuote, ComposedSwapResult, FetchQuoteData, Permit2ApprovalData, ProviderHealth, ProviderQuoteError, ProviderType, Quote,
+    QuoteAssetPrice, QuoteRequest, SwapResult, Swapper, SwapperChainAsset, SwapperError, SwapperMode, SwapperProvider, SwapperProviderMode, SwapperQuoteData, across,
+    alien::RpcProvider, cctp, chainflip, config::get_swap_config, cow_swap, cross_chain::VaultAddresses, exact_out, fees::DEFAULT_STABLE_SWAP_REFERRAL_BPS,
+    fees::is_stablecoin_symbol, hyperliquid, jupiter, near_intents, price_impact, provider_health::ProviderHealthTracker, proxy::provider_factory, quote_fees,
+    quote_fees::NetworkFeeRate, relay, route_composer, squid, thorchain, timeout, uniswap,
 };
-use num_bigint::BigInt;
+use alloy_primitives::U256;
+use futures::{
+    FutureExt, Stream, StreamExt,
+    stream::{self, FuturesUnordered},
+};
+use num_bigint::BigUint;
 use num_traits::ToPrimitive;
-use primitives::{AssetId, Chain, EVMChain};
+use primitives::{AssetId, Chain, EVMChain, swap::SwapStatus};
 use std::{
     borrow::Cow,
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     fmt::Debug,
+    future::Future,
+    str::FromStr,
     sync::Arc,
+    time::{Duration, Instant},
 };
 
 #[derive(Debug)]
 pub struct GemSwapper {
     pub rpc_provider: Arc<dyn RpcProvider>,
     pub swappers: Vec<Box<dyn Swapper>>,
+    limit_orders: GemLimitOrders,
+    health: ProviderHealthTracker,
+    fee_source: Arc<dyn FeeSource>,
+}
+
+enum ApprovalCheck {
+    NotRequired,
+    Required,
+    Allowance(AllowanceRequest, U256),
 }
 
 impl GemSwapper {
@@ -48,6 +69,31 @@ impl GemSwapper {
         preferred_providers.contains(provider)
     }
 
+    fn get_available_swappers(&self, request: &QuoteRequest) -> Result<Vec<&dyn Swapper>, SwapperError> {
+        let provider_ids: BTreeSet<_> = self.get_providers_for_request(request)?.into_iter().map(|p| p.id).collect();
+        let swappers: Vec<&dyn Swapper> = self
+            .swappers
+            .iter()
+            .filter(|x| provider_ids.contains(&x.provider().id) && self.health.allow_request(&x.provider().id))
+            .map(|x| &**x)
+            .collect();
+        if swappers.is_empty() {
+            return Err(SwapperError::NoAvailableProvider);
+        }
+        Ok(swappers)
+    }
+
+    async fn get_provider_quote(&self, swapper: &dyn Swapper, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        self.track(swapper.provider().id, exact_out::get_quote(swapper, request)).await
+    }
+
+    async fn track<T>(&self, provider: SwapperProvider, future: impl Future<Output = Result<T, SwapperError>>) -> Result<T, SwapperError> {
+        let started_at = Instant::now();
+        let result = future.await;
+        self.health.record(provider, started_at.elapsed(), &result);
+        result
+    }
+
     fn get_swapper_by_provider(&self, provider: &SwapperProvider) -> Result<&dyn Swapper, SwapperError> {
         self.swappers
             .iter()
@@ -96,7 +142,14 @@ impl GemSwapper {
             .collect();
 
         if input_errors.is_empty() {
-            return None;
+            return errors
+                .iter()
+                .filter_map(|err| match err {
+                    SwapperError::PriceImpactTooHigh { price_impact } => Some(*price_impact),
+                    _ => None,
+                })
+                .max_by(f64::total_cmp)
+                .map(|price_impact| SwapperError::PriceImpactTooHigh { price_impact });
         }
 
         input_errors
@@ -110,14 +163,106 @@ impl GemSwapper {
             .or(Some(SwapperError::InputAmountError { min_amount: None }))
     }
 
-    fn sort_quotes_by_output_amount(quotes: &mut [Quote]) {
-        quotes.sort_by(Self::compare_quotes_by_output_amount);
+    async fn get_destination_gas_price(&self, request: &QuoteRequest) -> Option<BigUint> {
+        let to_chain = request.to_asset.chain();
+        if to_chain == request.from_asset.chain() {
+            return None;
+        }
+        EVMChain::from_chain(to_chain)?;
+        match self.fee_source.get_gas_price(to_chain).await {
+            Ok(gas_price) => Some(gas_price),
+            Err(error) => {
+                tracing::warn!(chain = to_chain.as_ref(), %error, "failed to fetch destination gas price");
+                None
+            }
+        }
+    }
+
+    fn approval_check(&self, quote: &Quote) -> ApprovalCheck {
+        if !quote_fees::requires_approval(quote) {
+            return ApprovalCheck::NotRequired;
+        }
+        let Some(spender) = self.get_swapper_by_provider(&quote.data.provider.id).ok().and_then(|x| x.get_approval_spender(quote)) else {
+            return ApprovalCheck::Required;
+        };
+        let (Some(token), Ok(amount)) = (quote.request.from_asset.asset_id().token_id, U256::from_str(&quote.from_value)) else {
+            return ApprovalCheck::Required;
+        };
+        ApprovalCheck::Allowance(AllowanceRequest { token, spender }, amount)
     }
 
-    fn compare_quotes_by_output_amount(a: &Quote, b: &Quote) -> std::cmp::Ordering {
-        let a_amount = a.to_value.parse::<BigInt>().unwrap_or_default();
-        let b_amount = b.to_value.parse::<BigInt>().unwrap_or_default();
-        b_amount.cmp(&a_amount)
+    async fn requires_approvals(&self, request: &QuoteRequest, quotes: &[Quote]) -> Result<Vec<bool>, SwapperError> {
+        let checks: Vec<ApprovalCheck> = quotes.iter().map(|quote| self.approval_check(quote)).collect();
+        let requests: Vec<AllowanceRequest> = checks
+            .iter()
+            .filter_map(|check| match check {
+                ApprovalCheck::Allowance(request, _) => Some(request.clone()),
+                ApprovalCheck::NotRequired | ApprovalCheck::Required => None,
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let allowances: BTreeMap<AllowanceRequest, U256> = if requests.is_empty() {
+            BTreeMap::new()
+        } else {
+            let allowances = self.fee_source.get_allowances(request.from_asset.chain(), &request.wallet_address, &requests).await?;
+            requests.into_iter().zip(allowances).collect()
+        };
+        Ok(checks
+            .into_iter()
+            .map(|check| match check {
+                ApprovalCheck::NotRequired => false,
+                ApprovalCheck::Required => true,
+                ApprovalCheck::Allowance(request, amount) => allowances.get(&request).is_none_or(|allowance| *allowance < amount),
+            })
+            .collect())
+    }
+
+    async fn get_network_fee_rate(&self, chain: Chain) -> Option<NetworkFeeRate> {
+        let result = match EVMChain::from_chain(chain) {
+            Some(_) => self.fee_source.get_gas_price(chain).await.map(NetworkFeeRate::GasPrice),
+            None => self.fee_source.get_network_fee(chain).await.map(NetworkFeeRate::Transaction),
+        };
+        match result {
+            Ok(fee_rate) => Some(fee_rate),
+            Err(SwapperError::NotSupportedChain) => None,
+            Err(error) => {
+                tracing::warn!(chain = chain.as_ref(), %error, "failed to fetch network fee");
+                None
+            }
+        }
+    }
+
+    async fn estimate_quote_fees(&self, request: &QuoteRequest, quotes: &mut [Quote], fee_rate: &NetworkFeeRate, destination_gas_price: Option<&BigUint>) {
+        let approvals = match self.requires_approvals(request, quotes).await {
+            Ok(approvals) => approvals,
+            Err(error) => {
+                tracing::warn!(chain = request.from_asset.chain().as_ref(), %error, "failed to fetch allowances");
+                return;
+            }
+        };
+        for (quote, requires_approval) in quotes.iter_mut().zip(approvals) {
+            match quote_fees::estimate_quote_fees(quote, fee_rate, destination_gas_price, requires_approval) {
+                Ok(fees) => quote.fees = Some(fees),
+                Err(error) => tracing::warn!(provider = quote.data.provider.id.as_ref(), %error, "failed to estimate quote fees"),
+            }
+        }
+    }
+
+    async fn get_reference_prices(&self, request: &QuoteRequest) -> Vec<QuoteAssetPrice> {
+        let mut prices = request.options.prices.clone();
+        let missing: Vec<AssetId> = [&request.from_asset, &request.to_asset]
+            .into_iter()
+            .filter_map(|asset| AssetId::new(&asset.id))
+            .filter(|asset_id| price_impact::is_eth(asset_id) && !prices.iter().any(|x| x.asset_id == *asset_id))
+            .collect();
+        if missing.is_empty() {
+            return prices;
+        }
+        if let Ok(price) = self.fee_source.get_eth_usd_price().await {
+            prices.extend(missing.into_iter().map(|asset_id| QuoteAssetPrice { asset_id, price }));
+        }
+        prices
     }
 }
 
@@ -144,9 +289,27 @@ impl GemSwapper {
             Box::new(provider_factory::new_orca(rpc_provider.clone())),
             Box::new(squid::Squid::new(rpc_provider.clone())),
             uniswap::default::boxed_aerodrome(rpc_provider.clone()),
+            Box::new(cow_swap::CowSwap::new(rpc_provider.clone())),
+            uniswap::default::boxed_uniswap_v2(rpc_provider.clone()),
+            uniswap::default::boxed_pancakeswap_v2(rpc_provider.clone()),
+            uniswap::default::boxed_sushiswap_v2(rpc_provider.clone()),
+            uniswap::default::boxed_quickswap_v2(rpc_provider.clone()),
+            uniswap::default::boxed_camelot_v2(rpc_provider.clone()),
+            uniswap::default::boxed_spookyswap_v2(rpc_provider.clone()),
+            Box::new(cctp::Cctp::new(rpc_provider.clone())),
         ];
 
-        Self { rpc_provider, swappers }
+        Self {
+            limit_orders: GemLimitOrders::new(rpc_provider.clone()),
+            fee_source: Arc::new(RpcFeeSource::new(rpc_provider.clone())),
+            rpc_provider,
+            swappers,
+            health: ProviderHealthTracker::default(),
+        }
+    }
+
+    pub fn providers_health(&self) -> Vec<ProviderHealth> {
+        self.health.health()
     }
 
     pub fn supported_chains(&self) -> Vec<Chain> {
@@ -198,19 +361,42 @@ impl GemSwapper {
     }
 
     pub async fn get_quote(&self, request: &QuoteRequest) -> Result<Vec<Quote>, SwapperError> {
-        let provider_ids: BTreeSet<_> = self.get_providers_for_request(request)?.into_iter().map(|p| p.id).collect();
-        let providers = self.swappers.iter().filter(|x| provider_ids.contains(&x.provider().id)).collect::<Vec<_>>();
+        match self.get_provider_quotes(request).await {
+            Err(error @ (SwapperError::NoQuoteAvailable | SwapperError::NoAvailableProvider)) if request.from_asset.chain() != request.to_asset.chain() => {
+                let Ok(quote) = self.get_composed_route_quote(request).await else {
+                    return Err(error);
+                };
+                Ok(vec![quote])
+            }
+            result => result,
+        }
+    }
+
+    async fn get_composed_route_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        let composed = self.get_composed_quote(request).await?;
+        let prices = self.get_reference_prices(request).await;
+        price_impact::apply(route_composer::into_quote(request, composed)?, &prices, &get_swap_config())
+    }
+
+    async fn get_provider_quotes(&self, request: &QuoteRequest) -> Result<Vec<Quote>, SwapperError> {
+        let providers = self.get_available_swappers(request)?;
 
         let request_for_quote = Self::transform_request(request);
-        let quotes_futures = providers.into_iter().map(|x| x.get_quote(request_for_quote.as_ref()));
+        let quotes_futures = providers.into_iter().map(|x| self.get_provider_quote(x, request_for_quote.as_ref()));
 
-        let quote_results = futures::future::join_all(quotes_futures).await;
+        let (quote_results, fee_rate, destination_gas_price, prices) = futures::join!(
+            futures::future::join_all(quotes_futures),
+            self.get_network_fee_rate(request.from_asset.chain()),
+            self.get_destination_gas_price(request),
+            self.get_reference_prices(request)
+        );
+        let config = get_swap_config();
 
         let mut quotes = Vec::new();
         let mut errors = Vec::new();
 
         for result in quote_results {
-            match result {
+            match result.and_then(|quote| price_impact::apply(quote, &prices, &config)) {
                 Ok(quote) => quotes.push(quote),
                 Err(err) => {
                     errors.push(err);
@@ -225,23 +411,180 @@ impl GemSwapper {
             return Err(SwapperError::NoQuoteAvailable);
         }
 
-        Self::sort_quotes_by_output_amount(&mut quotes);
-
+        if let Some(fee_rate) = &fee_rate {
+            self.estimate_quote_fees(request_for_quote.as_ref(), &mut quotes, fee_rate, destination_gas_price.as_ref())
+                .await;
+        }
+        let quotes = quote_fees::sort_quotes(request.mode, quotes);
+        if quotes.is_empty() {
+            return Err(SwapperError::NoQuoteAvailable);
+        }
         Ok(quotes)
     }
 
+    pub fn get_quote_stream<'a>(&'a self, request: &QuoteRequest, timeout: Duration) -> Result<impl Stream<Item = Result<Quote, ProviderQuoteError>> + 'a, SwapperError> {
+        let is_cross_chain = request.from_asset.chain() != request.to_asset.chain();
+        let providers = match self.get_available_swappers(request) {
+            Err(SwapperError::NoAvailableProvider) if is_cross_chain => vec![],
+            result => result?,
+        };
+        let composed_request = request.clone();
+        let request = Arc::new(Self::transform_request(request).into_owned());
+        let fee_rate = self.get_network_fee_rate(request.from_asset.chain()).shared();
+        let destination_request = request.clone();
+        let destination_gas_price = async move { self.get_destination_gas_price(&destination_request).await }.shared();
+        let prices_request = request.clone();
+        let prices = async move { self.get_reference_prices(&prices_request).await }.shared();
+
+        let futures = providers.into_iter().map(move |swapper| {
+            let request = request.clone();
+            let fee_rate = fee_rate.clone();
+            let destination_gas_price = destination_gas_price.clone();
+            let prices = prices.clone();
+            async move {
+                let provider = swapper.provider().id;
+                let quote_timeout = swapper.quote_timeout().min(timeout);
+                let started_at = Instant::now();
+                let quote = async {
+                    let result = timeout::with_timeout(exact_out::get_quote(swapper, &request), quote_timeout)
+                        .await
+                        .unwrap_or(Err(SwapperError::Timeout));
+                    self.health.record(provider, started_at.elapsed(), &result);
+                    result
+                };
+                let enrichment = timeout::with_timeout(async { futures::join!(fee_rate, destination_gas_price, prices) }, quote_timeout).map(Ok);
+                let (mut quote, enrichment) = futures::try_join!(quote, enrichment).map_err(|error| ProviderQuoteError { provider, error })?;
+                let Some((fee_rate, destination_gas_price, prices)) = enrichment else {
+                    return Ok(quote);
+                };
+                if let Some(fee_rate) = fee_rate {
+                    let approvals = self.requires_approvals(&request, std::slice::from_ref(&quote));
+                    match timeout::with_timeout(approvals, quote_timeout.saturating_sub(started_at.elapsed())).await {
+                        Some(Ok(approvals)) => {
+                            if let Some(requires_approval) = approvals.first() {
+                                let fees = quote_fees::estimate_quote_fees(&quote, &fee_rate, destination_gas_price.as_ref(), *requires_approval)
+                                    .map_err(|error| ProviderQuoteError { provider, error })?;
+                                quote.fees = Some(fees);
+                            }
+                        }
+                        Some(Err(error)) => tracing::warn!(provider = provider.as_ref(), %error, "failed to fetch allowances"),
+                        None => tracing::warn!(provider = provider.as_ref(), "allowance check timed out"),
+                    }
+                }
+                price_impact::apply(quote, &prices, &get_swap_config()).map_err(|error| ProviderQuoteError { provider, error })
+            }
+        });
+
+        let quotes = futures.collect::<FuturesUnordered<_>>();
+        Ok(stream::unfold(
+            (quotes, Vec::new(), false, Some(composed_request)),
+            move |(mut quotes, mut errors, mut has_quote, composed_request)| async move {
+                if let Some(result) = quotes.next().await {
+                    match &result {
+                        Ok(_) => has_quote = true,
+                        Err(error) => errors.push(error.error.clone()),
+                    }
+                    return Some((result, (quotes, errors, has_quote, composed_request)));
+                }
+                let composed_request = composed_request?;
+                if has_quote || !is_cross_chain || Self::prioritized_error(&errors).is_some() {
+                    return None;
+                }
+                let result = timeout::with_timeout(self.get_composed_route_quote(&composed_request), timeout)
+                    .await
+                    .unwrap_or(Err(SwapperError::Timeout))
+                    .map_err(|error| ProviderQuoteError {
+                        provider: SwapperProvider::Composed,
+                        error,
+                    });
+                Some((result, (quotes, errors, has_quote, None)))
+            },
+        ))
+    }
+
+    pub async fn get_composed_quote(&self, request: &QuoteRequest) -> Result<ComposedQuote, SwapperError> {
+        let from_chain = request.from_asset.chain();
+        let to_chain = request.to_asset.chain();
+        if from_chain == to_chain {
+            return Err(SwapperError::NotSupportedChain);
+        }
+        if request.mode == SwapperMode::ExactOut {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+
+        let source_asset = route_composer::bridge_asset(from_chain);
+        let destination_asset = route_composer::bridge_asset(to_chain);
+        let source_leg = if request.from_asset.id == source_asset.id {
+            None
+        } else {
+            let leg_request = route_composer::leg_request(request, &request.from_asset, &source_asset, request.value.clone(), true);
+            Some(self.get_best_quote(&leg_request).await?)
+        };
+        let bridge_value = match &source_leg {
+            Some(quote) => route_composer::min_output(quote)?,
+            None => request.value.clone(),
+        };
+        let is_first = source_leg.is_none();
+
+        let direct = async {
+            let leg_request = route_composer::leg_request(request, &source_asset, &request.to_asset, bridge_value.clone(), is_first);
+            Ok::<_, SwapperError>(vec![self.get_best_quote(&leg_request).await?])
+        };
+        let via_native = async {
+            if request.to_asset.id == destination_asset.id {
+                return Err(SwapperError::NoQuoteAvailable);
+            }
+            let leg_request = route_composer::leg_request(request, &source_asset, &destination_asset, bridge_value.clone(), is_first);
+            let bridge = self.get_best_quote(&leg_request).await?;
+            let leg_request = route_composer::leg_request(request, &destination_asset, &request.to_asset, route_composer::min_output(&bridge)?, false);
+            let destination = self.get_best_quote(&leg_request).await?;
+            Ok(vec![bridge, destination])
+        };
+        let (direct, via_native) = futures::join!(direct, via_native);
+
+        let mut errors = Vec::new();
+        let mut quotes = Vec::new();
+        for result in [direct, via_native] {
+            match result {
+                Ok(legs) => quotes.push(route_composer::compose(request, source_leg.iter().cloned().chain(legs).collect())?),
+                Err(error) => errors.push(error),
+            }
+        }
+        route_composer::best_quote(quotes)?.ok_or_else(|| Self::prioritized_error(&errors).unwrap_or(SwapperError::NoQuoteAvailable))
+    }
+
+    pub async fn get_composed_swap_result(&self, quote: &ComposedQuote, transaction_hashes: &[String]) -> Result<ComposedSwapResult, SwapperError> {
+        let mut legs = Vec::new();
+        for (leg, transaction_hash) in quote.legs.iter().zip(transaction_hashes) {
+            let result = self.get_swap_result(leg.request.from_asset.chain(), leg.data.provider.id, transaction_hash).await?;
+            let is_completed = result.status == SwapStatus::Completed;
+            legs.push(result);
+            if !is_completed {
+                break;
+            }
+        }
+        let (status, next_leg) = route_composer::composed_status(quote.legs.len(), &legs);
+        Ok(ComposedSwapResult { status, legs, next_leg })
+    }
+
+    async fn get_best_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        self.get_provider_quotes(request).await?.into_iter().next().ok_or(SwapperError::NoQuoteAvailable)
+    }
+
     pub async fn get_quote_by_provider(&self, provider: SwapperProvider, request: QuoteRequest) -> Result<Quote, SwapperError> {
         let provider = self.get_swapper_by_provider(&provider)?;
         let request_for_quote = Self::transform_request(&request);
-        provider.get_quote(request_for_quote.as_ref()).await
+        self.get_provider_quote(provider, request_for_quote.as_ref()).await
     }
 
     pub async fn get_permit2_for_quote(&self, quote: &Quote) -> Result<Option<Permit2ApprovalData>, SwapperError> {
+        let quote = Self::executed_quote(quote)?;
         let provider = self.get_swapper_by_provider(&quote.data.provider.id)?;
         provider.get_permit2_for_quote(quote).await
     }
 
     pub async fn get_quote_data(&self, quote: &Quote, data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError> {
+        let quote = Self::executed_quote(quote)?;
         let provider = self.get_swapper_by_provider(&quote.data.provider.id)?;
         let mut quote_data = provider.get_quote_data(quote, data).await?;
         if let Some(gas_limit) = quote_data.gas_limit.take() {
@@ -250,18 +593,43 @@ impl GemSwapper {
         Ok(quote_data)
     }
 
+    fn executed_quote(quote: &Quote) -> Result<&Quote, SwapperError> {
+        match &quote.composed {
+            Some(composed) => composed.legs.first().ok_or(SwapperError::InvalidRoute),
+            None => Ok(quote),
+        }
+    }
+
+    pub async fn submit_order(&self, quote: &Quote, typed_data: &str, signature: &str) -> Result<String, SwapperError> {
+        self.get_swapper_by_provider(&quote.data.provider.id)?.submit_order(quote, typed_data, signature).await
+    }
+
     pub async fn get_swap_result(&self, chain: Chain, provider: SwapperProvider, transaction_hash: &str) -> Result<SwapResult, SwapperError> {
+        if let Some(limit_order_provider) = LimitOrderProvider::from_swap_provider(provider) {
+            return self.limit_orders.get_swap_result_by_id(limit_order_provider, chain, transaction_hash).await;
+        }
         self.get_swapper_by_provider(&provider)?.get_swap_result(chain, transaction_hash).await
     }
 
     pub async fn get_vault_addresses(&self, provider: &SwapperProvider, from_timestamp: Option<u64>) -> Result<VaultAddresses, SwapperError> {
         self.get_swapper_by_provider(provider)?.get_vault_addresses(from_timestamp).await
     }
+
+    pub async fn get_cctp_attestation_status(&self, chain: Chain, transaction_hash: &str) -> Result<CctpAttestationStatus, SwapperError> {
+        self.get_swapper_by_provider(&SwapperProvider::Cctp)?.get_attestation_status(chain, transaction_hash).await
+    }
+
+    pub async fn get_cctp_mint_data(&self, chain: Chain, transaction_hash: &str, wallet_address: &str) -> Result<SwapperQuoteData, SwapperError> {
+        self.get_swapper_by_provider(&SwapperProvider::Cctp)?
+            .get_mint_data(chain, transaction_hash, wallet_address)
+            .await
+    }
 }
 
 #[cfg(all(test, feature = "reqwest_provider"))]
 mod tests {
 
+    use futures::StreamExt;
     use std::{borrow::Cow, collections::BTreeSet, sync::Arc, vec};
 
     use primitives::{
@@ -271,10 +639,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        Options, SwapperChainAsset, SwapperMode, SwapperProvider, SwapperQuoteAsset, SwapperSlippage, SwapperSlippageMode,
+        Options, ProviderHealthState, QuoteStreamSummary, SwapperChainAsset, SwapperMode, SwapperProvider, SwapperQuoteAsset, SwapperSlippage, SwapperSlippageMode,
         alien::reqwest_provider::NativeProvider,
         fees::{DEFAULT_STABLE_SWAP_REFERRAL_BPS, DEFAULT_SWAP_FEE_BPS, ReferralFees},
-        testkit::{MockSwapper, mock_quote},
+        testkit::{MockFeeSource, MockSwapper, mock_quote},
         uniswap::default::{new_pancakeswap, new_uniswap_v3},
     };
 
@@ -302,6 +670,7 @@ mod tests {
                 fee,
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         }
     }
@@ -443,6 +812,38 @@ mod tests {
         assert!(matches!(GemSwapper::transform_request(&stable_without_fees), Cow::Borrowed(_)));
     }
 
+    #[test]
+    fn test_prioritized_error_price_impact() {
+        let errors = vec![
+            SwapperError::NoQuoteAvailable,
+            SwapperError::PriceImpactTooHigh { price_impact: -80.0 },
+            SwapperError::PriceImpactTooHigh { price_impact: -60.0 },
+        ];
+
+        assert_eq!(GemSwapper::prioritized_error(&errors), Some(SwapperError::PriceImpactTooHigh { price_impact: -60.0 }));
+        assert_eq!(GemSwapper::prioritized_error(&[SwapperError::NoQuoteAvailable]), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_cctp_attestation_status_uses_registered_provider() {
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || Err(SwapperError::NoQuoteAvailable)))],
+        };
+
+        assert_eq!(
+            gem_swapper.get_cctp_attestation_status(Chain::Ethereum, "0x1").await,
+            Err(SwapperError::NoAvailableProvider)
+        );
+        assert_eq!(
+            gem_swapper.get_cctp_mint_data(Chain::Ethereum, "0x1", "0x2").await.err(),
+            Some(SwapperError::NoAvailableProvider)
+        );
+    }
+
     #[tokio::test]
     async fn test_fetch_quote_input_amount_error() {
         let request = mock_quote(
@@ -452,6 +853,9 @@ mod tests {
 
         let gem_swapper = GemSwapper {
             rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
             swappers: vec![
                 Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || Err(SwapperError::InputAmountError { min_amount: None }))),
                 Box::new(MockSwapper::new(SwapperProvider::PancakeswapV3, || {
@@ -464,6 +868,9 @@ mod tests {
 
         let gem_swapper = GemSwapper {
             rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
             swappers: vec![
                 Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
                     Err(SwapperError::InputAmountError {
@@ -490,15 +897,315 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_quote_stream() {
+        let request = mock_quote(
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+        );
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "100"))
+                })),
+                Box::new(MockSwapper::new(SwapperProvider::PancakeswapV3, || Err(SwapperError::NoQuoteAvailable))),
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV4, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV4, "101"))
+                })),
+            ],
+        };
+
+        let results: Vec<_> = gem_swapper.get_quote_stream(&request, Duration::from_secs(10)).unwrap().collect().await;
+        let (quotes, errors): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+        let summary = QuoteStreamSummary::new(
+            request.mode,
+            quotes.into_iter().map(Result::unwrap).collect(),
+            errors.into_iter().map(Result::unwrap_err).collect(),
+        );
+
+        assert_eq!(
+            summary.quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::UniswapV4, SwapperProvider::UniswapV3]
+        );
+        assert_eq!(
+            summary.errors,
+            vec![ProviderQuoteError {
+                provider: SwapperProvider::PancakeswapV3,
+                error: SwapperError::NoQuoteAvailable,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_skips_unavailable_provider() {
+        let request = mock_quote(
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+        );
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Err(SwapperError::ComputeQuoteError("HTTP error: status 503".into()))
+                })),
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV4, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV4, "101"))
+                })),
+            ],
+        };
+
+        for _ in 0..5 {
+            gem_swapper.get_quote(&request).await.unwrap();
+        }
+        let providers = gem_swapper.get_available_swappers(&request).unwrap();
+
+        assert_eq!(providers.iter().map(|x| x.provider().id).collect::<Vec<_>>(), vec![SwapperProvider::UniswapV4]);
+        assert_eq!(
+            gem_swapper.providers_health().iter().map(|x| (x.provider, x.state)).collect::<Vec<_>>(),
+            vec![
+                (SwapperProvider::UniswapV3, ProviderHealthState::Unavailable),
+                (SwapperProvider::UniswapV4, ProviderHealthState::Healthy),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_estimates_fees() {
+        let request = mock_quote(
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+        );
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource {
+                gas_price: Some(1_000_000_000),
+                network_fee: None,
+                eth_usd_price: None,
+            }),
+            swappers: vec![Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "100"))
+            }))],
+        };
+
+        let quotes = gem_swapper.get_quote(&request).await.unwrap();
+        let fees = quotes[0].fees.as_ref().unwrap();
+
+        assert_eq!(fees.network_fee, "500000000000000");
+        assert_eq!(fees.approval_fee, "0");
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_estimates_network_fee() {
+        let usdc = AssetId::from_token(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let request = mock_quote(SwapperQuoteAsset::from(usdc), SwapperQuoteAsset::from(AssetId::from_chain(Chain::Solana)));
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource {
+                gas_price: None,
+                network_fee: Some(5_000),
+                eth_usd_price: None,
+            }),
+            swappers: vec![Box::new(
+                MockSwapper::new(SwapperProvider::Jupiter, || {
+                    let mut quote = Quote::mock_with_provider(SwapperProvider::Jupiter, "1000000000");
+                    quote.request = QuoteRequest::mock(Chain::Solana, Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"));
+                    Ok(quote)
+                })
+                .with_supported_assets(vec![SwapperChainAsset::All(Chain::Solana)]),
+            )],
+        };
+
+        let quotes = gem_swapper.get_quote(&request).await.unwrap();
+        let fees = quotes[0].fees.as_ref().unwrap();
+
+        assert_eq!(fees.network_fee, "5000");
+        assert_eq!(fees.approval_fee, "0");
+        assert_eq!(fees.net_value.as_deref(), Some("999995000"));
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_drops_invalid_quote() {
+        let request = mock_quote(
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Ethereum)),
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+        );
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource {
+                gas_price: Some(1_000_000_000),
+                network_fee: None,
+                eth_usd_price: None,
+            }),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "invalid"))
+                })),
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV4, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV4, "100"))
+                })),
+            ],
+        };
+
+        let quotes = gem_swapper.get_quote(&request).await.unwrap();
+
+        assert_eq!(quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(), vec![SwapperProvider::UniswapV4]);
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_falls_back_to_composed_quote() {
+        let mut request = mock_quote(
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Solana)),
+        );
+        request.options.preferred_providers = vec![SwapperProvider::UniswapV3];
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "1000000000000000000"))
+                })),
+                Box::new(
+                    MockSwapper::new(SwapperProvider::Mayan, || Ok(Quote::mock_with_provider(SwapperProvider::Mayan, "25000000000")))
+                        .with_supported_assets(vec![SwapperChainAsset::All(Chain::Ethereum), SwapperChainAsset::All(Chain::Solana)]),
+                ),
+            ],
+        };
+
+        assert_eq!(gem_swapper.get_provider_quotes(&request).await, Err(SwapperError::NoAvailableProvider));
+
+        let quotes = gem_swapper.get_quote(&request).await.unwrap();
+        let composed = quotes[0].composed.as_ref().unwrap();
+
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].to_value, "25000000000");
+        assert_eq!(quotes[0].data.provider.id, SwapperProvider::Composed);
+        assert_eq!(
+            composed.legs.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::UniswapV3, SwapperProvider::Mayan]
+        );
+        assert_eq!(GemSwapper::executed_quote(&quotes[0]).unwrap(), &composed.legs[0]);
+
+        assert!(gem_swapper.get_swap_result(Chain::Ethereum, SwapperProvider::Composed, "0x1").await.is_err());
+
+        request.to_asset = SwapperQuoteAsset::from(ETHEREUM_USDT_ASSET_ID.clone());
+        request.options.preferred_providers = vec![SwapperProvider::Mayan];
+
+        assert_eq!(gem_swapper.get_quote(&request).await, Err(SwapperError::NoAvailableProvider));
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_skips_composed_quote_on_provider_error() {
+        let request = mock_quote(
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Solana)),
+        );
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "1000000000000000000"))
+                })),
+                Box::new(
+                    MockSwapper::new(SwapperProvider::Mayan, || Err(SwapperError::InputAmountError { min_amount: None }))
+                        .with_supported_assets(vec![SwapperChainAsset::All(Chain::Ethereum), SwapperChainAsset::All(Chain::Solana)]),
+                ),
+            ],
+        };
+
+        assert_eq!(gem_swapper.get_quote(&request).await, Err(SwapperError::InputAmountError { min_amount: None }));
+
+        let results: Vec<_> = gem_swapper.get_quote_stream(&request, Duration::from_secs(10)).unwrap().collect().await;
+
+        assert_eq!(
+            results,
+            vec![Err(ProviderQuoteError {
+                provider: SwapperProvider::Mayan,
+                error: SwapperError::InputAmountError { min_amount: None },
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_stream_falls_back_to_composed_quote() {
+        let mut request = mock_quote(
+            SwapperQuoteAsset::from(ETHEREUM_USDC_ASSET_ID.clone()),
+            SwapperQuoteAsset::from(AssetId::from_chain(Chain::Solana)),
+        );
+        request.options.preferred_providers = vec![SwapperProvider::UniswapV3];
+        let gem_swapper = GemSwapper {
+            rpc_provider: Arc::new(NativeProvider::default()),
+            limit_orders: GemLimitOrders { providers: vec![] },
+            health: ProviderHealthTracker::default(),
+            fee_source: Arc::new(MockFeeSource::default()),
+            swappers: vec![
+                Box::new(MockSwapper::new(SwapperProvider::UniswapV3, || {
+                    Ok(Quote::mock_with_provider(SwapperProvider::UniswapV3, "1000000000000000000"))
+                })),
+                Box::new(
+                    MockSwapper::new(SwapperProvider::Mayan, || Ok(Quote::mock_with_provider(SwapperProvider::Mayan, "25000000000")))
+                        .with_supported_assets(vec![SwapperChainAsset::All(Chain::Ethereum), SwapperChainAsset::All(Chain::Solana)]),
+                ),
+            ],
+        };
+
+        let results: Vec<_> = gem_swapper.get_quote_stream(&request, Duration::from_secs(10)).unwrap().collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().data.provider.id, SwapperProvider::Composed);
+        assert_eq!(results[0].as_ref().unwrap().to_value, "25000000000");
+
+        request.to_asset = SwapperQuoteAsset::from(ETHEREUM_USDT_ASSET_ID.clone());
+        request.options.preferred_providers = vec![SwapperProvider::Mayan];
+
+        assert!(gem_swapper.get_quote_stream(&request, Duration::from_secs(10)).is_err());
+    }
+
+    #[test]
+    fn test_quote_stream_summary_exact_out() {
+        let quote = |provider: SwapperProvider, from_value: &str| Quote {
+            from_value: from_value.to_string(),
+            ..Quote::mock_with_provider(provider, "100")
+        };
+        let summary = QuoteStreamSummary::new(
+            SwapperMode::ExactOut,
+            vec![quote(SwapperProvider::UniswapV3, "102"), quote(SwapperProvider::UniswapV4, "101")],
+            vec![],
+        );
+
+        assert_eq!(
+            summary.quotes.iter().map(|x| x.data.provider.id).collect::<Vec<_>>(),
+            vec![SwapperProvider::UniswapV4, SwapperProvider::UniswapV3]
+        );
+    }
+
     #[test]
-    fn test_sort_quotes_by_output_amount_desc() {
-        let mut quotes = [
+    fn test_sort_quotes_by_net_value_without_fees() {
+        let quotes = vec![
             Quote::mock_with_provider(SwapperProvider::UniswapV3, "101"),
             Quote::mock_with_provider(SwapperProvider::UniswapV4, "100"),
             Quote::mock_with_provider(SwapperProvider::PancakeswapV3, "102"),
         ];
 
-        GemSwapper::sort_quotes_by_output_amount(&mut quotes);
+        let quotes = quote_fees::sort_quotes_by_net_value(quotes);
 
         assert_eq!(quotes[0].to_value, "102");
         assert_eq!(quotes[1].to_value, "101");
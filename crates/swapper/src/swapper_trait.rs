@@ -2,10 +2,11 @@ use super::{
     SwapperProviderMode, SwapperQuoteData,
     cross_chain::VaultAddresses,
     error::SwapperError,
-    models::{FetchQuoteData, Permit2ApprovalData, ProviderType, Quote, QuoteRequest, SwapResult, SwapperChainAsset},
+    models::{CctpAttestationStatus, FetchQuoteData, Permit2ApprovalData, ProviderType, Quote, QuoteRequest, SwapResult, SwapperChainAsset},
+    timeout,
 };
 use async_trait::async_trait;
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use primitives::{Chain, swap::SwapStatus};
 
@@ -14,10 +15,22 @@ pub trait Swapper: Send + Sync + Debug {
     fn provider(&self) -> &ProviderType;
     fn supported_assets(&self) -> Vec<SwapperChainAsset>;
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError>;
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        false
+    }
+    fn get_approval_spender(&self, _quote: &Quote) -> Option<String> {
+        None
+    }
+    fn quote_timeout(&self) -> Duration {
+        timeout::default_quote_timeout(&self.provider().mode)
+    }
     async fn get_permit2_for_quote(&self, _quote: &Quote) -> Result<Option<Permit2ApprovalData>, SwapperError> {
         Ok(None)
     }
     async fn get_quote_data(&self, quote: &Quote, data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError>;
+    async fn submit_order(&self, _quote: &Quote, _typed_data: &str, _signature: &str) -> Result<String, SwapperError> {
+        Err(SwapperError::NotSupportedChain)
+    }
     async fn get_vault_addresses(&self, _from_timestamp: Option<u64>) -> Result<VaultAddresses, SwapperError> {
         Ok(VaultAddresses { deposit: vec![], send: vec![] })
     }
@@ -31,6 +44,12 @@ pub trait Swapper: Send + Sync + Debug {
             Err(SwapperError::NotSupportedAsset)
         }
     }
+    async fn get_attestation_status(&self, _chain: Chain, _transaction_hash: &str) -> Result<CctpAttestationStatus, SwapperError> {
+        Err(SwapperError::NotSupportedChain)
+    }
+    async fn get_mint_data(&self, _chain: Chain, _transaction_hash: &str, _wallet_address: &str) -> Result<SwapperQuoteData, SwapperError> {
+        Err(SwapperError::NotSupportedChain)
+    }
 }
 
 impl dyn Swapper {
@@ -1,8 +1,12 @@
 use crate::{
     FetchQuoteData, ProviderData, ProviderType, Route, Swapper, SwapperChainAsset, SwapperError, SwapperProvider, SwapperQuoteAsset, SwapperQuoteData, SwapperSlippage,
-    SwapperSlippageMode, config::get_swap_config,
+    SwapperSlippageMode,
+    config::get_swap_config,
+    fee_source::{AllowanceRequest, FeeSource},
 };
+use alloy_primitives::U256;
 use async_trait::async_trait;
+use num_bigint::BigUint;
 use primitives::{AssetId, Chain};
 
 use super::{Options, Quote, QuoteRequest, SwapperMode};
@@ -53,6 +57,9 @@ impl Quote {
             data: ProviderData::mock(),
             request: QuoteRequest::mock(chain, token_id),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         }
     }
 
@@ -67,6 +74,9 @@ impl Quote {
             },
             request: QuoteRequest::mock(Chain::Ethereum, None),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         }
     }
 }
@@ -89,6 +99,7 @@ pub fn mock_quote(from_asset: SwapperQuoteAsset, to_asset: SwapperQuoteAsset) ->
             fee: Some(config.referral_fee.clone()),
             preferred_providers: vec![],
             use_max_amount: false,
+            prices: vec![],
         },
     }
 }
@@ -110,6 +121,10 @@ impl MockSwapper {
             response,
         }
     }
+
+    pub fn with_supported_assets(self, supported_assets: Vec<SwapperChainAsset>) -> Self {
+        Self { supported_assets, ..self }
+    }
 }
 
 #[async_trait]
@@ -130,3 +145,29 @@ impl Swapper for MockSwapper {
         todo!("MockSwapper fetch_quote_data not implemented")
     }
 }
+
+#[derive(Debug, Default)]
+pub struct MockFeeSource {
+    pub gas_price: Option<u64>,
+    pub network_fee: Option<u64>,
+    pub eth_usd_price: Option<f64>,
+}
+
+#[async_trait]
+impl FeeSource for MockFeeSource {
+    async fn get_gas_price(&self, _chain: Chain) -> Result<BigUint, SwapperError> {
+        self.gas_price.map(BigUint::from).ok_or(SwapperError::NotSupportedChain)
+    }
+
+    async fn get_network_fee(&self, _chain: Chain) -> Result<BigUint, SwapperError> {
+        self.network_fee.map(BigUint::from).ok_or(SwapperError::NotSupportedChain)
+    }
+
+    async fn get_eth_usd_price(&self) -> Result<f64, SwapperError> {
+        self.eth_usd_price.ok_or(SwapperError::NotSupportedAsset)
+    }
+
+    async fn get_allowances(&self, _chain: Chain, _owner: &str, requests: &[AllowanceRequest]) -> Result<Vec<U256>, SwapperError> {
+        Ok(vec![U256::ZERO; requests.len()])
+    }
+}
@@ -24,7 +24,7 @@ const DUST_THRESHOLD_MULTIPLIER: i64 = 2;
 const OUTBOUND_DELAY_SECONDS: u32 = 60;
 
 // FIXME: estimate gas limit with memo x bytes
-const DEFAULT_DEPOSIT_GAS_LIMIT: u64 = 90_000;
+pub(crate) const DEFAULT_DEPOSIT_GAS_LIMIT: u64 = 90_000;
 
 #[derive(Debug)]
 pub struct ThorChain<C>
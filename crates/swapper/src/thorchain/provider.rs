@@ -145,6 +145,9 @@ where
             },
             request: request.clone(),
             eta_in_seconds: Some(self.get_eta_in_seconds(request.to_asset.chain(), quote.total_swap_seconds)),
+            fees: None,
+            price_impact: None,
+            composed: None,
         };
 
         Ok(quote)
@@ -0,0 +1,34 @@
+use std::{future::Future, time::Duration};
+
+use crate::SwapperProviderMode;
+
+const ON_CHAIN_QUOTE_TIMEOUT: Duration = Duration::from_secs(5);
+const CROSS_CHAIN_QUOTE_TIMEOUT: Duration = Duration::from_secs(15);
+
+pub(crate) fn default_quote_timeout(mode: &SwapperProviderMode) -> Duration {
+    match mode {
+        SwapperProviderMode::OnChain => ON_CHAIN_QUOTE_TIMEOUT,
+        SwapperProviderMode::Bridge | SwapperProviderMode::CrossChain | SwapperProviderMode::OmniChain(_) => CROSS_CHAIN_QUOTE_TIMEOUT,
+    }
+}
+
+pub(crate) async fn with_timeout<F: Future>(future: F, duration: Duration) -> Option<F::Output> {
+    tokio::time::timeout(duration, future).await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_with_timeout() {
+        assert_eq!(with_timeout(async { 1 }, Duration::from_secs(5)).await, Some(1));
+        assert_eq!(with_timeout(futures::future::pending::<u32>(), Duration::from_millis(10)).await, None);
+    }
+
+    #[test]
+    fn test_default_quote_timeout() {
+        assert_eq!(default_quote_timeout(&SwapperProviderMode::OnChain), ON_CHAIN_QUOTE_TIMEOUT);
+        assert_eq!(default_quote_timeout(&SwapperProviderMode::Bridge), CROSS_CHAIN_QUOTE_TIMEOUT);
+    }
+}
@@ -1,5 +1,9 @@
-use super::{universal_router, v3::UniswapV3, v4::UniswapV4};
-use crate::{Swapper, alien::RpcProvider};
+use super::{universal_router, v2::UniswapV2, v3::UniswapV3, v4::UniswapV4};
+use crate::{Swapper, SwapperProvider, alien::RpcProvider};
+use gem_evm::uniswap::deployment::v2::{
+    get_camelot_v2_deployment_by_chain, get_pancakeswap_v2_deployment_by_chain, get_quickswap_v2_deployment_by_chain, get_spookyswap_v2_deployment_by_chain,
+    get_sushiswap_v2_deployment_by_chain, get_uniswap_v2_deployment_by_chain,
+};
 use std::sync::Arc;
 
 pub fn new_uniswap_v3(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV3 {
@@ -26,6 +30,30 @@ pub fn new_uniswap_v4(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV4 {
     universal_router::new_uniswap_v4(rpc_provider)
 }
 
+pub fn new_uniswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::UniswapV2, get_uniswap_v2_deployment_by_chain, rpc_provider)
+}
+
+pub fn new_pancakeswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::PancakeswapV2, get_pancakeswap_v2_deployment_by_chain, rpc_provider)
+}
+
+pub fn new_sushiswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::SushiswapV2, get_sushiswap_v2_deployment_by_chain, rpc_provider)
+}
+
+pub fn new_quickswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::QuickswapV2, get_quickswap_v2_deployment_by_chain, rpc_provider)
+}
+
+pub fn new_camelot_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::CamelotV2, get_camelot_v2_deployment_by_chain, rpc_provider)
+}
+
+pub fn new_spookyswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> UniswapV2 {
+    UniswapV2::new(SwapperProvider::SpookyswapV2, get_spookyswap_v2_deployment_by_chain, rpc_provider)
+}
+
 pub fn boxed_uniswap_v3(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
     Box::new(new_uniswap_v3(rpc_provider))
 }
@@ -49,3 +77,27 @@ pub fn boxed_wagmi(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
 pub fn boxed_uniswap_v4(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
     Box::new(new_uniswap_v4(rpc_provider))
 }
+
+pub fn boxed_uniswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_uniswap_v2(rpc_provider))
+}
+
+pub fn boxed_pancakeswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_pancakeswap_v2(rpc_provider))
+}
+
+pub fn boxed_sushiswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_sushiswap_v2(rpc_provider))
+}
+
+pub fn boxed_quickswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_quickswap_v2(rpc_provider))
+}
+
+pub fn boxed_camelot_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_camelot_v2(rpc_provider))
+}
+
+pub fn boxed_spookyswap_v2(rpc_provider: Arc<dyn RpcProvider>) -> Box<dyn Swapper> {
+    Box::new(new_spookyswap_v2(rpc_provider))
+}
@@ -6,6 +6,7 @@ mod swap_route;
 
 pub mod default;
 pub mod universal_router;
+pub mod v2;
 pub mod v3;
 pub mod v4;
 
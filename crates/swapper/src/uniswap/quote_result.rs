@@ -37,3 +37,19 @@ where
         .max_by_key(|quote| quote.amount_out)
         .ok_or(SwapperError::NoQuoteAvailable)
 }
+
+pub fn get_best_amount_in<F>(batch_results: &[Result<JsonRpcResults<String>, JsonRpcError>], decoder: F) -> Result<U256, SwapperError>
+where
+    F: Fn(&JsonRpcResponse<String>) -> Result<(U256, U256), SwapperError>,
+{
+    batch_results
+        .iter()
+        .filter_map(|batch_result| batch_result.as_ref().ok())
+        .flat_map(|results| results.0.iter())
+        .filter_map(|result| match result {
+            JsonRpcResult::Value(value) => decoder(value).ok().map(|quoter_tuple| quoter_tuple.0),
+            _ => None,
+        })
+        .min()
+        .ok_or(SwapperError::NoQuoteAvailable)
+}
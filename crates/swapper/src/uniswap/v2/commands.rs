@@ -0,0 +1,177 @@
+use crate::{QuoteRequest, SwapperError, eth_address, uniswap::requires_native_wrapping};
+use alloy_primitives::{Address, U256};
+use gem_evm::uniswap::command::{ADDRESS_THIS, PayPortion, Permit2Permit, Sweep, Transfer, UniversalRouterCommand, UnwrapWeth, V2SwapExactIn, WrapEth};
+use std::str::FromStr;
+
+// amount_out_min is what the recipient receives after the referral fee is paid.
+#[allow(clippy::too_many_arguments)]
+pub fn build_commands(
+    request: &QuoteRequest,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: U256,
+    amount_out_min: U256,
+    path: Vec<Address>,
+    permit: Option<Permit2Permit>,
+    fee_token_is_input: bool,
+) -> Result<Vec<UniversalRouterCommand>, SwapperError> {
+    let fee_options = request.options.fee.clone().unwrap_or_default().evm;
+    let recipient = eth_address::parse_str(&request.destination_address)?;
+    let router = Address::from_str(ADDRESS_THIS).unwrap();
+    let wrap_input_eth = requires_native_wrapping(&request.from_asset.asset_id());
+    let unwrap_output_weth = requires_native_wrapping(&request.to_asset.asset_id());
+    let pay_fees = fee_options.bps > 0;
+    let fee_on_output = pay_fees && !fee_token_is_input;
+
+    let mut commands: Vec<UniversalRouterCommand> = vec![];
+
+    if wrap_input_eth {
+        commands.push(UniversalRouterCommand::WRAP_ETH(WrapEth {
+            recipient: router,
+            amount_min: amount_in,
+        }));
+    } else if let Some(permit) = permit {
+        commands.push(UniversalRouterCommand::PERMIT2_PERMIT(permit));
+    }
+
+    let mut swap_amount_in = amount_in;
+    if pay_fees && fee_token_is_input {
+        let fee = amount_in * U256::from(fee_options.bps) / U256::from(10000);
+        let transfer = Transfer {
+            token: *token_in,
+            recipient: eth_address::parse_str(&fee_options.address)?,
+            value: fee,
+        };
+        commands.push(if wrap_input_eth {
+            UniversalRouterCommand::TRANSFER(transfer)
+        } else {
+            UniversalRouterCommand::PERMIT2_TRANSFER_FROM(transfer)
+        });
+        swap_amount_in = amount_in - fee;
+    }
+
+    commands.push(UniversalRouterCommand::V2_SWAP_EXACT_IN(V2SwapExactIn {
+        recipient: if fee_on_output || unwrap_output_weth { router } else { recipient },
+        amount_in: swap_amount_in,
+        amount_out_min: if fee_on_output { U256::ZERO } else { amount_out_min },
+        path,
+        payer_is_user: !wrap_input_eth,
+    }));
+
+    if fee_on_output {
+        commands.push(UniversalRouterCommand::PAY_PORTION(PayPortion {
+            token: *token_out,
+            recipient: eth_address::parse_str(&fee_options.address)?,
+            bips: U256::from(fee_options.bps),
+        }));
+    }
+
+    if unwrap_output_weth {
+        commands.push(UniversalRouterCommand::UNWRAP_WETH(UnwrapWeth {
+            recipient,
+            amount_min: amount_out_min,
+        }));
+    } else if fee_on_output {
+        commands.push(UniversalRouterCommand::SWEEP(Sweep {
+            token: *token_out,
+            recipient,
+            amount_min: amount_out_min,
+        }));
+    }
+
+    Ok(commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        SwapperQuoteAsset,
+        fees::{ReferralFee, ReferralFees},
+        testkit::mock_quote,
+    };
+    use primitives::{
+        AssetId, Chain,
+        asset_constants::{ETHEREUM_USDC_TOKEN_ID, ETHEREUM_WETH_TOKEN_ID},
+    };
+
+    fn request(from: AssetId, to: AssetId, fee_bps: u32) -> QuoteRequest {
+        let mut request = mock_quote(SwapperQuoteAsset::from(from), SwapperQuoteAsset::from(to));
+        request.options.fee = Some(ReferralFees::evm(ReferralFee {
+            bps: fee_bps,
+            address: "0x3d83ec320541ae96c4c91e9202643870458fb290".into(),
+        }));
+        request
+    }
+
+    #[test]
+    fn test_build_commands_eth_to_token() {
+        let weth = eth_address::parse_str(ETHEREUM_WETH_TOKEN_ID).unwrap();
+        let usdc = eth_address::parse_str(ETHEREUM_USDC_TOKEN_ID).unwrap();
+        let amount_in = U256::from(1_000_000u64);
+        let amount_out_min = U256::from(900u64);
+        let eth = AssetId::from_chain(Chain::Ethereum);
+        let token = AssetId::from_token(Chain::Ethereum, ETHEREUM_USDC_TOKEN_ID);
+
+        let commands = build_commands(
+            &request(eth.clone(), token.clone(), 0),
+            &weth,
+            &usdc,
+            amount_in,
+            amount_out_min,
+            vec![weth, usdc],
+            None,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(commands.len(), 2);
+        assert!(matches!(commands[0], UniversalRouterCommand::WRAP_ETH(_)));
+        assert!(matches!(&commands[1], UniversalRouterCommand::V2_SWAP_EXACT_IN(swap) if swap.amount_out_min == amount_out_min && !swap.payer_is_user));
+
+        let commands = build_commands(
+            &request(eth.clone(), token.clone(), 50),
+            &weth,
+            &usdc,
+            amount_in,
+            amount_out_min,
+            vec![weth, usdc],
+            None,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(commands.len(), 4);
+        assert!(matches!(&commands[1], UniversalRouterCommand::V2_SWAP_EXACT_IN(swap) if swap.amount_out_min.is_zero()));
+        assert!(matches!(&commands[2], UniversalRouterCommand::PAY_PORTION(portion) if portion.bips == U256::from(50)));
+        assert!(matches!(&commands[3], UniversalRouterCommand::SWEEP(sweep) if sweep.amount_min == amount_out_min));
+
+        let commands = build_commands(&request(eth, token, 50), &weth, &usdc, amount_in, amount_out_min, vec![weth, usdc], None, true).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(&commands[1], UniversalRouterCommand::TRANSFER(transfer) if transfer.value == U256::from(5_000u64)));
+        assert!(matches!(&commands[2], UniversalRouterCommand::V2_SWAP_EXACT_IN(swap) if swap.amount_in == U256::from(995_000u64)));
+    }
+
+    #[test]
+    fn test_build_commands_token_to_eth() {
+        let weth = eth_address::parse_str(ETHEREUM_WETH_TOKEN_ID).unwrap();
+        let usdc = eth_address::parse_str(ETHEREUM_USDC_TOKEN_ID).unwrap();
+        let amount_in = U256::from(1_000_000u64);
+        let amount_out_min = U256::from(900u64);
+        let request = request(AssetId::from_token(Chain::Ethereum, ETHEREUM_USDC_TOKEN_ID), AssetId::from_chain(Chain::Ethereum), 50);
+
+        let commands = build_commands(&request, &usdc, &weth, amount_in, amount_out_min, vec![usdc, weth], None, true).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(commands[0], UniversalRouterCommand::PERMIT2_TRANSFER_FROM(_)));
+        assert!(matches!(&commands[1], UniversalRouterCommand::V2_SWAP_EXACT_IN(swap) if swap.payer_is_user));
+        assert!(matches!(&commands[2], UniversalRouterCommand::UNWRAP_WETH(unwrap) if unwrap.amount_min == amount_out_min));
+
+        let commands = build_commands(&request, &usdc, &weth, amount_in, amount_out_min, vec![usdc, weth], None, false).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(commands[1], UniversalRouterCommand::PAY_PORTION(_)));
+        assert!(matches!(commands[2], UniversalRouterCommand::UNWRAP_WETH(_)));
+    }
+}
@@ -0,0 +1,8 @@
+mod commands;
+mod reserves;
+mod transfer_tax;
+
+pub mod provider;
+pub use provider::UniswapV2;
+
+pub(crate) const DEFAULT_SWAP_GAS_LIMIT: u64 = 300_000; // gwei
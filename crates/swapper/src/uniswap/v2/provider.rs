@@ -0,0 +1,547 @@
+use crate::{
+    FetchQuoteData, Permit2ApprovalData, ProviderData, ProviderType, Quote, QuoteRequest, Route, Swapper, SwapperError, SwapperMode, SwapperProvider, SwapperQuoteData,
+    alien::RpcProvider,
+    approval::{check_approval_erc20, check_approval_permit2_with_client},
+    client_factory::{create_client_with_chain, create_eth_client},
+    eth_address, exact_out,
+    fees::{apply_slippage_in_bp, reverse_slippage_in_bp},
+    models::*,
+    uniswap::{
+        deadline::get_sig_deadline,
+        fee_token::{FeeToken, get_fee_token},
+        requires_native_wrapping,
+        swap_route::{RouteData, build_swap_route},
+    },
+};
+use alloy_primitives::{Address, U256, hex::encode_prefixed as HexEncode};
+use alloy_sol_types::SolCall;
+use async_trait::async_trait;
+use gem_evm::{
+    contracts::erc20::IERC20,
+    multicall3::{IMulticall3, create_call3, decode_call3_return},
+    uniswap::{
+        command::encode_commands,
+        contracts::v2::{ICamelotRouter, IUniswapV2Factory, IUniswapV2Pair, IUniswapV2Router02},
+        deployment::v2::{V2Deployment, V2RouterType},
+        path::{BasePair, get_base_pair},
+    },
+};
+use primitives::{AssetId, Chain, EVMChain};
+use std::{collections::BTreeMap, fmt, str::FromStr, sync::Arc};
+
+use super::{
+    DEFAULT_SWAP_GAS_LIMIT,
+    commands::build_commands,
+    reserves::{PathQuote, Reserves, build_paths, get_best_path, get_best_path_exact_out, get_pairs, get_path_amount_in, get_path_amount_out, sort_tokens},
+    transfer_tax::{apply_tax, gross_up, received_amount, tax_bps},
+};
+
+type DeploymentFn = fn(&Chain) -> Option<V2Deployment>;
+type PairAddresses = BTreeMap<(Address, Address), Address>;
+
+pub struct UniswapV2 {
+    provider: ProviderType,
+    deployment_fn: DeploymentFn,
+    rpc_provider: Arc<dyn RpcProvider>,
+}
+
+impl UniswapV2 {
+    pub fn new(id: SwapperProvider, deployment_fn: DeploymentFn, rpc_provider: Arc<dyn RpcProvider>) -> Self {
+        Self {
+            provider: ProviderType::new(id),
+            deployment_fn,
+            rpc_provider,
+        }
+    }
+
+    fn get_deployment(&self, chain: &Chain) -> Result<V2Deployment, SwapperError> {
+        (self.deployment_fn)(chain).ok_or(SwapperError::NotSupportedChain)
+    }
+
+    fn parse_request(request: &QuoteRequest) -> Result<(EVMChain, Address, Address, U256), SwapperError> {
+        let evm_chain = EVMChain::from_chain(request.from_asset.chain()).ok_or(SwapperError::NotSupportedChain)?;
+        let token_in = eth_address::parse_or_weth_address(&request.from_asset.asset_id(), evm_chain)?;
+        let token_out = eth_address::parse_or_weth_address(&request.to_asset.asset_id(), evm_chain)?;
+        let amount_in = U256::from_str(&request.value).map_err(SwapperError::from)?;
+
+        Ok((evm_chain, token_in, token_out, amount_in))
+    }
+
+    // Referral fees are taken by the universal router, deployments without one swap through their own router without a fee.
+    fn referral_fee(request: &QuoteRequest, deployment: &V2Deployment, base_pair: &BasePair, token_in: Address, token_out: Address) -> (u32, bool) {
+        let fee_bps = match deployment.universal_router {
+            Some(_) => request.options.fee.as_ref().map(|fee| fee.evm.bps).unwrap_or_default(),
+            None => 0,
+        };
+        let fee_token_in = FeeToken::new(token_in, request.from_asset.symbol.as_str());
+        let fee_token_out = FeeToken::new(token_out, request.to_asset.symbol.as_str());
+        let fee_preference = get_fee_token(&SwapperMode::ExactIn, Some(base_pair), &fee_token_in, &fee_token_out);
+        (fee_bps, fee_preference.is_input_token)
+    }
+
+    async fn multicall3(&self, chain: Chain, calls: Vec<IMulticall3::Call3>) -> Result<Vec<IMulticall3::Result>, SwapperError> {
+        create_eth_client(self.rpc_provider.clone(), chain)?
+            .multicall3(calls)
+            .await
+            .map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))
+    }
+
+    async fn get_pair_addresses(&self, chain: Chain, deployment: &V2Deployment, pairs: Vec<(Address, Address)>) -> Result<Vec<((Address, Address), Address)>, SwapperError> {
+        if deployment.init_code_hash.is_some() {
+            return Ok(pairs
+                .into_iter()
+                .filter_map(|(token_a, token_b)| deployment.pair_address(token_a, token_b).map(|address| ((token_a, token_b), address)))
+                .collect());
+        }
+
+        let calls = pairs
+            .iter()
+            .map(|(token_a, token_b)| {
+                create_call3(
+                    deployment.factory,
+                    IUniswapV2Factory::getPairCall {
+                        tokenA: *token_a,
+                        tokenB: *token_b,
+                    },
+                )
+            })
+            .collect();
+        let pair_addresses = self.multicall3(chain, calls).await?;
+
+        Ok(pairs
+            .into_iter()
+            .zip(pair_addresses.iter())
+            .filter_map(|(pair, result)| decode_call3_return::<IUniswapV2Factory::getPairCall>(result).ok().map(|address| (pair, address)))
+            .filter(|(_, address)| !address.is_zero())
+            .collect())
+    }
+
+    async fn get_reserves(&self, chain: Chain, deployment: &V2Deployment, pairs: Vec<(Address, Address)>) -> Result<(Reserves, PairAddresses), SwapperError> {
+        let pools = self.get_pair_addresses(chain, deployment, pairs).await?;
+        if pools.is_empty() {
+            return Err(SwapperError::NoQuoteAvailable);
+        }
+
+        let calls = pools
+            .iter()
+            .map(|(_, address)| create_call3(&address.to_string(), IUniswapV2Pair::getReservesCall {}))
+            .collect();
+        let results = self.multicall3(chain, calls).await?;
+
+        let reserves = pools
+            .iter()
+            .zip(results.iter())
+            .filter_map(|((pair, _), result)| {
+                let reserves = decode_call3_return::<IUniswapV2Pair::getReservesCall>(result).ok()?;
+                Some((*pair, (U256::from(reserves.reserve0), U256::from(reserves.reserve1))))
+            })
+            .collect();
+        Ok((reserves, pools.into_iter().collect()))
+    }
+
+    // Fee-on-transfer tokens deliver less than the transferred amount, measured by simulating the swap's own transfers.
+    async fn get_transfer_tax_bps(&self, chain: Chain, token: Address, from: Address, to: Address, amount: U256) -> Result<u32, SwapperError> {
+        let client = create_eth_client(self.rpc_provider.clone(), chain)?;
+        let data = HexEncode(IERC20::transferCall { to, value: amount }.abi_encode());
+        let blocks = client
+            .simulate_v1(&from.to_string(), &token.to_string(), None, Some(&data))
+            .await
+            .map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))?;
+        match blocks.first().and_then(|block| block.calls.first()) {
+            Some(call) if call.is_success() => {
+                let received = received_amount(&call.logs, &token, &to);
+                Ok(if received.is_zero() { 0 } else { tax_bps(amount, received) })
+            }
+            _ => Err(SwapperError::NoQuoteAvailable),
+        }
+    }
+
+    async fn get_transfer_taxes(&self, request: &QuoteRequest, best: &PathQuote, pairs: &PairAddresses) -> Result<(u32, u32), SwapperError> {
+        let chain = request.from_asset.chain();
+        let (Some(first), Some(last)) = (best.path.windows(2).next(), best.path.windows(2).last()) else {
+            return Err(SwapperError::InvalidRoute);
+        };
+        let first_pair = pairs.get(&sort_tokens(first[0], first[1])).ok_or(SwapperError::InvalidRoute)?;
+        let last_pair = pairs.get(&sort_tokens(last[0], last[1])).ok_or(SwapperError::InvalidRoute)?;
+        let wallet = eth_address::parse_str(&request.wallet_address)?;
+        let destination = eth_address::parse_str(&request.destination_address)?;
+
+        let tax_in = async {
+            match requires_native_wrapping(&request.from_asset.asset_id()) {
+                true => Ok(0),
+                false => self.get_transfer_tax_bps(chain, first[0], wallet, *first_pair, best.amount_in).await,
+            }
+        };
+        let tax_out = async {
+            match requires_native_wrapping(&request.to_asset.asset_id()) {
+                true => Ok(0),
+                false => self.get_transfer_tax_bps(chain, last[1], *last_pair, destination, best.amount_out).await,
+            }
+        };
+        futures::try_join!(tax_in, tax_out)
+    }
+
+    fn apply_transfer_taxes(mode: &SwapperMode, best: PathQuote, reserves: &Reserves, fee_bps: u32, (tax_in, tax_out): (u32, u32)) -> Option<PathQuote> {
+        match mode {
+            SwapperMode::ExactIn => {
+                let amount_out = get_path_amount_out(&best.path, apply_tax(best.amount_in, tax_in), reserves, fee_bps)?;
+                Some(PathQuote {
+                    amount_out: apply_tax(amount_out, tax_out),
+                    ..best
+                })
+            }
+            SwapperMode::ExactOut => {
+                let amount_in = get_path_amount_in(&best.path, gross_up(best.amount_out, tax_out)?, reserves, fee_bps)?;
+                Some(PathQuote {
+                    amount_in: gross_up(amount_in, tax_in)?,
+                    ..best
+                })
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn swap_call_data(
+        router_type: V2RouterType,
+        wrap_input_eth: bool,
+        unwrap_output_eth: bool,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<Address>,
+        to: Address,
+        deadline: U256,
+    ) -> Vec<u8> {
+        let referrer = Address::ZERO;
+        match (router_type, wrap_input_eth, unwrap_output_eth) {
+            (V2RouterType::UniswapV2, true, _) => IUniswapV2Router02::swapExactETHForTokensSupportingFeeOnTransferTokensCall {
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                deadline,
+            }
+            .abi_encode(),
+            (V2RouterType::UniswapV2, false, true) => IUniswapV2Router02::swapExactTokensForETHSupportingFeeOnTransferTokensCall {
+                amountIn: amount_in,
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                deadline,
+            }
+            .abi_encode(),
+            (V2RouterType::UniswapV2, false, false) => IUniswapV2Router02::swapExactTokensForTokensSupportingFeeOnTransferTokensCall {
+                amountIn: amount_in,
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                deadline,
+            }
+            .abi_encode(),
+            (V2RouterType::Camelot, true, _) => ICamelotRouter::swapExactETHForTokensSupportingFeeOnTransferTokensCall {
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                referrer,
+                deadline,
+            }
+            .abi_encode(),
+            (V2RouterType::Camelot, false, true) => ICamelotRouter::swapExactTokensForETHSupportingFeeOnTransferTokensCall {
+                amountIn: amount_in,
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                referrer,
+                deadline,
+            }
+            .abi_encode(),
+            (V2RouterType::Camelot, false, false) => ICamelotRouter::swapExactTokensForTokensSupportingFeeOnTransferTokensCall {
+                amountIn: amount_in,
+                amountOutMin: amount_out_min,
+                path,
+                to,
+                referrer,
+                deadline,
+            }
+            .abi_encode(),
+        }
+    }
+
+    // Returns the user's total input and the amount left for the recipient once the referral fee is paid.
+    fn apply_referral_fee(mode: &SwapperMode, amount: U256, best: &PathQuote, fee_bps: u32, fee_token_is_input: bool) -> (U256, U256) {
+        let amount_in = match mode {
+            SwapperMode::ExactIn => amount,
+            SwapperMode::ExactOut if fee_token_is_input => reverse_slippage_in_bp(best.amount_in, fee_bps),
+            SwapperMode::ExactOut => best.amount_in,
+        };
+        let amount_out = if fee_token_is_input {
+            best.amount_out
+        } else {
+            apply_slippage_in_bp(&best.amount_out, fee_bps)
+        };
+        (amount_in, amount_out)
+    }
+
+    fn get_path(routes: &[Route]) -> Result<Vec<Address>, SwapperError> {
+        let first = routes.first().ok_or(SwapperError::InvalidRoute)?;
+        let mut path = vec![eth_address::parse_asset_id(&first.input)?];
+        for route in routes {
+            path.push(eth_address::parse_asset_id(&route.output)?);
+        }
+        Ok(path)
+    }
+}
+
+impl fmt::Debug for UniswapV2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniswapV2").field("provider", &self.provider.id).finish()
+    }
+}
+
+#[async_trait]
+impl Swapper for UniswapV2 {
+    fn provider(&self) -> &ProviderType {
+        &self.provider
+    }
+
+    fn supported_assets(&self) -> Vec<SwapperChainAsset> {
+        Chain::all().into_iter().filter(|x| (self.deployment_fn)(x).is_some()).map(SwapperChainAsset::All).collect()
+    }
+
+    fn get_approval_spender(&self, quote: &Quote) -> Option<String> {
+        (self.deployment_fn)(&quote.request.from_asset.chain()).map(|x| match x.universal_router {
+            Some(universal_router) => universal_router.permit2.to_string(),
+            None => x.router.to_string(),
+        })
+    }
+
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
+    async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        let from_chain = request.from_asset.chain();
+        let deployment = self.get_deployment(&from_chain)?;
+        let (evm_chain, token_in, token_out, amount) = Self::parse_request(request)?;
+        if token_in == token_out {
+            return Err(SwapperError::NotSupportedAsset);
+        }
+
+        let base_pair = get_base_pair(&evm_chain, true).ok_or(SwapperError::ComputeQuoteError("base pair not found".into()))?;
+        let (fee_bps, fee_token_is_input) = Self::referral_fee(request, &deployment, &base_pair, token_in, token_out);
+        let quote_amount = match (request.mode, fee_token_is_input) {
+            (SwapperMode::ExactIn, true) => apply_slippage_in_bp(&amount, fee_bps),
+            (SwapperMode::ExactOut, false) => reverse_slippage_in_bp(amount, fee_bps),
+            _ => amount,
+        };
+        let paths = build_paths(&token_in, &token_out, &base_pair);
+        let (reserves, pairs) = self.get_reserves(from_chain, &deployment, get_pairs(&paths)).await?;
+        let best = match request.mode {
+            SwapperMode::ExactIn => get_best_path(&paths, quote_amount, &reserves, deployment.fee_bps),
+            SwapperMode::ExactOut => get_best_path_exact_out(&paths, quote_amount, &reserves, deployment.fee_bps),
+        }
+        .ok_or(SwapperError::NoQuoteAvailable)?;
+        let taxes = self.get_transfer_taxes(request, &best, &pairs).await?;
+        let best = Self::apply_transfer_taxes(&request.mode, best, &reserves, deployment.fee_bps, taxes).ok_or(SwapperError::NoQuoteAvailable)?;
+        let (amount_in, amount_out) = Self::apply_referral_fee(&request.mode, amount, &best, fee_bps, fee_token_is_input);
+        // Swaps always spend an exact input, exact output quotes are returned for the input they need.
+        let request = match request.mode {
+            SwapperMode::ExactIn => request.clone(),
+            SwapperMode::ExactOut => exact_out::exact_in_request(request, amount_in),
+        };
+
+        let route_data = RouteData {
+            fee_tier: deployment.fee_bps.to_string(),
+            min_amount_out: apply_slippage_in_bp(&amount_out, request.options.slippage.bps).to_string(),
+        };
+        let asset_ids: Vec<AssetId> = best.path.iter().map(|x| AssetId::from_token(from_chain, &x.to_checksum(None))).collect();
+        let intermediary = if asset_ids.len() > 2 { asset_ids.get(1) } else { None };
+        let routes = build_swap_route(&asset_ids[0], intermediary, &asset_ids[asset_ids.len() - 1], &route_data);
+
+        Ok(Quote {
+            from_value: amount_in.to_string(),
+            to_value: amount_out.to_string(),
+            data: ProviderData {
+                provider: self.provider.clone(),
+                routes,
+                slippage_bps: request.options.slippage.bps,
+            },
+            request,
+            eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
+        })
+    }
+
+    async fn get_permit2_for_quote(&self, quote: &Quote) -> Result<Option<Permit2ApprovalData>, SwapperError> {
+        let request = &quote.request;
+        let from_chain = request.from_asset.chain();
+        let Some(universal_router) = self.get_deployment(&from_chain)?.universal_router else {
+            return Ok(None);
+        };
+        if requires_native_wrapping(&request.from_asset.asset_id()) {
+            return Ok(None);
+        }
+        let (_, token_in, _, amount_in) = Self::parse_request(request)?;
+        let client = create_client_with_chain(self.rpc_provider.clone(), from_chain);
+        Ok(check_approval_permit2_with_client(
+            universal_router.permit2,
+            request.wallet_address.clone(),
+            token_in.to_checksum(None),
+            universal_router.address.to_string(),
+            amount_in,
+            &client,
+        )
+        .await?
+        .permit2_data())
+    }
+
+    async fn get_quote_data(&self, quote: &Quote, data: FetchQuoteData) -> Result<SwapperQuoteData, SwapperError> {
+        let request = &quote.request;
+        let from_chain = request.from_asset.chain();
+        let deployment = self.get_deployment(&from_chain)?;
+        let (evm_chain, token_in, token_out, amount_in) = Self::parse_request(request)?;
+
+        let route_data: RouteData = serde_json::from_str(&quote.data.routes.first().ok_or(SwapperError::InvalidRoute)?.route_data).map_err(|_| SwapperError::InvalidRoute)?;
+        let amount_out_min = U256::from_str(&route_data.min_amount_out).map_err(SwapperError::from)?;
+        let path = Self::get_path(&quote.data.routes)?;
+        let to = eth_address::parse_str(&request.destination_address)?;
+        let deadline = U256::from(get_sig_deadline());
+
+        let wrap_input_eth = requires_native_wrapping(&request.from_asset.asset_id());
+        let unwrap_output_eth = requires_native_wrapping(&request.to_asset.asset_id());
+
+        let (router, spender, data) = match &deployment.universal_router {
+            Some(universal_router) => {
+                let base_pair = get_base_pair(&evm_chain, true).ok_or(SwapperError::ComputeQuoteError("base pair not found".into()))?;
+                let (_, fee_token_is_input) = Self::referral_fee(request, &deployment, &base_pair, token_in, token_out);
+                let permit = data.permit2_data().map(|data| data.into());
+                let commands = build_commands(request, &token_in, &token_out, amount_in, amount_out_min, path, permit, fee_token_is_input)?;
+                (universal_router.address, universal_router.permit2, encode_commands(&commands, deadline))
+            }
+            None => (
+                deployment.router,
+                deployment.router,
+                Self::swap_call_data(deployment.router_type, wrap_input_eth, unwrap_output_eth, amount_in, amount_out_min, path, to, deadline),
+            ),
+        };
+
+        let approval = if wrap_input_eth {
+            None
+        } else {
+            check_approval_erc20(
+                request.wallet_address.clone(),
+                token_in.to_checksum(None),
+                spender.to_string(),
+                amount_in,
+                self.rpc_provider.clone(),
+                &from_chain,
+            )
+            .await?
+            .approval_data()
+        };
+        let gas_limit = approval.as_ref().map(|_| DEFAULT_SWAP_GAS_LIMIT.to_string());
+        let value = if wrap_input_eth { request.value.clone() } else { String::from("0") };
+
+        Ok(SwapperQuoteData::new_contract(router.to_string(), value, HexEncode(data), approval, gas_limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_path() {
+        let weth = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+        let usdc = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+        let pepe = "0x6982508145454Ce325dDbE47a25d4ec3d2311933";
+        let route_data = RouteData {
+            fee_tier: "30".to_string(),
+            min_amount_out: "1".to_string(),
+        };
+        let routes = build_swap_route(
+            &AssetId::from_token(Chain::Ethereum, pepe),
+            Some(&AssetId::from_token(Chain::Ethereum, weth)),
+            &AssetId::from_token(Chain::Ethereum, usdc),
+            &route_data,
+        );
+
+        let path = UniswapV2::get_path(&routes).unwrap();
+
+        assert_eq!(path, vec![pepe.parse::<Address>().unwrap(), weth.parse().unwrap(), usdc.parse().unwrap()]);
+        assert!(UniswapV2::get_path(&[]).is_err());
+    }
+
+    #[test]
+    fn test_swap_call_data() {
+        let path = vec![Address::repeat_byte(1), Address::repeat_byte(2)];
+        let to = Address::repeat_byte(3);
+        let amount_in = U256::from(1000u64);
+        let amount_out_min = U256::from(900u64);
+        let deadline = U256::from(1u64);
+
+        let data = UniswapV2::swap_call_data(V2RouterType::UniswapV2, false, false, amount_in, amount_out_min, path.clone(), to, deadline);
+        assert_eq!(data[..4], IUniswapV2Router02::swapExactTokensForTokensSupportingFeeOnTransferTokensCall::SELECTOR);
+
+        let data = UniswapV2::swap_call_data(V2RouterType::Camelot, false, false, amount_in, amount_out_min, path.clone(), to, deadline);
+        let call = ICamelotRouter::swapExactTokensForTokensSupportingFeeOnTransferTokensCall::abi_decode(&data).unwrap();
+        assert_eq!(call.referrer, Address::ZERO);
+        assert_eq!(call.path, path);
+
+        let data = UniswapV2::swap_call_data(V2RouterType::Camelot, true, false, amount_in, amount_out_min, path.clone(), to, deadline);
+        assert_eq!(data[..4], ICamelotRouter::swapExactETHForTokensSupportingFeeOnTransferTokensCall::SELECTOR);
+
+        let data = UniswapV2::swap_call_data(V2RouterType::Camelot, false, true, amount_in, amount_out_min, path, to, deadline);
+        assert_eq!(data[..4], ICamelotRouter::swapExactTokensForETHSupportingFeeOnTransferTokensCall::SELECTOR);
+    }
+
+    #[test]
+    fn test_apply_referral_fee() {
+        let best = PathQuote {
+            path: vec![],
+            amount_in: U256::from(9_950u64),
+            amount_out: U256::from(20_000u64),
+        };
+        let amount = U256::from(10_000u64);
+
+        assert_eq!(
+            UniswapV2::apply_referral_fee(&SwapperMode::ExactIn, amount, &best, 50, true),
+            (amount, U256::from(20_000u64))
+        );
+        assert_eq!(
+            UniswapV2::apply_referral_fee(&SwapperMode::ExactIn, amount, &best, 50, false),
+            (amount, U256::from(19_900u64))
+        );
+        assert_eq!(
+            UniswapV2::apply_referral_fee(&SwapperMode::ExactOut, amount, &best, 50, true),
+            (U256::from(10_000u64), U256::from(20_000u64))
+        );
+        assert_eq!(
+            UniswapV2::apply_referral_fee(&SwapperMode::ExactOut, amount, &best, 0, false),
+            (U256::from(9_950u64), U256::from(20_000u64))
+        );
+    }
+
+    #[test]
+    fn test_apply_transfer_taxes() {
+        let token_a = Address::repeat_byte(1);
+        let token_b = Address::repeat_byte(2);
+        let reserves: Reserves = BTreeMap::from([(sort_tokens(token_a, token_b), (U256::from(1_000_000u64), U256::from(1_000_000u64)))]);
+        let best = PathQuote {
+            path: vec![token_a, token_b],
+            amount_in: U256::from(1000u64),
+            amount_out: U256::from(996u64),
+        };
+
+        let quote = UniswapV2::apply_transfer_taxes(&SwapperMode::ExactIn, best.clone(), &reserves, 30, (0, 0)).unwrap();
+        assert_eq!(quote.amount_out, U256::from(996u64));
+
+        let quote = UniswapV2::apply_transfer_taxes(&SwapperMode::ExactIn, best.clone(), &reserves, 30, (500, 500)).unwrap();
+        assert_eq!(quote.amount_in, U256::from(1000u64));
+        assert_eq!(quote.amount_out, U256::from(898u64));
+
+        let quote = UniswapV2::apply_transfer_taxes(&SwapperMode::ExactOut, best, &reserves, 30, (500, 500)).unwrap();
+        assert_eq!(quote.amount_out, U256::from(996u64));
+        assert!(quote.amount_in > U256::from(1100u64));
+    }
+}
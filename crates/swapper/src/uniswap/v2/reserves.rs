@@ -0,0 +1,169 @@
+use alloy_primitives::{Address, U256};
+use gem_evm::uniswap::path::BasePair;
+use std::collections::BTreeMap;
+
+use crate::uniswap::swap_route::get_intermediaries;
+
+const BPS_DENOMINATOR: u32 = 10_000;
+
+pub type Reserves = BTreeMap<(Address, Address), (U256, U256)>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathQuote {
+    pub path: Vec<Address>,
+    pub amount_in: U256,
+    pub amount_out: U256,
+}
+
+pub fn sort_tokens(token_a: Address, token_b: Address) -> (Address, Address) {
+    if token_a < token_b { (token_a, token_b) } else { (token_b, token_a) }
+}
+
+// https://github.com/Uniswap/v2-periphery/blob/master/contracts/libraries/UniswapV2Library.sol#L43
+pub fn get_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> U256 {
+    if amount_in.is_zero() || reserve_in.is_zero() || reserve_out.is_zero() {
+        return U256::ZERO;
+    }
+    let amount_in_with_fee = amount_in * U256::from(BPS_DENOMINATOR - fee_bps);
+    let numerator = amount_in_with_fee * reserve_out;
+    let denominator = reserve_in * U256::from(BPS_DENOMINATOR) + amount_in_with_fee;
+    numerator / denominator
+}
+
+// https://github.com/Uniswap/v2-periphery/blob/master/contracts/libraries/UniswapV2Library.sol#L53
+pub fn get_amount_in(amount_out: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> Option<U256> {
+    if amount_out.is_zero() || reserve_in.is_zero() || amount_out >= reserve_out {
+        return None;
+    }
+    let numerator = reserve_in * amount_out * U256::from(BPS_DENOMINATOR);
+    let denominator = (reserve_out - amount_out) * U256::from(BPS_DENOMINATOR - fee_bps);
+    Some(numerator / denominator + U256::from(1))
+}
+
+pub fn build_paths(token_in: &Address, token_out: &Address, base_pair: &BasePair) -> Vec<Vec<Address>> {
+    let mut paths = vec![vec![*token_in, *token_out]];
+    paths.extend(get_intermediaries(token_in, token_out, base_pair).into_iter().map(|x| vec![*token_in, x, *token_out]));
+    paths
+}
+
+pub fn get_pairs(paths: &[Vec<Address>]) -> Vec<(Address, Address)> {
+    let mut pairs: Vec<_> = paths.iter().flat_map(|path| path.windows(2).map(|x| sort_tokens(x[0], x[1]))).collect();
+    pairs.sort();
+    pairs.dedup();
+    pairs
+}
+
+pub fn get_path_amount_out(path: &[Address], amount_in: U256, reserves: &Reserves, fee_bps: u32) -> Option<U256> {
+    path.windows(2).try_fold(amount_in, |amount, hop| {
+        let (reserve_in, reserve_out) = get_reserves(reserves, hop[0], hop[1])?;
+        Some(get_amount_out(amount, reserve_in, reserve_out, fee_bps)).filter(|x| !x.is_zero())
+    })
+}
+
+fn get_reserves(reserves: &Reserves, token_in: Address, token_out: Address) -> Option<(U256, U256)> {
+    let (reserve0, reserve1) = reserves.get(&sort_tokens(token_in, token_out))?;
+    if token_in < token_out {
+        Some((*reserve0, *reserve1))
+    } else {
+        Some((*reserve1, *reserve0))
+    }
+}
+
+pub fn get_path_amount_in(path: &[Address], amount_out: U256, reserves: &Reserves, fee_bps: u32) -> Option<U256> {
+    path.windows(2).rev().try_fold(amount_out, |amount, hop| {
+        let (reserve_in, reserve_out) = get_reserves(reserves, hop[0], hop[1])?;
+        get_amount_in(amount, reserve_in, reserve_out, fee_bps)
+    })
+}
+
+pub fn get_best_path(paths: &[Vec<Address>], amount_in: U256, reserves: &Reserves, fee_bps: u32) -> Option<PathQuote> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            get_path_amount_out(path, amount_in, reserves, fee_bps).map(|amount_out| PathQuote {
+                path: path.clone(),
+                amount_in,
+                amount_out,
+            })
+        })
+        .max_by_key(|x| x.amount_out)
+}
+
+pub fn get_best_path_exact_out(paths: &[Vec<Address>], amount_out: U256, reserves: &Reserves, fee_bps: u32) -> Option<PathQuote> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            get_path_amount_in(path, amount_out, reserves, fee_bps).map(|amount_in| PathQuote {
+                path: path.clone(),
+                amount_in,
+                amount_out,
+            })
+        })
+        .min_by_key(|x| x.amount_in)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const WETH: Address = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+    const USDC: Address = address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    const PEPE: Address = address!("0x6982508145454Ce325dDbE47a25d4ec3d2311933");
+
+    fn base_pair() -> BasePair {
+        BasePair {
+            native: WETH,
+            stables: vec![USDC],
+            alternatives: vec![],
+        }
+    }
+
+    #[test]
+    fn test_get_amount_out() {
+        let amount_out = get_amount_out(U256::from(1_000_000u64), U256::from(100_000_000u64), U256::from(50_000_000_000u64), 30);
+
+        assert_eq!(amount_out, U256::from(493_579_017u64));
+        assert_eq!(get_amount_out(U256::from(1u64), U256::ZERO, U256::from(1u64), 30), U256::ZERO);
+    }
+
+    #[test]
+    fn test_get_amount_in() {
+        let amount_in = get_amount_in(U256::from(493_579_017u64), U256::from(100_000_000u64), U256::from(50_000_000_000u64), 30).unwrap();
+
+        assert_eq!(amount_in, U256::from(1_000_000u64));
+        assert!(get_amount_out(amount_in, U256::from(100_000_000u64), U256::from(50_000_000_000u64), 30) >= U256::from(493_579_017u64));
+        assert_eq!(get_amount_in(U256::from(1u64), U256::from(1u64), U256::from(1u64), 30), None);
+    }
+
+    #[test]
+    fn test_build_paths() {
+        let paths = build_paths(&PEPE, &USDC, &base_pair());
+
+        assert_eq!(paths, vec![vec![PEPE, USDC], vec![PEPE, WETH, USDC]]);
+        assert_eq!(get_pairs(&paths), vec![sort_tokens(PEPE, USDC), sort_tokens(PEPE, WETH), sort_tokens(WETH, USDC)]);
+    }
+
+    #[test]
+    fn test_get_best_path() {
+        let paths = build_paths(&PEPE, &USDC, &base_pair());
+        let amount_in = U256::from(1_000_000u64);
+
+        let mut reserves = Reserves::new();
+        reserves.insert(sort_tokens(PEPE, WETH), (U256::from(1_000_000_000u64), U256::from(1_000_000u64)));
+        reserves.insert(sort_tokens(WETH, USDC), (U256::from(2_000_000_000u64), U256::from(1_000_000u64)));
+
+        let best = get_best_path(&paths, amount_in, &reserves, 30).unwrap();
+        assert_eq!(best.path, vec![PEPE, WETH, USDC]);
+
+        reserves.insert(sort_tokens(PEPE, USDC), (U256::from(1_000_000_000u64), U256::from(10_000_000_000u64)));
+
+        let best = get_best_path(&paths, amount_in, &reserves, 30).unwrap();
+        assert_eq!(best.path, vec![PEPE, USDC]);
+        assert!(get_best_path(&paths, amount_in, &Reserves::new(), 30).is_none());
+
+        let best = get_best_path_exact_out(&paths, best.amount_out, &reserves, 30).unwrap();
+        assert_eq!(best.path, vec![PEPE, USDC]);
+        assert!(best.amount_in <= amount_in);
+    }
+}
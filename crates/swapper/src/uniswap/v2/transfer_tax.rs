@@ -0,0 +1,79 @@
+use alloy_primitives::{Address, B256, U256};
+use gem_evm::rpc::{mapper::TRANSFER_TOPIC, model::Log};
+use std::str::FromStr;
+
+const BPS_DENOMINATOR: u32 = 10_000;
+
+pub fn received_amount(logs: &[Log], token: &Address, recipient: &Address) -> U256 {
+    logs.iter()
+        .filter(|log| Address::from_str(&log.address).is_ok_and(|address| address == *token))
+        .filter(|log| log.topics.len() == 3 && log.topics[0] == TRANSFER_TOPIC)
+        .filter(|log| B256::from_str(&log.topics[2]).is_ok_and(|topic| Address::from_word(topic) == *recipient))
+        .filter_map(|log| U256::from_str(&log.data).ok())
+        .fold(U256::ZERO, |total, value| total.saturating_add(value))
+}
+
+pub fn tax_bps(amount: U256, received: U256) -> u32 {
+    if amount.is_zero() || received >= amount {
+        return 0;
+    }
+    let taxed = (amount - received) * U256::from(BPS_DENOMINATOR);
+    let bps = taxed.div_ceil(amount);
+    bps.to::<u32>().min(BPS_DENOMINATOR)
+}
+
+pub fn apply_tax(amount: U256, bps: u32) -> U256 {
+    amount * U256::from(BPS_DENOMINATOR - bps) / U256::from(BPS_DENOMINATOR)
+}
+
+pub fn gross_up(amount: U256, bps: u32) -> Option<U256> {
+    if bps >= BPS_DENOMINATOR {
+        return None;
+    }
+    Some((amount * U256::from(BPS_DENOMINATOR)).div_ceil(U256::from(BPS_DENOMINATOR - bps)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const TOKEN: Address = address!("0x6982508145454Ce325dDbE47a25d4ec3d2311933");
+    const RECIPIENT: Address = address!("0x514BCb1F9AAbb904e6106Bd1052B66d2706dBbb7");
+
+    fn transfer_log(token: Address, to: Address, value: u64) -> Log {
+        Log {
+            address: token.to_string(),
+            topics: vec![TRANSFER_TOPIC.to_string(), B256::ZERO.to_string(), to.into_word().to_string()],
+            data: B256::from(U256::from(value)).to_string(),
+            transaction_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_received_amount() {
+        let logs = vec![
+            transfer_log(TOKEN, RECIPIENT, 950),
+            transfer_log(TOKEN, Address::ZERO, 50),
+            transfer_log(Address::ZERO, RECIPIENT, 1000),
+        ];
+
+        assert_eq!(received_amount(&logs, &TOKEN, &RECIPIENT), U256::from(950u64));
+        assert_eq!(received_amount(&[], &TOKEN, &RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_tax_bps() {
+        assert_eq!(tax_bps(U256::from(1000u64), U256::from(950u64)), 500);
+        assert_eq!(tax_bps(U256::from(1000u64), U256::from(1000u64)), 0);
+        assert_eq!(tax_bps(U256::from(3u64), U256::from(2u64)), 3334);
+        assert_eq!(tax_bps(U256::ZERO, U256::ZERO), 0);
+    }
+
+    #[test]
+    fn test_apply_tax() {
+        assert_eq!(apply_tax(U256::from(1000u64), 500), U256::from(950u64));
+        assert_eq!(gross_up(U256::from(950u64), 500), Some(U256::from(1000u64)));
+        assert_eq!(gross_up(U256::from(1u64), 10_000), None);
+    }
+}
@@ -172,6 +172,7 @@ mod tests {
             })),
             preferred_providers: vec![],
             use_max_amount: false,
+            prices: vec![],
         };
         request.options = options;
 
@@ -243,6 +244,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         };
 
@@ -287,6 +289,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         };
 
@@ -348,6 +351,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         };
 
@@ -413,6 +417,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         };
         let path = build_direct_pair(&token_usdt, &token_celo, FeeTier::Hundred);
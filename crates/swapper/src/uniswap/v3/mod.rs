@@ -16,4 +16,4 @@ pub trait UniversalRouterProvider: Send + Sync + Debug {
     fn get_deployment_by_chain(&self, chain: &Chain) -> Option<V3Deployment>;
 }
 
-const DEFAULT_SWAP_GAS_LIMIT: u64 = 500_000; // gwei
+pub(crate) const DEFAULT_SWAP_GAS_LIMIT: u64 = 500_000; // gwei
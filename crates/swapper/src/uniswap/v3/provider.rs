@@ -1,14 +1,14 @@
 use crate::{
-    FetchQuoteData, Permit2ApprovalData, ProviderData, ProviderType, Quote, QuoteRequest, Swapper, SwapperError, SwapperQuoteData,
+    FetchQuoteData, Permit2ApprovalData, ProviderData, ProviderType, Quote, QuoteRequest, Swapper, SwapperError, SwapperMode, SwapperQuoteData,
     alien::{RpcClient, RpcProvider},
     approval::{check_approval_erc20_with_client, check_approval_permit2_with_client},
-    eth_address,
-    fees::apply_slippage_in_bp,
+    eth_address, exact_out,
+    fees::{apply_slippage_in_bp, reverse_slippage_in_bp},
     models::*,
     uniswap::{
         deadline::get_sig_deadline,
         fee_token::{FeeToken, get_fee_token},
-        quote_result::get_best_quote,
+        quote_result::{get_best_amount_in, get_best_quote},
         requires_native_wrapping,
         swap_route::{RouteData, build_swap_route},
     },
@@ -59,6 +59,43 @@ impl UniswapV3 {
         Ok((evm_chain, token_in, token_out, amount_in))
     }
 
+    // Quoter exact output paths run from the output token back to the input token.
+    async fn get_exact_out_amount_in(&self, request: &QuoteRequest) -> Result<U256, SwapperError> {
+        let from_chain = request.from_asset.chain();
+        let deployment = self.provider.get_deployment_by_chain(&from_chain).ok_or(SwapperError::NotSupportedChain)?;
+        let (evm_chain, token_in, token_out, amount_out) = Self::parse_request(request)?;
+        let base_pair = get_base_pair(&evm_chain, evm_chain.weth_contract().is_some()).ok_or(SwapperError::ComputeQuoteError("base pair not found".into()))?;
+
+        let fee_token_in = FeeToken::new(token_in, request.from_asset.symbol.as_str());
+        let fee_token_out = FeeToken::new(token_out, request.to_asset.symbol.as_str());
+        let fee_preference = get_fee_token(&SwapperMode::ExactIn, Some(&base_pair), &fee_token_in, &fee_token_out);
+        let fee_bps = request.options.clone().fee.unwrap_or_default().evm.bps;
+        let quote_amount_out = if fee_preference.is_input_token {
+            amount_out
+        } else {
+            reverse_slippage_in_bp(amount_out, fee_bps)
+        };
+
+        let client = Arc::new(self.client_for(from_chain)?);
+        let paths_array = super::path::build_paths(&token_out, &token_in, &self.provider.get_tiers(), &base_pair);
+        let requests = paths_array.iter().map(|paths| {
+            let client = client.clone();
+            let calls: Vec<EthereumRpc> = paths
+                .iter()
+                .map(|path| super::quoter_v2::build_quoter_request(&SwapperMode::ExactOut, &request.wallet_address, deployment.quoter_v2, quote_amount_out, &path.1))
+                .collect();
+            async move { client.batch_call_requests(calls).await }
+        });
+        let batch_results = futures::future::join_all(requests).await;
+        let amount_in = get_best_amount_in(&batch_results, super::quoter_v2::decode_quoter_response)?;
+
+        if fee_preference.is_input_token {
+            Ok(reverse_slippage_in_bp(amount_in, fee_bps))
+        } else {
+            Ok(amount_in)
+        }
+    }
+
     async fn check_erc20_approval(
         &self,
         client: &JsonRpcClient<RpcClient>,
@@ -111,7 +148,19 @@ impl Swapper for UniswapV3 {
         Chain::all().iter().filter(|x| self.support_chain(x)).map(|x| SwapperChainAsset::All(*x)).collect()
     }
 
+    fn get_approval_spender(&self, quote: &Quote) -> Option<String> {
+        self.provider.get_deployment_by_chain(&quote.request.from_asset.chain()).map(|x| x.permit2.to_string())
+    }
+
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        if request.mode == SwapperMode::ExactOut {
+            let amount_in = self.get_exact_out_amount_in(request).await?;
+            return self.get_quote(&exact_out::exact_in_request(request, amount_in)).await;
+        }
         let from_chain = request.from_asset.chain();
         let to_chain = request.to_asset.chain();
         let deployment = self.provider.get_deployment_by_chain(&from_chain).ok_or(SwapperError::NotSupportedChain)?;
@@ -190,6 +239,9 @@ impl Swapper for UniswapV3 {
             },
             request: request.clone(),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
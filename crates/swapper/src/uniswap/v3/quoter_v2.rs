@@ -25,7 +25,7 @@ pub fn build_quoter_request(mode: &SwapperMode, wallet_address: &str, quoter_v2:
     EthereumRpc::Call(TransactionObject::new_call_with_from(wallet_address, quoter_v2, call_data), BlockParameter::Latest)
 }
 
-// Returns (amountOut, gasEstimate)
+// Returns (amountOut, gasEstimate), or (amountIn, gasEstimate) for exact output quotes sharing the same layout
 pub fn decode_quoter_response(response: &JsonRpcResponse<String>) -> Result<(U256, U256), SwapperError> {
     let decoded = HexDecode(&response.result).map_err(|_| SwapperError::ComputeQuoteError("Failed to decode quoter response".into()))?;
     let quoter_return = IQuoterV2::quoteExactInputCall::abi_decode_returns(&decoded).map_err(SwapperError::from)?;
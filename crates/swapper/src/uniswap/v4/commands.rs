@@ -184,6 +184,7 @@ mod tests {
                 })),
                 preferred_providers: vec![],
                 use_max_amount: false,
+                prices: vec![],
             },
         };
         let routes = vec![Route::mock(
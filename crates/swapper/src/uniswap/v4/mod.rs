@@ -5,4 +5,4 @@ mod quoter;
 pub mod provider;
 pub use provider::UniswapV4;
 
-const DEFAULT_SWAP_GAS_LIMIT: u64 = 300_000; // gwei
+pub(crate) const DEFAULT_SWAP_GAS_LIMIT: u64 = 300_000; // gwei
@@ -5,7 +5,7 @@ use gem_evm::uniswap::{
     path::TokenPair,
 };
 
-use crate::{Route, SwapperError, error::INVALID_ADDRESS, eth_address, uniswap::swap_route::RouteData};
+use crate::{Route, SwapperError, SwapperMode, error::INVALID_ADDRESS, eth_address, uniswap::swap_route::RouteData};
 
 // return (currency0, currency1)
 fn sort_addresses(token_in: &Address, token_out: &Address) -> (Address, Address) {
@@ -46,8 +46,10 @@ pub fn build_pool_keys(token_in: &Address, token_out: &Address, fee_tiers: &[Fee
         .collect()
 }
 
+// Exact output paths name the currency each hop comes from, starting at the input.
 pub fn build_quote_exact_params(
-    amount_in: u128,
+    mode: &SwapperMode,
+    amount: u128,
     token_in: &Address,
     token_out: &Address,
     fee_tiers: &[FeeTier],
@@ -61,19 +63,26 @@ pub fn build_quote_exact_params(
                 .map(|fee_tier| TokenPair::new_two_hop(token_in, intermediary, token_out, *fee_tier))
                 .filter(|token_pairs| token_pairs.len() >= 2)
                 .map(|token_pairs| {
+                    let exact_currency = match mode {
+                        SwapperMode::ExactIn => token_pairs[0].token_in,
+                        SwapperMode::ExactOut => token_pairs[token_pairs.len() - 1].token_out,
+                    };
                     let quote_exact_params = QuoteExactParams {
-                        exactCurrency: token_pairs[0].token_in,
+                        exactCurrency: exact_currency,
                         path: token_pairs
                             .iter()
                             .map(|token_pair| PathKey {
-                                intermediateCurrency: token_pair.token_out,
+                                intermediateCurrency: match mode {
+                                    SwapperMode::ExactIn => token_pair.token_out,
+                                    SwapperMode::ExactOut => token_pair.token_in,
+                                },
                                 fee: token_pair.fee_tier.as_u24(),
                                 tickSpacing: token_pair.fee_tier.default_tick_spacing(),
                                 hooks: Address::ZERO,
                                 hookData: Bytes::new(),
                             })
                             .collect(),
-                        exactAmount: amount_in,
+                        exactAmount: amount,
                     };
 
                     (token_pairs, quote_exact_params)
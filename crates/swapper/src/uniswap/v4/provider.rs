@@ -3,16 +3,16 @@ use async_trait::async_trait;
 use std::{collections::HashSet, fmt, str::FromStr, sync::Arc, vec};
 
 use crate::{
-    FetchQuoteData, Permit2ApprovalData, ProviderData, ProviderType, Quote, QuoteRequest, Swapper, SwapperChainAsset, SwapperError, SwapperProvider, SwapperQuoteData,
+    FetchQuoteData, Permit2ApprovalData, ProviderData, ProviderType, Quote, QuoteRequest, Swapper, SwapperChainAsset, SwapperError, SwapperMode, SwapperProvider, SwapperQuoteData,
     alien::{RpcClient, RpcProvider},
     approval::evm::{check_approval_erc20_with_client, check_approval_permit2_with_client},
-    eth_address,
-    fees::apply_slippage_in_bp,
+    eth_address, exact_out,
+    fees::{apply_slippage_in_bp, reverse_slippage_in_bp},
     uniswap::{
         deadline::get_sig_deadline,
         fee_token::{FeeToken, get_fee_token},
         is_native_erc20,
-        quote_result::get_best_quote,
+        quote_result::{get_best_amount_in, get_best_quote},
         requires_native_wrapping,
         swap_route::{RouteData, build_swap_route, get_intermediaries},
     },
@@ -87,6 +87,48 @@ impl UniswapV4 {
 
         Ok((evm_chain, token_in, token_out, amount_in))
     }
+
+    async fn get_exact_out_amount_in(&self, request: &QuoteRequest) -> Result<U256, SwapperError> {
+        let from_chain = request.from_asset.chain();
+        let deployment = get_uniswap_deployment_by_chain(&from_chain).ok_or(SwapperError::NotSupportedChain)?;
+        let (evm_chain, token_in, token_out, amount_out) = Self::parse_request(request)?;
+        let fee_tiers = self.get_tiers();
+        let base_pair = get_base_pair(&evm_chain, is_native_erc20(from_chain)).ok_or(SwapperError::ComputeQuoteError("base pair not found".into()))?;
+        let fee_token_in = FeeToken::new(token_in, request.from_asset.symbol.as_str());
+        let fee_token_out = FeeToken::new(token_out, request.to_asset.symbol.as_str());
+        let fee_preference = get_fee_token(&SwapperMode::ExactIn, Some(&base_pair), &fee_token_in, &fee_token_out);
+        let fee_bps = request.options.clone().fee.unwrap_or_default().evm.bps;
+        let quote_amount_out = if fee_preference.is_input_token {
+            amount_out
+        } else {
+            u128::try_from(reverse_slippage_in_bp(U256::from(amount_out), fee_bps)).map_err(|_| SwapperError::ComputeQuoteError("amount out overflow".into()))?
+        };
+
+        let client = Arc::new(self.client_for(from_chain)?);
+        let mut calls: Vec<Vec<EthereumRpc>> = vec![
+            build_pool_keys(&token_in, &token_out, &fee_tiers)
+                .iter()
+                .map(|pool_key| build_quote_exact_single_request(&SwapperMode::ExactOut, &token_in, deployment.quoter, quote_amount_out, &pool_key.1))
+                .collect(),
+        ];
+        if !Self::is_base_pair(&token_in, &token_out, &evm_chain) {
+            let intermediaries = get_intermediaries(&token_in, &token_out, &base_pair);
+            let quote_exact_params = build_quote_exact_params(&SwapperMode::ExactOut, quote_amount_out, &token_in, &token_out, &fee_tiers, &intermediaries);
+            calls.extend(build_quote_exact_requests(&SwapperMode::ExactOut, deployment.quoter, &quote_exact_params));
+        }
+        let batch_results = join_all(calls.into_iter().map(|calls| {
+            let client = client.clone();
+            async move { client.batch_call_requests(calls).await }
+        }))
+        .await;
+        let amount_in = get_best_amount_in(&batch_results, super::quoter::decode_quoter_response)?;
+
+        if fee_preference.is_input_token {
+            Ok(reverse_slippage_in_bp(amount_in, fee_bps))
+        } else {
+            Ok(amount_in)
+        }
+    }
 }
 
 impl fmt::Debug for UniswapV4 {
@@ -105,7 +147,19 @@ impl Swapper for UniswapV4 {
         Chain::all().iter().filter(|x| self.support_chain(x)).map(|x| SwapperChainAsset::All(*x)).collect()
     }
 
+    fn get_approval_spender(&self, quote: &Quote) -> Option<String> {
+        get_uniswap_deployment_by_chain(&quote.request.from_asset.chain()).map(|x| x.permit2.to_string())
+    }
+
+    fn supports_exact_out(&self, _request: &QuoteRequest) -> bool {
+        true
+    }
+
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, SwapperError> {
+        if request.mode == SwapperMode::ExactOut {
+            let amount_in = self.get_exact_out_amount_in(request).await?;
+            return self.get_quote(&exact_out::exact_in_request(request, amount_in)).await;
+        }
         let from_chain = request.from_asset.chain();
         let to_chain = request.to_asset.chain();
         let deployment = get_uniswap_deployment_by_chain(&from_chain).ok_or(SwapperError::NotSupportedChain)?;
@@ -129,19 +183,21 @@ impl Swapper for UniswapV4 {
         let initial_client = Arc::clone(&client);
         let direct_calls: Vec<EthereumRpc> = pool_keys
             .iter()
-            .map(|pool_key| build_quote_exact_single_request(&token_in, deployment.quoter, quote_amount_in, &pool_key.1))
+            .map(|pool_key| build_quote_exact_single_request(&SwapperMode::ExactIn, &token_in, deployment.quoter, quote_amount_in, &pool_key.1))
             .collect();
         requests.push(Box::pin(async move { initial_client.batch_call_requests(direct_calls).await }));
 
         let quote_exact_params: Vec<Vec<(Vec<TokenPair>, QuoteExactParams)>>;
         if !Self::is_base_pair(&token_in, &token_out, &evm_chain) {
             let intermediaries = get_intermediaries(&token_in, &token_out, &base_pair);
-            quote_exact_params = build_quote_exact_params(quote_amount_in, &token_in, &token_out, &fee_tiers, &intermediaries);
-            build_quote_exact_requests(deployment.quoter, &quote_exact_params).iter().for_each(|call_array| {
-                let client = Arc::clone(&client);
-                let calls = call_array.clone();
-                requests.push(Box::pin(async move { client.batch_call_requests(calls).await }));
-            });
+            quote_exact_params = build_quote_exact_params(&SwapperMode::ExactIn, quote_amount_in, &token_in, &token_out, &fee_tiers, &intermediaries);
+            build_quote_exact_requests(&SwapperMode::ExactIn, deployment.quoter, &quote_exact_params)
+                .iter()
+                .for_each(|call_array| {
+                    let client = Arc::clone(&client);
+                    let calls = call_array.clone();
+                    requests.push(Box::pin(async move { client.batch_call_requests(calls).await }));
+                });
         } else {
             quote_exact_params = vec![];
         }
@@ -186,6 +242,9 @@ impl Swapper for UniswapV4 {
             },
             request: request.clone(),
             eta_in_seconds: None,
+            fees: None,
+            price_impact: None,
+            composed: None,
         })
     }
 
@@ -323,6 +382,7 @@ mod tests {
                 })),
                 preferred_providers: vec![SwapperProvider::UniswapV4],
                 use_max_amount: false,
+                prices: vec![],
             };
 
             let request = QuoteRequest {
@@ -1,4 +1,4 @@
-use crate::SwapperError;
+use crate::{SwapperError, SwapperMode};
 use alloy_primitives::{Address, Bytes, U256, hex::decode as HexDecode};
 use alloy_sol_types::SolCall;
 use gem_evm::{
@@ -10,33 +10,38 @@ use gem_evm::{
 };
 use gem_jsonrpc::types::JsonRpcResponse;
 
-pub fn build_quote_exact_single_request(token_in: &Address, v4_quoter: &str, amount_in: u128, pool: &PoolKey) -> EthereumRpc {
+pub fn build_quote_exact_single_request(mode: &SwapperMode, token_in: &Address, v4_quoter: &str, amount: u128, pool: &PoolKey) -> EthereumRpc {
     let zero_for_one = *token_in == pool.currency0;
     let params = IV4Quoter::QuoteExactSingleParams {
         poolKey: pool.clone(),
         zeroForOne: zero_for_one,
-        exactAmount: amount_in,
+        exactAmount: amount,
         hookData: Bytes::new(),
     };
-    let quote_single = IV4Quoter::quoteExactInputSingleCall { params };
-    let call_data: Vec<u8> = quote_single.abi_encode();
+    let call_data: Vec<u8> = match mode {
+        SwapperMode::ExactIn => IV4Quoter::quoteExactInputSingleCall { params }.abi_encode(),
+        SwapperMode::ExactOut => IV4Quoter::quoteExactOutputSingleCall { params }.abi_encode(),
+    };
     EthereumRpc::Call(TransactionObject::new_call(v4_quoter, call_data), BlockParameter::Latest)
 }
 
-pub fn build_quote_exact_requests(v4_quoter: &str, quote_params: &[Vec<(Vec<TokenPair>, IV4Quoter::QuoteExactParams)>]) -> Vec<Vec<EthereumRpc>> {
+pub fn build_quote_exact_requests(mode: &SwapperMode, v4_quoter: &str, quote_params: &[Vec<(Vec<TokenPair>, IV4Quoter::QuoteExactParams)>]) -> Vec<Vec<EthereumRpc>> {
     quote_params
         .iter()
-        .map(|quote_array| quote_array.iter().map(|x| build_quote_exact_request(v4_quoter, &x.1).clone()).collect::<Vec<_>>())
+        .map(|quote_array| quote_array.iter().map(|x| build_quote_exact_request(mode, v4_quoter, &x.1).clone()).collect::<Vec<_>>())
         .collect()
 }
 
-pub fn build_quote_exact_request(v4_quoter: &str, params: &IV4Quoter::QuoteExactParams) -> EthereumRpc {
-    let quote = IV4Quoter::quoteExactInputCall { params: params.clone() };
-    let call_data: Vec<u8> = quote.abi_encode();
+pub fn build_quote_exact_request(mode: &SwapperMode, v4_quoter: &str, params: &IV4Quoter::QuoteExactParams) -> EthereumRpc {
+    let params = params.clone();
+    let call_data: Vec<u8> = match mode {
+        SwapperMode::ExactIn => IV4Quoter::quoteExactInputCall { params }.abi_encode(),
+        SwapperMode::ExactOut => IV4Quoter::quoteExactOutputCall { params }.abi_encode(),
+    };
     EthereumRpc::Call(TransactionObject::new_call(v4_quoter, call_data), BlockParameter::Latest)
 }
 
-// Returns (amountOut, gasEstimate)
+// Returns (amountOut, gasEstimate), or (amountIn, gasEstimate) for exact output quotes sharing the same layout
 pub fn decode_quoter_response(response: &JsonRpcResponse<String>) -> Result<(U256, U256), SwapperError> {
     let decoded = HexDecode(&response.result).map_err(|e| SwapperError::ComputeQuoteError(e.to_string()))?;
     let quoter_return = IV4Quoter::quoteExactInputSingleCall::abi_decode_returns(&decoded).map_err(SwapperError::from)?;
@@ -76,7 +81,7 @@ mod tests {
 
         assert_eq!(HexEncode(pool_id), "0x25939956ef14a098d95051d86c75890cfd623a9eeba055e46d8dd9135980b37c");
 
-        let rpc = build_quote_exact_single_request(&token_in, v4_quoter, amount_in, pool_key);
+        let rpc = build_quote_exact_single_request(&SwapperMode::ExactIn, &token_in, v4_quoter, amount_in, pool_key);
 
         if let EthereumRpc::Call(call, _) = rpc {
             assert!(call.data.starts_with("0xaa9d21cb"));
@@ -93,12 +98,21 @@ mod tests {
         let v4_quoter = OPTIMISM_UNISWAP_V4_QUOTER_CONTRACT;
         let amount_in = 10000000000000000_u128;
 
-        let quote_params = build_quote_exact_params(amount_in, &token_in, &token_out, &fee_tiers, &base_pair.path_building_array());
-        let rpc_calls = build_quote_exact_requests(v4_quoter, &quote_params);
+        let quote_params = build_quote_exact_params(&SwapperMode::ExactIn, amount_in, &token_in, &token_out, &fee_tiers, &base_pair.path_building_array());
+        let rpc_calls = build_quote_exact_requests(&SwapperMode::ExactIn, v4_quoter, &quote_params);
 
         assert_eq!(rpc_calls.len(), 3); // 3 intermediaries (ETH, USDC, USDT)
 
         // 3 fee tiers
         rpc_calls.iter().for_each(|call_array| assert_eq!(call_array.len(), 3));
+
+        let quote_params = build_quote_exact_params(&SwapperMode::ExactOut, amount_in, &token_in, &token_out, &fee_tiers, &base_pair.path_building_array());
+        let params = &quote_params[0][0].1;
+
+        assert_eq!(params.exactCurrency, token_out);
+        assert_eq!(
+            params.path.iter().map(|x| x.intermediateCurrency).collect::<Vec<_>>(),
+            vec![token_in, quote_params[0][0].0[0].token_out]
+        );
     }
 }
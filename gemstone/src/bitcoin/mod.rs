@@ -0,0 +1,3 @@
+pub mod psbt;
+
+pub use psbt::{BitcoinPsbt, GemPsbtInputSummary, GemPsbtOutputSummary, GemPsbtSignResult, GemPsbtSummary};
@@ -0,0 +1,100 @@
+use crate::GemstoneError;
+use gem_bitcoin::signer::{Psbt, PsbtInputSummary, PsbtOutputSummary, PsbtSummary};
+use primitives::{BitcoinChain, Chain};
+
+pub type GemPsbtSummary = PsbtSummary;
+pub type GemPsbtInputSummary = PsbtInputSummary;
+pub type GemPsbtOutputSummary = PsbtOutputSummary;
+
+#[uniffi::remote(Record)]
+pub struct GemPsbtSummary {
+    pub inputs: Vec<GemPsbtInputSummary>,
+    pub outputs: Vec<GemPsbtOutputSummary>,
+    pub total_input: Option<u64>,
+    pub total_output: u64,
+    pub sent: u64,
+    pub fee: Option<u64>,
+}
+
+#[uniffi::remote(Record)]
+pub struct GemPsbtInputSummary {
+    pub transaction_id: String,
+    pub vout: u32,
+    pub address: Option<String>,
+    pub value: Option<u64>,
+    pub is_mine: bool,
+    pub is_signed: bool,
+}
+
+#[uniffi::remote(Record)]
+pub struct GemPsbtOutputSummary {
+    pub address: Option<String>,
+    pub value: u64,
+    pub is_change: bool,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct GemPsbtSignResult {
+    pub psbt: String,
+    pub transaction: Option<String>,
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct BitcoinPsbt {
+    chain: BitcoinChain,
+}
+
+impl BitcoinPsbt {
+    fn parse(&self, psbt: &str) -> Result<Psbt, GemstoneError> {
+        Ok(Psbt::from_string(self.chain, psbt)?)
+    }
+}
+
+#[uniffi::export]
+impl BitcoinPsbt {
+    #[uniffi::constructor]
+    pub fn new(chain: Chain) -> Result<Self, GemstoneError> {
+        let chain = BitcoinChain::from_chain(chain).ok_or_else(|| GemstoneError::from(format!("{chain} is not a Bitcoin chain")))?;
+        Ok(Self { chain })
+    }
+
+    pub fn summary(&self, psbt: String, address: String) -> Result<GemPsbtSummary, GemstoneError> {
+        Ok(self.parse(&psbt)?.summary(&address)?)
+    }
+
+    pub fn sign(&self, psbt: String, address: String, private_key: Vec<u8>, finalize: bool) -> Result<GemPsbtSignResult, GemstoneError> {
+        let mut psbt = self.parse(&psbt)?;
+        if psbt.sign(&address, &private_key)? == 0 {
+            return Err(GemstoneError::from("PSBT has no inputs to sign for this address"));
+        }
+        if finalize {
+            psbt.finalize()?;
+        }
+        let transaction = if psbt.is_finalized() { Some(psbt.extract()?) } else { None };
+        Ok(GemPsbtSignResult {
+            psbt: psbt.to_base64(),
+            transaction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-174 valid test vector 1
+    const PSBT: &str = "cHNidP8BAHUCAAAAASaBcTce3/KF6Tet7qSze3gADAVmy7OtZGQXE8pCFxv2AAAAAAD+////AtPf9QUAAAAAGXapFNDFmQPFusKGh2DpD9UhpGZap2UgiKwA4fUFAAAAABepFDVF5uM7gyxHBQ8k0+65PJwDlIvHh7MuEwAAAQD9pQEBAAAAAAECiaPHHqtNIOA3G7ukzGmPopXJRjr6Ljl/hTPMti+VZ+UBAAAAFxYAFL4Y0VKpsBIDna89p95PUzSe7LmF/////4b4qkOnHf8USIk6UwpyN+9rRgi7st0tAXHmOuxqSJC0AQAAABcWABT+Pp7xp0XpdNkCxDVZQ6vLNL1TU/////8CAMLrCwAAAAAZdqkUhc/xCX/Z4Ai7NK9wnGIZeziXikiIrHL++E4sAAAAF6kUM5cluiHv1irHU6m80GfWx6ajnQWHAkcwRAIgJxK+IuAnDzlPVoMR3HyppolwuAJf3TskAinwf4pfOiQCIAGLONfc0xTnNMkna9b7QPZzMlvEuqFEyADS8vAtsnZcASED0uFWdJQbrUqZY3LLh+GFbTZSYG2YVi/jnF6efkE/IQUCSDBFAiEA0SuFLYXc2WHS9fSrZgZU327tzHlMDDPOXMMJ/7X85Y0CIGczio4OFyXBl/saiK9Z9R5E5CVbIBZ8hoQDHAXR8lkqASECI7cr7vCWXRC+B3jv7NYfysb3mk6haTkzgHNEZPhPKrMAAAAAAAAA";
+
+    #[test]
+    fn test_bitcoin_psbt() {
+        assert!(BitcoinPsbt::new(Chain::Ethereum).is_err());
+
+        let psbt = BitcoinPsbt::new(Chain::Bitcoin).unwrap();
+        let summary = psbt.summary(PSBT.to_string(), "36YhUacEtcnkfhSbxwm11wDCexLGBLgJF6".to_string()).unwrap();
+        assert_eq!(summary.fee, Some(301));
+
+        let private_key = hex::decode("eb696a065ef48a2192da5b28b694f87544b30fae8327c4510137a922f32c6dcf").unwrap();
+        let result = psbt.sign(PSBT.to_string(), "bc1q0yy3juscd3zfavw76g4h3eqdqzda7qyf58rj4m".to_string(), private_key, true);
+        assert!(result.is_err());
+    }
+}
@@ -11,6 +11,7 @@ pub struct SwapConfig {
     pub permit2_sig_deadline: u64,
     pub referral_fee: SwapReferralFees,
     pub high_price_impact_percent: u32,
+    pub max_price_impact_percent: u32,
 }
 
 #[uniffi::remote(Record)]
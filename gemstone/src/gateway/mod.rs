@@ -19,11 +19,11 @@ use gem_aptos::rpc::client::AptosClient;
 use gem_bitcoin::rpc::client::BitcoinClient;
 use gem_cardano::rpc::client::CardanoClient;
 use gem_cosmos::rpc::client::CosmosClient;
-use gem_evm::rpc::EthereumClient;
+use gem_evm::rpc::{EthereumClient, private_relay_url};
 use gem_hypercore::rpc::client::HyperCoreClient;
 use gem_near::rpc::client::NearClient;
 use gem_polkadot::rpc::client::PolkadotClient;
-use gem_solana::rpc::client::SolanaClient;
+use gem_solana::rpc::{JitoClient, client::SolanaClient, jito::JITO_BLOCK_ENGINE_URL};
 use gem_stellar::rpc::client::StellarClient;
 use gem_sui::rpc::client::SuiClient;
 use gem_ton::rpc::client::TonClient;
@@ -105,7 +105,10 @@ impl GemGateway {
             Chain::Ton => Ok(Arc::new(TonClient::new(alien_client))),
             Chain::Tron => Ok(Arc::new(TronClient::new(alien_client.clone(), TronGridClient::new(alien_client.clone(), String::new())))),
             Chain::Polkadot => Ok(Arc::new(PolkadotClient::new(alien_client))),
-            Chain::Solana => Ok(Arc::new(SolanaClient::new(JsonRpcClient::new(alien_client.clone())))),
+            Chain::Solana => {
+                let jito_client = JitoClient::new(new_alien_client(JITO_BLOCK_ENGINE_URL.to_string(), self.provider.clone()));
+                Ok(Arc::new(SolanaClient::new(JsonRpcClient::new(alien_client.clone())).with_jito_client(jito_client)))
+            }
             Chain::Ethereum
             | Chain::Arbitrum
             | Chain::SmartChain
@@ -133,7 +136,16 @@ impl GemGateway {
             | Chain::Plasma
             | Chain::Monad
             | Chain::XLayer
-            | Chain::Stable => Ok(Arc::new(EthereumClient::new(JsonRpcClient::new(alien_client), EVMChain::from_chain(chain).unwrap()))),
+            | Chain::Stable => {
+                let evm_chain = EVMChain::from_chain(chain).unwrap();
+                let client = EthereumClient::new(JsonRpcClient::new(alien_client), evm_chain);
+                match private_relay_url(evm_chain) {
+                    Some(relay_url) => Ok(Arc::new(
+                        client.with_private_client(JsonRpcClient::new(new_alien_client(relay_url.to_string(), self.provider.clone()))),
+                    )),
+                    None => Ok(Arc::new(client)),
+                }
+            }
         }
     }
 }
@@ -10,4 +10,6 @@ pub enum SwapperError {
     ComputeQuoteError(String),
     TransactionError(String),
     NoQuoteAvailable,
+    Timeout,
+    PriceImpactTooHigh { price_impact: f64 },
 }
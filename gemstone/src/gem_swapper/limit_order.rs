@@ -0,0 +1,113 @@
+use super::{error::SwapperError, remote_types::SwapperQuoteAsset};
+use crate::{
+    alien::{AlienProvider, AlienProviderWrapper},
+    models::swap::{GemApprovalData, GemSwapQuoteData},
+};
+use primitives::{AssetId, Chain};
+use std::sync::Arc;
+pub use swapper::{
+    SwapperSwapResult,
+    limit_order::{
+        LimitOrder as SwapperLimitOrder, LimitOrderData as SwapperLimitOrderData, LimitOrderProvider as SwapperLimitOrderProvider, LimitOrderRequest as SwapperLimitOrderRequest,
+        LimitOrderStatus as SwapperLimitOrderStatus,
+    },
+};
+
+type LimitOrders = swapper::limit_order::GemLimitOrders;
+
+#[uniffi::remote(Enum)]
+pub enum SwapperLimitOrderProvider {
+    Jupiter,
+    OneInch,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperLimitOrderRequest {
+    pub from_asset: SwapperQuoteAsset,
+    pub to_asset: SwapperQuoteAsset,
+    pub wallet_address: String,
+    pub from_value: String,
+    pub to_value: String,
+    pub expires_at: Option<u64>,
+}
+
+#[uniffi::remote(Enum)]
+pub enum SwapperLimitOrderData {
+    Transaction {
+        order_id: String,
+        data: GemSwapQuoteData,
+    },
+    SignMessage {
+        order_id: String,
+        typed_data: String,
+        approval: Option<GemApprovalData>,
+    },
+}
+
+#[uniffi::remote(Enum)]
+pub enum SwapperLimitOrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+    Expired,
+    Invalid,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperLimitOrder {
+    pub provider: SwapperLimitOrderProvider,
+    pub id: String,
+    pub wallet_address: String,
+    pub from_asset: AssetId,
+    pub to_asset: AssetId,
+    pub from_value: String,
+    pub to_value: String,
+    pub filled_from_value: String,
+    pub filled_to_value: String,
+    pub status: SwapperLimitOrderStatus,
+    pub created_at: Option<u64>,
+    pub expires_at: Option<u64>,
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct GemLimitOrders {
+    inner: LimitOrders,
+}
+
+#[uniffi::export]
+impl GemLimitOrders {
+    #[uniffi::constructor]
+    pub fn new(rpc_provider: Arc<dyn AlienProvider>) -> Self {
+        Self {
+            inner: LimitOrders::new(Arc::new(AlienProviderWrapper::new(rpc_provider))),
+        }
+    }
+
+    pub fn supported_chains(&self) -> Vec<Chain> {
+        self.inner.supported_chains()
+    }
+
+    pub async fn create_order(&self, request: SwapperLimitOrderRequest) -> Result<SwapperLimitOrderData, SwapperError> {
+        self.inner.create_order(&request).await
+    }
+
+    pub async fn submit_order(&self, provider: SwapperLimitOrderProvider, chain: Chain, typed_data: String, signature: String) -> Result<String, SwapperError> {
+        self.inner.submit_order(provider, chain, &typed_data, &signature).await
+    }
+
+    pub async fn get_orders(&self, chain: Chain, wallet_address: String) -> Result<Vec<SwapperLimitOrder>, SwapperError> {
+        self.inner.get_orders(chain, &wallet_address).await
+    }
+
+    pub async fn get_order(&self, provider: SwapperLimitOrderProvider, chain: Chain, wallet_address: String, order_id: String) -> Result<SwapperLimitOrder, SwapperError> {
+        self.inner.get_order(provider, chain, &wallet_address, &order_id).await
+    }
+
+    pub async fn cancel_order(&self, order: SwapperLimitOrder) -> Result<GemSwapQuoteData, SwapperError> {
+        self.inner.cancel_order(&order).await
+    }
+
+    pub async fn get_swap_result(&self, provider: SwapperLimitOrderProvider, chain: Chain, wallet_address: String, order_id: String) -> Result<SwapperSwapResult, SwapperError> {
+        self.inner.get_swap_result(provider, chain, &wallet_address, &order_id).await
+    }
+}
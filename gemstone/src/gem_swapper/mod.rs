@@ -1,14 +1,21 @@
 mod error;
+mod limit_order;
 mod permit2;
+mod quote_listener;
+mod recurring_order;
 use error::SwapperError;
+pub use limit_order::GemLimitOrders;
 use permit2::*;
+pub use quote_listener::SwapperQuoteListener;
+pub use recurring_order::GemRecurringOrders;
 mod remote_types;
 use remote_types::*;
 type Swapper = swapper::swapper::GemSwapper;
 
 use crate::alien::{AlienProvider, AlienProviderWrapper};
+use futures::StreamExt;
 use primitives::{AssetId, Chain};
-use std::sync::Arc;
+use std::{pin::pin, sync::Arc, time::Duration};
 
 #[derive(Debug, uniffi::Object)]
 pub struct GemSwapper {
@@ -36,6 +43,10 @@ impl GemSwapper {
         self.inner.get_providers()
     }
 
+    pub fn providers_health(&self) -> Vec<SwapperProviderHealth> {
+        self.inner.providers_health()
+    }
+
     pub fn get_providers_for_request(&self, request: &SwapperQuoteRequest) -> Result<Vec<SwapperProviderType>, SwapperError> {
         self.inner.get_providers_for_request(request)
     }
@@ -48,6 +59,14 @@ impl GemSwapper {
         self.inner.get_quote_by_provider(provider, request).await
     }
 
+    pub async fn get_composed_quote(&self, request: &SwapperQuoteRequest) -> Result<SwapperComposedQuote, SwapperError> {
+        self.inner.get_composed_quote(request).await
+    }
+
+    pub async fn get_composed_swap_result(&self, quote: &SwapperComposedQuote, transaction_hashes: Vec<String>) -> Result<SwapperComposedSwapResult, SwapperError> {
+        self.inner.get_composed_swap_result(quote, &transaction_hashes).await
+    }
+
     pub async fn get_permit2_for_quote(&self, quote: &SwapperQuote) -> Result<Option<Permit2ApprovalData>, SwapperError> {
         self.inner.get_permit2_for_quote(quote).await
     }
@@ -56,7 +75,39 @@ impl GemSwapper {
         self.inner.get_quote_data(quote, data).await
     }
 
+    pub async fn submit_order(&self, quote: &SwapperQuote, typed_data: &str, signature: &str) -> Result<String, SwapperError> {
+        self.inner.submit_order(quote, typed_data, signature).await
+    }
+
     pub async fn get_swap_result(&self, chain: Chain, provider: SwapperProvider, transaction_hash: &str) -> Result<SwapperSwapResult, SwapperError> {
         self.inner.get_swap_result(chain, provider, transaction_hash).await
     }
+
+    pub async fn get_cctp_attestation_status(&self, chain: Chain, transaction_hash: &str) -> Result<SwapperCctpAttestationStatus, SwapperError> {
+        self.inner.get_cctp_attestation_status(chain, transaction_hash).await
+    }
+
+    pub async fn get_cctp_mint_data(&self, chain: Chain, transaction_hash: &str, wallet_address: &str) -> Result<GemSwapQuoteData, SwapperError> {
+        self.inner.get_cctp_mint_data(chain, transaction_hash, wallet_address).await
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl GemSwapper {
+    pub async fn get_quote_stream(&self, request: &SwapperQuoteRequest, timeout_ms: u64, listener: Arc<dyn SwapperQuoteListener>) -> Result<(), SwapperError> {
+        let mut stream = pin!(self.inner.get_quote_stream(request, Duration::from_millis(timeout_ms))?);
+        let mut quotes = Vec::new();
+        let mut errors = Vec::new();
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(quote) => {
+                    listener.on_quote(quote.clone());
+                    quotes.push(quote);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+        listener.on_complete(SwapperQuoteStreamSummary::new(request.mode, quotes, errors));
+        Ok(())
+    }
 }
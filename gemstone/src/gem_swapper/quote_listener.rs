@@ -0,0 +1,7 @@
+use super::remote_types::{SwapperQuote, SwapperQuoteStreamSummary};
+
+#[uniffi::export(with_foreign)]
+pub trait SwapperQuoteListener: Send + Sync {
+    fn on_quote(&self, quote: SwapperQuote);
+    fn on_complete(&self, summary: SwapperQuoteStreamSummary);
+}
@@ -0,0 +1,87 @@
+use super::{
+    error::SwapperError,
+    remote_types::{SwapperProvider, SwapperQuoteAsset},
+};
+use crate::{
+    alien::{AlienProvider, AlienProviderWrapper},
+    models::swap::GemSwapQuoteData,
+};
+use primitives::{AssetId, Chain, RecurringSwapFrequency, RecurringSwapStatus};
+use std::sync::Arc;
+pub use swapper::recurring_order::{RecurringOrder as SwapperRecurringOrder, RecurringOrderRequest as SwapperRecurringOrderRequest};
+
+type RecurringOrders = swapper::recurring_order::GemRecurringOrders;
+
+#[uniffi::remote(Enum)]
+pub enum RecurringSwapFrequency {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[uniffi::remote(Enum)]
+pub enum RecurringSwapStatus {
+    Active,
+    Paused,
+    Completed,
+    Cancelled,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperRecurringOrderRequest {
+    pub from_asset: SwapperQuoteAsset,
+    pub to_asset: SwapperQuoteAsset,
+    pub wallet_address: String,
+    pub amount: String,
+    pub frequency: RecurringSwapFrequency,
+    pub start_at: u64,
+    pub end_at: u64,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperRecurringOrder {
+    pub provider: SwapperProvider,
+    pub id: String,
+    pub wallet_address: String,
+    pub from_asset: AssetId,
+    pub to_asset: AssetId,
+    pub amount: String,
+    pub deposited_value: String,
+    pub used_value: String,
+    pub received_value: String,
+    pub interval_in_seconds: u64,
+    pub status: RecurringSwapStatus,
+    pub created_at: Option<u64>,
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct GemRecurringOrders {
+    inner: RecurringOrders,
+}
+
+#[uniffi::export]
+impl GemRecurringOrders {
+    #[uniffi::constructor]
+    pub fn new(rpc_provider: Arc<dyn AlienProvider>) -> Self {
+        Self {
+            inner: RecurringOrders::new(Arc::new(AlienProviderWrapper::new(rpc_provider))),
+        }
+    }
+
+    pub fn supported_chains(&self) -> Vec<Chain> {
+        self.inner.supported_chains()
+    }
+
+    pub async fn create_order(&self, request: SwapperRecurringOrderRequest) -> Result<GemSwapQuoteData, SwapperError> {
+        self.inner.create_order(&request).await
+    }
+
+    pub async fn get_orders(&self, chain: Chain, wallet_address: String) -> Result<Vec<SwapperRecurringOrder>, SwapperError> {
+        self.inner.get_orders(chain, &wallet_address).await
+    }
+
+    pub async fn cancel_order(&self, order: SwapperRecurringOrder) -> Result<GemSwapQuoteData, SwapperError> {
+        self.inner.cancel_order(&order).await
+    }
+}
@@ -1,10 +1,14 @@
+use super::error::SwapperError;
 use crate::config::swap_config::SwapReferralFees;
 use primitives::{AssetId, Chain};
 use std::str::FromStr;
 pub use swapper::{
-    AssetList as SwapperAssetList, FetchQuoteData, Options as SwapperOptions, ProviderData as SwapperProviderData, ProviderType as SwapperProviderType, Quote as SwapperQuote,
-    QuoteRequest as SwapperQuoteRequest, Route as SwapperRoute, SwapperMode, SwapperProvider, SwapperProviderMode, SwapperQuoteAsset, SwapperSlippage, SwapperSlippageMode,
-    SwapperSwapResult, SwapperSwapStatus, SwapperTransactionSwapMetadata, permit2_data::Permit2Data,
+    AssetList as SwapperAssetList, CctpAttestationStatus as SwapperCctpAttestationStatus, ComposedQuote as SwapperComposedQuote, ComposedSwapResult as SwapperComposedSwapResult,
+    FetchQuoteData, Options as SwapperOptions, ProviderData as SwapperProviderData, ProviderErrorCount as SwapperProviderErrorCount, ProviderHealth as SwapperProviderHealth,
+    ProviderHealthState as SwapperProviderHealthState, ProviderQuoteError as SwapperProviderQuoteError, ProviderType as SwapperProviderType, Quote as SwapperQuote,
+    QuoteAssetPrice as SwapperQuoteAssetPrice, QuoteFees as SwapperQuoteFees, QuotePriceImpact as SwapperQuotePriceImpact, QuoteRequest as SwapperQuoteRequest,
+    QuoteStreamSummary as SwapperQuoteStreamSummary, Route as SwapperRoute, SwapperMode, SwapperProvider, SwapperProviderMode, SwapperQuoteAsset, SwapperSlippage,
+    SwapperSlippageMode, SwapperSwapResult, SwapperSwapStatus, SwapperTransactionSwapMetadata, permit2_data::Permit2Data,
 };
 
 pub use crate::models::swap::GemSwapQuoteData;
@@ -71,6 +75,13 @@ pub struct SwapperOptions {
     pub fee: Option<SwapReferralFees>,
     pub preferred_providers: Vec<SwapperProvider>,
     pub use_max_amount: bool,
+    pub prices: Vec<SwapperQuoteAssetPrice>,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperQuoteAssetPrice {
+    pub asset_id: AssetId,
+    pub price: f64,
 }
 
 #[uniffi::remote(Record)]
@@ -105,6 +116,53 @@ pub struct SwapperQuote {
     pub data: SwapperProviderData,
     pub request: SwapperQuoteRequest,
     pub eta_in_seconds: Option<u32>,
+    pub fees: Option<SwapperQuoteFees>,
+    pub price_impact: Option<SwapperQuotePriceImpact>,
+    pub composed: Option<SwapperComposedQuote>,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperQuoteFees {
+    pub network_fee: String,
+    pub approval_fee: String,
+    pub destination_fee: String,
+    pub fee_value: Option<String>,
+    pub net_value: Option<String>,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperQuotePriceImpact {
+    pub percentage: f64,
+    pub is_high: bool,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperComposedQuote {
+    pub from_value: String,
+    pub to_value: String,
+    pub legs: Vec<SwapperQuote>,
+    pub eta_in_seconds: Option<u32>,
+    pub fee_value: Option<String>,
+    pub net_value: Option<String>,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperComposedSwapResult {
+    pub status: SwapperSwapStatus,
+    pub legs: Vec<SwapperSwapResult>,
+    pub next_leg: Option<u32>,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperProviderQuoteError {
+    pub provider: SwapperProvider,
+    pub error: SwapperError,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperQuoteStreamSummary {
+    pub quotes: Vec<SwapperQuote>,
+    pub errors: Vec<SwapperProviderQuoteError>,
 }
 
 #[uniffi::remote(Enum)]
@@ -129,6 +187,16 @@ pub enum SwapperProvider {
     Hyperliquid,
     Orca,
     Squid,
+    CowSwap,
+    UniswapV2,
+    PancakeswapV2,
+    SushiswapV2,
+    QuickswapV2,
+    CamelotV2,
+    SpookyswapV2,
+    Cctp,
+    OneInch,
+    Composed,
 }
 
 #[uniffi::remote(Enum)]
@@ -171,6 +239,36 @@ pub enum SwapperSwapStatus {
     Failed,
 }
 
+#[uniffi::remote(Enum)]
+pub enum SwapperCctpAttestationStatus {
+    PendingAttestation,
+    Attested,
+    Minted,
+}
+
+#[uniffi::remote(Enum)]
+pub enum SwapperProviderHealthState {
+    Healthy,
+    Degraded,
+    Unavailable,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperProviderErrorCount {
+    pub error: String,
+    pub count: u32,
+}
+
+#[uniffi::remote(Record)]
+pub struct SwapperProviderHealth {
+    pub provider: SwapperProvider,
+    pub state: SwapperProviderHealthState,
+    pub requests: u32,
+    pub success_rate: f64,
+    pub average_latency_ms: u64,
+    pub errors: Vec<SwapperProviderErrorCount>,
+}
+
 #[uniffi::export]
 fn swapper_provider_from_str(s: &str) -> Option<SwapperProvider> {
     SwapperProvider::from_str(s).ok()
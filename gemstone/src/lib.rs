@@ -2,6 +2,7 @@ pub mod address_formatter;
 pub mod alien;
 pub mod api_client;
 pub mod auth;
+pub mod bitcoin;
 pub mod block_explorer;
 pub mod config;
 pub mod ethereum;
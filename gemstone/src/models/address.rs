@@ -5,4 +5,5 @@ pub type GemAddressStatus = AddressStatus;
 #[uniffi::remote(Enum)]
 pub enum AddressStatus {
     MultiSignature,
+    Eip7702Delegation,
 }
@@ -16,6 +16,7 @@ pub type GemBroadcastOptions = BroadcastOptions;
 #[uniffi::remote(Record)]
 pub struct BroadcastOptions {
     pub skip_preflight: bool,
+    pub mev_protection: bool,
 }
 
 #[derive(Debug, Clone, uniffi::Enum)]
@@ -36,6 +37,7 @@ pub struct GemTransactionPreloadInput {
     pub input_type: GemTransactionInputType,
     pub sender_address: String,
     pub destination_address: String,
+    pub mev_protection: bool,
 }
 
 impl From<GasPriceType> for GemGasPriceType {
@@ -74,6 +76,7 @@ impl From<TransactionPreloadInput> for GemTransactionPreloadInput {
             input_type: input.input_type.into(),
             sender_address: input.sender_address,
             destination_address: input.destination_address,
+            mev_protection: input.mev_protection,
         }
     }
 }
@@ -84,6 +87,7 @@ impl From<GemTransactionPreloadInput> for TransactionPreloadInput {
             input_type: input.input_type.into(),
             sender_address: input.sender_address,
             destination_address: input.destination_address,
+            mev_protection: input.mev_protection,
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::models::custom_types::GemBigInt;
 use primitives::{
-    AssetId, SimulationBalanceChange, SimulationHeader, SimulationPayloadField, SimulationPayloadFieldDisplay, SimulationPayloadFieldKind, SimulationPayloadFieldType,
-    SimulationResult, SimulationSeverity, SimulationWarning, SimulationWarningApproval, SimulationWarningType,
+    AssetId, SimulationBalanceChange, SimulationEvent, SimulationEventType, SimulationHeader, SimulationPayloadField, SimulationPayloadFieldDisplay, SimulationPayloadFieldKind,
+    SimulationPayloadFieldType, SimulationResult, SimulationSeverity, SimulationWarning, SimulationWarningApproval, SimulationWarningType,
 };
 
 #[uniffi::remote(Enum)]
@@ -41,6 +41,21 @@ pub struct SimulationBalanceChange {
     pub value: String,
 }
 
+#[uniffi::remote(Enum)]
+pub enum SimulationEventType {
+    Transfer,
+    Approval,
+}
+
+#[uniffi::remote(Record)]
+pub struct SimulationEvent {
+    pub event_type: SimulationEventType,
+    pub asset_id: AssetId,
+    pub from: String,
+    pub to: String,
+    pub value: String,
+}
+
 #[uniffi::remote(Enum)]
 pub enum SimulationPayloadFieldType {
     Text,
@@ -84,6 +99,8 @@ pub struct SimulationHeader {
 pub struct SimulationResult {
     pub warnings: Vec<SimulationWarning>,
     pub balance_changes: Vec<SimulationBalanceChange>,
+    pub events: Vec<SimulationEvent>,
     pub payload: Vec<SimulationPayloadField>,
     pub header: Option<SimulationHeader>,
+    pub revert_reason: Option<String>,
 }
@@ -33,6 +33,9 @@ pub enum GemStakeChain {
     Tron,
     Aptos,
     HyperCore,
+    Cardano,
+    Ton,
+    Near,
 }
 
 #[uniffi::remote(Enum)]
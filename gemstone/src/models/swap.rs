@@ -22,6 +22,7 @@ pub struct GemApprovalData {
 pub enum GemSwapQuoteDataType {
     Contract,
     Transfer,
+    Eip712,
 }
 
 #[uniffi::remote(Record)]
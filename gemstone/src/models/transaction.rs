@@ -2,10 +2,11 @@ use crate::models::*;
 use num_bigint::BigInt;
 use primitives::contract_call_data::ContractCallData;
 use primitives::{
-    AccountDataType, Asset, EarnType, FeeOption, GasPriceType, HyperliquidOrder, PerpetualConfirmData, PerpetualDirection, PerpetualMarginType, PerpetualProvider, PerpetualType,
-    Resource, SignerInput, StakeType, TransactionChange, TransactionFee, TransactionInputType, TransactionLoadInput, TransactionLoadMetadata, TransactionMetadata,
-    TransactionPerpetualMetadata, TransactionState, TransactionStateRequest, TransactionType, TransactionUpdate, TransferDataExtra, TransferDataOutputAction,
-    TransferDataOutputType, TronStakeData, TronUnfreeze, TronVote, UInt64, WalletConnectionSessionAppMetadata,
+    AccountDataType, Asset, CardanoUTXOToken, EarnType, EvmAccessListItem, FeeOption, GasPriceType, HyperliquidOrder, PerpetualConfirmData, PerpetualDirection,
+    PerpetualMarginType, PerpetualProvider, PerpetualType, PolkadotCallIndex, Resource, SignerInput, StakeType, TonStakePoolType, TransactionChange, TransactionFee,
+    TransactionInputType, TransactionLoadInput, TransactionLoadMetadata, TransactionMetadata, TransactionPerpetualMetadata, TransactionState, TransactionStateRequest,
+    TransactionType, TransactionUpdate, TransferDataExtra, TransferDataOutputAction, TransferDataOutputType, TronStakeData, TronUnfreeze, TronVote, UInt64,
+    WalletConnectionSessionAppMetadata,
     perpetual::{CancelOrderData, PerpetualModifyConfirmData, PerpetualModifyPositionType, PerpetualReduceData, TPSLOrderData},
 };
 use std::collections::HashMap;
@@ -160,6 +161,43 @@ pub struct GemHyperliquidOrder {
     pub agent_private_key: String,
 }
 
+pub type GemCardanoUTXOToken = CardanoUTXOToken;
+
+#[uniffi::remote(Record)]
+pub struct GemCardanoUTXOToken {
+    pub transaction_id: String,
+    pub vout: i32,
+    pub token_id: String,
+    pub quantity: String,
+}
+
+pub type GemEvmAccessListItem = EvmAccessListItem;
+
+#[uniffi::remote(Record)]
+pub struct GemEvmAccessListItem {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
+
+pub type GemTonStakePoolType = TonStakePoolType;
+
+#[uniffi::remote(Enum)]
+pub enum GemTonStakePoolType {
+    Nominator,
+    Whales,
+    Liquid,
+}
+
+pub type GemPolkadotCallIndex = PolkadotCallIndex;
+
+#[uniffi::remote(Record)]
+pub struct GemPolkadotCallIndex {
+    pub pallet: String,
+    pub call: String,
+    pub pallet_index: u8,
+    pub call_index: u8,
+}
+
 pub type GemContractCallData = ContractCallData;
 
 #[uniffi::remote(Record)]
@@ -409,11 +447,13 @@ pub enum GemTransactionLoadMetadata {
         recipient_token_address: Option<String>,
         token_program: Option<GemSolanaTokenProgramId>,
         block_hash: String,
+        jito_tip_lamports: Option<u64>,
     },
     Ton {
         sender_token_address: Option<String>,
         recipient_token_address: Option<String>,
         sequence: u64,
+        stake_pool_type: Option<GemTonStakePoolType>,
     },
     Cosmos {
         account_number: u64,
@@ -429,15 +469,25 @@ pub enum GemTransactionLoadMetadata {
     },
     Cardano {
         utxos: Vec<GemUTXO>,
+        tokens: Vec<GemCardanoUTXOToken>,
+        min_fee_a: u64,
+        min_fee_b: u64,
+        coins_per_utxo_byte: u64,
+        key_deposit: u64,
+        ttl: u64,
+        is_stake_registered: bool,
+        stake_rewards: u64,
     },
     Evm {
         nonce: u64,
         chain_id: u64,
         contract_call: Option<GemContractCallData>,
+        access_list: Vec<GemEvmAccessListItem>,
     },
     Near {
         sequence: u64,
         block_hash: String,
+        is_destination_storage_registered: bool,
     },
     Stellar {
         sequence: u64,
@@ -464,6 +514,7 @@ pub enum GemTransactionLoadMetadata {
         spec_version: u64,
         transaction_version: u64,
         period: u64,
+        call_indices: Vec<GemPolkadotCallIndex>,
     },
     Tron {
         block_number: u64,
@@ -491,20 +542,24 @@ impl From<TransactionLoadMetadata> for GemTransactionLoadMetadata {
                 recipient_token_address,
                 token_program,
                 block_hash,
+                jito_tip_lamports,
             } => GemTransactionLoadMetadata::Solana {
                 sender_token_address,
                 recipient_token_address,
                 token_program,
                 block_hash,
+                jito_tip_lamports,
             },
             TransactionLoadMetadata::Ton {
                 sender_token_address,
                 recipient_token_address,
                 sequence,
+                stake_pool_type,
             } => GemTransactionLoadMetadata::Ton {
                 sender_token_address,
                 recipient_token_address,
                 sequence,
+                stake_pool_type,
             },
             TransactionLoadMetadata::Cosmos {
                 account_number,
@@ -517,9 +572,47 @@ impl From<TransactionLoadMetadata> for GemTransactionLoadMetadata {
             },
             TransactionLoadMetadata::Bitcoin { utxos } => GemTransactionLoadMetadata::Bitcoin { utxos },
             TransactionLoadMetadata::Zcash { utxos, branch_id } => GemTransactionLoadMetadata::Zcash { utxos, branch_id },
-            TransactionLoadMetadata::Cardano { utxos } => GemTransactionLoadMetadata::Cardano { utxos },
-            TransactionLoadMetadata::Evm { nonce, chain_id, contract_call } => GemTransactionLoadMetadata::Evm { nonce, chain_id, contract_call },
-            TransactionLoadMetadata::Near { sequence, block_hash } => GemTransactionLoadMetadata::Near { sequence, block_hash },
+            TransactionLoadMetadata::Cardano {
+                utxos,
+                tokens,
+                min_fee_a,
+                min_fee_b,
+                coins_per_utxo_byte,
+                key_deposit,
+                ttl,
+                is_stake_registered,
+                stake_rewards,
+            } => GemTransactionLoadMetadata::Cardano {
+                utxos,
+                tokens,
+                min_fee_a,
+                min_fee_b,
+                coins_per_utxo_byte,
+                key_deposit,
+                ttl,
+                is_stake_registered,
+                stake_rewards,
+            },
+            TransactionLoadMetadata::Evm {
+                nonce,
+                chain_id,
+                contract_call,
+                access_list,
+            } => GemTransactionLoadMetadata::Evm {
+                nonce,
+                chain_id,
+                contract_call,
+                access_list,
+            },
+            TransactionLoadMetadata::Near {
+                sequence,
+                block_hash,
+                is_destination_storage_registered,
+            } => GemTransactionLoadMetadata::Near {
+                sequence,
+                block_hash,
+                is_destination_storage_registered,
+            },
             TransactionLoadMetadata::Stellar {
                 sequence,
                 is_destination_address_exist,
@@ -538,6 +631,7 @@ impl From<TransactionLoadMetadata> for GemTransactionLoadMetadata {
                 spec_version,
                 transaction_version,
                 period,
+                call_indices,
             } => GemTransactionLoadMetadata::Polkadot {
                 sequence,
                 genesis_hash,
@@ -546,6 +640,7 @@ impl From<TransactionLoadMetadata> for GemTransactionLoadMetadata {
                 spec_version,
                 transaction_version,
                 period,
+                call_indices,
             },
             TransactionLoadMetadata::Tron {
                 block_number,
@@ -579,20 +674,24 @@ impl From<GemTransactionLoadMetadata> for TransactionLoadMetadata {
                 recipient_token_address,
                 token_program,
                 block_hash,
+                jito_tip_lamports,
             } => TransactionLoadMetadata::Solana {
                 sender_token_address,
                 recipient_token_address,
                 token_program,
                 block_hash,
+                jito_tip_lamports,
             },
             GemTransactionLoadMetadata::Ton {
                 sender_token_address,
                 recipient_token_address,
                 sequence,
+                stake_pool_type,
             } => TransactionLoadMetadata::Ton {
                 sender_token_address,
                 recipient_token_address,
                 sequence,
+                stake_pool_type,
             },
             GemTransactionLoadMetadata::Cosmos {
                 account_number,
@@ -605,9 +704,47 @@ impl From<GemTransactionLoadMetadata> for TransactionLoadMetadata {
             },
             GemTransactionLoadMetadata::Bitcoin { utxos } => TransactionLoadMetadata::Bitcoin { utxos },
             GemTransactionLoadMetadata::Zcash { utxos, branch_id } => TransactionLoadMetadata::Zcash { utxos, branch_id },
-            GemTransactionLoadMetadata::Cardano { utxos } => TransactionLoadMetadata::Cardano { utxos },
-            GemTransactionLoadMetadata::Evm { nonce, chain_id, contract_call } => TransactionLoadMetadata::Evm { nonce, chain_id, contract_call },
-            GemTransactionLoadMetadata::Near { sequence, block_hash } => TransactionLoadMetadata::Near { sequence, block_hash },
+            GemTransactionLoadMetadata::Cardano {
+                utxos,
+                tokens,
+                min_fee_a,
+                min_fee_b,
+                coins_per_utxo_byte,
+                key_deposit,
+                ttl,
+                is_stake_registered,
+                stake_rewards,
+            } => TransactionLoadMetadata::Cardano {
+                utxos,
+                tokens,
+                min_fee_a,
+                min_fee_b,
+                coins_per_utxo_byte,
+                key_deposit,
+                ttl,
+                is_stake_registered,
+                stake_rewards,
+            },
+            GemTransactionLoadMetadata::Evm {
+                nonce,
+                chain_id,
+                contract_call,
+                access_list,
+            } => TransactionLoadMetadata::Evm {
+                nonce,
+                chain_id,
+                contract_call,
+                access_list,
+            },
+            GemTransactionLoadMetadata::Near {
+                sequence,
+                block_hash,
+                is_destination_storage_registered,
+            } => TransactionLoadMetadata::Near {
+                sequence,
+                block_hash,
+                is_destination_storage_registered,
+            },
             GemTransactionLoadMetadata::Stellar {
                 sequence,
                 is_destination_address_exist,
@@ -626,6 +763,7 @@ impl From<GemTransactionLoadMetadata> for TransactionLoadMetadata {
                 spec_version,
                 transaction_version,
                 period,
+                call_indices,
             } => TransactionLoadMetadata::Polkadot {
                 sequence,
                 genesis_hash,
@@ -634,6 +772,7 @@ impl From<GemTransactionLoadMetadata> for TransactionLoadMetadata {
                 spec_version,
                 transaction_version,
                 period,
+                call_indices,
             },
             GemTransactionLoadMetadata::Tron {
                 block_number,
@@ -1,16 +1,20 @@
 use crate::{GemstoneError, models::transaction::GemSignerInput};
 use gem_algorand::AlgorandChainSigner;
 use gem_aptos::AptosChainSigner;
+use gem_bitcoin::signer::BitcoinChainSigner;
+use gem_cardano::signer::CardanoChainSigner;
 use gem_cosmos::signer::CosmosChainSigner;
 use gem_evm::signer::EvmChainSigner;
 use gem_hypercore::signer::HyperCoreSigner;
 use gem_near::NearChainSigner;
+use gem_polkadot::signer::PolkadotChainSigner;
 use gem_solana::signer::SolanaChainSigner;
 use gem_stellar::StellarChainSigner;
 use gem_sui::signer::SuiChainSigner;
 use gem_ton::signer::TonChainSigner;
 use gem_tron::TronChainSigner;
-use primitives::{Chain, ChainSigner, ChainType, EVMChain, SignerError, SignerInput};
+use gem_xrp::signer::XrpChainSigner;
+use primitives::{BitcoinChain, Chain, ChainSigner, ChainType, EVMChain, SignerError, SignerInput};
 
 #[derive(uniffi::Object)]
 pub struct GemChainSigner {
@@ -24,6 +28,7 @@ impl GemChainSigner {
     pub fn new(chain: Chain) -> Self {
         let signer: Box<dyn ChainSigner> = match chain.chain_type() {
             ChainType::Ethereum => Box::new(EvmChainSigner::new(EVMChain::from_chain(chain).unwrap())),
+            ChainType::Bitcoin => Box::new(BitcoinChainSigner::new(BitcoinChain::from_chain(chain).unwrap())),
             ChainType::Aptos => Box::new(AptosChainSigner),
             ChainType::HyperCore => Box::new(HyperCoreSigner),
             ChainType::Sui => Box::new(SuiChainSigner),
@@ -34,7 +39,9 @@ impl GemChainSigner {
             ChainType::Near => Box::new(NearChainSigner),
             ChainType::Algorand => Box::new(AlgorandChainSigner),
             ChainType::Stellar => Box::new(StellarChainSigner),
-            _ => todo!("Signer not implemented for chain {:?}", chain),
+            ChainType::Xrp => Box::new(XrpChainSigner),
+            ChainType::Polkadot => Box::new(PolkadotChainSigner),
+            ChainType::Cardano => Box::new(CardanoChainSigner),
         };
 
         Self { chain, signer }
@@ -63,7 +63,9 @@ impl WalletConnectSimulationClient {
     async fn simulate_ethereum_transaction(&self, chain: Chain, data: &str) -> Result<SimulationResult, GemstoneError> {
         let (transaction, bytes) = simulation::decode_ethereum_calldata(data).ok_or("Failed to decode transaction")?;
         let client = self.ethereum_client(chain).ok_or("No RPC client available")?;
-        Ok(SimulationClient::new(&client).simulate_evm_calldata(chain, &bytes, &transaction.to).await?)
+        Ok(SimulationClient::new(&client)
+            .simulate_evm_transaction(chain, &transaction.from, &transaction.to, transaction.value.as_deref(), &bytes)
+            .await?)
     }
 
     fn ethereum_client(&self, chain: Chain) -> Option<EthereumClient<AlienClient>> {